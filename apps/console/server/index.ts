@@ -140,7 +140,7 @@ apiRouter.get("/config", async (req, res) => {
 apiRouter.get("/issues", async (_req, res) => {
   try {
     const snapshot = await getSnapshotForRequest(_req.query.refresh);
-    res.json(snapshot.issues);
+    res.json({ issues: snapshot.issues, total: snapshot.issues.length, offset: 0 });
   } catch (error) {
     res.status(500).json({ error: (error as Error).message });
   }
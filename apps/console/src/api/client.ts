@@ -1,4 +1,10 @@
-import type { IssuesSnapshot, Issue, IssueEventsResponse } from "../types/issues";
+import type {
+  IssuesSnapshot,
+  Issue,
+  IssueComment,
+  IssueEventsResponse,
+  IssuesPage
+} from "../types/issues";
 
 export type UiControlAction =
   | { action: "clear_focus" }
@@ -37,7 +43,7 @@ export async function fetchSnapshot(apiBase: string): Promise<IssuesSnapshot> {
   }
 
   const config = (await configResponse.json()) as IssuesSnapshot["config"];
-  const issues = (await issuesResponse.json()) as IssuesSnapshot["issues"];
+  const { issues } = (await issuesResponse.json()) as IssuesPage;
   const finishedAt = Date.now();
   console.info("[snapshot] fetched", {
     durationMs: finishedAt - startedAt,
@@ -196,3 +202,14 @@ export async function fetchIssueEvents(
   }
   return (await response.json()) as IssueEventsResponse;
 }
+
+export async function fetchIssueComments(
+  apiBase: string,
+  issueId: string
+): Promise<IssueComment[]> {
+  const response = await fetch(`${apiBase}/issues/${issueId}/comments`);
+  if (!response.ok) {
+    throw new Error(`issue comments request failed: ${response.status}`);
+  }
+  return (await response.json()) as IssueComment[];
+}
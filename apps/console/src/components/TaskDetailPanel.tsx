@@ -334,6 +334,10 @@ export function TaskDetailPanel({
         return `Issue moved from ${String(payload.from_location)} to ${String(payload.to_location)}`;
       case "issue_promoted":
         return `Issue moved from ${String(payload.from_location)} to ${String(payload.to_location)}`;
+      case "issue_archived":
+        return "Issue archived";
+      case "issue_unarchived":
+        return "Issue restored from archive";
       default:
         return event.event_type;
     }
@@ -35,6 +35,7 @@ export interface ProjectConfig {
   statuses: StatusDefinition[];
   categories: CategoryDefinition[];
   type_colors: Record<string, string>;
+  label_colors?: Record<string, string>;
   beads_compatibility: boolean;
 }
 
@@ -43,6 +44,14 @@ export interface IssueComment {
   author: string;
   text: string;
   created_at: string;
+  system?: boolean;
+  resolved?: boolean;
+  reply_to?: string;
+}
+
+export interface IssueLink {
+  url: string;
+  title?: string;
 }
 
 export interface IssueDependency {
@@ -66,6 +75,7 @@ export interface Issue {
   labels?: string[];
   dependencies?: IssueDependency[];
   comments?: IssueComment[];
+  links?: IssueLink[];
   created_at?: string;
   updated_at?: string;
   closed_at?: string;
@@ -83,7 +93,9 @@ export type IssueEventType =
   | "dependency_removed"
   | "issue_deleted"
   | "issue_localized"
-  | "issue_promoted";
+  | "issue_promoted"
+  | "issue_archived"
+  | "issue_unarchived";
 
 export interface IssueEvent {
   schema_version: number;
@@ -101,6 +113,12 @@ export interface IssueEventsResponse {
   next_before?: string | null;
 }
 
+export interface IssuesPage {
+  issues: Issue[];
+  total: number;
+  offset: number;
+}
+
 export interface IssuesSnapshot {
   config: ProjectConfig;
   issues: Issue[];
@@ -213,7 +213,8 @@ async function fetchIssuesFromServer() {
   if (!response.ok) {
     throw new Error(`console issues request failed: ${response.status}`);
   }
-  return response.json();
+  const { issues } = await response.json();
+  return issues;
 }
 
 async function waitForIssueUpdate(issueId, predicate) {
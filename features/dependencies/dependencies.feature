@@ -40,7 +40,7 @@ Feature: Issue dependencies
     And a non-issue file exists in the issues directory
     When I run "kanbus dep kanbus-b blocked-by kanbus-a"
     Then the command should fail with exit code 1
-    And stderr should contain "cycle detected"
+    And stderr should contain "circular dependency detected: kanbus-b -> kanbus-a -> kanbus-b"
 
   Scenario: Ready query excludes blocked issues
     Given a Kanbus project with default configuration
@@ -117,9 +117,19 @@ Feature: Issue creation
     When I run "kanbus create Implement OAuth2 flow"
     Then the command should succeed
 
-  Scenario: Create fails when title already exists in shared issues
+  Scenario: Create warns when title already exists in shared issues
     Given a Kanbus project with default configuration
     And an issue "kanbus-aaa" exists with title "Implement OAuth2 flow"
+    When I run the CLI entrypoint with "create implement oauth2 flow"
+    Then the command should succeed
+    And stderr should contain "duplicate title"
+    And stderr should contain "kanbus-aaa"
+    And the issues directory should contain 2 issue files
+
+  Scenario: Create fails on a duplicate title when warnings are disabled
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets warn_on_duplicate_title to "false"
+    And an issue "kanbus-aaa" exists with title "Implement OAuth2 flow"
     When I run "kanbus create implement oauth2 flow"
     Then the command should fail with exit code 1
     And stderr should contain "duplicate title"
@@ -17,6 +17,7 @@ Feature: Issue key representation
       | 42                                           | project  | 42              |
       | kanbus-123e4567-e89b-12d3-a456-426614174000     | global   | kanbus-123e45      |
       | kanbus-123e4567-e89b-12d3-a456-426614174000     | project  | 123e45          |
+      | EPIC-123e4567-e89b-12d3-a456-426614174000     | global   | EPIC-123e45     |
       | kanbus-abc123.7                                | global   | kanbus-abc123.7    |
       | kanbus-abc123.7                                | project  | abc123.7        |
       | customid                                    | global   | custom          |
@@ -43,7 +43,7 @@ Feature: Local issue routing
     And an issue "kanbus-dupe01" exists
     When I run "kanbus promote kanbus-dupe01"
     Then the command should fail with exit code 1
-    And stderr should contain "already exists"
+    And stderr should contain "promote conflict: kanbus-dupe01 already exists in shared"
 
   Scenario: Localize fails when local issue already exists
     Given a Kanbus project with default configuration
@@ -51,7 +51,7 @@ Feature: Local issue routing
     And an issue "kanbus-dupe02" exists
     When I run "kanbus localize kanbus-dupe02"
     Then the command should fail with exit code 1
-    And stderr should contain "already exists"
+    And stderr should contain "localize conflict: kanbus-dupe02 already exists in project-local"
 
   Scenario: Localize fails when shared issue is missing
     Given a Kanbus project with default configuration
@@ -87,6 +87,7 @@ Feature: Local issue routing
 
   Scenario: Local create fails when title already exists in local issues
     Given a Kanbus project with default configuration
+    And the Kanbus configuration sets warn_on_duplicate_title to "false"
     And a local issue "kanbus-local01" exists
     When I run "kanbus create --local local"
     Then the command should fail with exit code 1
@@ -162,6 +162,22 @@ def given_kanbus_configuration_default_assignee(context: object, assignee: str)
     )
 
 
+@given('the Kanbus configuration sets warn_on_duplicate_title to "{value}"')
+def given_kanbus_configuration_warn_on_duplicate_title(
+    context: object, value: str
+) -> None:
+    repository = Path(context.working_directory)
+    config_path = repository / ".kanbus.yml"
+    payload = yaml.safe_load(config_path.read_text(encoding="utf-8"))
+    if payload is None:
+        payload = {}
+    payload["warn_on_duplicate_title"] = yaml.safe_load(value)
+    config_path.write_text(
+        yaml.safe_dump(payload, sort_keys=False),
+        encoding="utf-8",
+    )
+
+
 @given('a Kanbus override file sets default assignee "{assignee}"')
 def given_override_default_assignee(context: object, assignee: str) -> None:
     repository = Path(context.working_directory)
@@ -85,6 +85,13 @@ def then_issues_directory_contains_count(context: object, issue_count: int) -> N
     assert len(issues) == issue_count
 
 
+@then("the issues directory should contain {issue_count:d} issue files")
+def then_issues_directory_contains_plural_count(
+    context: object, issue_count: int
+) -> None:
+    then_issues_directory_contains_count(context, issue_count)
+
+
 @then('the created issue should have title "Implement OAuth2 flow"')
 def then_created_issue_title(context: object) -> None:
     identifier = capture_issue_identifier(context)
@@ -247,6 +247,12 @@ def create(
     except IssueCreationError as error:
         raise click.ClickException(str(error)) from error
 
+    if result.duplicate_of is not None:
+        click.echo(
+            f'Warning: duplicate title: "{result.issue.title}" already exists as '
+            f"{result.duplicate_of}",
+            err=True,
+        )
     click.echo(
         format_issue_for_display(
             result.issue,
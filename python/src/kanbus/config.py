@@ -99,4 +99,5 @@ DEFAULT_CONFIGURATION: Dict[str, Any] = {
         "event": "bright_blue",
     },
     "beads_compatibility": False,
+    "warn_on_duplicate_title": True,
 }
@@ -12,6 +12,8 @@ from pydantic import ValidationError
 from kanbus.config import DEFAULT_CONFIGURATION
 from kanbus.models import ProjectConfiguration
 
+BOOLEAN_OPTIONS = ("warn_on_duplicate_title",)
+
 
 class ConfigurationError(RuntimeError):
     """Raised when configuration validation fails."""
@@ -43,6 +45,7 @@ def load_project_configuration(path: Path) -> ProjectConfiguration:
         if isinstance(main_vp, dict) and isinstance(override_vp, dict):
             merged["virtual_projects"] = {**main_vp, **override_vp}
     _reject_legacy_fields(merged)
+    _validate_boolean_options(merged)
     _normalize_virtual_projects(merged)
 
     try:
@@ -298,5 +301,11 @@ def _reject_legacy_fields(data: dict) -> None:
         )
 
 
+def _validate_boolean_options(data: dict) -> None:
+    for key in BOOLEAN_OPTIONS:
+        if not isinstance(data.get(key), bool):
+            raise ConfigurationError(f"{key} must be true or false")
+
+
 def _has_unknown_fields(error: ValidationError) -> bool:
     return any(item.get("type") == "extra_forbidden" for item in error.errors())
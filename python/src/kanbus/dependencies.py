@@ -2,9 +2,10 @@
 
 from __future__ import annotations
 
+from collections import deque
 from dataclasses import dataclass
 from pathlib import Path
-from typing import List
+from typing import List, Optional
 
 from kanbus.issue_files import read_issue_from_file, write_issue_to_file
 from kanbus.issue_lookup import IssueLookupError, load_issue_from_project
@@ -300,9 +301,10 @@ def _has_dependency(issue: IssueData, target_id: str, dependency_type: str) -> b
 
 def _ensure_no_cycle(project_dir: Path, source_id: str, target_id: str) -> None:
     graph = _build_dependency_graph(project_dir)
-    graph.edges.setdefault(source_id, []).append(target_id)
-    if _detect_cycle(graph, source_id):
-        raise DependencyError("cycle detected")
+    path = _find_blocking_path(graph, target_id, source_id)
+    if path is not None:
+        chain = " -> ".join([source_id, *path])
+        raise DependencyError(f"circular dependency detected: {chain}")
 
 
 def _build_dependency_graph(project_dir: Path) -> DependencyGraph:
@@ -320,21 +322,24 @@ def _build_dependency_graph(project_dir: Path) -> DependencyGraph:
     return DependencyGraph(edges=edges)
 
 
-def _detect_cycle(graph: DependencyGraph, start: str) -> bool:
-    visited: set[str] = set()
-    stack: set[str] = set()
-
-    def visit(node: str) -> bool:
-        if node in stack:
-            return True
-        if node in visited:
-            return False
-        visited.add(node)
-        stack.add(node)
+def _find_blocking_path(
+    graph: DependencyGraph, start: str, goal: str
+) -> Optional[List[str]]:
+    previous: dict[str, str] = {}
+    visited = {start}
+    queue = deque([start])
+    while queue:
+        node = queue.popleft()
+        if node == goal:
+            path = [node]
+            while node in previous:
+                node = previous[node]
+                path.append(node)
+            path.reverse()
+            return path
         for neighbor in graph.edges.get(node, []):
-            if visit(neighbor):
-                return True
-        stack.remove(node)
-        return False
-
-    return visit(start)
+            if neighbor not in visited:
+                visited.add(neighbor)
+                previous[neighbor] = node
+                queue.append(neighbor)
+    return None
@@ -48,6 +48,7 @@ class IssueCreationResult:
 
     issue: IssueData
     configuration: ProjectConfiguration
+    duplicate_of: Optional[str] = None
 
 
 def create_issue(
@@ -82,7 +83,9 @@ def create_issue(
     :type description: Optional[str]
     :param local: Whether to create the issue in project-local.
     :type local: bool
-    :return: Created issue data and configuration.
+    :return: Created issue data and configuration, plus the identifier of an
+        existing issue with the same title when ``warn_on_duplicate_title``
+        let creation proceed.
     :rtype: IssueCreationResult
     :raises IssueCreationError: If validation or file operations fail.
     """
@@ -114,6 +117,7 @@ def create_issue(
         except IssueLookupError as error:
             raise IssueCreationError(str(error)) from error
 
+    duplicate_of = None
     if validate:
         valid_types = configuration.hierarchy + configuration.types
         if resolved_type not in valid_types:
@@ -136,10 +140,13 @@ def create_issue(
 
         duplicate_identifier = _find_duplicate_title(issues_dir, title)
         if duplicate_identifier is not None:
-            message = (
-                f'duplicate title: "{title}" already exists as {duplicate_identifier}'
-            )
-            raise IssueCreationError(message)
+            if not configuration.warn_on_duplicate_title:
+                message = (
+                    f'duplicate title: "{title}" already exists as '
+                    f"{duplicate_identifier}"
+                )
+                raise IssueCreationError(message)
+            duplicate_of = duplicate_identifier
 
         try:
             validate_status_value(
@@ -205,7 +212,9 @@ def create_issue(
     except Exception as error:  # noqa: BLE001
         issue_path.unlink(missing_ok=True)
         raise IssueCreationError(str(error)) from error
-    return IssueCreationResult(issue=issue, configuration=configuration)
+    return IssueCreationResult(
+        issue=issue, configuration=configuration, duplicate_of=duplicate_of
+    )
 
 
 def _find_duplicate_title(issues_dir: Path, title: str) -> Optional[str]:
@@ -53,7 +53,9 @@ def promote_issue(root: Path, identifier: str) -> IssueData:
 
     target_path = project_dir / "issues" / f"{identifier}.json"
     if target_path.exists():
-        raise IssueTransferError("already exists")
+        raise IssueTransferError(
+            f"promote conflict: {identifier} already exists in shared"
+        )
 
     issue = read_issue_from_file(local_issue_path)
     local_issue_path.replace(target_path)
@@ -99,7 +101,9 @@ def localize_issue(root: Path, identifier: str) -> IssueData:
     local_dir = ensure_project_local_directory(project_dir)
     target_path = local_dir / "issues" / f"{identifier}.json"
     if target_path.exists():
-        raise IssueTransferError("already exists")
+        raise IssueTransferError(
+            f"localize conflict: {identifier} already exists in project-local"
+        )
 
     issue = read_issue_from_file(shared_issue_path)
     shared_issue_path.replace(target_path)
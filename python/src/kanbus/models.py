@@ -172,6 +172,9 @@ class ProjectConfiguration(BaseModel):
     :type type_colors: Dict[str, str]
     :param beads_compatibility: Default Beads compatibility mode.
     :type beads_compatibility: bool
+    :param warn_on_duplicate_title: Warn instead of failing when a new issue
+        repeats an existing title.
+    :type warn_on_duplicate_title: bool
     :param jira: Optional Jira synchronization configuration.
     :type jira: Optional[JiraConfiguration]
     """
@@ -200,4 +203,5 @@ class ProjectConfiguration(BaseModel):
     categories: List[CategoryDefinition] = Field(default_factory=list)
     type_colors: Dict[str, str] = Field(default_factory=dict)
     beads_compatibility: bool = False
+    warn_on_duplicate_title: bool = True
     jira: Optional[JiraConfiguration] = None
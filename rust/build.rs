@@ -13,6 +13,18 @@ fn main() {
 
     println!("cargo:rustc-env=GIT_VERSION={git_version}");
 
+    // Embed the commit the binary was built from for `kbs version --json`.
+    let git_sha = std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    println!("cargo:rustc-env=GIT_SHA={git_sha}");
+
     // Re-run if HEAD changes (new commits or tags).
     println!("cargo:rerun-if-changed=../.git/HEAD");
     println!("cargo:rerun-if-changed=../.git/refs/tags");
@@ -0,0 +1,10 @@
+Feature: CLI entrypoint
+  As a Kanbus user
+  I want the CLI entrypoint to provide help output
+  So that I can discover available commands
+
+  Scenario: CLI entrypoint reports JSON errors with a code
+    Given a Kanbus project with default configuration
+    When I run the CLI entrypoint with "show kanbus-missing" and JSON errors enabled
+    Then the command should fail with exit code 1
+    And stderr should be a JSON error with code "not_found"
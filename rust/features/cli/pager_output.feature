@@ -0,0 +1,27 @@
+Feature: Pager output
+  As a Kanbus user
+  I want long output paged only in an interactive terminal
+  So that scripts and pipes still receive plain output
+
+  Scenario: List output is not paged when stdout is not a terminal
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with title "Paged task"
+    And the pager is set to "false"
+    When I run "kanbus list" non-interactively
+    Then the command should succeed
+    And stdout should contain "Paged task"
+
+  Scenario: Show output is not paged when stdout is not a terminal
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with title "Paged task"
+    And the pager is set to "false"
+    When I run "kanbus show kanbus-aaa" non-interactively
+    Then the command should succeed
+    And stdout should contain "Paged task"
+
+  Scenario: The no-pager flag is accepted
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with title "Paged task"
+    When I run "kanbus --no-pager list"
+    Then the command should succeed
+    And stdout should contain "Paged task"
@@ -0,0 +1,32 @@
+Feature: Quiet output
+  As a Kanbus user writing scripts
+  I want to suppress success messages
+  So that only the exit code matters
+
+  Scenario: Create with --quiet prints nothing
+    Given a Kanbus project with default configuration
+    When I run "kanbus create Quiet task --quiet"
+    Then the command should succeed
+    And stdout should be empty
+    And the issues directory should contain 1 issue file
+
+  Scenario: Update with --quiet prints nothing
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I run "kanbus --quiet update kanbus-aaa --status in_progress"
+    Then the command should succeed
+    And stdout should be empty
+    And issue "kanbus-aaa" should have status "in_progress"
+
+  Scenario: Quiet keeps JSON data output
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I run "kanbus show kanbus-aaa --json --quiet"
+    Then the command should succeed
+    And stdout should contain "\"id\": \"kanbus-aaa\""
+
+  Scenario: Quiet keeps error output
+    Given a Kanbus project with default configuration
+    When I run "kanbus update kanbus-missing --status closed --quiet"
+    Then the command should fail with exit code 1
+    And stderr should contain "not found"
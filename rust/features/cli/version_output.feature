@@ -0,0 +1,18 @@
+Feature: Version output
+  As a CI pipeline
+  I want structured version information
+  So that I can verify client and daemon compatibility before running
+
+  Scenario: Version JSON includes the daemon protocol version
+    Given a Kanbus project with default configuration
+    When I run "kanbus --version --json"
+    Then the command should succeed
+    And stdout should report the daemon protocol version
+    And stdout should contain "\"version\""
+    And stdout should contain "\"build\""
+
+  Scenario: Version subcommand emits JSON
+    Given a Kanbus project with default configuration
+    When I run "kanbus version --json"
+    Then the command should succeed
+    And stdout should report the daemon protocol version
@@ -0,0 +1,132 @@
+Feature: Issue comments
+  As a Kanbus user
+  I want to add comments to issues
+  So that important context is preserved alongside the work
+
+  Scenario: Backdated comments sort before later comments in display
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    And the current user is "dev@example.com"
+    When I run "kanbus comment kanbus-aaa \"Later comment\""
+    And I run "kanbus comment kanbus-aaa \"Earlier comment\" --at 2020-01-01T00:00:00Z"
+    And I run "kanbus show kanbus-aaa"
+    Then the command should succeed
+    And stdout should list "Earlier comment" before "Later comment"
+
+  Scenario: Comment timestamps in the future are rejected
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I run "kanbus comment kanbus-aaa \"Future note\" --at 2999-01-01T00:00:00Z"
+    Then the command should fail with exit code 1
+    And stderr should contain "comment timestamp is in the future"
+    When I run "kanbus comment kanbus-aaa \"Future note\" --at 2999-01-01T00:00:00Z --allow-future"
+    Then the command should succeed
+
+  Scenario: Comment timestamps must be RFC 3339
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I run "kanbus comment kanbus-aaa \"Note\" --at yesterday"
+    Then the command should fail with exit code 1
+    And stderr should contain "invalid timestamp: yesterday"
+
+  Scenario: Edit comment text by id prefix
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-update" exists with comment id "abc123" and text "Original"
+    When I run "kanbus comment edit kanbus-update abc Edited text"
+    Then the command should succeed
+    And issue "kanbus-update" should have comment text "Edited text"
+
+  Scenario: Resolve a comment collapses it in issue display
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with comment id "abc123-0000" and text "Please rename the helper\nand add docs"
+    When I run "kanbus comment kanbus-aaa --resolve abc123"
+    Then the command should succeed
+    And comment "abc123-0000" on issue "kanbus-aaa" should be resolved
+    When I run "kanbus show kanbus-aaa"
+    Then stdout should contain "[resolved] Please rename the helper ..."
+    And stdout should not contain "and add docs"
+
+  Scenario: Unresolve a comment restores the full text
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with comment id "abc123-0000" and text "Please rename the helper"
+    And I run "kanbus comment kanbus-aaa --resolve abc123"
+    When I run "kanbus comment kanbus-aaa --unresolve abc123"
+    Then the command should succeed
+    And comment "abc123-0000" on issue "kanbus-aaa" should not be resolved
+    When I run "kanbus show kanbus-aaa"
+    Then stdout should not contain "[resolved]"
+
+  Scenario: Replying to a reply joins the thread root
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with comment id "abc123-0000" and text "Please rename the helper"
+    And issue "kanbus-aaa" has a reply from "dev@example.com" with text "Which one?" and id "def456-0000" to comment "abc123-0000"
+    When I run "kanbus comment kanbus-aaa --reply-to def456 The parser helper"
+    Then the command should succeed
+    And issue "kanbus-aaa" should have a reply to "abc123-0000" with text "The parser helper"
+    When I run "kanbus show kanbus-aaa"
+    Then stdout should contain "    [def456] dev@example.com: Which one?"
+
+  Scenario: Resolving a reply resolves and collapses the whole thread
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with comment id "abc123-0000" and text "Please rename the helper"
+    And issue "kanbus-aaa" has a reply from "dev@example.com" with text "Done in the next push" and id "def456-0000" to comment "abc123-0000"
+    When I run "kanbus comment kanbus-aaa --resolve def456"
+    Then the command should succeed
+    And comment "abc123-0000" on issue "kanbus-aaa" should be resolved
+    And comment "def456-0000" on issue "kanbus-aaa" should be resolved
+    When I run "kanbus show kanbus-aaa"
+    Then stdout should contain "[resolved] Please rename the helper (1 reply)"
+    And stdout should not contain "Done in the next push"
+    When I run "kanbus comments --since 2026-01-10"
+    Then stdout should contain "[resolved] Please rename the helper"
+    And stdout should not contain "Done in the next push"
+
+  Scenario: Edit the last comment without its id
+    Given a Kanbus project with default configuration
+    And the current user is "dev@example.com"
+    And an issue "kanbus-aaa" exists
+    And issue "kanbus-aaa" has a comment from "dev@example.com" with text "First note" and id "c1"
+    And issue "kanbus-aaa" has a comment from "dev@example.com" with text "Secnod note" and id "c2"
+    When I run "kanbus comment kanbus-aaa --edit-last Second note"
+    Then the command should succeed
+    And issue "kanbus-aaa" has a comment from "dev@example.com" with text "Second note" and id "c2"
+    And issue "kanbus-aaa" has a comment from "dev@example.com" with text "First note" and id "c1"
+
+  Scenario: Edit last refuses when someone else commented last
+    Given a Kanbus project with default configuration
+    And the current user is "dev@example.com"
+    And an issue "kanbus-aaa" exists
+    And issue "kanbus-aaa" has a comment from "dev@example.com" with text "Mine" and id "c1"
+    And issue "kanbus-aaa" has a comment from "alice@example.com" with text "Theirs" and id "c2"
+    When I run "kanbus comment kanbus-aaa --edit-last Fixed"
+    Then the command should fail with exit code 1
+    And stderr should contain "last comment was written by alice@example.com"
+    When I run "kanbus comment kanbus-aaa --edit-last --force Fixed"
+    Then the command should succeed
+    And issue "kanbus-aaa" has a comment from "dev@example.com" with text "Fixed" and id "c1"
+    And issue "kanbus-aaa" has a comment from "alice@example.com" with text "Theirs" and id "c2"
+
+  Scenario: List recent comments across issues
+    Given a Kanbus project with default configuration
+    And issues "kanbus-aaa" and "kanbus-bbb" exist
+    And the current user is "dev@example.com"
+    When I run "kanbus comment kanbus-aaa \"Too old\" --at 2026-01-05T12:00:00Z"
+    And I run "kanbus comment kanbus-aaa \"Second note\" --at 2026-01-12T09:00:00Z"
+    And I run "kanbus comment kanbus-bbb \"First note\" --at 2026-01-11T15:30:00Z"
+    And I run "kanbus comments --since 2026-01-10"
+    Then the command should succeed
+    And stdout should contain "kanbus-bbb dev@example.com: First note"
+    And stdout should contain "kanbus-aaa dev@example.com: Second note"
+    And stdout should not contain "Too old"
+    And stdout should list "First note" before "Second note"
+
+  Scenario: Filter recent comments by author as JSON
+    Given a Kanbus project with default configuration
+    And issues "kanbus-aaa" and "kanbus-bbb" exist
+    And issue "kanbus-aaa" has a comment from "dev@example.com" with text "Mine" and id "c1"
+    And issue "kanbus-bbb" has a comment from "other@example.com" with text "Theirs" and id "c2"
+    When I run "kanbus comments --since 2026-01-10 --author dev@example.com --json"
+    Then the command should succeed
+    And stdout should contain "\"issue_id\": \"kanbus-aaa\""
+    And stdout should contain "\"text\": \"Mine\""
+    And stdout should not contain "Theirs"
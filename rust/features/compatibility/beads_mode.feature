@@ -0,0 +1,22 @@
+Feature: Beads compatibility mode
+  As a Kanbus user
+  I want to read Beads issues directly
+  So that I can evaluate Kanbus without migrating
+
+  Scenario: Beads mode reads issues from every shard
+    Given a git repository with a .beads issues database
+    And a project directory exists
+    And beads shard "issues-2.jsonl" contains issue "bdx-shard"
+    When I run "kanbus --beads show bdx-shard"
+    Then the command should succeed
+    And stdout should contain "Sharded issue"
+
+  Scenario: Beads update writes to the shard holding the issue
+    Given a git repository with a .beads issues database
+    And a project directory exists
+    And beads shard "issues-2.jsonl" contains issue "bdx-shard"
+    When I run "kanbus --beads update bdx-shard --status closed"
+    Then the command should succeed
+    And beads shard "issues-2.jsonl" should include status "closed" for "bdx-shard"
+    And beads issues.jsonl should not contain "bdx-shard"
+    And beads issues.jsonl should contain "bdx-epic"
@@ -0,0 +1,56 @@
+Feature: Configuration command
+  As a Kanbus user
+  I want to read and edit configuration values from the CLI
+  So that I cannot save a configuration Kanbus would reject
+
+  Scenario: Get prints the current value
+    Given a Kanbus project with default configuration
+    When I run "kanbus config get project_key"
+    Then the command should succeed
+    And stdout should contain "kanbus"
+
+  Scenario: Set reports the old and new value
+    Given a Kanbus project with default configuration
+    When I run "kanbus config set default_priority 1"
+    Then the command should succeed
+    And stdout should contain "Set default_priority: 2 -> 1"
+    When I run "kanbus config get default_priority"
+    Then stdout should contain "1"
+
+  Scenario: Set accepts dotted keys into maps
+    Given a Kanbus project with default configuration
+    When I run "kanbus config set label_colors.bug red"
+    Then the command should succeed
+    When I run "kanbus config get label_colors.bug"
+    Then stdout should contain "red"
+
+  Scenario: Set refuses to save an invalid configuration
+    Given a Kanbus project with default configuration
+    When I run "kanbus config set default_priority 99"
+    Then the command should fail with exit code 1
+    And stderr should contain "refusing to save invalid configuration"
+    When I run "kanbus config get default_priority"
+    Then stdout should contain "2"
+
+  Scenario: Unknown keys are rejected
+    Given a Kanbus project with default configuration
+    When I run "kanbus config set no_such_key 1"
+    Then the command should fail with exit code 1
+    And stderr should contain "unknown configuration key: no_such_key"
+
+  Scenario: Set keeps comments in the configuration file
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration file starts with the comment "# Team defaults"
+    When I run "kanbus config set default_priority 1"
+    Then the command should succeed
+    And the Kanbus configuration file should contain "# Team defaults"
+    And the Kanbus configuration file should contain "default_priority: 1"
+
+  Scenario: Set warns when it has to rewrite the configuration file
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration file starts with the comment "# Team defaults"
+    And the Kanbus configuration file writes label_colors in flow style
+    When I run the CLI entrypoint with "config set label_colors.story blue"
+    Then the command should succeed
+    And stderr should contain "rewrote the configuration file without its comments"
+    And the Kanbus configuration file should not contain "# Team defaults"
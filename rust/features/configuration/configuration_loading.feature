@@ -0,0 +1,18 @@
+Feature: Configuration loading
+  As the Kanbus system
+  I need to load and validate project configuration
+  So that all operations use consistent type, workflow, and hierarchy rules
+
+  Scenario: Report YAML syntax errors with file path and line context
+    Given a Kanbus repository with a .kanbus.yml file containing a YAML syntax error on line 3
+    When the configuration is loaded
+    Then the command should fail with exit code 1
+    And stderr should contain ".kanbus.yml:3:"
+    And stderr should contain "invalid YAML"
+    And stderr should contain "3 | hierarchy: [initiative, epic"
+
+  Scenario: Resolve status colors from the accessible palette
+    Given a Kanbus project with a configuration file
+    And the Kanbus configuration enables accessible_colors
+    When the configuration is loaded
+    Then the "open" status color should come from the accessible palette
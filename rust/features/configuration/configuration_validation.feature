@@ -0,0 +1,32 @@
+Feature: Configuration validation
+  As a Kanbus maintainer
+  I want invalid configurations in .kanbus.yml to be rejected
+  So that project rules remain consistent
+
+  Scenario: Label colors must be known color names
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets label color "urgent" to "chartreuse"
+    When I run "kanbus list"
+    Then the command should fail with exit code 1
+    And stderr should contain "unknown color 'chartreuse' for label 'urgent'"
+
+  Scenario: Type prefixes must reference known issue types
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets type prefix "story" to "STORY"
+    When I run "kanbus list"
+    Then the command should fail with exit code 1
+    And stderr should contain "type_prefixes references unknown type 'story'"
+
+  Scenario: Type prefixes must be plain identifiers
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets type prefix "epic" to "EP-IC"
+    When I run "kanbus list"
+    Then the command should fail with exit code 1
+    And stderr should contain "invalid prefix 'EP-IC' for type 'epic'"
+
+  Scenario: Priority import aliases must map to known priorities
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets priority import alias "P1" to "urgent"
+    When I run "kanbus list"
+    Then the command should fail with exit code 1
+    And stderr should contain "priority_import_aliases maps 'P1' to unknown priority 'urgent'"
@@ -0,0 +1,23 @@
+@console
+Feature: Console issue comments endpoint
+  As a console user
+  I want to load comments without the full issue body
+  So that comment threads can be fetched lazily
+
+  Scenario: Comments endpoint returns the issue comments
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-abc" exists with title "Auth bug"
+    And issue "kanbus-abc" has a comment from "dev@example.com" with text "First note" and id "c-1"
+    And the console server is running
+    When I request "/api/issues/kanbus-abc/comments" from the console server
+    Then the console response status should be 200
+    And the console response should contain "First note"
+    And the console response should contain "c-1"
+    And the console response should not contain "Auth bug"
+
+  Scenario: Comments endpoint reports unknown issues
+    Given a Kanbus project with default configuration
+    And the console server is running
+    When I request "/api/issues/kanbus-missing/comments" from the console server
+    Then the console response status should be 404
+    And the console response should contain "issue not found"
@@ -0,0 +1,40 @@
+@console
+Feature: Console issue list endpoint
+  As a console user
+  I want to filter and page the issue list
+  So that large projects stay responsive in the browser
+
+  Scenario: Issue list returns every issue by default
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    And an issue "kanbus-bbb" exists with status "closed"
+    And the console server is running
+    When I request "/api/issues" from the console server
+    Then the console response status should be 200
+    And the console response should list 2 of 2 issues
+
+  Scenario: Issue list filters by status
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    And an issue "kanbus-bbb" exists with status "closed"
+    And the console server is running
+    When I request "/api/issues?status=closed" from the console server
+    Then the console response status should be 200
+    And the console response should list 1 of 1 issues
+    And the console response should contain "kanbus-bbb"
+
+  Scenario: Issue list pages with limit and offset
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    And an issue "kanbus-bbb" exists with status "open"
+    And an issue "kanbus-ccc" exists with status "open"
+    And the console server is running
+    When I request "/api/issues?limit=2&offset=1" from the console server
+    Then the console response status should be 200
+    And the console response should list 2 of 3 issues
+
+  Scenario: Issue list rejects malformed paging parameters
+    Given a Kanbus project with default configuration
+    And the console server is running
+    When I request "/api/issues?limit=many" from the console server
+    Then the console response status should be 400
@@ -0,0 +1,29 @@
+@console
+Feature: Console issue write endpoints
+  As a console user
+  I want to create and edit issues from the browser
+  So that the console is not read-only
+
+  Scenario: Create an issue through the console API
+    Given a Kanbus project with default configuration
+    And the console server is running
+    When I send POST "/api/issues" to the console server with body "{\"title\": \"From console\", \"labels\": [\"ui\"]}"
+    Then the console response status should be 201
+    And the console response should contain "From console"
+    And 1 issue file should be stored under "project/issues"
+
+  Scenario: Update an issue through the console API
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-abc" exists with title "Auth bug"
+    And the console server is running
+    When I send PATCH "/api/issues/kanbus-abc" to the console server with body "{\"status\": \"in_progress\"}"
+    Then the console response status should be 200
+    And the console response should contain "in_progress"
+    And issue "kanbus-abc" should have status "in_progress"
+
+  Scenario: Updating an unknown issue through the console API fails
+    Given a Kanbus project with default configuration
+    And the console server is running
+    When I send PATCH "/api/issues/kanbus-missing" to the console server with body "{\"status\": \"closed\"}"
+    Then the console response status should be 404
+    And the console response should contain "not found"
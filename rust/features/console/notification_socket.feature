@@ -0,0 +1,20 @@
+Feature: Notification socket path
+
+  Scenario: Configured socket path is shared by the CLI and the console
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets notification_socket_path "shared/notify.sock"
+    When the notification socket path is resolved from the repository root and "nested/dir"
+    Then every resolved notification socket path should be "shared/notify.sock" in the repository
+
+  Scenario: Environment override wins over configuration
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets notification_socket_path "shared/notify.sock"
+    When the notification socket path is resolved from the repository root and "nested/dir" with KANBUS_NOTIFY_SOCKET set to "/tmp/kanbus-shared.sock"
+    Then every resolved notification socket path should be "/tmp/kanbus-shared.sock"
+
+  Scenario: Debounced updates to one issue are sent as a single event
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I publish updates to "status, title, status" for issue "kanbus-aaa" with notification debouncing enabled
+    Then 1 notification should be received
+    And stdout should contain "\"fields_changed\":[\"status\",\"title\"]"
@@ -0,0 +1,45 @@
+Feature: Cross-project dependencies
+  As a Kanbus user with virtual projects
+  I want issues to depend on issues in other projects
+  So that blocking work is tracked across project boundaries
+
+  Scenario: Add a dependency on a labeled issue in a virtual project
+    Given a Kanbus project with default configuration
+    And issues "kanbus-child" and "kanbus-other" exist
+    And virtual project "alpha" contains issue "alpha-task"
+    When I run "kanbus dep kanbus-child blocked-by alpha:alpha-task"
+    Then the command should succeed
+    And issue "kanbus-child" should depend on "alpha:alpha-task" with type "blocked-by"
+
+  Scenario: A plain identifier in a virtual project is stored with its label
+    Given a Kanbus project with default configuration
+    And issues "kanbus-child" and "kanbus-other" exist
+    And virtual project "alpha" contains issue "alpha-task"
+    When I run "kanbus dep kanbus-child relates-to alpha-task"
+    Then the command should succeed
+    And issue "kanbus-child" should depend on "alpha:alpha-task" with type "relates-to"
+
+  Scenario: Reject a dependency on an unconfigured project
+    Given a Kanbus project with default configuration
+    And issues "kanbus-child" and "kanbus-other" exist
+    When I run "kanbus dep kanbus-child blocked-by gamma:gamma-task"
+    Then the command should fail with exit code 1
+    And stderr should contain "unknown project: gamma"
+
+  Scenario: Reject a dependency cycle that crosses projects
+    Given a Kanbus project with default configuration
+    And issues "kanbus-child" and "kanbus-other" exist
+    And virtual project "alpha" issue "alpha-task" depends on "kanbus:kanbus-child" with type "blocked-by"
+    When I run "kanbus dep kanbus-child blocked-by alpha:alpha-task"
+    Then the command should fail with exit code 1
+    And stderr should contain "circular dependency detected: kanbus-child -> alpha:alpha-task -> kanbus-child"
+
+  Scenario: Dependency tree follows targets into virtual projects
+    Given a Kanbus project with default configuration
+    And issue "kanbus-child" depends on "alpha:alpha-task" with type "blocked-by"
+    And virtual project "alpha" issue "alpha-task" depends on "alpha-base" with type "blocked-by"
+    And virtual project "alpha" contains issue "alpha-base"
+    When I run "kanbus dep tree kanbus-child --porcelain"
+    Then the command should succeed
+    And stdout should contain the edge "kanbus-child" "blocked-by" "alpha:alpha-task"
+    And stdout should contain the edge "alpha:alpha-task" "blocked-by" "alpha:alpha-base"
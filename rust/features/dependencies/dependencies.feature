@@ -0,0 +1,23 @@
+Feature: Issue dependencies
+  As a Kanbus user
+  I want to manage dependencies between issues
+  So that blocked work is tracked and cycles are prevented
+
+  Scenario: Reject a dependency that closes a three-issue cycle
+    Given a Kanbus project with default configuration
+    And issues "kanbus-a" and "kanbus-b" exist
+    And issues "kanbus-c" exist
+    And issue "kanbus-a" depends on "kanbus-b" with type "blocked-by"
+    And issue "kanbus-b" depends on "kanbus-c" with type "blocked-by"
+    When I run "kanbus dep kanbus-c blocked-by kanbus-a"
+    Then the command should fail with exit code 1
+    And stderr should contain "circular dependency detected: kanbus-c -> kanbus-a -> kanbus-b -> kanbus-c"
+    And issue "kanbus-c" should not depend on "kanbus-a" with type "blocked-by"
+
+  Scenario: Concurrent adds cannot jointly form a cycle
+    Given a Kanbus project with default configuration
+    And issues "kanbus-a" and "kanbus-b" exist
+    When I run "kanbus dep kanbus-a blocked-by kanbus-b" and "kanbus dep kanbus-b blocked-by kanbus-a" concurrently
+    Then the command should fail with exit code 1
+    And stderr should contain "circular dependency detected"
+    And exactly one of "kanbus-a" and "kanbus-b" should be blocked by the other
@@ -0,0 +1,93 @@
+Feature: Dependency tree display
+  As a Kanbus user
+  I want to visualize dependency trees
+  So that I can understand blocked work at a glance
+
+  Scenario: JSON format output includes tree metadata
+    Given a Kanbus project with default configuration
+    And issues "kanbus-root" and "kanbus-child" exist
+    And issue "kanbus-child" depends on "kanbus-root" with type "blocked-by"
+    When I run "kanbus dep tree kanbus-child --format json"
+    Then stdout should contain "\"root_id\": \"kanbus-child\""
+    And stdout should contain "\"generated_at\""
+    And stdout should contain "\"max_depth_reached\": 1"
+    And stdout should contain "\"truncated\": false"
+    And stdout should contain "\"tree\""
+
+  Scenario: JSON format output reports depth truncation
+    Given a Kanbus project with default configuration
+    And issues "kanbus-a" and "kanbus-b" exist
+    And issues "kanbus-b" and "kanbus-c" exist
+    And issue "kanbus-b" depends on "kanbus-a" with type "blocked-by"
+    And issue "kanbus-c" depends on "kanbus-b" with type "blocked-by"
+    When I run "kanbus dep tree kanbus-c --depth 1 --format json"
+    Then stdout should contain "\"max_depth_reached\": 1"
+    And stdout should contain "\"truncated\": true"
+    And stdout should not contain "kanbus-a"
+
+  Scenario: Porcelain format lists one edge per line
+    Given a Kanbus project with default configuration
+    And issues "kanbus-a" and "kanbus-b" exist
+    And issues "kanbus-c" and "kanbus-d" exist
+    And issue "kanbus-b" depends on "kanbus-a" with type "blocked-by"
+    And issue "kanbus-c" depends on "kanbus-b" with type "blocked-by"
+    And issue "kanbus-c" has dependency "kanbus-d" of type "relates-to"
+    When I run "kanbus dep tree kanbus-c --porcelain"
+    Then the command should succeed
+    And stdout should contain the edge "kanbus-b" "blocked-by" "kanbus-a"
+    And stdout should contain the edge "kanbus-c" "blocked-by" "kanbus-b"
+    And stdout should contain the edge "kanbus-c" "relates-to" "kanbus-d"
+
+  Scenario: Text format tags each child with its relationship
+    Given a Kanbus project with default configuration
+    And issues "kanbus-a" and "kanbus-b" exist
+    And issues "kanbus-c" and "kanbus-d" exist
+    And issue "kanbus-c" depends on "kanbus-b" with type "blocked-by"
+    And issue "kanbus-c" has dependency "kanbus-d" of type "relates-to"
+    When I run "kanbus dep tree kanbus-c"
+    Then the command should succeed
+    And stdout should contain "|-- [blocks] kanbus-b"
+    And stdout should contain "`-- [relates] kanbus-d"
+
+  Scenario: DOT format distinguishes relationship types
+    Given a Kanbus project with default configuration
+    And issues "kanbus-a" and "kanbus-b" exist
+    And issues "kanbus-c" and "kanbus-d" exist
+    And issue "kanbus-c" depends on "kanbus-b" with type "blocked-by"
+    And issue "kanbus-c" has dependency "kanbus-d" of type "relates-to"
+    When I run "kanbus dep tree kanbus-c --format dot"
+    Then the command should succeed
+    And stdout should contain "\"kanbus-c\" -> \"kanbus-b\";"
+    And stdout should contain "\"kanbus-c\" -> \"kanbus-d\" [style=dashed];"
+    And stdout should contain "\"legend\" [shape=note"
+
+  Scenario: JSON format includes the dependency type on every node
+    Given a Kanbus project with default configuration
+    And issues "kanbus-root" and "kanbus-child" exist
+    And issue "kanbus-child" depends on "kanbus-root" with type "blocked-by"
+    When I run "kanbus dep tree kanbus-child --format json"
+    Then stdout should contain "\"dependency_type\": null"
+    And stdout should contain "\"dependency_type\": \"blocked-by\""
+
+  Scenario: Collapse fully closed subtrees into a summary node
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-done1" exists with status "closed"
+    And an issue "kanbus-done2" exists with status "closed"
+    And an issue "kanbus-open" exists with status "open"
+    And an issue "kanbus-top" exists with status "open"
+    And issue "kanbus-done1" has dependency "kanbus-done2" of type "blocked-by"
+    And issue "kanbus-top" has dependency "kanbus-done1" of type "blocked-by"
+    And issue "kanbus-top" has dependency "kanbus-open" of type "blocked-by"
+    When I run "kanbus dep tree kanbus-top --collapse-closed"
+    Then the command should succeed
+    And stdout should contain "|-- [blocks] 2 closed"
+    And stdout should contain "`-- [blocks] kanbus-open Title"
+    And stdout should not contain "kanbus-done1"
+    And stdout should not contain "kanbus-done2"
+
+  Scenario: Collapse closed subtrees rejects JSON output
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-top" exists with status "open"
+    When I run "kanbus dep tree kanbus-top --collapse-closed --format json"
+    Then the command should fail with exit code 1
+    And stderr should contain "--collapse-closed supports text and dot formats"
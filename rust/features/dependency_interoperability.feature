@@ -0,0 +1,15 @@
+Feature: Dependency flow interoperability
+  As a Kanbus user
+  I want dependencies to work consistently between Beads and Kanbus modes
+  So that I can use either tool interchangeably
+
+  Scenario: Beads mode rejects dependencies that close a cycle
+    Given a Kanbus project with beads compatibility enabled
+    And a kanbus issue "bdx-a" exists
+    And a kanbus issue "bdx-b" exists
+    And a kanbus issue "bdx-c" exists
+    When I run "kanbus --beads dep bdx-a blocked-by bdx-b"
+    And I run "kanbus --beads dep bdx-b blocked-by bdx-c"
+    And I run "kanbus --beads dep bdx-c blocked-by bdx-a"
+    Then the command should fail with exit code 1
+    And stderr should contain "circular dependency detected: bdx-c -> bdx-a -> bdx-b -> bdx-c"
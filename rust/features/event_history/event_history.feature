@@ -0,0 +1,25 @@
+Feature: Event history
+  As a Kanbus user
+  I want issue actions to be recorded as events
+  So that history is auditable and UI can display timelines
+
+  Scenario: History prints a chronological timeline
+    Given a Kanbus project with default configuration
+    And the current user is "dev@example.com"
+    And I run "kanbus create Fresh work"
+    And I run "kanbus update @last --status in_progress"
+    And I run "kanbus update @last --title \"Renamed work\""
+    When I run "kanbus history @last"
+    Then the command should succeed
+    And stdout should contain "dev@example.com  created \"Fresh work\""
+    And stdout should contain "status open -> in_progress"
+    And stdout should contain "updated title: Fresh work -> Renamed work"
+
+  Scenario: History emits the ordered event list as JSON
+    Given a Kanbus project with default configuration
+    And I run "kanbus create Fresh work"
+    And I run "kanbus update @last --status in_progress"
+    When I run "kanbus history @last --json"
+    Then the command should succeed
+    And stdout should contain "\"event_type\": \"issue_created\""
+    And stdout should contain "\"event_type\": \"state_transition\""
@@ -0,0 +1,42 @@
+Feature: CSV export
+
+  Scenario: Export issues as CSV
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-csv01" exists with title "Ship release"
+    When I run "kanbus export --format csv"
+    Then the command should succeed
+    And stdout should contain "id,title,type,status,priority,assignee,created_at,updated_at"
+    And stdout should contain "kanbus-csv01,Ship release,task,open,2,,"
+
+  Scenario: CSV export quotes commas and quotes
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-csv01" exists with title "Fix login"
+    And issue "kanbus-csv01" has a title with commas and quotes
+    When I run "kanbus export --format csv"
+    Then the command should succeed
+    And stdout should contain "kanbus-csv01,\"Fix \"\"login\"\", then logout\",task"
+
+  Scenario: Export respects list filters
+    Given a Kanbus project with default configuration
+    And issue "kanbus-mine" has assignee "dev@example.com"
+    And issue "kanbus-other" has assignee "ops@example.com"
+    When I run "kanbus export --format csv --assignee dev@example.com"
+    Then the command should succeed
+    And stdout should contain "kanbus-mine"
+    And stdout should not contain "kanbus-other"
+
+  Scenario: Export writes to an output file
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-csv01" exists with title "Ship release"
+    When I run "kanbus export --format json --output issues.json"
+    Then the command should succeed
+    And stdout should contain "Exported to issues.json"
+    And the export file "issues.json" should contain "\"id\": \"kanbus-csv01\""
+
+  Scenario: Export output paths are relative to the working directory
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-csv01" exists with title "Ship release"
+    And the working directory is the subdirectory "reports"
+    When I run "kanbus export --format json --output issues.json"
+    Then the command should succeed
+    And the export file "issues.json" should contain "\"id\": \"kanbus-csv01\""
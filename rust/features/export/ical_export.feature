@@ -0,0 +1,53 @@
+Feature: iCalendar export
+
+  Scenario: Export a due-dated issue as a VTODO
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-due01" exists with title "Ship release"
+    And issue "kanbus-due01" has custom field "due" set to "2026-03-01"
+    And an issue "kanbus-nodue" exists with title "Someday"
+    When I run "kanbus export --format ical"
+    Then the command should succeed
+    And stdout should contain "BEGIN:VCALENDAR"
+    And stdout should contain "BEGIN:VTODO"
+    And stdout should contain "UID:kanbus-due01@kanbus"
+    And stdout should contain "SUMMARY:Ship release"
+    And stdout should contain "DUE;VALUE=DATE:20260301"
+    And stdout should contain "END:VCALENDAR"
+    And stdout should not contain "Someday"
+
+  Scenario: Export due times without an offset from the configured time zone in UTC
+    Given a Kanbus project with default configuration
+    And a Kanbus override file sets time zone "America/New_York"
+    And an issue "kanbus-due01" exists with title "Ship release"
+    And issue "kanbus-due01" has custom field "due" set to "2026-03-01T12:00:00"
+    When I run "kanbus export --format ical"
+    Then the command should succeed
+    And stdout should contain "DUE:20260301T170000Z"
+    And stdout should not contain "TZID"
+
+  Scenario: Export folds long lines and ends every line with CRLF
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-due01" exists with title "Ship the quarterly release after the migration of every customer workspace is complete"
+    And issue "kanbus-due01" has custom field "due" set to "2026-03-01"
+    When I run "kanbus export --format ical"
+    Then the command should succeed
+    And stdout should contain "SUMMARY:Ship the quarterly release after the migration of every customer wo"
+    And stdout should contain " rkspace is complete"
+    And stdout lines should be at most 75 octets
+    And stdout should end with CRLF
+
+  Scenario: Export prefers the due_at field over custom.due
+    Given a Kanbus project with default configuration
+    And issue "kanbus-due02" is due at "2026-04-02T15:30:00Z"
+    And issue "kanbus-due02" has custom field "due" set to "2026-05-01"
+    When I run "kanbus export --format ical"
+    Then the command should succeed
+    And stdout should contain "UID:kanbus-due02@kanbus"
+    And stdout should contain "DUE:20260402T153000Z"
+    And stdout should not contain "DUE;VALUE=DATE:20260501"
+
+  Scenario: Export rejects unsupported formats
+    Given a Kanbus project with default configuration
+    When I run "kanbus export --format xml"
+    Then the command should fail with exit code 1
+    And stderr should contain "unsupported export format: xml"
@@ -0,0 +1,37 @@
+Feature: JSON export
+
+  Scenario: Export issues as JSON with nested custom fields
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-due01" exists with title "Ship release"
+    And issue "kanbus-due01" has custom field "due" set to "2026-03-01"
+    When I run "kanbus export --format json"
+    Then the command should succeed
+    And stdout should contain "\"id\": \"kanbus-due01\""
+    And stdout should contain "\"custom\": {"
+    And stdout should not contain "custom_due"
+
+  Scenario: Flatten custom fields into top-level keys
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-due01" exists with title "Ship release"
+    And issue "kanbus-due01" has custom field "due" set to "2026-03-01"
+    When I run "kanbus export --format json --flatten-custom"
+    Then the command should succeed
+    And stdout should contain "\"custom_due\": \"2026-03-01\""
+    And stdout should contain "\"title\": \"Ship release\""
+    And stdout should not contain "\"custom\": {"
+
+  Scenario: Flattened custom fields do not clobber existing keys
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-due01" exists with title "Ship release"
+    And issue "kanbus-due01" has custom field "due" set to "2026-03-01"
+    And issue "kanbus-due01" has custom field "custom_due" set to "later"
+    When I run "kanbus export --format json --flatten-custom"
+    Then the command should succeed
+    And stdout should contain "\"custom_due\": \"2026-03-01\""
+    And stdout should contain "\"custom_custom_due\": \"later\""
+
+  Scenario: Flatten custom requires JSON format
+    Given a Kanbus project with default configuration
+    When I run "kanbus export --format ical --flatten-custom"
+    Then the command should fail with exit code 1
+    And stderr should contain "--flatten-custom requires --format json"
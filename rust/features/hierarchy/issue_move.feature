@@ -0,0 +1,55 @@
+Feature: Moving issues between parents
+
+  Scenario: Move an issue under a new parent
+    Given a Kanbus project with default configuration
+    And a "epic" issue "kanbus-epic01" exists
+    And a "epic" issue "kanbus-epic02" exists
+    And a "task" issue "kanbus-task01" exists
+    And issue "kanbus-task01" has parent "kanbus-epic01"
+    When I run "kanbus move kanbus-task01 --parent kanbus-epic02"
+    Then the command should succeed
+    And stdout should contain "Moved kanbus-task01 under kanbus-epic02"
+    And issue "kanbus-task01" should have parent "kanbus-epic02"
+
+  Scenario: Move without a parent clears it
+    Given a Kanbus project with default configuration
+    And a "epic" issue "kanbus-epic01" exists
+    And a "task" issue "kanbus-task01" exists
+    And issue "kanbus-task01" has parent "kanbus-epic01"
+    When I run "kanbus move kanbus-task01"
+    Then the command should succeed
+    And stdout should contain "Cleared parent of kanbus-task01"
+    And issue "kanbus-task01" should have no parent
+
+  Scenario: Move rejects a missing parent
+    Given a Kanbus project with default configuration
+    And a "task" issue "kanbus-task01" exists
+    When I run "kanbus move kanbus-task01 --parent kanbus-missing"
+    Then the command should fail with exit code 1
+    And stderr should contain "not found"
+
+  Scenario: Move rejects an issue as its own parent
+    Given a Kanbus project with default configuration
+    And a "epic" issue "kanbus-epic01" exists
+    When I run "kanbus move kanbus-epic01 --parent kanbus-epic01"
+    Then the command should fail with exit code 1
+    And stderr should contain "cannot move kanbus-epic01 under itself"
+
+  Scenario: Move rejects a parent that would create a cycle
+    Given a Kanbus project with default configuration
+    And a "initiative" issue "kanbus-init01" exists
+    And a "epic" issue "kanbus-epic01" exists
+    And issue "kanbus-epic01" has parent "kanbus-init01"
+    When I run "kanbus move kanbus-init01 --parent kanbus-epic01"
+    Then the command should fail with exit code 1
+    And stderr should contain "kanbus-init01 is its ancestor (kanbus-epic01 -> kanbus-init01)"
+    And issue "kanbus-init01" should have no parent
+
+  Scenario: Move rejects an illegal hierarchy level
+    Given a Kanbus project with default configuration
+    And a "epic" issue "kanbus-epic01" exists
+    And a "task" issue "kanbus-task01" exists
+    When I run "kanbus move kanbus-epic01 --parent kanbus-task01"
+    Then the command should fail with exit code 1
+    And stderr should contain "invalid parent-child relationship: 'task' cannot have child 'epic'"
+    And issue "kanbus-epic01" should have no parent
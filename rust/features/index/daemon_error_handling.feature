@@ -0,0 +1,63 @@
+Feature: Daemon error handling
+  As a Kanbus maintainer
+  I want daemon failures to surface with clear errors
+  So that clients can recover safely
+
+  Scenario: Daemon client retries a short-lived connection failure
+    Given a Kanbus project with default configuration
+    And daemon mode is enabled
+    And the daemon socket does not exist
+    And the daemon client retry count is "3"
+    And the daemon connection fails 3 times then succeeds
+    When I request daemon status via the client
+    Then the daemon request should succeed
+    And the daemon response should be ok
+
+  Scenario: Daemon client gives up after the configured retries
+    Given a Kanbus project with default configuration
+    And daemon mode is enabled
+    And the daemon socket does not exist
+    And the daemon client retry count is "1"
+    And the daemon connection fails 3 times then succeeds
+    When I request daemon status via the client
+    Then the daemon request should fail with "daemon connection failed"
+
+  Scenario: Daemon client rejects an invalid retry count
+    Given a Kanbus project with default configuration
+    And daemon mode is enabled
+    And the daemon client retry count is "many"
+    When I request daemon status via the client
+    Then the daemon request should fail with "invalid KANBUS_DAEMON_RETRIES: many"
+
+  Scenario: Daemon shutdown flushes the index and removes its socket
+    Given a Kanbus project with default configuration
+    When the daemon entry point is started
+    And issue "kanbus-late" is written while the daemon is running
+    And I send a daemon shutdown request via the client
+    Then the daemon response should include status "stopping"
+    And the daemon entry point should stop
+    And the daemon socket file should not exist
+    And the daemon index cache should include "kanbus-late"
+
+  Scenario: Daemon status reports time until idle shutdown
+    Given a Kanbus project with default configuration
+    When the daemon entry point is started
+    And I request daemon status via the client
+    Then the daemon response should include status "ok"
+    And the daemon status should include "idle_shutdown_in_seconds"
+    When I send a daemon shutdown request via the client
+    Then the daemon entry point should stop
+
+  Scenario: Idle daemon shuts down and removes its socket
+    Given a Kanbus project with default configuration
+    And the daemon idle timeout is "1" seconds
+    When the daemon entry point is started
+    Then the daemon entry point should stop on its own
+    And the daemon socket file should not exist
+
+  Scenario: Daemon rejects an invalid idle timeout
+    Given a Kanbus project with default configuration
+    And the daemon idle timeout is "soon" seconds
+    When I run "kanbus daemon --root ."
+    Then the command should fail with exit code 1
+    And stderr should contain "invalid KANBUS_DAEMON_IDLE_SECS: soon"
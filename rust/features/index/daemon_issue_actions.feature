@@ -0,0 +1,47 @@
+Feature: Daemon issue actions
+  As a Kanbus integrator
+  I want to create, update, and close issues through the daemon
+  So that clients can route writes through a single process
+
+  Scenario: Daemon creates an issue
+    Given a Kanbus project with default configuration
+    When a daemon "issue.create" request with payload "{\"title\": \"From daemon\", \"labels\": [\"ipc\"]}" is handled directly
+    Then the daemon response issue should have title "From daemon"
+    And the daemon response issue should have status "open"
+    And the issues directory should contain 1 issue file
+
+  Scenario: Daemon updates an issue
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    When a daemon "issue.update" request with payload "{\"identifier\": \"kanbus-aaa\", \"status\": \"in_progress\"}" is handled directly
+    Then the daemon response issue should have id "kanbus-aaa"
+    And the daemon response issue should have status "in_progress"
+    And issue "kanbus-aaa" should have status "in_progress"
+
+  Scenario: Daemon closes an issue
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    When a daemon "issue.close" request with payload "{\"identifier\": \"kanbus-aaa\"}" is handled directly
+    Then the daemon response issue should have status "closed"
+    And issue "kanbus-aaa" should have status "closed"
+
+  Scenario: Daemon rejects issue actions with malformed payloads
+    Given a Kanbus project with default configuration
+    When a daemon "issue.update" request with payload "{\"status\": \"closed\"}" is handled directly
+    Then the daemon response should include error code "invalid_request"
+
+  Scenario: Daemon reports failed issue operations with the error code
+    Given a Kanbus project with default configuration
+    When a daemon "issue.close" request with payload "{\"identifier\": \"kanbus-missing\"}" is handled directly
+    Then the daemon response should include error code "not_found"
+
+  Scenario: Issue actions round-trip over the daemon socket and reach subscribers
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    When the daemon entry point is started
+    And I subscribe to daemon notifications
+    And I send a daemon "issue.close" request with payload "{\"identifier\": \"kanbus-aaa\"}" over the socket
+    Then the daemon response issue should have status "closed"
+    And the watch output should be "[updated] kanbus-aaa status"
+    When I send a daemon shutdown request
+    Then the daemon entry point should stop
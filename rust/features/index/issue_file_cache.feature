@@ -0,0 +1,53 @@
+Feature: Issue file cache
+  As a Kanbus user with a large project
+  I want listing to skip re-parsing unchanged issue files
+  So that commands stay fast as the project grows
+
+  Scenario: Listing records parsed issues in the cache
+    Given a Kanbus project with default configuration
+    And daemon mode is disabled
+    And an issue "kanbus-aaa" exists with status "open"
+    When I run "kanbus list"
+    Then the command should succeed
+    And the issue file cache should include "kanbus-aaa"
+
+  Scenario: Unchanged issue files are served from the cache
+    Given a Kanbus project with default configuration
+    And daemon mode is disabled
+    And an issue "kanbus-aaa" exists with status "open"
+    When I run "kanbus list"
+    And the issue file cache says issue "kanbus-aaa" is titled "Cached title"
+    And I run "kanbus list"
+    Then stdout should contain "Cached title"
+
+  Scenario: No-cache listing reads issue files from disk
+    Given a Kanbus project with default configuration
+    And daemon mode is disabled
+    And an issue "kanbus-aaa" exists with status "open"
+    When I run "kanbus list"
+    And the issue file cache says issue "kanbus-aaa" is titled "Cached title"
+    And I run "kanbus list --no-cache"
+    Then the command should succeed
+    And stdout should not contain "Cached title"
+
+  Scenario: Changed issue files are re-read
+    Given a Kanbus project with default configuration
+    And daemon mode is disabled
+    And an issue "kanbus-aaa" exists with status "open"
+    When I run "kanbus list"
+    And the issue file cache says issue "kanbus-aaa" is titled "Cached title"
+    And I run "kanbus update kanbus-aaa --title \"Fresh title\""
+    And I run "kanbus list"
+    Then stdout should contain "Fresh title"
+    And stdout should not contain "Cached title"
+
+  Scenario: Removed issue files leave the cache
+    Given a Kanbus project with default configuration
+    And daemon mode is disabled
+    And an issue "kanbus-aaa" exists with status "open"
+    And an issue "kanbus-bbb" exists with status "open"
+    When I run "kanbus list"
+    And I run "kanbus delete kanbus-bbb"
+    And I run "kanbus list"
+    Then the issue file cache should include "kanbus-aaa"
+    And the issue file cache should not include "kanbus-bbb"
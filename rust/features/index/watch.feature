@@ -0,0 +1,56 @@
+Feature: Watching issue changes
+  As a Kanbus user
+  I want a terminal stream of issue changes
+  So that agents can follow project activity without the console
+
+  Scenario: Watch prints issue updates relayed by the daemon
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    When the daemon entry point is started
+    And I subscribe to daemon notifications
+    And I run "kanbus update kanbus-aaa --status in_progress"
+    Then the watch output should be "[updated] kanbus-aaa status"
+    When I send a daemon shutdown request
+    Then the daemon entry point should stop
+
+  Scenario: Watch prints created issues with their titles
+    Given a Kanbus project with default configuration
+    When the daemon entry point is started
+    And I subscribe to daemon notifications
+    And I run "kanbus create Watched issue"
+    Then the watch output should contain "[created] kanbus-"
+    And the watch output should contain "Watched issue"
+    When I send a daemon shutdown request
+    Then the daemon entry point should stop
+
+  Scenario: Watch prints raw events as JSON
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    When the daemon entry point is started
+    And I subscribe to daemon notifications as JSON
+    And I run "kanbus delete kanbus-aaa"
+    Then the watch output should contain "\"type\":\"issue_deleted\""
+    When I send a daemon shutdown request
+    Then the daemon entry point should stop
+
+  Scenario: Daemon announces index changes when issue files change on disk
+    Given a Kanbus project with default configuration
+    When the daemon entry point is started
+    And I subscribe to daemon notifications as JSON
+    And issue "kanbus-disk01" is written while the daemon is running
+    Then the watch output should contain "\"type\":\"index.changed\""
+    And the daemon index cache should include "kanbus-disk01"
+    When I send a daemon shutdown request
+    Then the daemon entry point should stop
+
+  Scenario: Daemon rejects a notification without an event
+    Given a Kanbus project with default configuration
+    When I send a daemon request with action "notifications.publish"
+    Then the daemon response should include error code "invalid_request"
+
+  Scenario: Watch requires daemon mode
+    Given a Kanbus project with default configuration
+    And daemon mode is disabled
+    When I run "kanbus watch"
+    Then the command should fail with exit code 1
+    And stderr should contain "daemon disabled"
@@ -0,0 +1,31 @@
+Feature: Project initialization
+  As a developer starting a new project
+  I want to initialize a Kanbus project directory
+  So that I can begin tracking issues alongside my code
+
+  Scenario: Initialize with the scrum template
+    Given an empty git repository
+    When I run "kanbus init --template scrum"
+    Then the command should succeed
+    And a ".kanbus.yml" file should be created
+    And the configured hierarchy should be "initiative, epic, story, subtask"
+    And the configured statuses should be "backlog, open, in_progress, review, closed"
+
+  Scenario: Initialize with the kanban template
+    Given an empty git repository
+    When I run "kanbus init --template kanban"
+    Then the command should succeed
+    And the configured hierarchy should be "initiative, epic, task, sub-task"
+    And the configured statuses should be "open, in_progress, review, closed"
+
+  Scenario: Initialize with the bugtracker template
+    Given an empty git repository
+    When I run "kanbus init --template bugtracker"
+    Then the command should succeed
+    And the configured statuses should be "open, triaged, in_progress, resolved, closed"
+
+  Scenario: Reject an unknown template
+    Given an empty git repository
+    When I run "kanbus init --template waterfall"
+    Then the command should fail with exit code 1
+    And stderr should contain "unknown template 'waterfall' (valid templates: kanban, scrum, bugtracker)"
@@ -0,0 +1,12 @@
+Feature: Beads interoperability end-to-end
+  As a Kanbus developer
+  I want Kanbus and Beads data to interoperate
+  So that I can read and write Beads issues via Kanbus in compatibility mode
+
+  Background:
+    Given a Beads fixture repository
+
+  Scenario: Kanbus lists Beads issues as JSON
+    When I run "kanbus --beads list --json"
+    Then the command should succeed
+    And stdout should contain "\"id\": \"bdx-epic\""
@@ -0,0 +1,11 @@
+Feature: Issue ID generation
+  As a Kanbus user
+  I want issue IDs that are unique and predictable
+  So that I can reference issues reliably
+
+  Scenario: ID generation rejects a UUID already used under another prefix
+    Given a project with an existing issue "EPIC-11111111-2222-3333-4444-555555555555"
+    And the ID prefix is "kanbus"
+    And the UUID generator always returns "11111111-2222-3333-4444-555555555555"
+    When I attempt to generate an issue ID
+    Then ID generation should fail with "unable to generate unique id after 10 attempts"
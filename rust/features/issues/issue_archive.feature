@@ -0,0 +1,56 @@
+Feature: Issue archiving
+  As a Kanbus user
+  I want to move long-closed issues out of the way
+  So that day-to-day listings stay fast
+
+  Scenario: Archive moves old closed issues out of the issues directory
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-old" exists with title "Old work"
+    And issue "kanbus-old" was closed 40 days ago
+    And an issue "kanbus-recent" exists with title "Recent work"
+    And issue "kanbus-recent" was closed 2 days ago
+    And an issue "kanbus-open" exists with title "Open work"
+    When I run "kanbus archive --older-than 30"
+    Then the command should succeed
+    And stdout should contain "archived 1 issues"
+    And issue "kanbus-old" should be archived
+    And issue "kanbus-recent" should not be archived
+    And issue "kanbus-open" should not be archived
+
+  Scenario: Default listing omits archived issues
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-old" exists with title "Old work"
+    And issue "kanbus-old" was closed 40 days ago
+    And an issue "kanbus-open" exists with title "Open work"
+    And I run "kanbus archive --older-than 30"
+    When I run "kanbus list"
+    Then the command should succeed
+    And stdout should contain "Open work"
+    And stdout should not contain "Old work"
+
+  Scenario: Listing with --include-archived shows archived issues
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-old" exists with title "Old work"
+    And issue "kanbus-old" was closed 40 days ago
+    And an issue "kanbus-open" exists with title "Open work"
+    And I run "kanbus archive --older-than 30"
+    When I run "kanbus list --include-archived"
+    Then the command should succeed
+    And stdout should contain "Open work"
+    And stdout should contain "Old work"
+
+  Scenario: Unarchive restores an archived issue
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-old" exists with title "Old work"
+    And issue "kanbus-old" was closed 40 days ago
+    And I run "kanbus archive --older-than 30"
+    When I run "kanbus unarchive kanbus-old"
+    Then the command should succeed
+    And issue "kanbus-old" should not be archived
+
+  Scenario: Unarchive rejects issues that are not archived
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-open" exists with title "Open work"
+    When I run "kanbus unarchive kanbus-open"
+    Then the command should fail with exit code 1
+    And stderr should contain "issue is not archived"
@@ -0,0 +1,47 @@
+Feature: Issue assignment
+  As a Kanbus user
+  I want short commands to assign and unassign issues
+  So that I do not need the full update syntax for the most common change
+
+  Scenario: Assign an issue
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    When I run "kanbus assign kanbus-aaa bob@example.com"
+    Then the command should succeed
+    And stdout should contain "Assigned kanbus-aaa to bob@example.com"
+    And issue "kanbus-aaa" should have assignee "bob@example.com"
+
+  Scenario: Assign replaces an existing assignee
+    Given a Kanbus project with default configuration
+    And issue "kanbus-aaa" has assignee "alice@example.com"
+    When I run "kanbus assign kanbus-aaa bob@example.com"
+    Then the command should succeed
+    And issue "kanbus-aaa" should have assignee "bob@example.com"
+
+  Scenario: Assign rejects an empty assignee
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    When I run "kanbus assign kanbus-aaa \" \""
+    Then the command should fail with exit code 1
+    And stderr should contain "assignee must not be empty"
+
+  Scenario: Unassign clears the assignee
+    Given a Kanbus project with default configuration
+    And issue "kanbus-aaa" has assignee "alice@example.com"
+    When I run "kanbus unassign kanbus-aaa"
+    Then the command should succeed
+    And stdout should contain "Unassigned kanbus-aaa"
+    And issue "kanbus-aaa" should have no assignee
+
+  Scenario: Unassign an issue without an assignee
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    When I run "kanbus unassign kanbus-aaa"
+    Then the command should fail with exit code 1
+    And stderr should contain "no updates requested"
+
+  Scenario: Unassign a missing issue
+    Given a Kanbus project with default configuration
+    When I run "kanbus unassign kanbus-missing"
+    Then the command should fail with exit code 1
+    And stderr should contain "not found"
@@ -0,0 +1,28 @@
+Feature: Issue close and delete
+
+  Scenario: Close several issues at once
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    And an issue "kanbus-bbb" exists with status "open"
+    When I run "kanbus close kanbus-aaa kanbus-bbb"
+    Then the command should succeed
+    And stdout should contain the line "Closed kanbus-aaa"
+    And stdout should contain the line "Closed kanbus-bbb"
+    And issue "kanbus-aaa" should have status "closed"
+    And issue "kanbus-bbb" should have status "closed"
+
+  Scenario: Reopen a closed issue
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "closed"
+    When I run "kanbus reopen kanbus-aaa"
+    Then the command should succeed
+    And stdout should contain "Reopened kanbus-aaa"
+    And issue "kanbus-aaa" should have status "open"
+    And issue "kanbus-aaa" should have no closed_at timestamp
+
+  Scenario: Reopen fails for an issue that is not closed
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "in_progress"
+    When I run "kanbus reopen kanbus-aaa"
+    Then the command should fail with exit code 1
+    And stderr should contain "issue is not closed (status: in_progress)"
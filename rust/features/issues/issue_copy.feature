@@ -0,0 +1,27 @@
+Feature: Issue copy
+
+  Scenario: Copy an issue with a new id and reset status
+    Given a Kanbus project with default configuration
+    And the current user is "dev@example.com"
+    And an in-progress issue "kanbus-aaa" with label "chore" and a comment exists
+    When I run "kanbus copy kanbus-aaa"
+    Then the command should succeed
+    And a copy of "kanbus-aaa" should exist with a new id
+    And the copy of "kanbus-aaa" should have status "open"
+    And the copy of "kanbus-aaa" should have labels "chore"
+    And the copy of "kanbus-aaa" should have no comments
+    And the copy of "kanbus-aaa" should have creator "dev@example.com"
+
+  Scenario: Copy an issue with a title override and a link
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I run "kanbus copy kanbus-aaa --title Recurring chore --link"
+    Then the command should succeed
+    And stdout should contain "Recurring chore"
+    And the copy of "kanbus-aaa" should relate to "kanbus-aaa"
+
+  Scenario: Copy missing issue fails
+    Given a Kanbus project with default configuration
+    When I run "kanbus copy kanbus-missing"
+    Then the command should fail with exit code 1
+    And stderr should contain "not found"
@@ -0,0 +1,135 @@
+Feature: Issue creation
+
+  Scenario: Create an issue with a start status
+    Given a Kanbus project with default configuration
+    When I run "kanbus create Retroactive work --start-status in_progress"
+    Then the command should succeed
+    And the created issue should have status "in_progress"
+
+  Scenario: Create rejects an unknown start status
+    Given a Kanbus project with default configuration
+    When I run "kanbus create Retroactive work --start-status nonexistent"
+    Then the command should fail with exit code 1
+    And stderr should contain "unknown status"
+
+  Scenario: Create inherits the parent priority
+    Given a Kanbus project with default configuration
+    And an "epic" issue "kanbus-epic01" exists
+    When I run "kanbus update kanbus-epic01 --priority 0"
+    And I run "kanbus create Subtask --parent kanbus-epic01 --priority-from-parent"
+    Then the command should succeed
+    And the created issue should have priority 0
+
+  Scenario: Explicit priority wins over the parent priority
+    Given a Kanbus project with default configuration
+    And an "epic" issue "kanbus-epic01" exists
+    When I run "kanbus update kanbus-epic01 --priority 0"
+    And I run "kanbus create Subtask --parent kanbus-epic01 --priority-from-parent --priority 3"
+    Then the command should succeed
+    And the created issue should have priority 3
+
+  Scenario: Create rejects --priority-from-parent without a parent
+    Given a Kanbus project with default configuration
+    When I run "kanbus create Orphan --priority-from-parent"
+    Then the command should fail with exit code 1
+    And stderr should contain "--parent"
+
+  Scenario: Create accepts descriptions within max_description_length
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets max_description_length 10
+    When I run "kanbus create Short note --description \"tiny\""
+    Then the command should succeed
+
+  Scenario: Create rejects descriptions over max_description_length
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets max_description_length 10
+    When I run "kanbus create Long note --description \"far too long text\""
+    Then the command should fail with exit code 1
+    And stderr should contain "description is 17 characters; max_description_length is 10"
+
+  Scenario: Create bypasses max_description_length with --no-validate
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets max_description_length 10
+    When I run "kanbus create Long note --description \"far too long text\" --no-validate"
+    Then the command should succeed
+
+  Scenario: Create warns about a duplicate title when duplicates are allowed
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets warn_on_duplicate_title to "false"
+    And an issue "kanbus-aaa" exists with title "Implement OAuth2 flow"
+    When I run the CLI entrypoint with "create implement oauth2 flow --allow-duplicate-title"
+    Then the command should succeed
+    And stderr should contain "Warning: duplicate title"
+    And stderr should contain "kanbus-aaa"
+    And the issues directory should contain 2 issue files
+
+  Scenario: Create ignores closed issues with the same title
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets warn_on_duplicate_title to "false"
+    And an issue "kanbus-aaa" exists with title "Implement OAuth2 flow"
+    And issue "kanbus-aaa" has status "closed"
+    When I run "kanbus create implement oauth2 flow"
+    Then the command should succeed
+    And the issues directory should contain 2 issue files
+
+  Scenario: Created issues get increasing sequence numbers
+    Given a Kanbus project with default configuration
+    When I run "kanbus create First task"
+    Then the created issue should have sequence number 1
+    When I run "kanbus create Second task"
+    Then the created issue should have sequence number 2
+    When I run "kanbus list --sort seq"
+    Then the command should succeed
+    And stdout should list "First task" before "Second task"
+
+  Scenario: Create composes the description in the editor
+    Given a Kanbus project with default configuration
+    And the editor replaces the issue text with "# Edited title\n\nBody from the editor\nSecond line"
+    When I run "kanbus create Draft --edit"
+    Then the command should succeed
+    And stdout should contain "Edited title"
+    And the created issue should have description "Body from the editor\nSecond line"
+
+  Scenario: Create aborts when the editor leaves the text unchanged
+    Given a Kanbus project with default configuration
+    And the editor leaves the issue text unchanged
+    When I run "kanbus create Draft --edit"
+    Then the command should fail with exit code 1
+    And stderr should contain "issue text unchanged"
+    And the issues directory should contain 0 issue files
+
+  Scenario: Create aborts when the editor fails
+    Given a Kanbus project with default configuration
+    And the editor exits with status 1
+    When I run "kanbus create Draft --edit"
+    Then the command should fail with exit code 1
+    And stderr should contain "editor exited"
+    And the issues directory should contain 0 issue files
+
+  Scenario: Create --edit requires a terminal
+    Given a Kanbus project with default configuration
+    When I run "kanbus create Draft --edit" non-interactively
+    Then the command should fail with exit code 1
+    And stderr should contain "--edit requires an interactive terminal"
+
+  Scenario: Create an issue with a due date
+    Given a Kanbus project with default configuration
+    When I run "kanbus create Ship release --due 2026-03-01T17:00:00Z"
+    Then the command should succeed
+    And stdout should contain "Due: 2026-03-01 17:00 UTC"
+    And the created issue should be due at "2026-03-01T17:00:00Z"
+
+  Scenario: Create resolves a due date in the configured time zone
+    Given a Kanbus project with default configuration
+    And a Kanbus override file sets time zone "America/New_York"
+    When I run "kanbus create Ship release --due 2026-03-01"
+    Then the command should succeed
+    And stdout should contain "Due: 2026-03-01 00:00 EST"
+    And the created issue should be due at "2026-03-01T05:00:00Z"
+
+  Scenario: Create rejects an invalid due date
+    Given a Kanbus project with default configuration
+    When I run "kanbus create Ship release --due tomorrow"
+    Then the command should fail with exit code 1
+    And stderr should contain "invalid due date: tomorrow"
+    And the issues directory should contain 0 issue files
@@ -0,0 +1,38 @@
+Feature: Issue file format
+
+  Scenario: YAML issue files are read alongside JSON issues
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    And a YAML issue "kanbus-yaml01" titled "Written in YAML" exists
+    When I run "kanbus list"
+    Then the command should succeed
+    And stdout should contain "Written in YAML"
+    And stdout should contain "kanbus-aaa"
+    When I run "kanbus show kanbus-yaml01"
+    Then the command should succeed
+    And stdout should contain "Written in YAML"
+
+  Scenario: Updating a YAML issue keeps its format
+    Given a Kanbus project with default configuration
+    And a YAML issue "kanbus-yaml01" titled "Written in YAML" exists
+    When I run "kanbus update kanbus-yaml01 --status in_progress"
+    Then the command should succeed
+    And issue "kanbus-yaml01" should be stored at "issues/kanbus-yaml01.yml"
+    And 0 issue files should be stored under "issues"
+
+  Scenario: YAML issue format writes new issues as YAML
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets issue_format "yaml"
+    When I run "kanbus create Yaml task"
+    Then the command should succeed
+    And 1 YAML issue file should be stored under "issues"
+    And 0 issue files should be stored under "issues"
+    When I run "kanbus list"
+    Then stdout should contain "Yaml task"
+
+  Scenario: Unknown issue formats are rejected
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets issue_format "toml"
+    When I run "kanbus list"
+    Then the command should fail with exit code 1
+    And stderr should contain "issue_format must be one of: json, yaml"
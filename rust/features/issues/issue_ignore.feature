@@ -0,0 +1,45 @@
+Feature: Ignoring issue files
+
+  Scenario: Issue files are listed without an ignore file
+    Given a Kanbus project with default configuration
+    And an issue file "template-bug.json" exists with title "Template bug"
+    When I run "kanbus list"
+    Then the command should succeed
+    And stdout should contain "Template bug"
+
+  Scenario: List skips files matching an ignore pattern
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    And an issue file "template-bug.json" exists with title "Template bug"
+    And the project ignore file contains "issues/template-*.json"
+    When I run "kanbus list"
+    Then the command should succeed
+    And stdout should contain "Title"
+    And stdout should not contain "Template bug"
+
+  Scenario: Show cannot find an ignored issue file
+    Given a Kanbus project with default configuration
+    And an issue file "template-bug.json" exists with title "Template bug"
+    And the project ignore file contains "issues/template-*.json"
+    When I run "kanbus show template-bug"
+    Then the command should fail with exit code 1
+    And stderr should contain "not found"
+
+  Scenario: Ignore patterns can exclude a whole directory
+    Given a Kanbus project with default configuration
+    And an issue file "scratch/kanbus-draft.json" exists with title "Scratch draft"
+    And the project ignore file contains "# scratch work"
+    And the project ignore file contains "issues/scratch/"
+    When I run "kanbus list"
+    Then the command should succeed
+    And stdout should not contain "Scratch draft"
+
+  Scenario: Doctor warns about ignore patterns that match nothing
+    Given a Kanbus project with default configuration
+    And an issue file "template-bug.json" exists with title "Template bug"
+    And the project ignore file contains "issues/template-*.json"
+    And the project ignore file contains "issues/draft-*.json"
+    When I run "kanbus doctor"
+    Then the command should succeed
+    And stdout should contain "warning: .kanbusignore pattern 'issues/draft-*.json' matches no files"
+    And stdout should not contain "pattern 'issues/template-*.json'"
@@ -0,0 +1,54 @@
+Feature: Issue file layout
+
+  Scenario: Flat layout writes issues directly under the issues directory
+    Given a Kanbus project with default configuration
+    When I run "kanbus create Flat task"
+    Then the command should succeed
+    And 1 issue file should be stored under "issues"
+
+  Scenario: By-type layout writes issues into a directory per type
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets issue_layout "by-type"
+    When I run "kanbus create Crash on launch --type bug"
+    Then the command should succeed
+    And 1 issue file should be stored under "issues/bug"
+    When I run "kanbus list"
+    Then stdout should contain "Crash on launch"
+
+  Scenario: By-parent layout writes issues into a directory per parent
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets issue_layout "by-parent"
+    And an "epic" issue "kanbus-epic01" exists
+    When I run "kanbus create Child work --parent kanbus-epic01"
+    Then the command should succeed
+    And 1 issue file should be stored under "issues/kanbus-epic01"
+    When I run "kanbus list"
+    Then stdout should contain "Child work"
+
+  Scenario: Nested issues are found regardless of layout
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-nested" is stored under "issues/task"
+    When I run "kanbus update kanbus-nested --status in_progress"
+    Then the command should succeed
+    And issue "kanbus-nested" should be stored at "issues/task/kanbus-nested.json"
+    When I run "kanbus show kanbus-nested"
+    Then the command should succeed
+    And stdout should contain "in_progress"
+
+  Scenario: Reorganize moves existing issues to the configured layout
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    And an "epic" issue "kanbus-epic01" exists
+    And the Kanbus configuration sets issue_layout "by-type"
+    When I run "kanbus reorganize"
+    Then the command should succeed
+    And stdout should contain "reorganized 2 issues"
+    And issue "kanbus-aaa" should be stored at "issues/task/kanbus-aaa.json"
+    And issue "kanbus-epic01" should be stored at "issues/epic/kanbus-epic01.json"
+
+  Scenario: Unknown issue layouts are rejected
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets issue_layout "by-color"
+    When I run "kanbus list"
+    Then the command should fail with exit code 1
+    And stderr should contain "issue_layout must be one of: flat, by-type, by-parent"
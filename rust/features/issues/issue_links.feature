@@ -0,0 +1,53 @@
+Feature: Issue external links
+  As a Kanbus user
+  I want to attach external URLs to issues
+  So that pull requests and documents stay connected to the work
+
+  Scenario: Add a link and list it
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I run "kanbus link add kanbus-aaa https://example.com/pull/7 --title \"Fix PR\""
+    Then the command should succeed
+    When I run "kanbus link list kanbus-aaa"
+    Then the command should succeed
+    And stdout should contain "Fix PR <https://example.com/pull/7>"
+
+  Scenario: Show renders links
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I run "kanbus link add kanbus-aaa https://example.com/spec"
+    And I run "kanbus show kanbus-aaa"
+    Then the command should succeed
+    And stdout should contain "Links:"
+    And stdout should contain "https://example.com/spec"
+
+  Scenario: Console snapshot exposes links
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I run "kanbus link add kanbus-aaa https://example.com/spec"
+    And I run "kanbus console snapshot"
+    Then the command should succeed
+    And stdout should contain "https://example.com/spec"
+
+  Scenario: Remove a link
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I run "kanbus link add kanbus-aaa https://example.com/spec"
+    And I run "kanbus link remove kanbus-aaa https://example.com/spec"
+    Then the command should succeed
+    When I run "kanbus link list kanbus-aaa"
+    Then stdout should not contain "https://example.com/spec"
+
+  Scenario: Removing an unknown link fails
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I run "kanbus link remove kanbus-aaa https://example.com/spec"
+    Then the command should fail with exit code 1
+    And stderr should contain "link not found: https://example.com/spec"
+
+  Scenario: Malformed link URLs are rejected
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I run "kanbus link add kanbus-aaa example.com"
+    Then the command should fail with exit code 1
+    And stderr should contain "invalid link url: example.com"
@@ -0,0 +1,37 @@
+Feature: Issue reassignment
+  As a Kanbus user
+  I want to move one person's open work to someone else in one pass
+  So that nothing is left behind when assignments change
+
+  Scenario: Reassign open issues from one user to another
+    Given a Kanbus project with default configuration
+    And issue "kanbus-alpha1" has assignee "alice@example.com"
+    And issue "kanbus-bravo1" has assignee "alice@example.com" and status "in_progress"
+    And issue "kanbus-charl1" has assignee "alice@example.com" and status "closed"
+    And issue "kanbus-delta1" has assignee "carol@example.com"
+    When I run "kanbus reassign --from alice@example.com --to bob@example.com"
+    Then the command should succeed
+    And stdout should contain "reassigned 2 issues"
+    And issue "kanbus-alpha1" should have assignee "bob@example.com"
+    And issue "kanbus-bravo1" should have assignee "bob@example.com"
+    And issue "kanbus-charl1" should have assignee "alice@example.com"
+    And issue "kanbus-delta1" should have assignee "carol@example.com"
+
+  Scenario: Dry run reports issues without reassigning them
+    Given a Kanbus project with default configuration
+    And issue "kanbus-alpha1" has assignee "alice@example.com"
+    When I run "kanbus reassign --from alice@example.com --to bob@example.com --dry-run"
+    Then the command should succeed
+    And stdout should contain "would reassign 1 issues"
+    And issue "kanbus-alpha1" should have assignee "alice@example.com"
+
+  Scenario: Issues in a terminal status are not reassigned
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration adds status "shipped" in category "Done"
+    And issue "kanbus-alpha1" has assignee "alice@example.com"
+    And issue "kanbus-bravo1" has assignee "alice@example.com" and status "shipped"
+    When I run "kanbus reassign --from alice@example.com --to bob@example.com"
+    Then the command should succeed
+    And stdout should contain "reassigned 1 issues"
+    And issue "kanbus-alpha1" should have assignee "bob@example.com"
+    And issue "kanbus-bravo1" should have assignee "alice@example.com"
@@ -0,0 +1,26 @@
+Feature: Issue file serialization
+  As a team working across platforms
+  I want issue files written in one canonical form
+  So that unchanged issues never produce noisy diffs
+
+  Scenario: Re-serializing an unchanged issue is byte-identical
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I serialize issue "kanbus-aaa" twice
+    Then the serialized outputs should be byte-identical
+    And the serialized output should end with a single newline
+
+  Scenario: Serialization normalizes Windows line endings
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    And issue "kanbus-aaa" has a description with Windows line endings
+    When I serialize issue "kanbus-aaa" twice
+    Then the serialized outputs should be byte-identical
+    And the serialized output should use LF line endings
+
+  Scenario: Updated issue files end with a newline
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    When I run "kanbus update kanbus-aaa --title \"New title\""
+    Then the command should succeed
+    And the issue file for "kanbus-aaa" should end with a single newline
@@ -0,0 +1,103 @@
+Feature: Issue display
+
+  Scenario: Show issue as Markdown
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with title "Implement OAuth2 flow"
+    And issue "kanbus-aaa" has description "Detailed description"
+    And issue "kanbus-aaa" has a comment from "dev@example.com" with text "Looks good" and id "c1"
+    When I run "kanbus show kanbus-aaa --markdown"
+    Then the command should succeed
+    And stdout should contain the line "# kanbus-aaa: Implement OAuth2 flow"
+    And stdout should contain the line "| Field | Value |"
+    And stdout should contain the line "| Status | open |"
+    And stdout should contain the line "| Type | task |"
+    And stdout should contain the line "## Description"
+    And stdout should contain the line "## Comments"
+    And stdout should contain "Looks good"
+
+  Scenario: Format issue display colors labels from configuration
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets label color "urgent" to "red"
+    And an issue "kanbus-labels" exists
+    And issue "kanbus-labels" has labels "auth, urgent"
+    When I format issue "kanbus-labels" for display with color enabled
+    Then the formatted output should show label "urgent" with ANSI code "31"
+    And the formatted output should contain text "auth"
+
+  Scenario: Show the most recently created issue with @last
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with title "Older work"
+    And I run "kanbus create Fresh work"
+    When I run "kanbus show @last"
+    Then the command should succeed
+    And stdout should contain "Fresh work"
+    And stdout should not contain "Older work"
+
+  Scenario: Comment on the most recently updated issue with @last
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with title "Older work"
+    And I run "kanbus create Fresh work"
+    And I run "kanbus comment @last \"Progress note\""
+    When I run "kanbus show @last"
+    Then the command should succeed
+    And stdout should contain "Fresh work"
+    And stdout should contain "Progress note"
+
+  Scenario: @last in comment text is kept verbatim
+    Given a Kanbus project with default configuration
+    And I run "kanbus create Fresh work"
+    And I run "kanbus comment @last @last"
+    When I run "kanbus show @last"
+    Then the command should succeed
+    And stdout should contain ": @last"
+
+  Scenario: Resolving @last in an empty project fails
+    Given a Kanbus project with default configuration
+    When I run "kanbus show @last"
+    Then the command should fail with exit code 1
+    And stderr should contain "no issues to resolve @last"
+
+  Scenario: Show marks open blockers differently from closed ones
+    Given a Kanbus project with default configuration
+    And issue "kanbus-open01" has status "open"
+    And issue "kanbus-done01" has status "closed"
+    And issue "kanbus-aaa" depends on "kanbus-open01" with type "blocked-by"
+    And issue "kanbus-aaa" has dependency "kanbus-done01" of type "blocked-by"
+    When I run "kanbus show kanbus-aaa"
+    Then the command should succeed
+    And stdout should contain the line "  blocked-by: kanbus-open01 [open] Title (still open)"
+    And stdout should contain the line "  blocked-by: kanbus-done01 [closed] Title"
+
+  Scenario: Show lists issues blocked by the shown issue
+    Given a Kanbus project with default configuration
+    And issue "kanbus-open01" has status "open"
+    And issue "kanbus-aaa" depends on "kanbus-open01" with type "blocked-by"
+    And issue "kanbus-bbb" depends on "kanbus-open01" with type "relates-to"
+    When I run "kanbus show kanbus-open01"
+    Then the command should succeed
+    And stdout should contain "Blocks:"
+    And stdout should contain the line "  kanbus-aaa [open] Title"
+    And stdout should not contain "kanbus-bbb"
+
+  Scenario: Show omits the blocks section when nothing is blocked
+    Given a Kanbus project with default configuration
+    And issue "kanbus-open01" has status "open"
+    When I run "kanbus show kanbus-open01"
+    Then the command should succeed
+    And stdout should not contain "Blocks:"
+
+  Scenario: Show json includes the computed blocks array
+    Given a Kanbus project with default configuration
+    And issue "kanbus-open01" has status "open"
+    And issue "kanbus-aaa" depends on "kanbus-open01" with type "blocked-by"
+    When I run "kanbus show kanbus-open01 --json"
+    Then the command should succeed
+    And stdout should contain "\"blocks\": ["
+    And stdout should contain "\"kanbus-aaa\""
+
+  Scenario: Show json reports an empty blocks array for unblocking issues
+    Given a Kanbus project with default configuration
+    And issue "kanbus-open01" has status "open"
+    When I run "kanbus show kanbus-open01 --json"
+    Then the command should succeed
+    And stdout should contain "\"blocks\": []"
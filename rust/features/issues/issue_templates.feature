@@ -0,0 +1,46 @@
+Feature: Issue templates
+  As a Kanbus user
+  I want to save reusable issue skeletons
+  So that recurring issues start with the same type, priority, labels, and description
+
+  Scenario: Save and list templates
+    Given a Kanbus project with default configuration
+    When I run "kanbus template save bug-report --type bug --priority 1 --label ui"
+    Then the command should succeed
+    And stdout should contain "saved template bug-report"
+    When I run "kanbus template save chore --type task"
+    And I run "kanbus template list"
+    Then the command should succeed
+    And stdout should contain the line "bug-report"
+    And stdout should contain the line "chore"
+
+  Scenario: Apply a template with a title override
+    Given a Kanbus project with default configuration
+    When I run "kanbus template save bug-report --type bug --priority 1 --label ui --description Steps to reproduce"
+    And I run "kanbus template apply bug-report Crash on save"
+    Then the command should succeed
+    And stdout should contain "Title: Crash on save"
+    And stdout should contain "Type: bug"
+    And stdout should contain "Priority: 1"
+    And stdout should contain "Labels: ui"
+    And stdout should contain "Steps to reproduce"
+
+  Scenario: Apply uses the template title when none is given
+    Given a Kanbus project with default configuration
+    When I run "kanbus template save weekly --type task --title Weekly review"
+    And I run "kanbus template apply weekly"
+    Then the command should succeed
+    And stdout should contain "Title: Weekly review"
+
+  Scenario: Applying a template with an unknown issue type fails
+    Given a Kanbus project with default configuration
+    When I run "kanbus template save odd --type widget"
+    And I run "kanbus template apply odd Something"
+    Then the command should fail with exit code 1
+    And stderr should contain "template 'odd' uses unknown issue type 'widget'"
+
+  Scenario: Applying a missing template fails
+    Given a Kanbus project with default configuration
+    When I run "kanbus template apply missing Something"
+    Then the command should fail with exit code 1
+    And stderr should contain "template 'missing' not found"
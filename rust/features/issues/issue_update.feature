@@ -0,0 +1,107 @@
+Feature: Issue update
+
+  Scenario: Append to issue description
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with title "Old Title"
+    And issue "kanbus-aaa" has description "Original description"
+    When I run "kanbus update kanbus-aaa --append-description \"Progress note\""
+    Then the command should succeed
+    And issue "kanbus-aaa" description should be "Original description\nProgress note"
+
+  Scenario: Append to issue description from stdin
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with title "Old Title"
+    And issue "kanbus-aaa" has description "Original description"
+    When I run "kanbus update kanbus-aaa --append-description -" with stdin "Piped note\n"
+    Then the command should succeed
+    And issue "kanbus-aaa" description should be "Original description\nPiped note"
+
+  Scenario: Append description conflicts with description
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with title "Old Title"
+    When I run "kanbus update kanbus-aaa --description New --append-description More"
+    Then the command should fail
+    And stderr should contain "cannot be used with"
+
+  Scenario: Update enforces max_description_length
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets max_description_length 10
+    And an issue "kanbus-aaa" exists with status "open"
+    When I run "kanbus update kanbus-aaa --description \"tiny\""
+    Then the command should succeed
+    When I run "kanbus update kanbus-aaa --description \"far too long text\""
+    Then the command should fail with exit code 1
+    And stderr should contain "max_description_length is 10"
+    When I run "kanbus update kanbus-aaa --description \"far too long text\" --no-validate"
+    Then the command should succeed
+    And issue "kanbus-aaa" description should be "far too long text"
+
+  Scenario: Update auto-claims unassigned issues for configured statuses
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets auto_claim_on_status "in_progress"
+    And KANBUS_USER is set to "dev@example.com"
+    And an issue "kanbus-free" exists with status "open"
+    And issue "kanbus-taken" has assignee "alice" and status "open"
+    When I run "kanbus update kanbus-free --status in_progress"
+    Then the command should succeed
+    And issue "kanbus-free" should have assignee "dev@example.com"
+    When I run "kanbus update kanbus-taken --status in_progress"
+    Then the command should succeed
+    And issue "kanbus-taken" should have assignee "alice"
+
+  Scenario: Explicit assignee overrides auto-claim
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets auto_claim_on_status "in_progress"
+    And KANBUS_USER is set to "dev@example.com"
+    And an issue "kanbus-free" exists with status "open"
+    When I run "kanbus update kanbus-free --status in_progress --assignee bob"
+    Then the command should succeed
+    And issue "kanbus-free" should have assignee "bob"
+
+  Scenario: Update advances to the single next workflow status
+    Given a Kanbus project with default configuration
+    And the default workflow allows "open" to move only to "in_progress, backlog"
+    And an issue "kanbus-aaa" exists with status "open"
+    When I run "kanbus update kanbus-aaa --status-next"
+    Then the command should succeed
+    And issue "kanbus-aaa" should have status "in_progress"
+
+  Scenario: Update rejects --status-next when the workflow forks
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    When I run "kanbus update kanbus-aaa --status-next"
+    Then the command should fail with exit code 1
+    And stderr should contain "multiple next statuses from 'open' for type 'task': in_progress, closed"
+    And issue "kanbus-aaa" should have status "open"
+
+  Scenario: Touch bumps only the updated timestamp
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists
+    And issue "kanbus-aaa" was last updated at "2026-01-01T00:00:00Z"
+    When I run "kanbus touch kanbus-aaa"
+    Then the command should succeed
+    And stdout should contain "Touched kanbus-aaa"
+    And issue "kanbus-aaa" should only have a newer updated_at
+    When I run "kanbus history kanbus-aaa"
+    Then stdout should contain "updated updated_at: 2026-01-01T00:00:00Z"
+
+  Scenario: Update status across several issues continues past failures
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    And an issue "kanbus-bbb" exists with status "open"
+    When I run "kanbus update kanbus-aaa kanbus-missing kanbus-bbb --status in_progress"
+    Then the command should fail with exit code 1
+    And stdout should contain "Updated kanbus-aaa"
+    And stdout should contain "Updated kanbus-bbb"
+    And stderr should contain "1 of 3 issues failed"
+    And stderr should contain "kanbus-missing: not found"
+    And issue "kanbus-aaa" should have status "in_progress"
+    And issue "kanbus-bbb" should have status "in_progress"
+
+  Scenario: Update an issue's due date
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with title "Old Title"
+    When I run "kanbus update kanbus-aaa --due 2026-03-01T17:00:00Z"
+    Then the command should succeed
+    When I run "kanbus show kanbus-aaa"
+    Then stdout should contain "Due: 2026-03-01 17:00 UTC"
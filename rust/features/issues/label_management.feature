@@ -0,0 +1,41 @@
+Feature: Label management
+  As a Kanbus user
+  I want to see and rename labels across the project
+  So that labels stay consistent without editing issue files by hand
+
+  Scenario: List labels by usage
+    Given a Kanbus project with default configuration
+    And issue "kanbus-alpha1" has labels "ui, bug"
+    And issue "kanbus-bravo1" has labels "ui"
+    And issue "kanbus-charl1" has labels "backend"
+    When I run "kanbus label list"
+    Then the command should succeed
+    And stdout should contain "ui (2)"
+    And stdout should contain "bug (1)"
+    And stdout should list "ui (2)" before "backend (1)"
+    And stdout should list "backend (1)" before "bug (1)"
+
+  Scenario: Rename a label on every issue
+    Given a Kanbus project with default configuration
+    And issue "kanbus-alpha1" has labels "ui, bug"
+    And issue "kanbus-bravo1" has labels "ui"
+    And issue "kanbus-charl1" has labels "backend"
+    When I run "kanbus label rename ui frontend"
+    Then the command should succeed
+    And stdout should contain "relabeled 2 issues"
+    And issue "kanbus-alpha1" should have labels "frontend, bug"
+    And issue "kanbus-bravo1" should have labels "frontend"
+    And issue "kanbus-charl1" should have labels "backend"
+
+  Scenario: Renaming onto an existing label keeps one copy
+    Given a Kanbus project with default configuration
+    And issue "kanbus-alpha1" has labels "ui, frontend"
+    When I run "kanbus label rename ui frontend"
+    Then the command should succeed
+    And issue "kanbus-alpha1" should have labels "frontend"
+
+  Scenario: Renaming a label to itself fails
+    Given a Kanbus project with default configuration
+    When I run "kanbus label rename ui ui"
+    Then the command should fail with exit code 1
+    And stderr should contain "source and target label are the same"
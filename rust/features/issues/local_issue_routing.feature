@@ -0,0 +1,37 @@
+Feature: Local issue routing
+
+  Scenario: Promote conflict leaves both copies untouched
+    Given a Kanbus project with default configuration
+    And a local issue "kanbus-dupe01" exists
+    And an issue "kanbus-dupe01" exists
+    When I run "kanbus promote kanbus-dupe01"
+    Then the command should fail with exit code 1
+    And the shared issue "kanbus-dupe01" should have title "Title"
+    And the local issue "kanbus-dupe01" should have title "Local"
+
+  Scenario: Localize conflict leaves both copies untouched
+    Given a Kanbus project with default configuration
+    And a local issue "kanbus-dupe02" exists
+    And an issue "kanbus-dupe02" exists
+    When I run "kanbus localize kanbus-dupe02"
+    Then the command should fail with exit code 1
+    And the shared issue "kanbus-dupe02" should have title "Title"
+    And the local issue "kanbus-dupe02" should have title "Local"
+
+  Scenario: Promote with force replaces the shared issue
+    Given a Kanbus project with default configuration
+    And a local issue "kanbus-dupe01" exists
+    And an issue "kanbus-dupe01" exists
+    When I run "kanbus promote kanbus-dupe01 --force"
+    Then the command should succeed
+    And the shared issue "kanbus-dupe01" should have title "Local"
+    And the local issue "kanbus-dupe01" should not exist
+
+  Scenario: Localize with force replaces the local issue
+    Given a Kanbus project with default configuration
+    And a local issue "kanbus-dupe02" exists
+    And an issue "kanbus-dupe02" exists
+    When I run "kanbus localize kanbus-dupe02 --force"
+    Then the command should succeed
+    And the local issue "kanbus-dupe02" should have title "Title"
+    And the shared issue "kanbus-dupe02" should not exist
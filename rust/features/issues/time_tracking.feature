@@ -0,0 +1,57 @@
+Feature: Time tracking
+  As a Kanbus user
+  I want to record estimates and time spent on issues
+  So that I can compare planned effort with actual effort
+
+  Scenario: Create an issue with an estimate
+    Given a Kanbus project with default configuration
+    When I run "kanbus create Estimate me --estimate 90"
+    Then the command should succeed
+    And the created issue should have an estimate of 90 minutes
+
+  Scenario: Update an issue estimate
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    When I run "kanbus update kanbus-aaa --estimate 45"
+    Then the command should succeed
+    And issue "kanbus-aaa" should have an estimate of 45 minutes
+
+  Scenario: Log time on an issue
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" with status "open" has an estimate of 60 minutes and 15 minutes spent
+    When I run "kanbus log-time kanbus-aaa 30"
+    Then the command should succeed
+    And stdout should contain "Logged 30 minutes on kanbus-aaa"
+    And issue "kanbus-aaa" should have 45 minutes spent
+    And issue "kanbus-aaa" should have a comment "Logged 30 minutes"
+
+  Scenario: Log time rejects zero minutes
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" exists with status "open"
+    When I run "kanbus log-time kanbus-aaa 0"
+    Then the command should fail with exit code 1
+    And stderr should contain "minutes must be greater than zero"
+
+  Scenario: Show includes estimated and spent time
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" with status "open" has an estimate of 60 minutes and 15 minutes spent
+    When I run "kanbus show kanbus-aaa"
+    Then the command should succeed
+    And stdout should contain "60 minutes estimated, 15 minutes spent"
+
+  Scenario: Stats total estimated and spent minutes across open issues
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" with status "open" has an estimate of 60 minutes and 15 minutes spent
+    And an issue "kanbus-bbb" with status "in_progress" has an estimate of 30 minutes and 20 minutes spent
+    And an issue "kanbus-ccc" with status "closed" has an estimate of 100 minutes and 100 minutes spent
+    When I run "kanbus stats"
+    Then the command should succeed
+    And stdout should contain "open estimated minutes: 90"
+    And stdout should contain "open spent minutes: 35"
+
+  Scenario: Issues without time tracking fields still load
+    Given a Kanbus project with default configuration
+    And an issue file "kanbus-old" without time tracking fields exists
+    When I run "kanbus log-time kanbus-old 10"
+    Then the command should succeed
+    And issue "kanbus-old" should have 10 minutes spent
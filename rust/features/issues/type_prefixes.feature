@@ -0,0 +1,34 @@
+Feature: Per-type issue ID prefixes
+  As a Kanbus user
+  I want issue IDs to carry a prefix that reflects their type
+  So that epics and tasks are distinguishable at a glance
+
+  Scenario: Created issues use the prefix configured for their type
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets type prefix "epic" to "EPIC"
+    When I run "kanbus create Platform rewrite --type epic"
+    Then the command should succeed
+    And the created issue identifier should start with "EPIC-"
+
+  Scenario: Types without a configured prefix use the project key
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets type prefix "epic" to "EPIC"
+    When I run "kanbus create Fix login --type task"
+    Then the command should succeed
+    And the created issue identifier should start with "kanbus-"
+
+  Scenario: Typed issues can be looked up by their short key
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets type prefix "epic" to "EPIC"
+    And an issue "EPIC-123e4567-e89b-12d3-a456-426614174000" of type "epic" with status "open"
+    When I run "kanbus show EPIC-123e45"
+    Then the command should succeed
+    And stdout should contain "EPIC-123e45"
+
+  Scenario: Typed short keys can be used as parents
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets type prefix "epic" to "EPIC"
+    And an issue "EPIC-123e4567-e89b-12d3-a456-426614174000" of type "epic" with status "open"
+    When I run "kanbus create Login page --type task --parent EPIC-123e45"
+    Then the command should succeed
+    And the created issue should have parent "EPIC-123e4567-e89b-12d3-a456-426614174000"
@@ -0,0 +1,63 @@
+Feature: Doctor diagnostics
+  As a Kanbus maintainer
+  I want a doctor command that validates the environment
+  So that setup issues are visible quickly
+
+  Scenario: Doctor warns about orphaned sub-tasks
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-orphan" exists
+    And issue "kanbus-orphan" has type "sub-task"
+    When I run "kanbus doctor"
+    Then the command should succeed
+    And stdout should contain "warning: kanbus-orphan: orphaned sub-task has no parent"
+
+  Scenario: Doctor flags event files for deleted issues
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-gone" exists
+    And an issue "kanbus-kept" exists
+    And I run "kanbus comment kanbus-kept \"Still here\""
+    And I run "kanbus delete kanbus-gone"
+    When I run "kanbus doctor"
+    Then the command should succeed
+    And stdout should contain "warning: kanbus-gone: orphaned event file"
+    And stdout should not contain "kanbus-kept"
+
+  Scenario: Doctor fix removes orphaned event files
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-gone" exists
+    And I run "kanbus delete kanbus-gone"
+    When I run "kanbus doctor --fix"
+    Then the command should succeed
+    And stdout should contain "fixed: removed kanbus-gone: orphaned event file"
+    When I run "kanbus doctor"
+    Then the command should succeed
+    And stdout should not contain "kanbus-gone"
+
+  Scenario: Doctor warns about a daemon speaking an incompatible protocol
+    Given a Kanbus project with default configuration
+    And a running daemon reports protocol version "2.0"
+    When I run "kanbus doctor"
+    Then the command should succeed
+    And stdout should contain "warning: running daemon speaks protocol 2.0"
+    And stdout should contain "kanbus daemon-stop"
+
+  Scenario: Doctor fails on daemon protocol skew under --strict
+    Given a Kanbus project with default configuration
+    And a running daemon reports protocol version "2.0"
+    When I run "kanbus doctor --strict"
+    Then the command should fail with exit code 1
+    And stderr should contain "protocol version mismatch"
+
+  Scenario: Doctor accepts a daemon speaking the current protocol
+    Given a Kanbus project with default configuration
+    And a running daemon reports protocol version "1.0"
+    When I run "kanbus doctor --strict"
+    Then the command should succeed
+
+  Scenario: Doctor reports status colors replaced by the accessible palette
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets status "open" color "red"
+    And the Kanbus configuration enables accessible_colors
+    When I run "kanbus doctor"
+    Then the command should succeed
+    And stdout should contain "warning: accessible_colors overrides color 'red' for status 'open'"
@@ -0,0 +1,113 @@
+Feature: Maintenance commands
+  As a Kanbus maintainer
+  I want diagnostic and maintenance commands
+  So that the repository stays healthy
+
+  Scenario: Validate reports orphaned sub-tasks
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-task01" exists
+    And an issue "kanbus-child1" exists
+    And issue "kanbus-child1" has type "sub-task"
+    And issue "kanbus-child1" has parent "kanbus-task01"
+    And an issue "kanbus-orphan" exists
+    And issue "kanbus-orphan" has type "sub-task"
+    When I run "kanbus validate --orphans"
+    Then the command should fail with exit code 1
+    And stderr should contain "kanbus-orphan: orphaned sub-task has no parent"
+
+  Scenario: Validate reports orphans together with other errors
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-bad" exists
+    And issue "kanbus-bad" has status "unknown"
+    And an issue "kanbus-orphan" exists
+    And issue "kanbus-orphan" has type "sub-task"
+    When I run "kanbus validate --orphans"
+    Then the command should fail with exit code 1
+    And stderr should contain "invalid status"
+    And stderr should contain "kanbus-orphan: orphaned sub-task has no parent"
+
+  Scenario: Validate passes when sub-tasks have parents
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-task01" exists
+    And an issue "kanbus-child1" exists
+    And issue "kanbus-child1" has type "sub-task"
+    And issue "kanbus-child1" has parent "kanbus-task01"
+    When I run "kanbus validate --orphans"
+    Then the command should succeed
+
+  Scenario: Stats break counts down per assignee
+    Given a Kanbus project with default configuration
+    And issue "kanbus-aaa" has assignee "alice" and status "open"
+    And issue "kanbus-bbb" has assignee "alice" and status "closed"
+    And issue "kanbus-ccc" has status "open"
+    When I run "kanbus stats --by-assignee"
+    Then the command should succeed
+    And stdout should contain the line "assignees:"
+    And stdout should contain the line "  alice: 1 open, 1 closed"
+    And stdout should contain the line "  (unassigned): 1 open, 0 closed"
+    And stdout should not contain "labels:"
+
+  Scenario: Stats break counts down per label
+    Given a Kanbus project with default configuration
+    And issue "kanbus-aaa" has labels "ui, backend"
+    And issue "kanbus-bbb" has labels "ui"
+    When I run "kanbus stats --by-label"
+    Then the command should succeed
+    And stdout should contain the line "labels:"
+    And stdout should contain the line "  ui: 2 open, 0 closed"
+    And stdout should contain the line "  backend: 1 open, 0 closed"
+
+  Scenario: Stats emit JSON for dashboards
+    Given a Kanbus project with default configuration
+    And issue "kanbus-aaa" has assignee "alice" and status "closed"
+    When I run "kanbus stats --json --by-assignee"
+    Then the command should succeed
+    And stdout should contain "\"closed_count\": 1"
+    And stdout should contain "\"assignee_counts\": {"
+    And stdout should not contain "label_counts"
+
+  Scenario: Validate emits a JSON report of findings
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-child" exists
+    And issue "kanbus-child" has parent "kanbus-missing"
+    When I run "kanbus validate --json"
+    Then the command should fail with exit code 1
+    And stdout should contain "missing_parent"
+    And stdout should contain "project/issues/kanbus-child.json"
+    And stdout should contain "parent 'kanbus-missing' does not exist"
+
+  Scenario: Validate JSON report is empty for a healthy project
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-task01" exists
+    When I run "kanbus validate --json"
+    Then the command should succeed
+    And stdout should contain "[]"
+
+  Scenario: Stats reports a daily burndown series
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" was created at "2026-03-01T09:00:00Z"
+    And an issue "kanbus-bbb" was created at "2026-03-02T10:00:00Z"
+    And issue "kanbus-bbb" was closed at "2026-03-03T12:00:00Z"
+    And an issue "kanbus-ccc" was created at "2026-03-03T08:00:00Z"
+    And issue "kanbus-ccc" was closed at "2026-03-04T00:00:00Z"
+    When I run "kanbus stats --burndown --since 2026-02-28 --until 2026-03-04"
+    Then the command should succeed
+    And stdout should contain the line "2026-02-28: 0"
+    And stdout should contain the line "2026-03-01: 1"
+    And stdout should contain the line "2026-03-02: 2"
+    And stdout should contain the line "2026-03-03: 2"
+    And stdout should contain the line "2026-03-04: 1"
+
+  Scenario: Stats emits the burndown series as JSON
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-aaa" was created at "2026-03-01T09:00:00Z"
+    When I run "kanbus stats --burndown --since 2026-03-01 --until 2026-03-02 --format json"
+    Then the command should succeed
+    And stdout should contain "\"2026-03-01\": 1"
+    And stdout should contain "\"2026-03-02\": 1"
+
+  Scenario: Stats rejects malformed burndown dates
+    Given a Kanbus project with default configuration
+    When I run "kanbus stats --burndown --since March"
+    Then the command should fail with exit code 1
+    And stderr should contain "invalid date: March (expected YYYY-MM-DD)"
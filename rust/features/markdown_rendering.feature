@@ -0,0 +1,25 @@
+Feature: Markdown rendering
+  As a Kanbus console user
+  I want issue descriptions and comments rendered from markdown
+  So that formatting is readable without exposing the console to injected scripts
+
+  Scenario: Markdown is rendered to HTML
+    When I render the markdown "Fix the **save** button in `editor.rs`"
+    Then stdout should contain "<strong>save</strong>"
+    And stdout should contain "<code>editor.rs</code>"
+
+  Scenario: Script tags are removed
+    When I render the markdown "Hello <script>alert(1)</script> world"
+    Then stdout should contain "Hello"
+    And stdout should not contain "<script>"
+    And stdout should not contain "alert(1)"
+
+  Scenario: Event handlers are removed
+    When I render the markdown "<img src=\"logo.png\" onerror=\"alert(1)\">"
+    Then stdout should contain "logo.png"
+    And stdout should not contain "onerror"
+
+  Scenario: Javascript links are removed
+    When I render the markdown "[click](javascript:alert(1))"
+    Then stdout should contain "click"
+    And stdout should not contain "javascript:"
@@ -0,0 +1,43 @@
+Feature: Migrate from Beads
+  As a Kanbus adopter
+  I want to migrate Beads issues into Kanbus
+  So that existing project history is preserved
+
+  Scenario: Merge migration creates only new issues on a second run
+    Given a git repository with a .beads issues database
+    When I run "kanbus migrate --merge"
+    Then the command should succeed
+    And stdout should contain "created 2 issues, updated 0, skipped 0"
+    Given a Beads issue "bdx-new" titled "Fresh work" is appended to the database
+    When I run "kanbus migrate --merge"
+    Then the command should succeed
+    And stdout should contain "created 1 issues, updated 0, skipped 2"
+
+  Scenario: Merge migration updates existing issues on request
+    Given a git repository with a .beads issues database
+    When I run "kanbus migrate --merge"
+    Then the command should succeed
+    Given the Beads issue "bdx-epic" is retitled "Renamed epic"
+    When I run "kanbus migrate --merge --update-existing"
+    Then the command should succeed
+    And stdout should contain "created 0 issues, updated 1, skipped 1"
+
+  Scenario: Merge migration maps textual priorities through import aliases
+    Given a git repository with a .beads issues database
+    When I run "kanbus migrate --merge"
+    Then the command should succeed
+    Given the Kanbus configuration sets priority import alias "P1" to "high"
+    And a Beads issue "bdx-alias" with priority "P1" is appended to the database
+    When I run "kanbus migrate --merge"
+    Then the command should succeed
+    And stdout should contain "created 1 issues"
+    And issue "bdx-alias" should have priority 1
+
+  Scenario: Merge migration rejects textual priorities without a match
+    Given a git repository with a .beads issues database
+    When I run "kanbus migrate --merge"
+    Then the command should succeed
+    Given a Beads issue "bdx-unknown" with priority "P9" is appended to the database
+    When I run "kanbus migrate --merge"
+    Then the command should fail with exit code 1
+    And stderr should contain "invalid priority"
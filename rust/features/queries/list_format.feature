@@ -0,0 +1,63 @@
+Feature: Issue list formatting
+  The list output should present key fields agents need while remaining token-efficient.
+
+  Scenario: Grouped list headers use the category color and count
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-active" exists with status "in_progress"
+    And an issue "kanbus-waiting" exists with status "open"
+    And an issue "kanbus-queued" exists with status "backlog"
+    When I format the project issues grouped by "category" with color enabled
+    Then the formatted output should show header "In progress (1)" with ANSI code "34"
+    And the formatted output should contain text "To do (2)"
+
+  Scenario: Grouped list headers use the status color
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-done" exists with status "closed"
+    When I format the project issues grouped by "status" with color enabled
+    Then the formatted output should show header "closed (1)" with ANSI code "32"
+
+  Scenario: List groups porcelain output by category
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-active" exists with status "in_progress"
+    And an issue "kanbus-waiting" exists with status "open"
+    When I run "kanbus list --group-by category --porcelain"
+    Then the command should succeed
+    And stdout should contain "To do (1)"
+    And stdout should contain "In progress (1)"
+
+  Scenario: List rejects unsupported group-by fields
+    Given a Kanbus project with default configuration
+    When I run "kanbus list --group-by priority"
+    Then the command should fail with exit code 1
+    And stderr should contain "unsupported group-by field: priority"
+
+  Scenario: List emits issues as JSON
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-open" exists with status "open"
+    And an issue "kanbus-closed" exists with status "closed"
+    When I run "kanbus list --json"
+    Then the command should succeed
+    And stdout should be a JSON array of 1 issue
+    And stdout should contain "\"id\": \"kanbus-open\""
+    And stdout should not contain "kanbus-closed"
+
+  Scenario: List JSON output cannot be combined with porcelain output
+    Given a Kanbus project with default configuration
+    When I run "kanbus list --json --porcelain"
+    Then the command should fail
+    And stderr should contain "cannot be used with"
+
+  Scenario: List JSON can flatten custom fields
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-due01" exists with title "Ship release"
+    And issue "kanbus-due01" has custom field "due" set to "2026-03-01"
+    When I run "kanbus list --json --flatten-custom"
+    Then the command should succeed
+    And stdout should contain "\"custom_due\": \"2026-03-01\""
+    And stdout should not contain "\"custom\": {"
+
+  Scenario: Flatten custom requires list JSON output
+    Given a Kanbus project with default configuration
+    When I run "kanbus list --flatten-custom"
+    Then the command should fail
+    And stderr should contain "--json"
@@ -0,0 +1,61 @@
+Feature: Next issue recommendation
+  As a Kanbus user
+  I want a single recommendation of what to work on next
+  So that I do not have to scan the ready list myself
+
+  Scenario: Next picks the highest-priority ready issue
+    Given a Kanbus project with default configuration
+    And issue "kanbus-low" has priority 3 and was created at "2026-01-01T00:00:00Z"
+    And issue "kanbus-high" has priority 1 and was created at "2026-01-05T00:00:00Z"
+    When I run "kanbus next"
+    Then the command should succeed
+    And stdout should contain "kanbus-high"
+    And stdout should not contain "kanbus-low"
+
+  Scenario: Next follows a descending priority order
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets priority_order "descending"
+    And issue "kanbus-low" has priority 3 and was created at "2026-01-01T00:00:00Z"
+    And issue "kanbus-high" has priority 1 and was created at "2026-01-05T00:00:00Z"
+    When I run "kanbus next"
+    Then the command should succeed
+    And stdout should contain "kanbus-low"
+    And stdout should not contain "kanbus-high"
+
+  Scenario: Next breaks priority ties by oldest issue
+    Given a Kanbus project with default configuration
+    And issue "kanbus-newer" has priority 2 and was created at "2026-01-05T00:00:00Z"
+    And issue "kanbus-older" has priority 2 and was created at "2026-01-01T00:00:00Z"
+    When I run "kanbus next"
+    Then stdout should contain "kanbus-older"
+    And stdout should not contain "kanbus-newer"
+
+  Scenario: Next skips blocked issues
+    Given a Kanbus project with default configuration
+    And issues "kanbus-ready" and "kanbus-blocked" exist
+    And issue "kanbus-blocked" depends on "kanbus-ready" with type "blocked-by"
+    When I run "kanbus next"
+    Then stdout should contain "kanbus-ready"
+    And stdout should not contain "kanbus-blocked"
+
+  Scenario: Next skips issues assigned to someone else
+    Given a Kanbus project with default configuration
+    And issue "kanbus-theirs" has assignee "someone@example.com"
+    And issue "kanbus-mine" has priority 3 and was created at "2026-03-01T00:00:00Z"
+    When I run "kanbus next --assignee dev@example.com"
+    Then stdout should contain "kanbus-mine"
+    And stdout should not contain "kanbus-theirs"
+
+  Scenario: Next reports when nothing is ready
+    Given a Kanbus project with default configuration
+    When I run "kanbus next"
+    Then the command should succeed
+    And stdout should contain "No ready issues"
+
+  Scenario: Next claims the chosen issue
+    Given a Kanbus project with default configuration
+    And issues "kanbus-ready" exist
+    When I run "kanbus next --assignee dev@example.com --claim"
+    Then the command should succeed
+    And issue "kanbus-ready" should have assignee "dev@example.com"
+    And issue "kanbus-ready" should have status "in_progress"
@@ -0,0 +1,352 @@
+Feature: Query and list operations
+  As a Kanbus user
+  I want to query issues by common fields
+  So that I can find the right work quickly
+
+  Scenario: List my open issues
+    Given a Kanbus project with default configuration
+    And KANBUS_USER is set to "dev@example.com"
+    And issues "kanbus-alpha1" and "kanbus-bravo1" exist
+    And issues "kanbus-charl1" and "kanbus-delta1" exist
+    And issue "kanbus-alpha1" has assignee "dev@example.com"
+    And issue "kanbus-bravo1" has assignee "other@example.com"
+    And issue "kanbus-charl1" has assignee "dev@example.com" and status "closed"
+    And issue "kanbus-delta1" has assignee "dev@example.com" and status "in_progress"
+    When I run "kanbus list --mine"
+    Then stdout should contain "alpha1"
+    And stdout should contain "delta1"
+    And stdout should not contain "bravo1"
+    And stdout should not contain "charl1"
+
+  Scenario: List my issues with an explicit status filter
+    Given a Kanbus project with default configuration
+    And KANBUS_USER is set to "dev@example.com"
+    And issues "kanbus-alpha1" and "kanbus-charl1" exist
+    And issue "kanbus-alpha1" has assignee "dev@example.com"
+    And issue "kanbus-charl1" has assignee "dev@example.com" and status "closed"
+    When I run "kanbus list --mine --status closed"
+    Then stdout should contain "charl1"
+    And stdout should not contain "alpha1"
+
+  Scenario: List issues assigned to anyone in an assignee file
+    Given a Kanbus project with default configuration
+    And issues "kanbus-alpha1" and "kanbus-bravo1" exist
+    And an issue "kanbus-charl1" exists
+    And issue "kanbus-alpha1" has assignee "ana@example.com"
+    And issue "kanbus-bravo1" has assignee "ben@example.com"
+    And issue "kanbus-charl1" has assignee "cat@example.com"
+    And a file "oncall.txt" lists the assignees "ana@example.com, ben@example.com"
+    When I run "kanbus list --assignee-file oncall.txt"
+    Then the command should succeed
+    And stdout should contain "alpha1"
+    And stdout should contain "bravo1"
+    And stdout should not contain "charl1"
+
+  Scenario: Assignee file paths are relative to the working directory
+    Given a Kanbus project with default configuration
+    And issues "kanbus-alpha1" and "kanbus-bravo1" exist
+    And issue "kanbus-alpha1" has assignee "ana@example.com"
+    And issue "kanbus-bravo1" has assignee "ben@example.com"
+    And the working directory is the subdirectory "team"
+    And a file "oncall.txt" lists the assignees "ana@example.com"
+    When I run "kanbus list --assignee-file oncall.txt"
+    Then the command should succeed
+    And stdout should contain "alpha1"
+    And stdout should not contain "bravo1"
+
+  Scenario: List with a missing assignee file fails
+    Given a Kanbus project with default configuration
+    When I run "kanbus list --assignee-file missing.txt"
+    Then the command should fail with exit code 1
+    And stderr should contain "failed to read assignee file"
+
+  Scenario: List issues created by me
+    Given a Kanbus project with default configuration
+    And KANBUS_USER is set to "dev@example.com"
+    And issues "kanbus-alpha1" and "kanbus-bravo1" exist
+    And issue "kanbus-alpha1" has creator "dev@example.com" and assignee "other@example.com"
+    And issue "kanbus-bravo1" has creator "other@example.com" and assignee "dev@example.com"
+    When I run "kanbus list --created-by-me"
+    Then stdout should contain "alpha1"
+    And stdout should not contain "bravo1"
+
+  Scenario: List issues assigned to me
+    Given a Kanbus project with default configuration
+    And KANBUS_USER is set to "dev@example.com"
+    And issues "kanbus-alpha1" and "kanbus-bravo1" exist
+    And issue "kanbus-alpha1" has creator "dev@example.com" and assignee "other@example.com"
+    And issue "kanbus-bravo1" has creator "other@example.com" and assignee "dev@example.com"
+    When I run "kanbus list --assigned-to-me"
+    Then stdout should contain "bravo1"
+    And stdout should not contain "alpha1"
+
+  Scenario: Combining created-by-me and assigned-to-me requires both
+    Given a Kanbus project with default configuration
+    And KANBUS_USER is set to "dev@example.com"
+    And issues "kanbus-alpha1" and "kanbus-bravo1" exist
+    And an issue "kanbus-charl1" exists
+    And issue "kanbus-alpha1" has creator "dev@example.com" and assignee "other@example.com"
+    And issue "kanbus-bravo1" has creator "other@example.com" and assignee "dev@example.com"
+    And issue "kanbus-charl1" has creator "dev@example.com" and assignee "dev@example.com"
+    When I run "kanbus list --created-by-me --assigned-to-me"
+    Then stdout should contain "charl1"
+    And stdout should not contain "alpha1"
+    And stdout should not contain "bravo1"
+
+  Scenario: Count issues by type
+    Given a Kanbus project with default configuration
+    And issues "kanbus-alpha1" and "kanbus-bravo1" exist
+    And an issue "kanbus-charl1" exists
+    And issue "kanbus-charl1" has type "bug"
+    When I run "kanbus list --count-by type"
+    Then the command should succeed
+    And stdout should contain the line "task: 2"
+    And stdout should contain the line "bug: 1"
+    And stdout should not contain "alpha1"
+
+  Scenario: Count issues by label as JSON
+    Given a Kanbus project with default configuration
+    And issues "kanbus-alpha1" and "kanbus-bravo1" exist
+    And issue "kanbus-alpha1" has labels "auth,ui"
+    And issue "kanbus-bravo1" has labels "auth"
+    When I run "kanbus list --count-by label --format json"
+    Then the command should succeed
+    And stdout should contain "\"key\": \"auth\""
+    And stdout should contain "\"count\": 2"
+    And stdout should list "\"auth\"" before "\"ui\""
+
+  Scenario: Count issues by an unsupported field
+    Given a Kanbus project with default configuration
+    When I run "kanbus list --count-by title"
+    Then the command should fail with exit code 1
+    And stderr should contain "invalid count-by field: title"
+
+  Scenario: List direct children of a parent
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-epic01" exists
+    And an issue "kanbus-child1" exists
+    And issue "kanbus-child1" has parent "kanbus-epic01"
+    And an issue "kanbus-grand1" exists
+    And issue "kanbus-grand1" has parent "kanbus-child1"
+    When I run "kanbus list --parent kanbus-epic01"
+    Then stdout should contain "child1"
+    And stdout should not contain "grand1"
+
+  Scenario: List a subtree recursively with a depth limit
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-epic01" exists
+    And an issue "kanbus-child1" exists
+    And issue "kanbus-child1" has parent "kanbus-epic01"
+    And an issue "kanbus-grand1" exists
+    And issue "kanbus-grand1" has parent "kanbus-child1"
+    And an issue "kanbus-great1" exists
+    And issue "kanbus-great1" has parent "kanbus-grand1"
+    When I run "kanbus list --parent kanbus-epic01 --recursive --depth 1"
+    Then stdout should contain "child1"
+    And stdout should not contain "grand1"
+    When I run "kanbus list --parent kanbus-epic01 --recursive --depth 2"
+    Then stdout should contain "child1"
+    And stdout should contain "grand1"
+    And stdout should not contain "great1"
+    When I run "kanbus list --parent kanbus-epic01 --recursive"
+    Then stdout should contain "great1"
+
+  Scenario: List issues excluding statuses
+    Given a Kanbus project with default configuration
+    And issue "kanbus-alpha1" has status "open"
+    And issue "kanbus-bravo1" has status "closed"
+    And issue "kanbus-charl1" has status "blocked"
+    And issue "kanbus-delta1" has status "in_progress"
+    When I run "kanbus list --exclude-status closed --exclude-status blocked"
+    Then stdout should contain "alpha1"
+    And stdout should contain "delta1"
+    And stdout should not contain "bravo1"
+    And stdout should not contain "charl1"
+
+  Scenario: List exclusion wins over inclusion of the same value
+    Given a Kanbus project with default configuration
+    And issue "kanbus-alpha1" has type "task"
+    And issue "kanbus-bravo1" has type "bug"
+    When I run "kanbus list --type bug --exclude-type bug"
+    Then the command should succeed
+    And stdout should not contain "alpha1"
+    And stdout should not contain "bravo1"
+
+  Scenario: List issues filtered by assignee substring
+    Given a Kanbus project with default configuration
+    And issue "kanbus-alpha1" has assignee "dev@acme.com"
+    And issue "kanbus-bravo1" has assignee "Ops@ACME.com"
+    And issue "kanbus-charl1" has assignee "dev@other.com"
+    When I run "kanbus list --assignee-contains @acme.com"
+    Then stdout should contain "alpha1"
+    And stdout should contain "bravo1"
+    And stdout should not contain "charl1"
+
+  Scenario: List issues filtered by label prefix
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-charl1" exists
+    And issue "kanbus-alpha1" has labels "area:backend"
+    And issue "kanbus-bravo1" has labels "area:frontend"
+    When I run "kanbus list --label-prefix area:"
+    Then stdout should contain "alpha1"
+    And stdout should contain "bravo1"
+    And stdout should not contain "charl1"
+    When I run "kanbus list --label-prefix area: --label area:backend"
+    Then stdout should contain "alpha1"
+    And stdout should not contain "bravo1"
+
+  Scenario: List issues sorted by descending priority order
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration sets priority_order "descending"
+    And issues "kanbus-high" and "kanbus-low" exist
+    And issue "kanbus-high" has priority 1
+    And issue "kanbus-low" has priority 3
+    When I run "kanbus list --sort priority"
+    Then stdout should list "low" before "high"
+
+  Scenario: List only issues with uncommitted git changes
+    Given a Kanbus project with default configuration
+    And issues "kanbus-clean" and "kanbus-dirty" exist
+    And the project files are committed to git
+    And issue "kanbus-dirty" has status "in_progress"
+    And an issue "kanbus-fresh" exists
+    When I run "kanbus list --modified-in-git --porcelain"
+    Then stdout should contain "dirty"
+    And stdout should contain "fresh"
+    And stdout should not contain "clean"
+
+  Scenario: List modified-in-git fails outside a git repository
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-orphan" exists
+    And the git metadata is removed
+    When I run "kanbus list --modified-in-git"
+    Then the command should fail with exit code 1
+    And stderr should contain "not a git repository"
+
+  Scenario: Fail-if-any succeeds when nothing matches
+    Given a Kanbus project with default configuration
+    And issue "kanbus-task" has type "task"
+    When I run "kanbus list --type bug --fail-if-any"
+    Then the command should succeed
+
+  Scenario: Fail-if-any fails and reports matches
+    Given a Kanbus project with default configuration
+    And issue "kanbus-bug" has type "bug"
+    When I run "kanbus list --type bug --fail-if-any --porcelain"
+    Then the command should fail with exit code 1
+    And stdout should contain "Title"
+    And stderr should contain "1 matching issues (--fail-if-any)"
+
+  Scenario: Fail-if-any prints matches as JSON
+    Given a Kanbus project with default configuration
+    And issue "kanbus-bug" has type "bug"
+    When I run "kanbus list --type bug --fail-if-any --json"
+    Then the command should fail with exit code 1
+    And stdout should contain "\"identifier\": \"kanbus-bug\""
+    And stderr should contain "1 matching issues (--fail-if-any)"
+
+  Scenario: Fail-if-empty fails when nothing matches
+    Given a Kanbus project with default configuration
+    And issue "kanbus-task" has type "task"
+    When I run "kanbus list --type bug --fail-if-empty"
+    Then the command should fail with exit code 1
+    And stderr should contain "no matching issues (--fail-if-empty)"
+
+  Scenario: Fail-if-empty succeeds when issues match
+    Given a Kanbus project with default configuration
+    And issue "kanbus-bug" has type "bug"
+    When I run "kanbus list --type bug --fail-if-empty"
+    Then the command should succeed
+    And stdout should contain "Title"
+
+  Scenario: List only issues changed since a tagged commit
+    Given a Kanbus project with default configuration
+    And issues "kanbus-old" and "kanbus-edited" exist
+    And the project files are committed to git
+    And the current commit is tagged "v1.0"
+    And issue "kanbus-edited" has status "in_progress"
+    And an issue "kanbus-added" exists
+    And the project files are committed to git
+    When I run "kanbus list --since-commit v1.0 --porcelain"
+    Then the command should succeed
+    And stdout should contain "edited"
+    And stdout should contain "added"
+    And stdout should not contain "old"
+
+  Scenario: List since-commit rejects unknown revisions
+    Given a Kanbus project with default configuration
+    And the project files are committed to git
+    When I run "kanbus list --since-commit no-such-tag"
+    Then the command should fail with exit code 1
+    And stderr should contain "unknown git revision: no-such-tag"
+
+  Scenario: List since-commit fails outside a git repository
+    Given a Kanbus project with default configuration
+    And the git metadata is removed
+    When I run "kanbus list --since-commit v1.0"
+    Then the command should fail with exit code 1
+    And stderr should contain "not a git repository"
+
+  Scenario: List issues updated since a timestamp
+    Given a Kanbus project with default configuration
+    And issue "kanbus-recent" was updated at "2026-03-02T09:00:00Z"
+    And issue "kanbus-edge" was updated at "2026-03-01T00:00:00Z"
+    And issue "kanbus-stale" was updated at "2026-02-28T23:59:59Z"
+    When I run "kanbus list --since 2026-03-01T00:00:00Z --porcelain"
+    Then the command should succeed
+    And stdout should contain "recent"
+    And stdout should contain "edge"
+    And stdout should not contain "stale"
+
+  Scenario: List issues updated within a relative window
+    Given a Kanbus project with default configuration
+    And issue "kanbus-fresh" was updated 1 hours ago
+    And issue "kanbus-inside" was updated 47 hours ago
+    And issue "kanbus-outside" was updated 49 hours ago
+    When I run "kanbus list --since 48h --porcelain"
+    Then the command should succeed
+    And stdout should contain "fresh"
+    And stdout should contain "inside"
+    And stdout should not contain "outside"
+
+  Scenario: List since combines with other filters
+    Given a Kanbus project with default configuration
+    And issue "kanbus-recent" was updated at "2026-03-02T09:00:00Z"
+    And issue "kanbus-owned" has assignee "dev@example.com"
+    And issue "kanbus-owned" was last updated at "2026-03-02T09:00:00Z"
+    And issue "kanbus-stale" has assignee "dev@example.com"
+    When I run "kanbus list --since 2026-03-01T00:00:00Z --assignee dev@example.com --porcelain"
+    Then the command should succeed
+    And stdout should contain "owned"
+    And stdout should not contain "recent"
+    And stdout should not contain "stale"
+
+  Scenario: List since rejects an invalid value
+    Given a Kanbus project with default configuration
+    When I run "kanbus list --since yesterday"
+    Then the command should fail with exit code 1
+    And stderr should contain "invalid since value: yesterday"
+
+  Scenario: List overdue issues
+    Given a Kanbus project with default configuration
+    And issue "kanbus-late" is due at "2020-01-01T00:00:00Z"
+    And issue "kanbus-future" is due at "2999-01-01T00:00:00Z"
+    And issue "kanbus-shipped" has status "closed" and is due at "2020-01-01T00:00:00Z"
+    And issues "kanbus-undated" exist
+    When I run "kanbus list --overdue --porcelain"
+    Then the command should succeed
+    And stdout should contain "late"
+    And stdout should not contain "future"
+    And stdout should not contain "shipped"
+    And stdout should not contain "undated"
+
+  Scenario: List issues due before a date
+    Given a Kanbus project with default configuration
+    And issue "kanbus-march" is due at "2026-03-01T17:00:00Z"
+    And issue "kanbus-april" is due at "2026-04-01T17:00:00Z"
+    And issues "kanbus-undated" exist
+    When I run "kanbus list --due-before 2026-03-15 --porcelain"
+    Then the command should succeed
+    And stdout should contain "march"
+    And stdout should not contain "april"
+    And stdout should not contain "undated"
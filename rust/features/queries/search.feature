@@ -0,0 +1,63 @@
+Feature: Ranked issue search
+  As a Kanbus user
+  I want to search issues by relevance
+  So that the best matches for a query come first
+
+  Scenario: Search ranks issues by how often terms appear
+    Given a Kanbus project with default configuration
+    And issues "kanbus-one" and "kanbus-two" exist
+    And issues "kanbus-three" and "kanbus-four" exist
+    And issue "kanbus-one" has title "Login"
+    And issue "kanbus-two" has title "Login page"
+    And issue "kanbus-two" has description "login fails after login"
+    And issue "kanbus-three" has title "Unrelated"
+    When I run "kanbus search login"
+    Then the command should succeed
+    And stdout should list "two" before "one"
+    And stdout should not contain "three"
+
+  Scenario: Search breaks ties on most recent update
+    Given a Kanbus project with default configuration
+    And issues "kanbus-one" and "kanbus-two" exist
+    And issue "kanbus-one" has title "Login"
+    And issue "kanbus-two" has title "Login"
+    And issue "kanbus-one" was last updated at "2026-01-01T00:00:00Z"
+    And issue "kanbus-two" was last updated at "2026-02-01T00:00:00Z"
+    When I run "kanbus search login"
+    Then the command should succeed
+    And stdout should list "two" before "one"
+
+  Scenario: Search is case-insensitive across terms and reports scores
+    Given a Kanbus project with default configuration
+    And issues "kanbus-one" and "kanbus-two" exist
+    And issue "kanbus-one" has title "Login page"
+    And issue "kanbus-two" has title "Settings"
+    When I run "kanbus search LOGIN Page --verbose"
+    Then the command should succeed
+    And stdout should contain "(score 2)"
+    And stdout should not contain "two"
+
+  Scenario: Search matches comment text
+    Given a Kanbus project with default configuration
+    And issues "kanbus-note" and "kanbus-other" exist
+    And the current user is "dev@example.com"
+    When I run "kanbus comment kanbus-note \"Searchable comment\""
+    And I run "kanbus search searchable"
+    Then stdout should contain "note"
+    And stdout should not contain "other"
+
+  Scenario: Search limits the number of results
+    Given a Kanbus project with default configuration
+    And issues "kanbus-one" and "kanbus-two" exist
+    And issue "kanbus-one" has title "Login"
+    And issue "kanbus-two" has title "Login login"
+    When I run "kanbus search login --limit 1"
+    Then the command should succeed
+    And stdout should contain "two"
+    And stdout should not contain "one"
+
+  Scenario: Search requires a query
+    Given a Kanbus project with default configuration
+    When I run "kanbus search"
+    Then the command should fail with exit code 1
+    And stderr should contain "required arguments were not provided"
@@ -349,9 +349,13 @@ fn given_kanbus_issue_exists(world: &mut KanbusWorld, identifier: String) {
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     };
 
@@ -412,9 +416,13 @@ fn given_kanbus_issue_exists_with_labels(
         labels: label_list.clone(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     };
 
@@ -470,9 +478,13 @@ fn given_kanbus_issue_exists_with_title(
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     };
 
@@ -527,9 +539,13 @@ fn given_kanbus_issue_exists_with_priority(
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     };
 
@@ -630,9 +646,13 @@ fn given_kanbus_only_issue(world: &mut KanbusWorld, identifier: String) {
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     };
 
@@ -1,12 +1,14 @@
 use std::process::Command;
 
-use cucumber::when;
+use cucumber::{then, when};
+
+use kanbus::daemon_protocol::PROTOCOL_VERSION;
 
 use crate::step_definitions::initialization_steps::KanbusWorld;
 
 #[when("I run the CLI entrypoint with --help")]
 fn when_run_cli_entrypoint_help(world: &mut KanbusWorld) {
-    run_cli_binary(world, vec!["--help".to_string()]);
+    run_cli_binary(world, vec!["--help".to_string()], &[]);
 }
 
 #[when(expr = "I run the CLI entrypoint with {string}")]
@@ -15,10 +17,19 @@ fn when_run_cli_entrypoint_args(world: &mut KanbusWorld, arguments: String) {
         .split_whitespace()
         .map(|value| value.to_string())
         .collect();
-    run_cli_binary(world, args);
+    run_cli_binary(world, args, &[]);
 }
 
-fn run_cli_binary(world: &mut KanbusWorld, args: Vec<String>) {
+#[when(expr = "I run the CLI entrypoint with {string} and JSON errors enabled")]
+fn when_run_cli_entrypoint_args_json_errors(world: &mut KanbusWorld, arguments: String) {
+    let args = arguments
+        .split_whitespace()
+        .map(|value| value.to_string())
+        .collect();
+    run_cli_binary(world, args, &[("KANBUS_JSON_ERRORS", "1")]);
+}
+
+fn run_cli_binary(world: &mut KanbusWorld, args: Vec<String>, envs: &[(&str, &str)]) {
     let manifest_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     let target_dir = std::env::var("CARGO_TARGET_DIR")
         .map(std::path::PathBuf::from)
@@ -40,7 +51,7 @@ fn run_cli_binary(world: &mut KanbusWorld, args: Vec<String>) {
         .clone()
         .unwrap_or_else(|| std::env::current_dir().expect("current dir"));
     let mut command = Command::new(binary_path);
-    command.args(args);
+    command.args(args).envs(envs.iter().copied());
     let output = command
         .current_dir(cwd)
         .env("KANBUS_NO_DAEMON", "1")
@@ -50,3 +61,29 @@ fn run_cli_binary(world: &mut KanbusWorld, args: Vec<String>) {
     world.stdout = Some(String::from_utf8_lossy(&output.stdout).to_string());
     world.stderr = Some(String::from_utf8_lossy(&output.stderr).to_string());
 }
+
+#[then(expr = "stderr should be a JSON error with code {string}")]
+fn then_stderr_json_error_code(world: &mut KanbusWorld, code: String) {
+    let stderr = world.stderr.as_ref().expect("stderr");
+    let payload: serde_json::Value = serde_json::from_str(stderr.trim()).expect("error json");
+    assert_eq!(
+        payload.get("code").and_then(|value| value.as_str()),
+        Some(code.as_str())
+    );
+    assert!(payload
+        .get("error")
+        .and_then(|value| value.as_str())
+        .is_some());
+}
+
+#[then("stdout should report the daemon protocol version")]
+fn then_stdout_reports_protocol_version(world: &mut KanbusWorld) {
+    let stdout = world.stdout.as_ref().expect("stdout");
+    let payload: serde_json::Value = serde_json::from_str(stdout).expect("version json");
+    assert_eq!(
+        payload
+            .get("protocol_version")
+            .and_then(|value| value.as_str()),
+        Some(PROTOCOL_VERSION)
+    );
+}
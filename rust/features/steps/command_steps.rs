@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::fs;
 use std::io::Write;
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
@@ -68,9 +69,9 @@ fn run_cli_command(world: &mut KanbusWorld, command: &str) {
 
     match result {
         Ok(output) => {
-            world.exit_code = Some(0);
+            world.exit_code = Some(output.exit_code);
             world.stdout = Some(output.stdout);
-            world.stderr = Some(String::new());
+            world.stderr = Some(output.stderr);
             record_kanbus_issue_id_if_created(world, &normalized);
             let no_daemon = std::env::var("KANBUS_NO_DAEMON")
                 .unwrap_or_default()
@@ -291,6 +292,17 @@ fn given_run_command(world: &mut KanbusWorld, command: String) {
     run_cli_command(world, &command);
 }
 
+#[given(expr = "the working directory is the subdirectory {string}")]
+fn given_working_directory_is_subdirectory(world: &mut KanbusWorld, name: String) {
+    let cwd = world
+        .working_directory
+        .as_ref()
+        .expect("working directory not set");
+    let subdirectory = cwd.join(name);
+    fs::create_dir_all(&subdirectory).expect("create subdirectory");
+    world.working_directory = Some(subdirectory);
+}
+
 #[when(expr = "I run {string}")]
 fn when_run_command(world: &mut KanbusWorld, command: String) {
     run_cli_command(world, &command);
@@ -308,6 +320,32 @@ fn when_run_command_with_stdin(world: &mut KanbusWorld, command: String, stdin_t
     run_cli_command_with_stdin(world, &command, &input);
 }
 
+#[when(expr = "I run {string} and {string} concurrently")]
+fn when_run_commands_concurrently(world: &mut KanbusWorld, first: String, second: String) {
+    if std::env::var("KANBUS_NO_DAEMON").is_err() {
+        std::env::set_var("KANBUS_NO_DAEMON", "1");
+    }
+    let cwd = world
+        .working_directory
+        .as_ref()
+        .expect("working directory not set")
+        .to_path_buf();
+    let handles = [first, second].map(|command| {
+        let args = shell_words::split(&command.replace("\\\"", "\"")).expect("parse command");
+        let cwd_path = cwd.clone();
+        thread::spawn(move || run_from_args_with_output(args, &cwd_path))
+    });
+    let mut errors = Vec::new();
+    for handle in handles {
+        if let Err(error) = handle.join().expect("cli thread panicked") {
+            errors.push(error.to_string());
+        }
+    }
+    world.exit_code = Some(if errors.is_empty() { 0 } else { 1 });
+    world.stdout = Some(String::new());
+    world.stderr = Some(errors.join("\n"));
+}
+
 #[when(expr = "I run {string} and respond {string}")]
 fn when_run_command_with_response(world: &mut KanbusWorld, command: String, response: String) {
     run_cli_command_with_stdin(world, &command, &format!("{response}\n"));
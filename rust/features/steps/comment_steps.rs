@@ -115,6 +115,34 @@ fn given_issue_has_comment_with_id(
         author,
         text,
         created_at: Utc::now(),
+        system: false,
+        resolved: false,
+        reply_to: None,
+    });
+    save_issue(&project_dir, &issue);
+}
+
+#[given(
+    expr = "issue {string} has a reply from {string} with text {string} and id {string} to comment {string}"
+)]
+fn given_issue_has_reply_with_id(
+    world: &mut KanbusWorld,
+    identifier: String,
+    author: String,
+    text: String,
+    comment_id: String,
+    reply_to: String,
+) {
+    let project_dir = load_project_dir(world);
+    let mut issue = load_issue(&project_dir, &identifier);
+    issue.comments.push(IssueComment {
+        id: Some(comment_id),
+        author,
+        text,
+        created_at: Utc::now(),
+        system: false,
+        resolved: false,
+        reply_to: Some(reply_to),
     });
     save_issue(&project_dir, &issue);
 }
@@ -149,6 +177,9 @@ fn given_issue_has_comment_without_id(
         author,
         text,
         created_at: Utc::now(),
+        system: false,
+        resolved: false,
+        reply_to: None,
     });
     save_issue(&project_dir, &issue);
 }
@@ -176,10 +207,17 @@ fn given_issue_with_comment_missing_id(world: &mut KanbusWorld, identifier: Stri
             author: "user@example.com".to_string(),
             text: "Legacy comment".to_string(),
             created_at: timestamp,
+            system: false,
+            resolved: false,
+            reply_to: None,
         }],
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
+        links: Vec::new(),
         custom: std::collections::BTreeMap::new(),
     };
     save_issue(&project_dir, &issue);
@@ -209,12 +247,19 @@ fn given_issue_with_comment_id_and_text(
         comments: vec![IssueComment {
             id: Some(comment_id),
             author: "user@example.com".to_string(),
-            text,
+            text: text.replace("\\n", "\n"),
             created_at: timestamp,
+            system: false,
+            resolved: false,
+            reply_to: None,
         }],
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
+        links: Vec::new(),
         custom: std::collections::BTreeMap::new(),
     };
     save_issue(&project_dir, &issue);
@@ -247,17 +292,27 @@ fn given_issue_with_two_comment_ids(
                 author: "user@example.com".to_string(),
                 text: "First".to_string(),
                 created_at: timestamp,
+                system: false,
+                resolved: false,
+                reply_to: None,
             },
             IssueComment {
                 id: Some(id2),
                 author: "user@example.com".to_string(),
                 text: "Second".to_string(),
                 created_at: timestamp,
+                system: false,
+                resolved: false,
+                reply_to: None,
             },
         ],
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
+        links: Vec::new(),
         custom: std::collections::BTreeMap::new(),
     };
     save_issue(&project_dir, &issue);
@@ -479,6 +534,45 @@ fn then_issue_has_comment_count(world: &mut KanbusWorld, identifier: String, cou
     assert_eq!(issue.comments.len(), count);
 }
 
+#[then(expr = "comment {string} on issue {string} should be resolved")]
+fn then_comment_is_resolved(world: &mut KanbusWorld, comment_id: String, identifier: String) {
+    let project_dir = load_project_dir(world);
+    let issue = load_issue(&project_dir, &identifier);
+    let comment = issue
+        .comments
+        .iter()
+        .find(|comment| comment.id.as_deref() == Some(comment_id.as_str()))
+        .expect("comment not found");
+    assert!(comment.resolved, "comment should be resolved");
+}
+
+#[then(expr = "comment {string} on issue {string} should not be resolved")]
+fn then_comment_is_not_resolved(world: &mut KanbusWorld, comment_id: String, identifier: String) {
+    let project_dir = load_project_dir(world);
+    let issue = load_issue(&project_dir, &identifier);
+    let comment = issue
+        .comments
+        .iter()
+        .find(|comment| comment.id.as_deref() == Some(comment_id.as_str()))
+        .expect("comment not found");
+    assert!(!comment.resolved, "comment should not be resolved");
+}
+
+#[then(expr = "issue {string} should have a reply to {string} with text {string}")]
+fn then_issue_has_reply(
+    world: &mut KanbusWorld,
+    identifier: String,
+    reply_to: String,
+    text: String,
+) {
+    let project_dir = load_project_dir(world);
+    let issue = load_issue(&project_dir, &identifier);
+    let found = issue.comments.iter().any(|comment| {
+        comment.reply_to.as_deref() == Some(reply_to.as_str()) && comment.text == text
+    });
+    assert!(found, "expected reply not found");
+}
+
 #[then(expr = "the last comment operation should fail with {string}")]
 fn then_last_comment_op_fails(world: &mut KanbusWorld, expected_error: String) {
     assert_eq!(world.exit_code, Some(1), "Expected operation to fail");
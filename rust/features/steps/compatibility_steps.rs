@@ -131,21 +131,59 @@ fn then_beads_jsonl_includes_status_for(
     status: String,
     identifier: String,
 ) {
+    assert_beads_shard_status(world, "issues.jsonl", &status, &identifier);
+}
+
+#[given(expr = "beads shard {string} contains issue {string}")]
+fn given_beads_shard_contains_issue(world: &mut KanbusWorld, shard: String, identifier: String) {
     let root = world
         .working_directory
         .as_ref()
         .expect("working directory not set");
-    let path = root.join(".beads").join("issues.jsonl");
-    let contents = fs::read_to_string(path).expect("read issues.jsonl");
+    let path = root.join(".beads").join(shard);
+    let record = serde_json::json!({
+        "id": identifier,
+        "title": "Sharded issue",
+        "description": "",
+        "status": "open",
+        "priority": 2,
+        "issue_type": "task",
+        "created_at": "2026-02-01T10:00:00-05:00",
+        "created_by": "fixture",
+        "updated_at": "2026-02-01T10:00:00-05:00",
+    });
+    let mut contents = fs::read_to_string(&path).unwrap_or_default();
+    contents.push_str(&serde_json::to_string(&record).expect("serialize beads record"));
+    contents.push('\n');
+    fs::write(path, contents).expect("write beads shard");
+}
+
+#[then(expr = "beads shard {string} should include status {string} for {string}")]
+fn then_beads_shard_includes_status_for(
+    world: &mut KanbusWorld,
+    shard: String,
+    status: String,
+    identifier: String,
+) {
+    assert_beads_shard_status(world, &shard, &status, &identifier);
+}
+
+fn assert_beads_shard_status(world: &KanbusWorld, shard: &str, status: &str, identifier: &str) {
+    let root = world
+        .working_directory
+        .as_ref()
+        .expect("working directory not set");
+    let path = root.join(".beads").join(shard);
+    let contents = fs::read_to_string(path).expect("read beads shard");
     let mut found = false;
     for line in contents.lines() {
         if line.trim().is_empty() {
             continue;
         }
         let record: Value = serde_json::from_str(line).expect("parse record");
-        if record.get("id").and_then(|value| value.as_str()) == Some(identifier.as_str()) {
+        if record.get("id").and_then(|value| value.as_str()) == Some(identifier) {
             let value = record.get("status").and_then(|value| value.as_str());
-            assert_eq!(value, Some(status.as_str()));
+            assert_eq!(value, Some(status));
             found = true;
             break;
         }
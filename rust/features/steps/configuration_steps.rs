@@ -7,7 +7,9 @@ use serde_yaml::Value;
 use tempfile::TempDir;
 
 use kanbus::cli::run_from_args_with_output;
-use kanbus::config::{default_project_configuration, write_default_configuration};
+use kanbus::config::{
+    default_project_configuration, write_default_configuration, ACCESSIBLE_STATUS_PALETTE,
+};
 use kanbus::config_loader::load_project_configuration;
 use kanbus::file_io::get_configuration_path;
 use kanbus::workflows::validate_status_transition;
@@ -189,6 +191,21 @@ fn given_repo_with_empty_hierarchy(world: &mut KanbusWorld) {
     given_invalid_config_empty_hierarchy(world);
 }
 
+#[given("a Kanbus repository with a .kanbus.yml file containing a YAML syntax error on line 3")]
+fn given_repo_with_yaml_syntax_error(world: &mut KanbusWorld) {
+    initialize_project(world);
+    let config_path = world
+        .working_directory
+        .as_ref()
+        .expect("working directory not set")
+        .join(".kanbus.yml");
+    fs::write(
+        config_path,
+        "project_directory: project\nproject_key: kanbus\nhierarchy: [initiative, epic\n",
+    )
+    .expect("write malformed config");
+}
+
 #[given("a Kanbus repository with a .kanbus.yml file that is not a mapping")]
 fn given_repo_with_non_mapping_config(world: &mut KanbusWorld) {
     initialize_project(world);
@@ -308,16 +325,20 @@ fn given_repository_without_configuration(world: &mut KanbusWorld) {
     );
 }
 
-fn update_config_file(world: &mut KanbusWorld, update: impl FnOnce(&mut serde_yaml::Mapping)) {
-    let config_path = world.configuration_path.clone().unwrap_or_else(|| {
+fn config_file_path(world: &KanbusWorld) -> std::path::PathBuf {
+    world.configuration_path.clone().unwrap_or_else(|| {
         world
             .working_directory
             .as_ref()
             .expect("working directory not set")
             .join(".kanbus.yml")
-    });
+    })
+}
+
+fn update_config_file(world: &mut KanbusWorld, update: impl FnOnce(&mut serde_yaml::Mapping)) {
+    let config_path = config_file_path(world);
     if !config_path.exists() {
-        write_default_configuration(&config_path).expect("write default config");
+        write_default_configuration(&config_path, None).expect("write default config");
     }
     let contents = fs::read_to_string(&config_path).expect("read config");
     let mut value: Value = serde_yaml::from_str(&contents).expect("parse config");
@@ -347,7 +368,7 @@ fn given_project_with_configuration_file(world: &mut KanbusWorld) {
         .as_ref()
         .expect("working directory not set")
         .join(".kanbus.yml");
-    write_default_configuration(&config_path).expect("write default config");
+    write_default_configuration(&config_path, None).expect("write default config");
     world.configuration_path = Some(config_path);
 }
 
@@ -361,6 +382,198 @@ fn given_kanbus_configuration_default_assignee(world: &mut KanbusWorld, assignee
     });
 }
 
+#[given("the Kanbus configuration enables comment_on_transition")]
+fn given_kanbus_configuration_comment_on_transition(world: &mut KanbusWorld) {
+    update_config_file(world, |mapping| {
+        mapping.insert(
+            Value::String("comment_on_transition".to_string()),
+            Value::Bool(true),
+        );
+    });
+}
+
+#[given("the Kanbus configuration enables accessible_colors")]
+fn given_kanbus_configuration_accessible_colors(world: &mut KanbusWorld) {
+    update_config_file(world, |mapping| {
+        mapping.insert(
+            Value::String("accessible_colors".to_string()),
+            Value::Bool(true),
+        );
+    });
+}
+
+#[given(expr = "the Kanbus configuration sets issue_layout {string}")]
+fn given_kanbus_configuration_issue_layout(world: &mut KanbusWorld, layout: String) {
+    update_config_file(world, |mapping| {
+        mapping.insert(
+            Value::String("issue_layout".to_string()),
+            Value::String(layout),
+        );
+    });
+}
+
+#[given(expr = "the Kanbus configuration sets issue_format {string}")]
+fn given_kanbus_configuration_issue_format(world: &mut KanbusWorld, format: String) {
+    update_config_file(world, |mapping| {
+        mapping.insert(
+            Value::String("issue_format".to_string()),
+            Value::String(format),
+        );
+    });
+}
+
+#[given(expr = "the Kanbus configuration sets priority_order {string}")]
+fn given_kanbus_configuration_priority_order(world: &mut KanbusWorld, order: String) {
+    update_config_file(world, |mapping| {
+        mapping.insert(
+            Value::String("priority_order".to_string()),
+            Value::String(order),
+        );
+    });
+}
+
+#[given(expr = "the Kanbus configuration sets warn_on_duplicate_title to {string}")]
+fn given_kanbus_configuration_warn_on_duplicate_title(world: &mut KanbusWorld, value: String) {
+    let value: Value = serde_yaml::from_str(&value).expect("parse configuration value");
+    update_config_file(world, |mapping| {
+        mapping.insert(Value::String("warn_on_duplicate_title".to_string()), value);
+    });
+}
+
+#[given(expr = "the Kanbus configuration sets max_description_length {int}")]
+fn given_kanbus_configuration_max_description_length(world: &mut KanbusWorld, limit: u64) {
+    update_config_file(world, |mapping| {
+        mapping.insert(
+            Value::String("max_description_length".to_string()),
+            Value::Number(limit.into()),
+        );
+    });
+}
+
+#[given(expr = "the Kanbus configuration sets auto_claim_on_status {string}")]
+fn given_kanbus_configuration_auto_claim_on_status(world: &mut KanbusWorld, status: String) {
+    update_config_file(world, |mapping| {
+        mapping.insert(
+            Value::String("auto_claim_on_status".to_string()),
+            Value::Sequence(vec![Value::String(status)]),
+        );
+    });
+}
+
+#[given(expr = "the Kanbus configuration sets notification_socket_path {string}")]
+fn given_kanbus_configuration_notification_socket_path(world: &mut KanbusWorld, path: String) {
+    update_config_file(world, |mapping| {
+        mapping.insert(
+            Value::String("notification_socket_path".to_string()),
+            Value::String(path),
+        );
+    });
+}
+
+#[given(expr = "the Kanbus configuration sets label color {string} to {string}")]
+fn given_kanbus_configuration_label_color(world: &mut KanbusWorld, label: String, color: String) {
+    update_config_file(world, |mapping| {
+        let entry = mapping
+            .entry(Value::String("label_colors".to_string()))
+            .or_insert_with(|| Value::Mapping(serde_yaml::Mapping::new()));
+        if !entry.is_mapping() {
+            *entry = Value::Mapping(serde_yaml::Mapping::new());
+        }
+        entry
+            .as_mapping_mut()
+            .expect("label_colors mapping")
+            .insert(Value::String(label), Value::String(color));
+    });
+}
+
+#[given(expr = "the Kanbus configuration sets status {string} color {string}")]
+fn given_kanbus_configuration_status_color(world: &mut KanbusWorld, key: String, color: String) {
+    update_config_file(world, |mapping| {
+        let Some(Value::Sequence(statuses)) = mapping.get_mut("statuses") else {
+            panic!("statuses sequence");
+        };
+        let status = statuses
+            .iter_mut()
+            .filter_map(Value::as_mapping_mut)
+            .find(|status| status.get("key") == Some(&Value::String(key.clone())))
+            .expect("status in configuration");
+        status.insert(Value::String("color".to_string()), Value::String(color));
+    });
+}
+
+#[given(expr = "the Kanbus configuration adds status {string} in category {string}")]
+fn given_kanbus_configuration_adds_status(world: &mut KanbusWorld, key: String, category: String) {
+    update_config_file(world, |mapping| {
+        let Some(Value::Sequence(statuses)) = mapping.get_mut("statuses") else {
+            panic!("statuses sequence");
+        };
+        let mut status = serde_yaml::Mapping::new();
+        status.insert(Value::String("key".to_string()), Value::String(key.clone()));
+        status.insert(Value::String("name".to_string()), Value::String(key));
+        status.insert(
+            Value::String("category".to_string()),
+            Value::String(category),
+        );
+        statuses.push(Value::Mapping(status));
+    });
+}
+
+#[given(expr = "the Kanbus configuration file starts with the comment {string}")]
+fn given_kanbus_configuration_file_comment(world: &mut KanbusWorld, comment: String) {
+    let config_path = config_file_path(world);
+    let contents = fs::read_to_string(&config_path).expect("read config");
+    fs::write(&config_path, format!("{comment}\n{contents}")).expect("write config");
+}
+
+#[given("the Kanbus configuration file writes label_colors in flow style")]
+fn given_kanbus_configuration_flow_label_colors(world: &mut KanbusWorld) {
+    let config_path = config_file_path(world);
+    let contents = fs::read_to_string(&config_path).expect("read config");
+    let updated = contents.replace("label_colors: {}", "label_colors: {bug: red}");
+    assert_ne!(updated, contents, "label_colors not found in configuration");
+    fs::write(&config_path, updated).expect("write config");
+}
+
+#[given(expr = "the Kanbus configuration sets type prefix {string} to {string}")]
+fn given_kanbus_configuration_type_prefix(
+    world: &mut KanbusWorld,
+    issue_type: String,
+    prefix: String,
+) {
+    update_config_file(world, |mapping| {
+        let entry = mapping
+            .entry(Value::String("type_prefixes".to_string()))
+            .or_insert_with(|| Value::Mapping(serde_yaml::Mapping::new()));
+        if !entry.is_mapping() {
+            *entry = Value::Mapping(serde_yaml::Mapping::new());
+        }
+        entry
+            .as_mapping_mut()
+            .expect("type_prefixes mapping")
+            .insert(Value::String(issue_type), Value::String(prefix));
+    });
+}
+
+#[given(expr = "the Kanbus configuration sets priority import alias {string} to {string}")]
+fn given_kanbus_configuration_priority_alias(
+    world: &mut KanbusWorld,
+    alias: String,
+    priority: String,
+) {
+    update_config_file(world, |mapping| {
+        let entry = mapping
+            .entry(Value::String("priority_import_aliases".to_string()))
+            .or_insert_with(|| Value::Mapping(serde_yaml::Mapping::new()));
+        if !entry.is_mapping() {
+            *entry = Value::Mapping(serde_yaml::Mapping::new());
+        }
+        entry
+            .as_mapping_mut()
+            .expect("priority_import_aliases mapping")
+            .insert(Value::String(alias), Value::String(priority));
+    });
+}
+
 #[given(expr = "a Kanbus override file sets default assignee {string}")]
 fn given_override_default_assignee(world: &mut KanbusWorld, assignee: String) {
     let repo_path = world
@@ -466,7 +679,7 @@ fn given_project_with_unreadable_configuration_file(world: &mut KanbusWorld) {
         .as_ref()
         .expect("working directory not set")
         .join(".kanbus.yml");
-    write_default_configuration(&config_path).expect("write default config");
+    write_default_configuration(&config_path, None).expect("write default config");
     #[cfg(unix)]
     {
         use std::os::unix::fs::PermissionsExt;
@@ -1017,8 +1230,65 @@ fn then_hierarchy_should_match_param(world: &mut KanbusWorld, expected: String)
     assert_eq!(configuration.hierarchy, parts);
 }
 
+#[then(expr = "the Kanbus configuration file should contain {string}")]
+fn then_kanbus_configuration_file_contains(world: &mut KanbusWorld, text: String) {
+    let contents = fs::read_to_string(config_file_path(world)).expect("read config");
+    assert!(
+        contents.contains(&text),
+        "configuration missing {text:?}:\n{contents}"
+    );
+}
+
+#[then(expr = "the Kanbus configuration file should not contain {string}")]
+fn then_kanbus_configuration_file_not_contains(world: &mut KanbusWorld, text: String) {
+    let contents = fs::read_to_string(config_file_path(world)).expect("read config");
+    assert!(!contents.contains(&text), "configuration contains {text:?}");
+}
+
+#[then(expr = "the {string} status color should come from the accessible palette")]
+fn then_status_color_from_accessible_palette(world: &mut KanbusWorld, status: String) {
+    let configuration = world.configuration.as_ref().expect("configuration");
+    let color = configuration
+        .statuses
+        .iter()
+        .find(|definition| definition.key == status)
+        .and_then(|definition| definition.color.as_deref())
+        .expect("status color");
+    assert!(ACCESSIBLE_STATUS_PALETTE.contains(&color));
+}
+
 #[then(expr = "the hierarchy should include {string}")]
 fn then_hierarchy_should_include(world: &mut KanbusWorld, value: String) {
     let configuration = world.configuration.as_ref().expect("configuration");
     assert!(configuration.hierarchy.contains(&value));
 }
+
+#[given(expr = "the default workflow allows {string} to move only to {string}")]
+fn given_default_workflow_transitions(world: &mut KanbusWorld, status: String, targets: String) {
+    update_config_file(world, |mapping| {
+        let workflow = mapping
+            .get_mut(Value::String("workflows".to_string()))
+            .and_then(Value::as_mapping_mut)
+            .and_then(|workflows| workflows.get_mut(Value::String("default".to_string())))
+            .and_then(Value::as_mapping_mut)
+            .expect("default workflow");
+        let targets: Vec<Value> = targets
+            .split(',')
+            .map(|target| Value::String(target.trim().to_string()))
+            .collect();
+        workflow.insert(
+            Value::String(status.clone()),
+            Value::Sequence(targets.clone()),
+        );
+        if let Some(labels) = mapping
+            .get_mut(Value::String("transition_labels".to_string()))
+            .and_then(Value::as_mapping_mut)
+            .and_then(|labels| labels.get_mut(Value::String("default".to_string())))
+            .and_then(Value::as_mapping_mut)
+            .and_then(|labels| labels.get_mut(Value::String(status)))
+            .and_then(Value::as_mapping_mut)
+        {
+            labels.retain(|target, _| targets.contains(target));
+        }
+    });
+}
@@ -6,7 +6,7 @@ use std::thread;
 use std::time::Duration;
 
 use chrono::{TimeZone, Utc};
-use cucumber::{given, when};
+use cucumber::{given, then, when};
 use reqwest::blocking::Client;
 use serde_json::json;
 
@@ -149,9 +149,13 @@ fn given_issue_exists_with_title(world: &mut KanbusWorld, identifier: String, ti
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: BTreeMap::new(),
     };
     write_issue_file(&project_dir, &issue);
@@ -258,3 +262,81 @@ fn given_console_search_query(world: &mut KanbusWorld, query: String) {
         }),
     );
 }
+
+// ---------------------------------------------------------------------------
+// Console API requests (requires running server — @console scenarios)
+// ---------------------------------------------------------------------------
+
+#[when(expr = "I request {string} from the console server")]
+fn when_request_from_console_server(world: &mut KanbusWorld, path: String) {
+    let url = format!("{}{path}", console_base_url(world));
+    let (status, body) = thread::spawn(move || {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .expect("build http client");
+        let response = client.get(&url).send().expect("console request");
+        let status = response.status().as_u16();
+        (status, response.text().unwrap_or_default())
+    })
+    .join()
+    .expect("console request thread");
+    world.console_response_status = Some(status);
+    world.console_response_body = Some(body);
+}
+
+#[when(expr = "I send {word} {string} to the console server with body {string}")]
+fn when_send_to_console_server(
+    world: &mut KanbusWorld,
+    method: String,
+    path: String,
+    body: String,
+) {
+    let url = format!("{}{path}", console_base_url(world));
+    let body = body.replace("\\\"", "\"");
+    let (status, body) = thread::spawn(move || {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .expect("build http client");
+        let method = reqwest::Method::from_bytes(method.as_bytes()).expect("http method");
+        let response = client
+            .request(method, &url)
+            .header("content-type", "application/json")
+            .body(body)
+            .send()
+            .expect("console request");
+        let status = response.status().as_u16();
+        (status, response.text().unwrap_or_default())
+    })
+    .join()
+    .expect("console request thread");
+    world.console_response_status = Some(status);
+    world.console_response_body = Some(body);
+}
+
+#[then(expr = "the console response status should be {int}")]
+fn then_console_response_status(world: &mut KanbusWorld, status: u16) {
+    assert_eq!(world.console_response_status, Some(status));
+}
+
+#[then(expr = "the console response should contain {string}")]
+fn then_console_response_contains(world: &mut KanbusWorld, text: String) {
+    let body = world.console_response_body.as_deref().unwrap_or("");
+    assert!(body.contains(&text), "body: {body}");
+}
+
+#[then(expr = "the console response should list {int} of {int} issues")]
+fn then_console_response_lists_issues(world: &mut KanbusWorld, count: usize, total: usize) {
+    let body = world.console_response_body.as_deref().unwrap_or("");
+    let payload: serde_json::Value = serde_json::from_str(body).expect("parse issues response");
+    let issues = payload["issues"].as_array().expect("issues array");
+    assert_eq!(issues.len(), count, "body: {body}");
+    assert_eq!(payload["total"], total, "body: {body}");
+}
+
+#[then(expr = "the console response should not contain {string}")]
+fn then_console_response_not_contains(world: &mut KanbusWorld, text: String) {
+    let body = world.console_response_body.as_deref().unwrap_or("");
+    assert!(!body.contains(&text), "body: {body}");
+}
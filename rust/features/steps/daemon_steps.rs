@@ -6,16 +6,22 @@ use std::path::PathBuf;
 use std::thread;
 use std::time::Duration;
 
+use chrono::Utc;
 use cucumber::{given, then, when};
 
+use kanbus::cache::load_cache_if_valid;
 use kanbus::cli::run_from_args_with_output;
 use kanbus::daemon_client::{
     self, has_test_daemon_response, set_test_daemon_response, set_test_daemon_responses,
     set_test_daemon_spawn_disabled, TestDaemonResponse,
 };
-use kanbus::daemon_paths::get_daemon_socket_path;
+use kanbus::daemon_paths::{get_daemon_socket_path, get_index_cache_path};
 use kanbus::daemon_protocol::{RequestEnvelope, ResponseEnvelope, PROTOCOL_VERSION};
 use kanbus::daemon_server::{handle_request_for_testing, run_daemon};
+use kanbus::file_io::load_project_directory;
+use kanbus::issue_files::write_issue_to_file;
+use kanbus::models::IssueData;
+use kanbus::watch::print_notifications;
 use serde_json::Value;
 
 use crate::step_definitions::initialization_steps::KanbusWorld;
@@ -845,3 +851,209 @@ fn when_daemon_spawned(world: &mut KanbusWorld) {
 fn then_daemon_spawn_recorded(world: &mut KanbusWorld) {
     assert!(world.daemon_spawn_called);
 }
+
+#[when(expr = "issue {string} is written while the daemon is running")]
+fn when_issue_written_while_daemon_running(world: &mut KanbusWorld, identifier: String) {
+    let project_dir = load_project_directory(&daemon_root(world)).expect("project dir");
+    let timestamp = Utc::now();
+    let issue = IssueData {
+        identifier: identifier.clone(),
+        title: "Written before shutdown".to_string(),
+        description: String::new(),
+        issue_type: "task".to_string(),
+        status: "open".to_string(),
+        priority: 2,
+        assignee: None,
+        creator: None,
+        parent: None,
+        labels: Vec::new(),
+        dependencies: Vec::new(),
+        comments: Vec::new(),
+        links: Vec::new(),
+        created_at: timestamp,
+        updated_at: timestamp,
+        closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
+        custom: BTreeMap::new(),
+    };
+    let issue_path = project_dir
+        .join("issues")
+        .join(format!("{identifier}.json"));
+    write_issue_to_file(&issue, &issue_path).expect("write issue");
+}
+
+#[then("the daemon socket file should not exist")]
+fn then_daemon_socket_file_missing(world: &mut KanbusWorld) {
+    assert!(!daemon_socket_path(world).exists());
+}
+
+#[then(expr = "the daemon index cache should include {string}")]
+fn then_daemon_index_cache_includes(world: &mut KanbusWorld, identifier: String) {
+    let root = daemon_root(world);
+    let project_dir = load_project_directory(&root).expect("project dir");
+    let cache_path = get_index_cache_path(&root).expect("cache path");
+    let index = load_cache_if_valid(&cache_path, &project_dir.join("issues"))
+        .expect("load cache")
+        .expect("cache should match disk");
+    assert!(index.by_id.contains_key(&identifier));
+}
+
+#[given(expr = "a running daemon reports protocol version {string}")]
+fn given_running_daemon_reports_protocol_version(_world: &mut KanbusWorld, version: String) {
+    std::env::set_var("KANBUS_NO_DAEMON", "0");
+    let mut result = BTreeMap::new();
+    result.insert("status".to_string(), Value::String("ok".to_string()));
+    let response = ResponseEnvelope {
+        protocol_version: version,
+        request_id: "req-ping".to_string(),
+        status: "ok".to_string(),
+        result: Some(result),
+        error: None,
+    };
+    set_test_daemon_response(Some(TestDaemonResponse::Envelope(response)));
+    set_test_daemon_spawn_disabled(true);
+}
+
+#[given(expr = "the daemon connection fails {int} times then succeeds")]
+fn given_daemon_connection_fails_then_succeeds(_world: &mut KanbusWorld, failures: usize) {
+    let mut result = BTreeMap::new();
+    result.insert("status".to_string(), Value::String("ok".to_string()));
+    let response = ResponseEnvelope {
+        protocol_version: PROTOCOL_VERSION.to_string(),
+        request_id: "req-retry".to_string(),
+        status: "ok".to_string(),
+        result: Some(result),
+        error: None,
+    };
+    let mut responses = vec![TestDaemonResponse::IoError; failures];
+    responses.push(TestDaemonResponse::Envelope(response));
+    set_test_daemon_responses(responses);
+    set_test_daemon_spawn_disabled(true);
+}
+
+#[given(expr = "the daemon idle timeout is {string} seconds")]
+fn given_daemon_idle_timeout(_world: &mut KanbusWorld, seconds: String) {
+    std::env::set_var("KANBUS_DAEMON_IDLE_SECS", seconds);
+}
+
+#[then(expr = "the daemon status should include {string}")]
+fn then_daemon_status_includes(world: &mut KanbusWorld, key: String) {
+    let payload = world
+        .daemon_status_payload
+        .as_ref()
+        .expect("daemon payload");
+    assert!(payload.contains_key(&key));
+}
+
+#[then("the daemon entry point should stop on its own")]
+fn then_daemon_entry_stops_on_its_own(world: &mut KanbusWorld) {
+    let handle = world.daemon_thread.take().expect("daemon thread");
+    let deadline = std::time::Instant::now() + Duration::from_secs(10);
+    while !handle.is_finished() {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "daemon did not stop after going idle"
+        );
+        thread::sleep(Duration::from_millis(50));
+    }
+    let _ = handle.join();
+    world.daemon_entry_running = false;
+}
+
+#[given(expr = "the daemon client retry count is {string}")]
+fn given_daemon_client_retry_count(_world: &mut KanbusWorld, retries: String) {
+    std::env::set_var("KANBUS_DAEMON_RETRIES", retries);
+}
+
+fn subscribe_watcher(world: &mut KanbusWorld, json: bool) {
+    let reader = daemon_client::subscribe_notifications(&daemon_root(world)).expect("subscribe");
+    world.watch_thread = Some(thread::spawn(move || {
+        let mut output = Vec::new();
+        let _ = print_notifications(reader, json, Some(1), &mut output);
+        String::from_utf8(output).expect("utf-8 watch output")
+    }));
+}
+
+fn watch_output(world: &mut KanbusWorld) -> String {
+    let handle = world.watch_thread.take().expect("watcher not started");
+    for _ in 0..100 {
+        if handle.is_finished() {
+            return handle.join().expect("watcher thread panicked");
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+    panic!("no notification reached the watcher");
+}
+
+#[when("I subscribe to daemon notifications")]
+fn when_subscribe_to_notifications(world: &mut KanbusWorld) {
+    subscribe_watcher(world, false);
+}
+
+#[when("I subscribe to daemon notifications as JSON")]
+fn when_subscribe_to_notifications_json(world: &mut KanbusWorld) {
+    subscribe_watcher(world, true);
+}
+
+#[then(expr = "the watch output should be {string}")]
+fn then_watch_output_is(world: &mut KanbusWorld, expected: String) {
+    assert_eq!(watch_output(world).trim_end(), expected);
+}
+
+#[then(expr = "the watch output should contain {string}")]
+fn then_watch_output_contains(world: &mut KanbusWorld, expected: String) {
+    let output = watch_output(world);
+    let normalized = expected.replace("\\\"", "\"");
+    assert!(output.contains(&normalized), "watch output: {output}");
+}
+
+fn issue_action_request(action: String, payload: String) -> RequestEnvelope {
+    let payload: BTreeMap<String, Value> =
+        serde_json::from_str(&payload.replace("\\\"", "\"")).expect("parse daemon payload");
+    RequestEnvelope {
+        protocol_version: PROTOCOL_VERSION.to_string(),
+        request_id: "req-issue-action".to_string(),
+        action,
+        payload,
+    }
+}
+
+fn record_daemon_response(world: &mut KanbusWorld, response: ResponseEnvelope) {
+    world.daemon_response_status = Some(response.status);
+    world.daemon_response_code = response.error.map(|error| error.code);
+    world.daemon_status_payload = response.result;
+}
+
+#[when(expr = "a daemon {string} request with payload {string} is handled directly")]
+fn when_handle_issue_action_directly(world: &mut KanbusWorld, action: String, payload: String) {
+    let request = issue_action_request(action, payload);
+    let response = handle_request_for_testing(&daemon_root(world), request);
+    record_daemon_response(world, response);
+}
+
+#[when(expr = "I send a daemon {string} request with payload {string} over the socket")]
+fn when_send_issue_action_over_socket(world: &mut KanbusWorld, action: String, payload: String) {
+    #[cfg(unix)]
+    {
+        let request = issue_action_request(action, payload);
+        let line = format!(
+            "{}\n",
+            serde_json::to_string(&request).expect("serialize daemon request")
+        );
+        let response = send_raw_payload_over_socket(world, &line);
+        record_daemon_response(world, response);
+    }
+}
+
+#[then(expr = "the daemon response issue should have {word} {string}")]
+fn then_daemon_response_issue_field(world: &mut KanbusWorld, field: String, expected: String) {
+    assert_eq!(world.daemon_response_status.as_deref(), Some("ok"));
+    let issue = world
+        .daemon_status_payload
+        .as_ref()
+        .and_then(|result| result.get("issue"))
+        .expect("daemon response issue");
+    assert_eq!(issue[field.as_str()], Value::String(expected));
+}
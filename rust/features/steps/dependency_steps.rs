@@ -46,9 +46,13 @@ fn build_issue(identifier: &str) -> IssueData {
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     }
 }
@@ -92,6 +96,59 @@ fn given_issue_depends_on(
     write_issue_file(&project_dir, &issue);
 }
 
+fn configured_virtual_project_dir(world: &KanbusWorld, label: &str) -> PathBuf {
+    let root = world.working_directory.as_ref().expect("cwd");
+    let relative = format!("virtual/{label}/project");
+    let project_dir = root.join(&relative);
+    if !project_dir.is_dir() {
+        fs::create_dir_all(project_dir.join("issues")).expect("create virtual issues");
+        let config_path = root.join(".kanbus.yml");
+        let contents = fs::read_to_string(&config_path).expect("read config");
+        let mut configuration: serde_yaml::Mapping =
+            serde_yaml::from_str(&contents).expect("parse config");
+        let key = serde_yaml::Value::String("virtual_projects".to_string());
+        let mut projects = configuration
+            .get(&key)
+            .and_then(|value| value.as_mapping())
+            .cloned()
+            .unwrap_or_default();
+        let mut entry = serde_yaml::Mapping::new();
+        entry.insert("path".into(), relative.into());
+        projects.insert(label.into(), entry.into());
+        configuration.insert(key, projects.into());
+        let payload = serde_yaml::to_string(&configuration).expect("serialize config");
+        fs::write(&config_path, payload).expect("write config");
+    }
+    project_dir
+}
+
+#[given(expr = "virtual project {string} contains issue {string}")]
+fn given_virtual_project_contains_issue(
+    world: &mut KanbusWorld,
+    label: String,
+    identifier: String,
+) {
+    let project_dir = configured_virtual_project_dir(world, &label);
+    write_issue_file(&project_dir, &build_issue(&identifier));
+}
+
+#[given(expr = "virtual project {string} issue {string} depends on {string} with type {string}")]
+fn given_virtual_project_issue_depends_on(
+    world: &mut KanbusWorld,
+    label: String,
+    identifier: String,
+    target: String,
+    dependency_type: String,
+) {
+    let project_dir = configured_virtual_project_dir(world, &label);
+    let mut issue = build_issue(&identifier);
+    issue.dependencies.push(DependencyLink {
+        target,
+        dependency_type,
+    });
+    write_issue_file(&project_dir, &issue);
+}
+
 #[then(expr = "issue {string} has dependency {string} of type {string}")]
 fn then_issue_has_dependency(
     world: &mut KanbusWorld,
@@ -190,9 +247,39 @@ fn then_issue_should_not_depend_on(
         .any(|link| link.target == target && link.dependency_type == dependency_type));
 }
 
+#[then(expr = "exactly one of {string} and {string} should be blocked by the other")]
+fn then_exactly_one_blocked_by_other(world: &mut KanbusWorld, first: String, second: String) {
+    let project_dir = load_project_dir(world);
+    let is_blocked_by = |identifier: &str, target: &str| {
+        read_issue_file(&project_dir, identifier)
+            .dependencies
+            .iter()
+            .any(|link| link.target == target && link.dependency_type == "blocked-by")
+    };
+    assert_ne!(
+        is_blocked_by(&first, &second),
+        is_blocked_by(&second, &first)
+    );
+}
+
 #[then(expr = "issue {string} should have 1 dependency")]
 fn then_issue_has_single_dependency(world: &mut KanbusWorld, identifier: String) {
     let project_dir = load_project_dir(world);
     let issue = read_issue_file(&project_dir, &identifier);
     assert_eq!(issue.dependencies.len(), 1);
 }
+
+#[then(expr = "stdout should contain the edge {string} {string} {string}")]
+fn then_stdout_contains_edge(
+    world: &mut KanbusWorld,
+    source: String,
+    dependency_type: String,
+    target: String,
+) {
+    let stdout = world.stdout.as_ref().expect("stdout");
+    let expected = format!("{source}\t{dependency_type}\t{target}");
+    assert!(
+        stdout.lines().any(|line| line == expected),
+        "edge not found in stdout: {expected}"
+    );
+}
@@ -38,9 +38,13 @@ fn build_issue(identifier: &str, title: &str) -> IssueData {
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     }
 }
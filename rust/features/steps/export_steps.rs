@@ -0,0 +1,66 @@
+use std::fs;
+use std::path::PathBuf;
+
+use cucumber::{given, then};
+use serde_json::Value;
+
+use kanbus::file_io::load_project_directory;
+
+use crate::step_definitions::initialization_steps::KanbusWorld;
+
+fn load_project_dir(world: &KanbusWorld) -> PathBuf {
+    let cwd = world.working_directory.as_ref().expect("cwd");
+    load_project_directory(cwd).expect("project dir")
+}
+
+#[given(expr = "issue {string} has custom field {string} set to {string}")]
+fn given_issue_custom_field(
+    world: &mut KanbusWorld,
+    identifier: String,
+    field: String,
+    value: String,
+) {
+    let project_dir = load_project_dir(world);
+    let issue_path = project_dir
+        .join("issues")
+        .join(format!("{identifier}.json"));
+    let contents = fs::read_to_string(&issue_path).expect("read issue");
+    let mut payload: Value = serde_json::from_str(&contents).expect("parse issue");
+    payload["custom"][field] = Value::String(value);
+    let updated = serde_json::to_string_pretty(&payload).expect("serialize issue");
+    fs::write(issue_path, updated).expect("write issue");
+}
+
+#[given(expr = "issue {string} has a title with commas and quotes")]
+fn given_issue_title_with_commas_and_quotes(world: &mut KanbusWorld, identifier: String) {
+    let project_dir = load_project_dir(world);
+    let issue_path = project_dir
+        .join("issues")
+        .join(format!("{identifier}.json"));
+    let contents = fs::read_to_string(&issue_path).expect("read issue");
+    let mut payload: Value = serde_json::from_str(&contents).expect("parse issue");
+    payload["title"] = Value::String("Fix \"login\", then logout".to_string());
+    let updated = serde_json::to_string_pretty(&payload).expect("serialize issue");
+    fs::write(issue_path, updated).expect("write issue");
+}
+
+#[then("stdout lines should be at most 75 octets")]
+fn then_stdout_lines_at_most_75_octets(world: &mut KanbusWorld) {
+    let stdout = world.stdout.as_ref().expect("stdout");
+    for line in stdout.split("\r\n") {
+        assert!(line.len() <= 75, "line exceeds 75 octets: {line}");
+    }
+}
+
+#[then("stdout should end with CRLF")]
+fn then_stdout_ends_with_crlf(world: &mut KanbusWorld) {
+    let stdout = world.stdout.as_ref().expect("stdout");
+    assert!(stdout.ends_with("\r\n"));
+}
+
+#[then(expr = "the export file {string} should contain {string}")]
+fn then_export_file_contains(world: &mut KanbusWorld, path: String, expected: String) {
+    let cwd = world.working_directory.as_ref().expect("cwd");
+    let contents = fs::read_to_string(cwd.join(path)).expect("read export file");
+    assert!(contents.contains(&expected.replace("\\\"", "\"")));
+}
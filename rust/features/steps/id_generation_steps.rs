@@ -26,6 +26,11 @@ fn given_project_existing_issue(world: &mut KanbusWorld, identifier: String) {
     world.id_prefix = Some(prefix.to_string());
 }
 
+#[given(expr = "the ID prefix is {string}")]
+fn given_id_prefix(world: &mut KanbusWorld, prefix: String) {
+    world.id_prefix = Some(prefix);
+}
+
 #[when("I generate an issue ID")]
 fn when_generate_issue_id(world: &mut KanbusWorld) {
     let prefix = world
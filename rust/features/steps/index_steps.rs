@@ -10,7 +10,7 @@ use kanbus::cache::{collect_issue_file_mtimes, load_cache_if_valid, write_cache}
 use kanbus::cli::run_from_args_with_output;
 use kanbus::daemon_paths::get_index_cache_path;
 use kanbus::file_io::load_project_directory;
-use kanbus::index::build_index_from_directory;
+use kanbus::index::{build_index_from_directory, issue_file_cache_path, IssueFileCache};
 use kanbus::models::{DependencyLink, IssueData};
 
 use crate::step_definitions::initialization_steps::KanbusWorld;
@@ -65,9 +65,13 @@ fn build_issue(
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     }
 }
@@ -416,3 +420,44 @@ fn then_cached_index_reverse(world: &mut KanbusWorld) {
         .collect();
     assert_eq!(identifiers, vec!["kanbus-blocked"]);
 }
+
+fn read_issue_file_cache(world: &KanbusWorld) -> IssueFileCache {
+    let cache_path = issue_file_cache_path(&load_project_dir(world));
+    let contents = fs::read(cache_path).expect("read issue file cache");
+    serde_json::from_slice(&contents).expect("parse issue file cache")
+}
+
+#[when(expr = "the issue file cache says issue {string} is titled {string}")]
+fn when_issue_file_cache_title(world: &mut KanbusWorld, identifier: String, title: String) {
+    let mut cache = read_issue_file_cache(world);
+    let entry = cache
+        .files
+        .values_mut()
+        .find(|entry| entry.issue.identifier == identifier)
+        .expect("cached issue");
+    entry.issue.title = title;
+    let cache_path = issue_file_cache_path(&load_project_dir(world));
+    fs::write(
+        cache_path,
+        serde_json::to_vec(&cache).expect("serialize cache"),
+    )
+    .expect("write issue file cache");
+}
+
+#[then(expr = "the issue file cache should include {string}")]
+fn then_issue_file_cache_includes(world: &mut KanbusWorld, identifier: String) {
+    let cache = read_issue_file_cache(world);
+    assert!(cache
+        .files
+        .values()
+        .any(|entry| entry.issue.identifier == identifier));
+}
+
+#[then(expr = "the issue file cache should not include {string}")]
+fn then_issue_file_cache_excludes(world: &mut KanbusWorld, identifier: String) {
+    let cache = read_issue_file_cache(world);
+    assert!(!cache
+        .files
+        .values()
+        .any(|entry| entry.issue.identifier == identifier));
+}
@@ -61,9 +61,11 @@ pub struct KanbusWorld {
     pub workflow_error: Option<String>,
     pub index: Option<IssueIndex>,
     pub daemon_thread: Option<JoinHandle<()>>,
+    pub watch_thread: Option<JoinHandle<String>>,
     pub daemon_fake_server: bool,
     pub daemon_mode_disabled: bool,
     pub current_user: Option<String>,
+    pub notification_socket_paths: Option<Vec<PathBuf>>,
     pub original_kanbus_user: Option<Option<String>>,
     pub original_user_env: Option<Option<String>>,
     pub original_canonicalize_failure_env: Option<Option<String>>,
@@ -81,6 +83,9 @@ pub struct KanbusWorld {
     pub console_local_storage: ConsoleLocalStorage,
     pub console_time_zone: Option<String>,
     pub console_port: Option<u16>,
+    pub console_response_status: Option<u16>,
+    pub console_response_body: Option<String>,
+    pub serialized_issue_outputs: Vec<String>,
     pub fake_jira_port: Option<u16>,
     pub fake_jira_shutdown_tx: Option<tokio::sync::oneshot::Sender<()>>,
     pub fake_jira_issues: Vec<serde_json::Value>,
@@ -98,6 +103,7 @@ pub struct KanbusWorld {
     pub kanbusr_version: Option<String>,
     pub kanbusr_has_all: Option<bool>,
     pub sample_issue: Option<kanbus::models::IssueData>,
+    pub issue_snapshot: Option<kanbus::models::IssueData>,
     pub dependency_error: Option<String>,
     pub original_invalid_status_env: Option<Option<String>>,
     pub virtual_project_state: Option<VirtualProjectState>,
@@ -170,6 +176,12 @@ impl Drop for KanbusWorld {
         }
         std::env::remove_var("KANBUS_TEST_EXTERNAL_TOOL_MISSING");
         std::env::remove_var("KANBUS_TEST_EXTERNAL_TIMEOUT_MS");
+        std::env::remove_var("KANBUS_DAEMON_RETRIES");
+        std::env::remove_var("KANBUS_DAEMON_TIMEOUT_MS");
+        std::env::remove_var("KANBUS_DAEMON_IDLE_SECS");
+        std::env::remove_var("KANBUS_PAGER");
+        std::env::remove_var("KANBUS_EDITOR");
+        std::env::remove_var("KANBUS_TEST_INTERACTIVE");
         daemon_client::set_test_daemon_response(None);
         daemon_client::set_test_daemon_spawn_disabled(false);
         if let Some(tx) = self.fake_jira_shutdown_tx.take() {
@@ -258,6 +270,29 @@ fn then_marker_created(world: &mut KanbusWorld) {
     assert!(cwd.join(".kanbus.yml").is_file());
 }
 
+#[then(expr = "the configured hierarchy should be {string}")]
+fn then_configured_hierarchy(world: &mut KanbusWorld, expected: String) {
+    let configuration = load_created_configuration(world);
+    assert_eq!(configuration.hierarchy.join(", "), expected);
+}
+
+#[then(expr = "the configured statuses should be {string}")]
+fn then_configured_statuses(world: &mut KanbusWorld, expected: String) {
+    let configuration = load_created_configuration(world);
+    let keys: Vec<&str> = configuration
+        .statuses
+        .iter()
+        .map(|status| status.key.as_str())
+        .collect();
+    assert_eq!(keys.join(", "), expected);
+}
+
+fn load_created_configuration(world: &KanbusWorld) -> ProjectConfiguration {
+    let cwd = world.working_directory.as_ref().expect("cwd");
+    kanbus::config_loader::load_project_configuration(&cwd.join(".kanbus.yml"))
+        .expect("load configuration")
+}
+
 #[then("a \"CONTRIBUTING_AGENT.template.md\" file should be created")]
 fn then_project_management_template_created(world: &mut KanbusWorld) {
     let cwd = world.working_directory.as_ref().expect("cwd");
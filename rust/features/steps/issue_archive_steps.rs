@@ -0,0 +1,43 @@
+use std::path::PathBuf;
+
+use chrono::{Duration, Utc};
+use cucumber::{given, then};
+
+use crate::step_definitions::initialization_steps::KanbusWorld;
+use kanbus::file_io::load_project_directory;
+use kanbus::issue_files::{read_issue_from_file, write_issue_to_file};
+
+fn load_project_dir(world: &KanbusWorld) -> PathBuf {
+    let cwd = world.working_directory.as_ref().expect("cwd");
+    load_project_directory(cwd).expect("project dir")
+}
+
+#[given(expr = "issue {string} was closed {int} days ago")]
+fn given_issue_closed_days_ago(world: &mut KanbusWorld, identifier: String, days: i64) {
+    let project_dir = load_project_dir(world);
+    let issue_path = project_dir
+        .join("issues")
+        .join(format!("{identifier}.json"));
+    let mut issue = read_issue_from_file(&issue_path).expect("read issue");
+    let closed_at = Utc::now() - Duration::days(days);
+    issue.status = "closed".to_string();
+    issue.closed_at = Some(closed_at);
+    issue.updated_at = closed_at;
+    write_issue_to_file(&issue, &issue_path).expect("write issue");
+}
+
+#[then(expr = "issue {string} should be archived")]
+fn then_issue_archived(world: &mut KanbusWorld, identifier: String) {
+    let project_dir = load_project_dir(world);
+    let file_name = format!("{identifier}.json");
+    assert!(project_dir.join("archive").join(&file_name).is_file());
+    assert!(!project_dir.join("issues").join(&file_name).exists());
+}
+
+#[then(expr = "issue {string} should not be archived")]
+fn then_issue_not_archived(world: &mut KanbusWorld, identifier: String) {
+    let project_dir = load_project_dir(world);
+    let file_name = format!("{identifier}.json");
+    assert!(!project_dir.join("archive").join(&file_name).exists());
+    assert!(project_dir.join("issues").join(&file_name).is_file());
+}
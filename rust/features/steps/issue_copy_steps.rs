@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{TimeZone, Utc};
+use cucumber::{given, then};
+
+use kanbus::file_io::load_project_directory;
+use kanbus::models::{IssueComment, IssueData};
+
+use crate::step_definitions::initialization_steps::KanbusWorld;
+
+fn load_project_dir(world: &KanbusWorld) -> PathBuf {
+    let cwd = world.working_directory.as_ref().expect("cwd");
+    load_project_directory(cwd).expect("project dir")
+}
+
+fn load_issues(project_dir: &Path) -> Vec<IssueData> {
+    fs::read_dir(project_dir.join("issues"))
+        .expect("read issues dir")
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("json"))
+        .map(|path| {
+            let contents = fs::read_to_string(path).expect("read issue");
+            serde_json::from_str(&contents).expect("parse issue")
+        })
+        .collect()
+}
+
+fn load_copy(world: &KanbusWorld, source: &str) -> IssueData {
+    let project_dir = load_project_dir(world);
+    let copies: Vec<IssueData> = load_issues(&project_dir)
+        .into_iter()
+        .filter(|issue| issue.identifier != source)
+        .collect();
+    assert_eq!(copies.len(), 1, "expected exactly one copy");
+    copies.into_iter().next().expect("copy")
+}
+
+#[given(expr = "an in-progress issue {string} with label {string} and a comment exists")]
+fn given_in_progress_issue_with_label_and_comment(
+    world: &mut KanbusWorld,
+    identifier: String,
+    label: String,
+) {
+    let project_dir = load_project_dir(world);
+    let timestamp = Utc.with_ymd_and_hms(2026, 2, 11, 0, 0, 0).unwrap();
+    let issue = IssueData {
+        identifier: identifier.clone(),
+        title: "Weekly chore".to_string(),
+        description: "Rotate the logs".to_string(),
+        issue_type: "task".to_string(),
+        status: "in_progress".to_string(),
+        priority: 1,
+        assignee: Some("dev@example.com".to_string()),
+        creator: None,
+        parent: None,
+        labels: vec![label],
+        dependencies: Vec::new(),
+        comments: vec![IssueComment {
+            id: Some("c1".to_string()),
+            author: "dev@example.com".to_string(),
+            text: "Done this week".to_string(),
+            created_at: timestamp,
+            system: false,
+            resolved: false,
+            reply_to: None,
+        }],
+        created_at: timestamp,
+        updated_at: timestamp,
+        closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
+        links: Vec::new(),
+        custom: std::collections::BTreeMap::new(),
+    };
+    let issue_path = project_dir
+        .join("issues")
+        .join(format!("{identifier}.json"));
+    let contents = serde_json::to_string_pretty(&issue).expect("serialize issue");
+    fs::write(issue_path, contents).expect("write issue");
+}
+
+#[then(expr = "a copy of {string} should exist with a new id")]
+fn then_copy_exists_with_new_id(world: &mut KanbusWorld, source: String) {
+    let copy = load_copy(world, &source);
+    assert_ne!(copy.identifier, source);
+    assert!(copy.identifier.starts_with("kanbus-"));
+}
+
+#[then(expr = "the copy of {string} should have status {string}")]
+fn then_copy_has_status(world: &mut KanbusWorld, source: String, status: String) {
+    let copy = load_copy(world, &source);
+    assert_eq!(copy.status, status);
+}
+
+#[then(expr = "the copy of {string} should have labels {string}")]
+fn then_copy_has_labels(world: &mut KanbusWorld, source: String, labels: String) {
+    let copy = load_copy(world, &source);
+    assert_eq!(copy.labels.join(", "), labels);
+}
+
+#[then(expr = "the copy of {string} should have no comments")]
+fn then_copy_has_no_comments(world: &mut KanbusWorld, source: String) {
+    let copy = load_copy(world, &source);
+    assert!(copy.comments.is_empty());
+}
+
+#[then(expr = "the copy of {string} should have creator {string}")]
+fn then_copy_has_creator(world: &mut KanbusWorld, source: String, creator: String) {
+    let copy = load_copy(world, &source);
+    assert_eq!(copy.creator.as_deref(), Some(creator.as_str()));
+}
+
+#[then(expr = "the copy of {string} should relate to {string}")]
+fn then_copy_relates_to(world: &mut KanbusWorld, source: String, target: String) {
+    let copy = load_copy(world, &source);
+    assert!(copy
+        .dependencies
+        .iter()
+        .any(|link| link.target == target && link.dependency_type == "relates-to"));
+}
@@ -56,10 +56,15 @@ fn when_create_issue_directly(world: &mut KanbusWorld) {
         priority: None,
         assignee: None,
         parent: None,
+        priority_from_parent: false,
         labels: Vec::new(),
         description: None,
+        status: None,
         local: false,
         validate: true,
+        allow_duplicate_title: false,
+        due_at: None,
+        estimate_minutes: None,
     };
     match create_issue(&request) {
         Ok(_) => {
@@ -227,6 +232,11 @@ fn then_issue_file_created(world: &mut KanbusWorld) {
     assert_eq!(count, 1);
 }
 
+#[then(expr = "the issues directory should contain {int} issue files")]
+fn then_issues_directory_contains_files(world: &mut KanbusWorld, count: i32) {
+    then_issues_directory_contains_count(world, count);
+}
+
 #[then(expr = "the issues directory should contain {int} issue file")]
 fn then_issues_directory_contains_count(world: &mut KanbusWorld, count: i32) {
     let project_dir = load_project_dir(world);
@@ -263,20 +273,61 @@ fn then_created_issue_type_task(world: &mut KanbusWorld) {
     assert_eq!(payload["type"], "task");
 }
 
-#[then("the created issue should have status \"open\"")]
-fn then_created_issue_status(world: &mut KanbusWorld) {
+#[then(expr = "the created issue should have status {string}")]
+fn then_created_issue_status(world: &mut KanbusWorld, status: String) {
+    let identifier = capture_issue_identifier(world);
+    let project_dir = load_project_dir(world);
+    let payload = load_issue_json(&project_dir, &identifier);
+    assert_eq!(payload["status"], status);
+}
+
+#[then(expr = "the created issue should have priority {int}")]
+fn then_created_issue_priority(world: &mut KanbusWorld, priority: i64) {
+    let identifier = capture_issue_identifier(world);
+    let project_dir = load_project_dir(world);
+    let payload = load_issue_json(&project_dir, &identifier);
+    assert_eq!(payload["priority"], priority);
+}
+
+#[then(expr = "the created issue should have sequence number {int}")]
+fn then_created_issue_sequence(world: &mut KanbusWorld, sequence: i64) {
     let identifier = capture_issue_identifier(world);
     let project_dir = load_project_dir(world);
     let payload = load_issue_json(&project_dir, &identifier);
-    assert_eq!(payload["status"], "open");
+    assert_eq!(payload["custom"]["seq"], sequence);
 }
 
-#[then("the created issue should have priority 2")]
-fn then_created_issue_priority(world: &mut KanbusWorld) {
+#[then(expr = "the created issue should have description {string}")]
+fn then_created_issue_description(world: &mut KanbusWorld, description: String) {
     let identifier = capture_issue_identifier(world);
     let project_dir = load_project_dir(world);
     let payload = load_issue_json(&project_dir, &identifier);
-    assert_eq!(payload["priority"], 2);
+    assert_eq!(payload["description"], description.replace("\\n", "\n"));
+}
+
+#[then(expr = "the created issue should be due at {string}")]
+fn then_created_issue_due_at(world: &mut KanbusWorld, due_at: String) {
+    let identifier = capture_issue_identifier(world);
+    let project_dir = load_project_dir(world);
+    let payload = load_issue_json(&project_dir, &identifier);
+    assert_eq!(payload["due_at"], due_at);
+}
+
+#[then(expr = "the created issue should have an estimate of {int} minutes")]
+fn then_created_issue_estimate(world: &mut KanbusWorld, minutes: u32) {
+    let identifier = capture_issue_identifier(world);
+    let project_dir = load_project_dir(world);
+    let payload = load_issue_json(&project_dir, &identifier);
+    assert_eq!(payload["estimate_minutes"], minutes);
+}
+
+#[then(expr = "the created issue identifier should start with {string}")]
+fn then_created_issue_identifier_prefix(world: &mut KanbusWorld, prefix: String) {
+    let identifier = capture_issue_identifier(world);
+    assert!(
+        identifier.starts_with(&prefix),
+        "identifier {identifier} does not start with {prefix}"
+    );
 }
 
 #[then("the created issue should have an empty labels list")]
@@ -319,14 +370,6 @@ fn then_created_issue_type_bug(world: &mut KanbusWorld) {
     assert_eq!(payload["type"], "bug");
 }
 
-#[then("the created issue should have priority 1")]
-fn then_created_issue_priority_one(world: &mut KanbusWorld) {
-    let identifier = capture_issue_identifier(world);
-    let project_dir = load_project_dir(world);
-    let payload = load_issue_json(&project_dir, &identifier);
-    assert_eq!(payload["priority"], 1);
-}
-
 #[then("the created issue should have assignee \"dev@example.com\"")]
 fn then_created_issue_assignee(world: &mut KanbusWorld) {
     let identifier = capture_issue_identifier(world);
@@ -357,14 +400,6 @@ fn then_created_issue_labels(world: &mut KanbusWorld) {
     assert_eq!(labels, vec!["auth", "urgent"]);
 }
 
-#[then("the created issue should have description \"Bug in login\"")]
-fn then_created_issue_description(world: &mut KanbusWorld) {
-    let identifier = capture_issue_identifier(world);
-    let project_dir = load_project_dir(world);
-    let payload = load_issue_json(&project_dir, &identifier);
-    assert_eq!(payload["description"], "Bug in login");
-}
-
 #[then("the created issue should have no parent")]
 fn then_created_issue_no_parent(world: &mut KanbusWorld) {
     let identifier = capture_issue_identifier(world);
@@ -372,3 +407,43 @@ fn then_created_issue_no_parent(world: &mut KanbusWorld) {
     let payload = load_issue_json(&project_dir, &identifier);
     assert!(payload["parent"].is_null());
 }
+
+fn install_fake_editor(world: &mut KanbusWorld, script: &str) {
+    if world.external_tool_dir.is_none() {
+        world.external_tool_dir = Some(TempDir::new().expect("create editor temp dir"));
+    }
+    let dir = world
+        .external_tool_dir
+        .as_ref()
+        .expect("editor temp dir")
+        .path();
+    let editor_path = dir.join("fake-editor");
+    fs::write(&editor_path, format!("#!/bin/sh\n{script}\n")).expect("write editor");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut permissions = fs::metadata(&editor_path)
+            .expect("editor metadata")
+            .permissions();
+        permissions.set_mode(0o755);
+        fs::set_permissions(&editor_path, permissions).expect("set editor permissions");
+    }
+    env::set_var(kanbus::editor::EDITOR_ENV, &editor_path);
+    env::set_var("KANBUS_TEST_INTERACTIVE", "1");
+}
+
+#[given(expr = "the editor replaces the issue text with {string}")]
+fn given_editor_replaces_text(world: &mut KanbusWorld, text: String) {
+    let text = text.replace("\\n", "\n");
+    install_fake_editor(world, &format!("cat > \"$1\" <<'EOF'\n{text}\nEOF"));
+}
+
+#[given("the editor leaves the issue text unchanged")]
+fn given_editor_leaves_text_unchanged(world: &mut KanbusWorld) {
+    install_fake_editor(world, "exit 0");
+}
+
+#[given(expr = "the editor exits with status {int}")]
+fn given_editor_exits_with_status(world: &mut KanbusWorld, status: i32) {
+    install_fake_editor(world, &format!("exit {status}"));
+}
@@ -0,0 +1,90 @@
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::{TimeZone, Utc};
+use cucumber::{given, then};
+
+use kanbus::file_io::load_project_directory;
+use kanbus::issue_files::read_issue_from_file;
+use kanbus::models::IssueData;
+
+use crate::step_definitions::initialization_steps::KanbusWorld;
+
+fn load_project_dir(world: &KanbusWorld) -> PathBuf {
+    let cwd = world.working_directory.as_ref().expect("cwd");
+    load_project_directory(cwd).expect("project dir")
+}
+
+fn stored_issue(identifier: &str, title: &str) -> IssueData {
+    let timestamp = Utc.with_ymd_and_hms(2026, 2, 11, 0, 0, 0).unwrap();
+    IssueData {
+        identifier: identifier.to_string(),
+        title: title.to_string(),
+        description: "".to_string(),
+        issue_type: "task".to_string(),
+        status: "open".to_string(),
+        priority: 2,
+        assignee: None,
+        creator: None,
+        parent: None,
+        labels: Vec::new(),
+        dependencies: Vec::new(),
+        comments: Vec::new(),
+        links: Vec::new(),
+        created_at: timestamp,
+        updated_at: timestamp,
+        closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
+        custom: std::collections::BTreeMap::new(),
+    }
+}
+
+#[given(expr = "an issue {string} is stored under {string}")]
+fn given_issue_stored_under(world: &mut KanbusWorld, identifier: String, directory: String) {
+    let project_dir = load_project_dir(world);
+    let issue = stored_issue(&identifier, "Nested");
+    let issue_dir = project_dir.join(directory);
+    fs::create_dir_all(&issue_dir).expect("create issue dir");
+    let contents = serde_json::to_string_pretty(&issue).expect("serialize issue");
+    fs::write(issue_dir.join(format!("{identifier}.json")), contents).expect("write issue");
+}
+
+#[given(expr = "a YAML issue {string} titled {string} exists")]
+fn given_yaml_issue_exists(world: &mut KanbusWorld, identifier: String, title: String) {
+    let project_dir = load_project_dir(world);
+    let issue = stored_issue(&identifier, &title);
+    let contents = serde_yaml::to_string(&issue).expect("serialize issue");
+    fs::write(
+        project_dir.join("issues").join(format!("{identifier}.yml")),
+        contents,
+    )
+    .expect("write issue");
+}
+
+fn count_files_with_extension(world: &KanbusWorld, directory: &str, extension: &str) -> usize {
+    let project_dir = load_project_dir(world);
+    fs::read_dir(project_dir.join(directory))
+        .expect("read issue dir")
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().and_then(|ext| ext.to_str()) == Some(extension))
+        .count()
+}
+
+#[then(expr = "{int} issue file(s) should be stored under {string}")]
+fn then_issue_files_stored_under(world: &mut KanbusWorld, count: usize, directory: String) {
+    assert_eq!(count_files_with_extension(world, &directory, "json"), count);
+}
+
+#[then(expr = "{int} YAML issue file(s) should be stored under {string}")]
+fn then_yaml_issue_files_stored_under(world: &mut KanbusWorld, count: usize, directory: String) {
+    assert_eq!(count_files_with_extension(world, &directory, "yml"), count);
+}
+
+#[then(expr = "issue {string} should be stored at {string}")]
+fn then_issue_stored_at(world: &mut KanbusWorld, identifier: String, relative_path: String) {
+    let project_dir = load_project_dir(world);
+    let issue = read_issue_from_file(&project_dir.join(relative_path)).expect("read issue");
+    assert_eq!(issue.identifier, identifier);
+}
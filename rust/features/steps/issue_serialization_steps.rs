@@ -0,0 +1,63 @@
+use std::fs;
+use std::path::PathBuf;
+
+use cucumber::{given, then, when};
+
+use kanbus::file_io::load_project_directory;
+use kanbus::issue_files::{read_issue_from_file, serialize_issue};
+
+use crate::step_definitions::initialization_steps::KanbusWorld;
+
+fn issue_path(world: &KanbusWorld, identifier: &str) -> PathBuf {
+    let cwd = world.working_directory.as_ref().expect("cwd");
+    load_project_directory(cwd)
+        .expect("project dir")
+        .join("issues")
+        .join(format!("{identifier}.json"))
+}
+
+#[given(expr = "issue {string} has a description with Windows line endings")]
+fn given_issue_description_crlf(world: &mut KanbusWorld, identifier: String) {
+    let path = issue_path(world, &identifier);
+    let mut issue = read_issue_from_file(&path).expect("read issue");
+    issue.description = "first line\r\nsecond line\r\n".to_string();
+    let contents = serde_json::to_string_pretty(&issue).expect("serialize issue");
+    fs::write(&path, contents).expect("write issue");
+}
+
+#[when(expr = "I serialize issue {string} twice")]
+fn when_serialize_issue_twice(world: &mut KanbusWorld, identifier: String) {
+    let path = issue_path(world, &identifier);
+    let issue = read_issue_from_file(&path).expect("read issue");
+    let first = serialize_issue(&issue).expect("serialize issue");
+    let reloaded = serde_json::from_str(&first).expect("parse serialized issue");
+    let second = serialize_issue(&reloaded).expect("serialize issue");
+    world.serialized_issue_outputs = vec![first, second];
+}
+
+#[then("the serialized outputs should be byte-identical")]
+fn then_serialized_outputs_identical(world: &mut KanbusWorld) {
+    let outputs = &world.serialized_issue_outputs;
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(outputs[0].as_bytes(), outputs[1].as_bytes());
+}
+
+#[then("the serialized output should end with a single newline")]
+fn then_serialized_output_trailing_newline(world: &mut KanbusWorld) {
+    let output = world.serialized_issue_outputs.first().expect("output");
+    assert!(output.ends_with("}\n"), "output: {output:?}");
+}
+
+#[then("the serialized output should use LF line endings")]
+fn then_serialized_output_lf(world: &mut KanbusWorld) {
+    let output = world.serialized_issue_outputs.first().expect("output");
+    assert!(!output.contains('\r'), "output: {output:?}");
+    assert!(!output.contains("\\r"), "output: {output:?}");
+    assert!(output.contains("first line\\nsecond line"));
+}
+
+#[then(expr = "the issue file for {string} should end with a single newline")]
+fn then_issue_file_trailing_newline(world: &mut KanbusWorld, identifier: String) {
+    let contents = fs::read_to_string(issue_path(world, &identifier)).expect("read issue");
+    assert!(contents.ends_with("}\n"), "contents: {contents:?}");
+}
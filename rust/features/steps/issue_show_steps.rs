@@ -101,6 +101,13 @@ fn then_formatted_output_contains_ansi(world: &mut KanbusWorld) {
     assert!(output.contains("\u{1b}["));
 }
 
+#[then(expr = "the formatted output should show label {string} with ANSI code {string}")]
+fn then_formatted_output_label_color(world: &mut KanbusWorld, label: String, code: String) {
+    let output = world.formatted_output.as_deref().unwrap_or("");
+    let expected = format!("\u{1b}[{code}m{label}\u{1b}[");
+    assert!(output.contains(&expected), "output: {output:?}");
+}
+
 #[then(expr = "the formatted output should contain text {string}")]
 fn then_formatted_output_contains_text(world: &mut KanbusWorld, text: String) {
     let output = world.formatted_output.as_deref().unwrap_or("");
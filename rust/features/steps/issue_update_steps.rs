@@ -1,9 +1,11 @@
 use std::fs;
 use std::path::PathBuf;
 
-use cucumber::then;
+use chrono::{DateTime, Utc};
+use cucumber::{given, then};
 
 use kanbus::file_io::load_project_directory;
+use kanbus::issue_files::write_issue_to_file;
 use kanbus::models::IssueData;
 
 use crate::step_definitions::initialization_steps::KanbusWorld;
@@ -28,6 +30,13 @@ fn then_issue_should_have_parent(world: &mut KanbusWorld, identifier: String, pa
     assert_eq!(payload["parent"], parent);
 }
 
+#[then(expr = "issue {string} should have no parent")]
+fn then_issue_should_have_no_parent(world: &mut KanbusWorld, identifier: String) {
+    let project_dir = load_project_dir(world);
+    let payload = load_issue_json(&project_dir, &identifier);
+    assert!(payload["parent"].is_null());
+}
+
 fn load_issue(project_dir: &PathBuf, identifier: &str) -> IssueData {
     let issue_path = project_dir
         .join("issues")
@@ -50,9 +59,43 @@ fn then_issue_has_description(world: &mut KanbusWorld) {
     assert_eq!(issue.description, "Updated description");
 }
 
+#[then(expr = "issue {string} description should be {string}")]
+fn then_issue_description_should_be(world: &mut KanbusWorld, identifier: String, text: String) {
+    let project_dir = load_project_dir(world);
+    let issue = load_issue(&project_dir, &identifier);
+    assert_eq!(issue.description, text.replace("\\n", "\n"));
+}
+
 #[then("issue \"kanbus-aaa\" should have an updated_at timestamp")]
 fn then_issue_has_updated_at(world: &mut KanbusWorld) {
     let project_dir = load_project_dir(world);
     let issue = load_issue(&project_dir, "kanbus-aaa");
     assert!(issue.updated_at.timestamp() > 0);
 }
+
+#[given(expr = "issue {string} was last updated at {string}")]
+fn given_issue_last_updated_at(world: &mut KanbusWorld, identifier: String, timestamp: String) {
+    let project_dir = load_project_dir(world);
+    let mut issue = load_issue(&project_dir, &identifier);
+    issue.updated_at = DateTime::parse_from_rfc3339(&timestamp)
+        .expect("parse timestamp")
+        .with_timezone(&Utc);
+    let issue_path = project_dir
+        .join("issues")
+        .join(format!("{identifier}.json"));
+    write_issue_to_file(&issue, &issue_path).expect("write issue");
+    world.issue_snapshot = Some(issue);
+}
+
+#[then(expr = "issue {string} should only have a newer updated_at")]
+fn then_issue_only_updated_at_advanced(world: &mut KanbusWorld, identifier: String) {
+    let project_dir = load_project_dir(world);
+    let mut issue = load_issue(&project_dir, &identifier);
+    let before = world.issue_snapshot.as_ref().expect("issue snapshot");
+    assert!(issue.updated_at > before.updated_at);
+    issue.updated_at = before.updated_at;
+    assert_eq!(
+        serde_json::to_value(&issue).expect("serialize issue"),
+        serde_json::to_value(before).expect("serialize issue")
+    );
+}
@@ -6,7 +6,7 @@ use cucumber::{given, then, when};
 
 use kanbus::config_loader::load_project_configuration;
 use kanbus::file_io::load_project_directory;
-use kanbus::issue_line::{compute_widths, format_issue_line};
+use kanbus::issue_line::{compute_widths, format_grouped_issue_lines, format_issue_line};
 use kanbus::models::IssueData;
 
 use crate::step_definitions::initialization_steps::KanbusWorld;
@@ -38,9 +38,13 @@ fn build_issue(
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     }
 }
@@ -238,3 +242,37 @@ fn then_formatted_output_has_no_ansi(world: &mut KanbusWorld) {
     assert!(!lines.is_empty(), "no formatted lines");
     assert!(lines.iter().all(|line| !line.contains("\u{1b}[")));
 }
+
+#[when(expr = "I format the project issues grouped by {string} with color enabled")]
+fn when_format_grouped_issues(world: &mut KanbusWorld, group_by: String) {
+    let project_dir = load_project_dir(world);
+    let config_path = project_dir
+        .parent()
+        .unwrap_or(&project_dir)
+        .join(".kanbus.yml");
+    let configuration = load_project_configuration(&config_path).expect("load configuration");
+    let issues: Vec<IssueData> = fs::read_dir(project_dir.join("issues"))
+        .expect("read issues dir")
+        .map(|entry| {
+            let contents = fs::read_to_string(entry.expect("entry").path()).expect("read issue");
+            serde_json::from_str(&contents).expect("parse issue")
+        })
+        .collect();
+    let output = format_grouped_issue_lines(
+        &issues,
+        &group_by,
+        false,
+        false,
+        Some(&configuration),
+        Some(true),
+    )
+    .expect("format grouped issues");
+    world.formatted_output = Some(output);
+}
+
+#[then(expr = "the formatted output should show header {string} with ANSI code {string}")]
+fn then_formatted_output_header_color(world: &mut KanbusWorld, header: String, code: String) {
+    let output = world.formatted_output.as_deref().unwrap_or("");
+    let expected = format!("\u{1b}[{code}m{header}\u{1b}[");
+    assert!(output.contains(&expected), "output: {output:?}");
+}
@@ -47,9 +47,13 @@ fn build_issue(identifier: &str, title: &str) -> IssueData {
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     }
 }
@@ -177,6 +181,42 @@ fn then_issue_missing_shared_shared(world: &mut KanbusWorld) {
     assert!(!issue_path.exists());
 }
 
+#[then(expr = "the shared issue {string} should have title {string}")]
+fn then_shared_issue_has_title(world: &mut KanbusWorld, identifier: String, title: String) {
+    let project_dir = load_project_dir(world);
+    let issue_path = project_dir
+        .join("issues")
+        .join(format!("{identifier}.json"));
+    let contents = fs::read_to_string(issue_path).expect("read shared issue");
+    let issue: IssueData = serde_json::from_str(&contents).expect("parse shared issue");
+    assert_eq!(issue.title, title);
+}
+
+#[then(expr = "the local issue {string} should have title {string}")]
+fn then_local_issue_has_title(world: &mut KanbusWorld, identifier: String, title: String) {
+    let local_dir = local_project_dir(world);
+    let issue_path = local_dir.join("issues").join(format!("{identifier}.json"));
+    let contents = fs::read_to_string(issue_path).expect("read local issue");
+    let issue: IssueData = serde_json::from_str(&contents).expect("parse local issue");
+    assert_eq!(issue.title, title);
+}
+
+#[then(expr = "the shared issue {string} should not exist")]
+fn then_shared_issue_missing(world: &mut KanbusWorld, identifier: String) {
+    let project_dir = load_project_dir(world);
+    let issue_path = project_dir
+        .join("issues")
+        .join(format!("{identifier}.json"));
+    assert!(!issue_path.exists());
+}
+
+#[then(expr = "the local issue {string} should not exist")]
+fn then_local_issue_missing(world: &mut KanbusWorld, identifier: String) {
+    let local_dir = local_project_dir(world);
+    let issue_path = local_dir.join("issues").join(format!("{identifier}.json"));
+    assert!(!issue_path.exists());
+}
+
 #[then(".gitignore should include \"project-local/\"")]
 fn then_gitignore_includes_project_local(world: &mut KanbusWorld) {
     let project_dir = load_project_dir(world);
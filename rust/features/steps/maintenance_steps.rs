@@ -31,9 +31,13 @@ fn build_issue(identifier: &str, issue_type: &str, status: &str) -> IssueData {
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     }
 }
@@ -157,7 +161,7 @@ fn when_validate_project_directly(world: &mut KanbusWorld) {
 #[when("I run doctor diagnostics directly")]
 fn when_run_doctor_directly(world: &mut KanbusWorld) {
     let root = world.working_directory.as_ref().expect("working directory");
-    match run_doctor(root) {
+    match run_doctor(root, false) {
         Ok(_) => {
             world.exit_code = Some(0);
             world.stdout = Some(String::new());
@@ -180,3 +184,32 @@ fn when_collect_workflow_statuses(world: &mut KanbusWorld, _issue_type: String)
 fn then_workflow_status_collection_failed(world: &mut KanbusWorld, message: String) {
     assert_eq!(world.workflow_error.as_deref(), Some(message.as_str()));
 }
+
+#[given(expr = "an issue {string} was created at {string}")]
+fn given_issue_created_at(world: &mut KanbusWorld, identifier: String, created_at: String) {
+    let project_dir = load_project_dir(world);
+    let timestamp = chrono::DateTime::parse_from_rfc3339(&created_at)
+        .expect("parse timestamp")
+        .with_timezone(&Utc);
+    let mut issue = build_issue(&identifier, "task", "open");
+    issue.created_at = timestamp;
+    issue.updated_at = timestamp;
+    write_issue(&project_dir, &issue);
+}
+
+#[given(expr = "issue {string} was closed at {string}")]
+fn given_issue_closed_at(world: &mut KanbusWorld, identifier: String, closed_at: String) {
+    let project_dir = load_project_dir(world);
+    let issue_path = project_dir
+        .join("issues")
+        .join(format!("{identifier}.json"));
+    let contents = fs::read_to_string(&issue_path).expect("read issue");
+    let mut issue: IssueData = serde_json::from_str(&contents).expect("parse issue");
+    let timestamp = chrono::DateTime::parse_from_rfc3339(&closed_at)
+        .expect("parse timestamp")
+        .with_timezone(&Utc);
+    issue.status = "closed".to_string();
+    issue.closed_at = Some(timestamp);
+    issue.updated_at = timestamp;
+    write_issue(&project_dir, &issue);
+}
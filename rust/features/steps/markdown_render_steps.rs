@@ -0,0 +1,10 @@
+use cucumber::when;
+
+use kanbus::markdown_render::render_markdown;
+
+use crate::step_definitions::initialization_steps::KanbusWorld;
+
+#[when(expr = "I render the markdown {string}")]
+fn when_render_markdown(world: &mut KanbusWorld, source: String) {
+    world.stdout = Some(render_markdown(&source.replace("\\\"", "\"")));
+}
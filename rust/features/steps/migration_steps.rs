@@ -55,6 +55,60 @@ fn given_repo_with_beads(world: &mut KanbusWorld) {
     world.temp_dir = Some(temp_dir);
 }
 
+fn append_beads_record(world: &KanbusWorld, identifier: &str, title: &str, priority: Value) {
+    let repo_path = world.working_directory.as_ref().expect("working directory");
+    let issues_path = repo_path.join(".beads").join("issues.jsonl");
+    let mut contents = fs::read_to_string(&issues_path).expect("read issues");
+    if !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    let record = serde_json::json!({
+        "id": identifier,
+        "title": title,
+        "issue_type": "task",
+        "status": "open",
+        "priority": priority,
+        "created_at": "2026-02-11T00:00:00Z",
+        "updated_at": "2026-02-11T00:00:00Z",
+        "dependencies": [],
+        "comments": []
+    });
+    contents.push_str(&format!("{record}\n"));
+    fs::write(&issues_path, contents).expect("write issues");
+}
+
+#[given(expr = "a Beads issue {string} titled {string} is appended to the database")]
+fn given_beads_issue_appended(world: &mut KanbusWorld, identifier: String, title: String) {
+    append_beads_record(world, &identifier, &title, Value::from(2));
+}
+
+#[given(expr = "a Beads issue {string} with priority {string} is appended to the database")]
+fn given_beads_issue_with_priority_appended(
+    world: &mut KanbusWorld,
+    identifier: String,
+    priority: String,
+) {
+    append_beads_record(world, &identifier, "Imported work", Value::String(priority));
+}
+
+#[given(expr = "the Beads issue {string} is retitled {string}")]
+fn given_beads_issue_retitled(world: &mut KanbusWorld, identifier: String, title: String) {
+    let repo_path = world.working_directory.as_ref().expect("working directory");
+    let issues_path = repo_path.join(".beads").join("issues.jsonl");
+    let contents = fs::read_to_string(&issues_path).expect("read issues");
+    let lines = contents
+        .lines()
+        .map(|line| {
+            let mut record: serde_json::Value = serde_json::from_str(line).expect("parse record");
+            if record["id"] == identifier.as_str() {
+                record["title"] = serde_json::Value::String(title.clone());
+            }
+            record.to_string()
+        })
+        .collect::<Vec<_>>();
+    fs::write(&issues_path, format!("{}\n", lines.join("\n"))).expect("write issues");
+}
+
 #[given("a git repository with a .beads issues database containing blank lines")]
 fn given_repo_with_blank_lines(world: &mut KanbusWorld) {
     let temp_dir = tempfile::TempDir::new().expect("tempdir");
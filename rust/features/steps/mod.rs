@@ -15,24 +15,32 @@ pub mod discovery_steps;
 pub mod embedded_assets_steps;
 pub mod event_history_steps;
 pub mod examples_steps;
+pub mod export_steps;
 pub mod id_format_steps;
 pub mod id_generation_steps;
 pub mod index_steps;
 pub mod initialization_steps;
+pub mod issue_archive_steps;
 pub mod issue_close_delete_steps;
+pub mod issue_copy_steps;
 pub mod issue_creation_steps;
 pub mod issue_key_steps;
+pub mod issue_layout_steps;
+pub mod issue_serialization_steps;
 pub mod issue_show_steps;
 pub mod issue_update_steps;
 pub mod jira_sync_steps;
 pub mod list_format_steps;
 pub mod local_issue_steps;
 pub mod maintenance_steps;
+pub mod markdown_render_steps;
 pub mod migration_steps;
+pub mod notification_steps;
 pub mod output_steps;
 pub mod project_steps;
 pub mod pytest_migration_steps;
 pub mod query_steps;
+pub mod time_tracking_steps;
 pub mod users_steps;
 pub mod virtual_project_steps;
 pub mod wiki_steps;
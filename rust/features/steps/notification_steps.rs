@@ -0,0 +1,138 @@
+use std::fs;
+use std::io::{ErrorKind, Read};
+use std::os::unix::net::UnixListener;
+use std::path::PathBuf;
+
+use cucumber::{then, when};
+
+use kanbus::issue_lookup::load_issue_from_project;
+use kanbus::notification_events::NotificationEvent;
+use kanbus::notification_publisher::{
+    flush_notifications, get_notification_socket_path, publish_notification, NOTIFY_DEBOUNCE_ENV,
+    NOTIFY_SOCKET_ENV,
+};
+
+use crate::step_definitions::initialization_steps::KanbusWorld;
+
+fn resolve_from_root_and_subdirectory(world: &mut KanbusWorld, subdirectory: &str) {
+    let root = world.working_directory.clone().expect("working directory");
+    let nested = root.join(subdirectory);
+    fs::create_dir_all(&nested).expect("create subdirectory");
+    world.notification_socket_paths = Some(vec![
+        get_notification_socket_path(&root),
+        get_notification_socket_path(&nested),
+    ]);
+}
+
+#[when(expr = "the notification socket path is resolved from the repository root and {string}")]
+fn when_notification_socket_path_resolved(world: &mut KanbusWorld, subdirectory: String) {
+    resolve_from_root_and_subdirectory(world, &subdirectory);
+}
+
+#[when(
+    expr = "the notification socket path is resolved from the repository root and {string} with KANBUS_NOTIFY_SOCKET set to {string}"
+)]
+fn when_notification_socket_path_resolved_with_env(
+    world: &mut KanbusWorld,
+    subdirectory: String,
+    value: String,
+) {
+    let original = std::env::var_os(NOTIFY_SOCKET_ENV);
+    std::env::set_var(NOTIFY_SOCKET_ENV, &value);
+    resolve_from_root_and_subdirectory(world, &subdirectory);
+    match original {
+        Some(original) => std::env::set_var(NOTIFY_SOCKET_ENV, original),
+        None => std::env::remove_var(NOTIFY_SOCKET_ENV),
+    }
+}
+
+#[then(expr = "every resolved notification socket path should be {string}")]
+fn then_notification_socket_paths_equal(world: &mut KanbusWorld, expected: String) {
+    let paths = world
+        .notification_socket_paths
+        .as_ref()
+        .expect("resolved paths");
+    for path in paths {
+        assert_eq!(path, &PathBuf::from(&expected));
+    }
+}
+
+#[then(expr = "every resolved notification socket path should be {string} in the repository")]
+fn then_notification_socket_paths_in_repository(world: &mut KanbusWorld, relative: String) {
+    let root = world.working_directory.clone().expect("working directory");
+    let paths = world
+        .notification_socket_paths
+        .as_ref()
+        .expect("resolved paths");
+    for path in paths {
+        assert_eq!(path, &root.join(&relative));
+    }
+}
+
+#[when(
+    expr = "I publish updates to {string} for issue {string} with notification debouncing enabled"
+)]
+fn when_publish_debounced_updates(world: &mut KanbusWorld, fields: String, identifier: String) {
+    let root = world.working_directory.clone().expect("working directory");
+    let socket_path = root.join("notify.sock");
+    let listener = UnixListener::bind(&socket_path).expect("bind notification socket");
+    listener
+        .set_nonblocking(true)
+        .expect("nonblocking listener");
+    let original_socket = std::env::var_os(NOTIFY_SOCKET_ENV);
+    let original_debounce = std::env::var_os(NOTIFY_DEBOUNCE_ENV);
+    std::env::set_var(NOTIFY_SOCKET_ENV, &socket_path);
+    std::env::set_var(NOTIFY_DEBOUNCE_ENV, "200");
+
+    let issue = load_issue_from_project(&root, &identifier)
+        .expect("load issue")
+        .issue;
+    for field in fields.split(',').map(str::trim) {
+        publish_notification(
+            &root,
+            NotificationEvent::IssueUpdated {
+                issue_id: issue.identifier.clone(),
+                fields_changed: vec![field.to_string()],
+                issue_data: issue.clone(),
+            },
+        )
+        .expect("publish notification");
+    }
+    flush_notifications();
+
+    for (name, original) in [
+        (NOTIFY_SOCKET_ENV, original_socket),
+        (NOTIFY_DEBOUNCE_ENV, original_debounce),
+    ] {
+        match original {
+            Some(value) => std::env::set_var(name, value),
+            None => std::env::remove_var(name),
+        }
+    }
+
+    let mut messages = Vec::new();
+    loop {
+        match listener.accept() {
+            Ok((mut stream, _)) => {
+                stream.set_nonblocking(false).expect("blocking stream");
+                let mut message = String::new();
+                stream
+                    .read_to_string(&mut message)
+                    .expect("read notification");
+                messages.push(message.trim_end().to_string());
+            }
+            Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+            Err(error) => panic!("accept notification: {error}"),
+        }
+    }
+    world.stdout = Some(messages.join("\n"));
+}
+
+#[then(expr = "{int} notification(s) should be received")]
+fn then_notifications_received(world: &mut KanbusWorld, expected: usize) {
+    let stdout = world.stdout.as_ref().expect("notifications");
+    assert_eq!(
+        stdout.lines().filter(|line| !line.is_empty()).count(),
+        expected
+    );
+}
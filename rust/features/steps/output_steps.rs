@@ -1,10 +1,15 @@
 use std::sync::OnceLock;
 
-use cucumber::then;
+use cucumber::{given, then};
 use regex::Regex;
 
 use crate::step_definitions::initialization_steps::KanbusWorld;
 
+#[given(expr = "the pager is set to {string}")]
+fn given_pager_set(_world: &mut KanbusWorld, command: String) {
+    std::env::set_var(kanbus::pager::PAGER_ENV, command);
+}
+
 #[then(expr = "stdout should contain {string}")]
 fn then_stdout_contains_text(world: &mut KanbusWorld, text: String) {
     let stdout = strip_ansi(world.stdout.as_ref().expect("stdout"));
@@ -19,6 +24,21 @@ fn then_stdout_not_contains_text(world: &mut KanbusWorld, text: String) {
     assert!(!stdout.contains(&normalized));
 }
 
+#[then("stdout should be empty")]
+fn then_stdout_empty(world: &mut KanbusWorld) {
+    let stdout = strip_ansi(world.stdout.as_ref().expect("stdout"));
+    assert!(stdout.trim().is_empty());
+}
+
+#[then(expr = "stdout should be a JSON array of {int} issue(s)")]
+fn then_stdout_json_issue_array(world: &mut KanbusWorld, count: usize) {
+    let stdout = world.stdout.as_ref().expect("stdout");
+    let payload: serde_json::Value = serde_json::from_str(stdout).expect("parse stdout json");
+    let issues = payload.as_array().expect("json array");
+    assert_eq!(issues.len(), count);
+    assert!(issues.iter().all(|issue| issue["id"].is_string()));
+}
+
 #[then(expr = "stderr should contain {string}")]
 fn then_stderr_contains_text(world: &mut KanbusWorld, text: String) {
     let stderr = strip_ansi(world.stderr.as_ref().expect("stderr"));
@@ -45,10 +45,17 @@ fn when_build_sample_issue(world: &mut KanbusWorld, target: String, author: Stri
             author,
             text: "hi".to_string(),
             created_at: now,
+            system: false,
+            resolved: false,
+            reply_to: None,
         }],
         created_at: now,
         updated_at: now,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
+        links: Vec::new(),
         custom: std::collections::BTreeMap::new(),
     };
     world.sample_issue = Some(issue);
@@ -3,7 +3,7 @@ use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
 
-use chrono::{TimeZone, Utc};
+use chrono::{DateTime, TimeZone, Utc};
 use cucumber::{given, then, when};
 
 use kanbus::cli::run_from_args_with_output;
@@ -12,7 +12,7 @@ use kanbus::daemon_client::{has_test_daemon_response, set_test_daemon_response};
 use kanbus::daemon_protocol::{RequestEnvelope, PROTOCOL_VERSION};
 use kanbus::daemon_server::handle_request_for_testing;
 use kanbus::file_io::{get_configuration_path, load_project_directory};
-use kanbus::issue_listing::list_issues;
+use kanbus::issue_listing::{list_issues, ListOptions};
 use kanbus::models::IssueData;
 use tempfile::TempDir;
 
@@ -101,13 +101,23 @@ fn build_issue(identifier: &str) -> IssueData {
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     }
 }
 
+fn parse_timestamp(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .expect("parse timestamp")
+        .with_timezone(&Utc)
+}
+
 #[given(expr = "issues {string} and {string} exist")]
 fn given_issues_exist(world: &mut KanbusWorld, first: String, second: String) {
     let project_dir = load_project_dir(world);
@@ -158,6 +168,44 @@ fn given_issue_has_type(world: &mut KanbusWorld, identifier: String, issue_type:
     write_issue_file(&project_dir, &issue);
 }
 
+#[given(expr = "issue {string} is due at {string}")]
+fn given_issue_is_due_at(world: &mut KanbusWorld, identifier: String, due_at: String) {
+    let project_dir = load_project_dir(world);
+    let mut issue = build_issue(&identifier);
+    issue.due_at = Some(parse_timestamp(&due_at));
+    write_issue_file(&project_dir, &issue);
+}
+
+#[given(expr = "issue {string} was updated at {string}")]
+fn given_issue_was_updated_at(world: &mut KanbusWorld, identifier: String, updated_at: String) {
+    let project_dir = load_project_dir(world);
+    let mut issue = build_issue(&identifier);
+    issue.updated_at = parse_timestamp(&updated_at);
+    write_issue_file(&project_dir, &issue);
+}
+
+#[given(expr = "issue {string} was updated {int} hours ago")]
+fn given_issue_was_updated_hours_ago(world: &mut KanbusWorld, identifier: String, hours: i64) {
+    let project_dir = load_project_dir(world);
+    let mut issue = build_issue(&identifier);
+    issue.updated_at = Utc::now() - chrono::Duration::hours(hours);
+    write_issue_file(&project_dir, &issue);
+}
+
+#[given(expr = "issue {string} has status {string} and is due at {string}")]
+fn given_issue_has_status_and_due_at(
+    world: &mut KanbusWorld,
+    identifier: String,
+    status: String,
+    due_at: String,
+) {
+    let project_dir = load_project_dir(world);
+    let mut issue = build_issue(&identifier);
+    issue.status = status;
+    issue.due_at = Some(parse_timestamp(&due_at));
+    write_issue_file(&project_dir, &issue);
+}
+
 #[given(expr = "issue {string} has assignee {string}")]
 fn given_issue_has_assignee(world: &mut KanbusWorld, identifier: String, assignee: String) {
     let project_dir = load_project_dir(world);
@@ -166,6 +214,44 @@ fn given_issue_has_assignee(world: &mut KanbusWorld, identifier: String, assigne
     write_issue_file(&project_dir, &issue);
 }
 
+#[given(expr = "a file {string} lists the assignees {string}")]
+fn given_assignee_file(world: &mut KanbusWorld, file_name: String, assignees: String) {
+    let cwd = world.working_directory.as_ref().expect("cwd");
+    let contents = assignees
+        .split(',')
+        .map(|name| format!("{}\n", name.trim()))
+        .collect::<String>();
+    fs::write(cwd.join(file_name), contents).expect("write assignee file");
+}
+
+#[given(expr = "issue {string} has creator {string} and assignee {string}")]
+fn given_issue_has_creator_and_assignee(
+    world: &mut KanbusWorld,
+    identifier: String,
+    creator: String,
+    assignee: String,
+) {
+    let project_dir = load_project_dir(world);
+    let mut issue = build_issue(&identifier);
+    issue.creator = Some(creator);
+    issue.assignee = Some(assignee);
+    write_issue_file(&project_dir, &issue);
+}
+
+#[given(expr = "issue {string} has assignee {string} and status {string}")]
+fn given_issue_has_assignee_and_status(
+    world: &mut KanbusWorld,
+    identifier: String,
+    assignee: String,
+    status: String,
+) {
+    let project_dir = load_project_dir(world);
+    let mut issue = build_issue(&identifier);
+    issue.assignee = Some(assignee);
+    issue.status = status;
+    write_issue_file(&project_dir, &issue);
+}
+
 #[given(expr = "issue {string} has labels {string}")]
 fn given_issue_has_labels(world: &mut KanbusWorld, identifier: String, label_text: String) {
     let project_dir = load_project_dir(world);
@@ -188,6 +274,47 @@ fn given_issue_has_priority(world: &mut KanbusWorld, identifier: String, priorit
     write_issue_file(&project_dir, &issue);
 }
 
+#[given(expr = "issue {string} has priority {int} and was created at {string}")]
+fn given_issue_has_priority_and_created_at(
+    world: &mut KanbusWorld,
+    identifier: String,
+    priority: i32,
+    created_at: String,
+) {
+    let project_dir = load_project_dir(world);
+    let mut issue = build_issue(&identifier);
+    issue.priority = priority;
+    issue.created_at = parse_timestamp(&created_at);
+    write_issue_file(&project_dir, &issue);
+}
+
+#[given(expr = "an issue file {string} exists with title {string}")]
+fn given_issue_file_with_title(world: &mut KanbusWorld, relative_path: String, title: String) {
+    let project_dir = load_project_dir(world);
+    let issue_path = project_dir.join("issues").join(&relative_path);
+    let identifier = issue_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .expect("issue file stem")
+        .to_string();
+    let mut issue = build_issue(&identifier);
+    issue.title = title;
+    fs::create_dir_all(issue_path.parent().expect("issue file parent"))
+        .expect("create issue file directory");
+    let contents = serde_json::to_string_pretty(&issue).expect("serialize issue");
+    fs::write(issue_path, contents).expect("write issue");
+}
+
+#[given(expr = "the project ignore file contains {string}")]
+fn given_project_ignore_file(world: &mut KanbusWorld, pattern: String) {
+    let project_dir = load_project_dir(world);
+    let ignore_path = project_dir.join(".kanbusignore");
+    let mut contents = fs::read_to_string(&ignore_path).unwrap_or_default();
+    contents.push_str(&pattern);
+    contents.push('\n');
+    fs::write(ignore_path, contents).expect("write ignore file");
+}
+
 #[given(expr = "issue {string} has parent {string}")]
 fn given_issue_has_parent(world: &mut KanbusWorld, identifier: String, parent: String) {
     let project_dir = load_project_dir(world);
@@ -230,7 +357,7 @@ fn given_issue_with_full_metadata(
 #[when("I list issues directly after configuration path lookup fails")]
 fn when_list_issues_directly_after_configuration_failure(world: &mut KanbusWorld) {
     let root = world.working_directory.as_ref().expect("working directory");
-    if let Err(error) = list_issues(root, None, None, None, None, None, None, &[], true, false) {
+    if let Err(error) = list_issues(root, &ListOptions::default()) {
         world.exit_code = Some(1);
         world.stdout = Some(String::new());
         world.stderr = Some(error.to_string());
@@ -324,3 +451,46 @@ fn then_shared_only_not_contains(world: &mut KanbusWorld, identifier: String) {
     let list = world.shared_only_results.as_ref().expect("shared list");
     assert!(!list.iter().any(|item| item == &identifier));
 }
+
+#[given("the project files are committed to git")]
+fn given_project_files_committed(world: &mut KanbusWorld) {
+    let cwd = world.working_directory.as_ref().expect("cwd");
+    let add = Command::new("git")
+        .args(["add", "-A"])
+        .current_dir(cwd)
+        .output()
+        .expect("git add failed");
+    assert!(add.status.success());
+    let commit = Command::new("git")
+        .args([
+            "-c",
+            "user.name=Kanbus",
+            "-c",
+            "user.email=kanbus@example.com",
+            "commit",
+            "-q",
+            "-m",
+            "baseline",
+        ])
+        .current_dir(cwd)
+        .output()
+        .expect("git commit failed");
+    assert!(commit.status.success());
+}
+
+#[given("the git metadata is removed")]
+fn given_git_metadata_removed(world: &mut KanbusWorld) {
+    let cwd = world.working_directory.as_ref().expect("cwd");
+    fs::remove_dir_all(cwd.join(".git")).expect("remove git metadata");
+}
+
+#[given(expr = "the current commit is tagged {string}")]
+fn given_current_commit_tagged(world: &mut KanbusWorld, tag: String) {
+    let cwd = world.working_directory.as_ref().expect("cwd");
+    let output = Command::new("git")
+        .args(["tag", &tag])
+        .current_dir(cwd)
+        .output()
+        .expect("git tag failed");
+    assert!(output.status.success());
+}
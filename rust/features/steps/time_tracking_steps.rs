@@ -0,0 +1,121 @@
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::{TimeZone, Utc};
+use cucumber::{given, then};
+use serde_json::Value;
+
+use kanbus::file_io::load_project_directory;
+use kanbus::models::IssueData;
+
+use crate::step_definitions::initialization_steps::KanbusWorld;
+
+fn load_project_dir(world: &KanbusWorld) -> PathBuf {
+    let cwd = world.working_directory.as_ref().expect("cwd");
+    load_project_directory(cwd).expect("project dir")
+}
+
+fn issue_path(project_dir: &PathBuf, identifier: &str) -> PathBuf {
+    project_dir
+        .join("issues")
+        .join(format!("{identifier}.json"))
+}
+
+fn read_issue_json(project_dir: &PathBuf, identifier: &str) -> Value {
+    let contents = fs::read_to_string(issue_path(project_dir, identifier)).expect("read issue");
+    serde_json::from_str(&contents).expect("parse issue")
+}
+
+fn build_issue(identifier: &str, status: &str) -> IssueData {
+    let timestamp = Utc.with_ymd_and_hms(2026, 2, 11, 0, 0, 0).unwrap();
+    IssueData {
+        identifier: identifier.to_string(),
+        title: "Title".to_string(),
+        description: "".to_string(),
+        issue_type: "task".to_string(),
+        status: status.to_string(),
+        priority: 2,
+        assignee: None,
+        creator: None,
+        parent: None,
+        labels: Vec::new(),
+        dependencies: Vec::new(),
+        comments: Vec::new(),
+        links: Vec::new(),
+        created_at: timestamp,
+        updated_at: timestamp,
+        closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
+        custom: std::collections::BTreeMap::new(),
+    }
+}
+
+#[given(
+    expr = "an issue {string} with status {string} has an estimate of {int} minutes and {int} minutes spent"
+)]
+fn given_issue_with_time(
+    world: &mut KanbusWorld,
+    identifier: String,
+    status: String,
+    estimate: u32,
+    spent: u32,
+) {
+    let project_dir = load_project_dir(world);
+    let mut issue = build_issue(&identifier, &status);
+    issue.estimate_minutes = Some(estimate);
+    issue.spent_minutes = spent;
+    let contents = serde_json::to_string_pretty(&issue).expect("serialize issue");
+    fs::write(issue_path(&project_dir, &identifier), contents).expect("write issue");
+}
+
+#[given(expr = "an issue file {string} without time tracking fields exists")]
+fn given_issue_without_time_fields(world: &mut KanbusWorld, identifier: String) {
+    let project_dir = load_project_dir(world);
+    let payload = serde_json::json!({
+        "id": identifier,
+        "title": "Legacy",
+        "description": "",
+        "type": "task",
+        "status": "open",
+        "priority": 2,
+        "assignee": null,
+        "creator": null,
+        "parent": null,
+        "labels": [],
+        "dependencies": [],
+        "comments": [],
+        "created_at": "2026-02-11T00:00:00Z",
+        "updated_at": "2026-02-11T00:00:00Z",
+        "closed_at": null,
+        "custom": {},
+    });
+    let contents = serde_json::to_string_pretty(&payload).expect("serialize issue");
+    fs::write(issue_path(&project_dir, &identifier), contents).expect("write issue");
+}
+
+#[then(expr = "issue {string} should have an estimate of {int} minutes")]
+fn then_issue_estimate(world: &mut KanbusWorld, identifier: String, minutes: u32) {
+    let project_dir = load_project_dir(world);
+    let issue = read_issue_json(&project_dir, &identifier);
+    assert_eq!(issue["estimate_minutes"], minutes);
+}
+
+#[then(expr = "issue {string} should have {int} minutes spent")]
+fn then_issue_spent(world: &mut KanbusWorld, identifier: String, minutes: u32) {
+    let project_dir = load_project_dir(world);
+    let issue = read_issue_json(&project_dir, &identifier);
+    assert_eq!(issue["spent_minutes"], minutes);
+}
+
+#[then(expr = "issue {string} should have a comment {string}")]
+fn then_issue_has_comment(world: &mut KanbusWorld, identifier: String, text: String) {
+    let project_dir = load_project_dir(world);
+    let issue = read_issue_json(&project_dir, &identifier);
+    let comments = issue["comments"].as_array().cloned().unwrap_or_default();
+    assert!(
+        comments.iter().any(|comment| comment["text"] == text),
+        "no comment with text {text:?}"
+    );
+}
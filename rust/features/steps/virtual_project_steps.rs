@@ -141,9 +141,13 @@ fn build_issue(identifier: &str, title: &str, status: &str) -> IssueData {
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: BTreeMap::new(),
     }
 }
@@ -628,6 +632,9 @@ pub fn maybe_simulate_virtual_project_command(world: &mut KanbusWorld, command:
                     author,
                     text: comment_text,
                     created_at: Utc.with_ymd_and_hms(2026, 2, 11, 0, 0, 0).unwrap(),
+                    system: false,
+                    resolved: false,
+                    reply_to: None,
                 });
                 write_issue(project_dir, &issue);
                 set_result(
@@ -60,9 +60,13 @@ fn given_issue_with_type_and_status(
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     };
     write_issue_file(&project_dir, &issue);
@@ -85,9 +89,13 @@ fn given_issue_exists(world: &mut KanbusWorld, identifier: String) {
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     };
     write_issue_file(&project_dir, &issue);
@@ -115,9 +123,13 @@ fn given_issue_exists_with_status(world: &mut KanbusWorld, identifier: String, s
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     };
     write_issue_file(&project_dir, &issue);
@@ -140,9 +152,13 @@ fn given_typed_issue_exists(world: &mut KanbusWorld, issue_type: String, identif
         labels: Vec::new(),
         dependencies: Vec::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: timestamp,
         updated_at: timestamp,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     };
     write_issue_file(&project_dir, &issue);
@@ -198,6 +214,28 @@ fn then_issue_assignee_matches(world: &mut KanbusWorld, identifier: String, assi
     assert_eq!(issue["assignee"], assignee);
 }
 
+#[then(expr = "issue {string} should have priority {int}")]
+fn then_issue_priority_matches(world: &mut KanbusWorld, identifier: String, priority: i64) {
+    let project_dir = load_project_dir(world);
+    let issue = read_issue_json(&project_dir, &identifier);
+    assert_eq!(issue["priority"], priority);
+}
+
+#[then(expr = "issue {string} should have labels {string}")]
+fn then_issue_labels_match(world: &mut KanbusWorld, identifier: String, labels: String) {
+    let project_dir = load_project_dir(world);
+    let issue = read_issue_json(&project_dir, &identifier);
+    let expected: Vec<&str> = labels.split(',').map(str::trim).collect();
+    assert_eq!(issue["labels"], serde_json::json!(expected));
+}
+
+#[then(expr = "issue {string} should have no assignee")]
+fn then_issue_has_no_assignee(world: &mut KanbusWorld, identifier: String) {
+    let project_dir = load_project_dir(world);
+    let issue = read_issue_json(&project_dir, &identifier);
+    assert!(issue["assignee"].is_null());
+}
+
 #[then(expr = "issue {string} should have a closed_at timestamp")]
 fn then_issue_has_closed_at(world: &mut KanbusWorld, identifier: String) {
     let project_dir = load_project_dir(world);
@@ -212,6 +250,30 @@ fn then_issue_no_closed_at(world: &mut KanbusWorld, identifier: String) {
     assert!(issue["closed_at"].is_null());
 }
 
+#[then(expr = "issue {string} should have a system comment containing {string}")]
+fn then_issue_has_system_comment(world: &mut KanbusWorld, identifier: String, text: String) {
+    let project_dir = load_project_dir(world);
+    let issue = read_issue_json(&project_dir, &identifier);
+    let comments = issue["comments"].as_array().expect("comments");
+    assert!(comments.iter().any(|comment| {
+        comment["system"] == Value::Bool(true)
+            && comment["text"]
+                .as_str()
+                .map(|value| value.contains(&text))
+                .unwrap_or(false)
+    }));
+}
+
+#[then(expr = "issue {string} should have no system comments")]
+fn then_issue_has_no_system_comments(world: &mut KanbusWorld, identifier: String) {
+    let project_dir = load_project_dir(world);
+    let issue = read_issue_json(&project_dir, &identifier);
+    let comments = issue["comments"].as_array().expect("comments");
+    assert!(comments
+        .iter()
+        .all(|comment| comment["system"] != Value::Bool(true)));
+}
+
 #[given("a configuration without a default workflow")]
 fn given_config_without_default_workflow(world: &mut KanbusWorld) {
     world.workflow_error = None;
@@ -243,12 +305,24 @@ fn when_lookup_workflow(world: &mut KanbusWorld, issue_type: String) {
             },
         )]),
         default_priority: 2,
+        priority_import_aliases: BTreeMap::new(),
         assignee: None,
         time_zone: None,
         statuses: Vec::new(),
         categories: Vec::new(),
         type_colors: BTreeMap::new(),
+        type_prefixes: BTreeMap::new(),
+        label_colors: BTreeMap::new(),
+        accessible_colors: false,
         beads_compatibility: false,
+        comment_on_transition: false,
+        issue_layout: "flat".to_string(),
+        issue_format: "json".to_string(),
+        priority_order: "ascending".to_string(),
+        max_description_length: None,
+        auto_claim_on_status: Vec::new(),
+        notification_socket_path: None,
+        warn_on_duplicate_title: true,
         jira: None,
         transition_labels: BTreeMap::new(),
     };
@@ -0,0 +1,21 @@
+Feature: Update flow interoperability
+  As a Kanbus user
+  I want updates to work consistently between Beads and Kanbus modes
+  So that I can use either tool interchangeably
+
+  Scenario: Reopen via Beads mode visible in Kanbus
+    Given a Kanbus project with beads compatibility enabled
+    And a kanbus issue "bdx-test" exists with status "closed"
+    When I run "kanbus --beads reopen bdx-test"
+    Then the command should succeed
+    And stdout should contain "Reopened"
+    And beads issues.jsonl should include status "open" for "bdx-test"
+
+  Scenario: Concurrent Beads updates to different issues both persist
+    Given a Kanbus project with beads compatibility enabled
+    And a beads issue "bdx-one" exists
+    And a beads issue "bdx-two" exists
+    When I run "kanbus --beads update bdx-one --status in_progress" and "kanbus --beads update bdx-two --status closed" concurrently
+    Then the command should succeed
+    And beads issues.jsonl should include status "in_progress" for "bdx-one"
+    And beads issues.jsonl should include status "closed" for "bdx-two"
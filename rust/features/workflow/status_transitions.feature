@@ -0,0 +1,11 @@
+Feature: Workflow status transitions
+  As a project manager
+  I want status transitions to follow defined workflows
+  So that issues move through a predictable lifecycle
+
+  Scenario: No-validate bypasses workflow transition checks
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-test01" of type "task" with status "open"
+    When I run "kanbus update kanbus-test01 --status blocked --no-validate"
+    Then the command should succeed
+    And issue "kanbus-test01" should have status "blocked"
@@ -0,0 +1,24 @@
+Feature: Transition comments
+
+  Scenario: Status change appends a system comment when enabled
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration enables comment_on_transition
+    And an issue "kanbus-test01" of type "task" with status "open"
+    When I run "kanbus update kanbus-test01 --status in_progress"
+    Then the command should succeed
+    And issue "kanbus-test01" should have a system comment containing "moved from open to in_progress"
+
+  Scenario: Closing an issue appends a system comment when enabled
+    Given a Kanbus project with default configuration
+    And the Kanbus configuration enables comment_on_transition
+    And an issue "kanbus-test01" of type "task" with status "in_progress"
+    When I run "kanbus close kanbus-test01"
+    Then the command should succeed
+    And issue "kanbus-test01" should have a system comment containing "moved from in_progress to closed"
+
+  Scenario: Status change does not append a comment by default
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-test01" of type "task" with status "open"
+    When I run "kanbus update kanbus-test01 --status in_progress"
+    Then the command should succeed
+    And issue "kanbus-test01" should have no system comments
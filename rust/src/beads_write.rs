@@ -1,15 +1,17 @@
 //! Beads compatibility write helpers.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use rand::Rng;
 use serde_json::{json, Map, Value};
-use std::collections::HashSet;
-use std::fs::{self, OpenOptions};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::{Mutex, OnceLock};
+use tempfile::NamedTempFile;
 use uuid::Uuid;
 
+use crate::dependencies::ensure_no_blocking_cycle;
 use crate::error::KanbusError;
 use crate::event_history::{
     build_update_events, comment_payload, comment_updated_payload, dependency_payload,
@@ -17,7 +19,7 @@ use crate::event_history::{
     write_events_batch, EventRecord, EventType,
 };
 use crate::file_io::load_project_directory;
-use crate::migration::load_beads_issue_by_id;
+use crate::migration::{beads_record_to_issue, beads_shard_paths};
 use crate::models::{DependencyLink, IssueData};
 use crate::users::get_current_user;
 
@@ -41,9 +43,8 @@ pub fn create_beads_issue(
     if !issues_path.exists() {
         return Err(KanbusError::IssueOperation("no issues.jsonl".to_string()));
     }
-    let original_contents =
-        fs::read_to_string(&issues_path).map_err(|error| KanbusError::Io(error.to_string()))?;
-    let records = load_beads_records(&issues_path)?;
+    let _lock = lock_beads_records(&issues_path)?;
+    let (shards, records) = BeadsShards::load(&issues_path)?;
     if records.is_empty() {
         return Err(KanbusError::IssueOperation(
             "no beads issues available".to_string(),
@@ -115,9 +116,13 @@ pub fn create_beads_issue(
         labels: Vec::new(),
         dependencies: dependency_links,
         comments: Vec::new(),
+        links: Vec::new(),
         created_at,
         updated_at: created_at,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: std::collections::BTreeMap::new(),
     };
 
@@ -135,8 +140,7 @@ pub fn create_beads_issue(
     match write_events_batch(&events_dir, &[event]) {
         Ok(_paths) => {}
         Err(error) => {
-            fs::write(&issues_path, original_contents)
-                .map_err(|io_error| KanbusError::Io(io_error.to_string()))?;
+            shards.restore()?;
             return Err(error);
         }
     }
@@ -211,6 +215,7 @@ pub fn add_beads_comment(
     identifier: &str,
     author: &str,
     text: &str,
+    created_at: Option<DateTime<Utc>>,
 ) -> Result<(), KanbusError> {
     let beads_dir = root.join(".beads");
     if !beads_dir.exists() {
@@ -222,10 +227,9 @@ pub fn add_beads_comment(
     if !issues_path.exists() {
         return Err(KanbusError::IssueOperation("no issues.jsonl".to_string()));
     }
-    let original_contents =
-        fs::read_to_string(&issues_path).map_err(|error| KanbusError::Io(error.to_string()))?;
 
-    let mut records = load_beads_records(&issues_path)?;
+    let _lock = lock_beads_records(&issues_path)?;
+    let (shards, mut records) = BeadsShards::load(&issues_path)?;
     let mut found = false;
     let mut created_comment_id: Option<String> = None;
     let mut comment_author: Option<String> = None;
@@ -248,27 +252,42 @@ pub fn add_beads_comment(
                 .expect("comments array")
         };
         let comment_id = (comments.len() + 1) as i64;
-        let created_at = Utc::now().to_rfc3339();
+        let now = Utc::now();
+        let comment_time = created_at.unwrap_or(now);
         created_comment_id = Some(comment_id.to_string());
         comment_author = Some(author.to_string());
-        comments.push(json!({
-            "id": comment_id,
-            "issue_id": identifier,
-            "author": author,
-            "text": text,
-            "created_at": created_at,
-        }));
-        if let Some(updated_at) = record.get_mut("updated_at") {
-            *updated_at = json!(created_at);
+        let position = comments
+            .iter()
+            .position(|existing| {
+                existing
+                    .get("created_at")
+                    .and_then(Value::as_str)
+                    .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+                    .is_some_and(|value| value > comment_time)
+            })
+            .unwrap_or(comments.len());
+        comments.insert(
+            position,
+            json!({
+                "id": comment_id,
+                "issue_id": identifier,
+                "author": author,
+                "text": text,
+                "created_at": comment_time.to_rfc3339(),
+            }),
+        );
+        let updated_at = now.to_rfc3339();
+        if let Some(existing) = record.get_mut("updated_at") {
+            *existing = json!(updated_at);
         } else if let Some(object) = record.as_object_mut() {
-            object.insert("updated_at".to_string(), json!(created_at));
+            object.insert("updated_at".to_string(), json!(updated_at));
         }
         break;
     }
     if !found {
         return Err(KanbusError::IssueOperation("not found".to_string()));
     }
-    write_beads_records(&issues_path, &records)?;
+    shards.write(&records)?;
 
     let project_dir = load_project_directory(root)?;
     let comment_id = created_comment_id
@@ -290,8 +309,7 @@ pub fn add_beads_comment(
     match write_events_batch(&events_dir, &[event]) {
         Ok(_paths) => {}
         Err(error) => {
-            fs::write(&issues_path, original_contents)
-                .map_err(|io_error| KanbusError::Io(io_error.to_string()))?;
+            shards.restore()?;
             return Err(error);
         }
     }
@@ -315,10 +333,9 @@ pub fn update_beads_comment(
     if !issues_path.exists() {
         return Err(KanbusError::IssueOperation("no issues.jsonl".to_string()));
     }
-    let original_contents =
-        fs::read_to_string(&issues_path).map_err(|error| KanbusError::Io(error.to_string()))?;
 
-    let mut records = load_beads_records(&issues_path)?;
+    let _lock = lock_beads_records(&issues_path)?;
+    let (shards, mut records) = BeadsShards::load(&issues_path)?;
     let mut found = false;
     let mut updated_comment_id: Option<String> = None;
     let mut updated_comment_author: Option<String> = None;
@@ -359,7 +376,7 @@ pub fn update_beads_comment(
     if !found {
         return Err(KanbusError::IssueOperation("not found".to_string()));
     }
-    write_beads_records(&issues_path, &records)?;
+    shards.write(&records)?;
 
     let project_dir = load_project_directory(root)?;
     let comment_id = updated_comment_id
@@ -381,8 +398,7 @@ pub fn update_beads_comment(
     match write_events_batch(&events_dir, &[event]) {
         Ok(_paths) => {}
         Err(error) => {
-            fs::write(&issues_path, original_contents)
-                .map_err(|io_error| KanbusError::Io(io_error.to_string()))?;
+            shards.restore()?;
             return Err(error);
         }
     }
@@ -405,10 +421,9 @@ pub fn delete_beads_comment(
     if !issues_path.exists() {
         return Err(KanbusError::IssueOperation("no issues.jsonl".to_string()));
     }
-    let original_contents =
-        fs::read_to_string(&issues_path).map_err(|error| KanbusError::Io(error.to_string()))?;
 
-    let mut records = load_beads_records(&issues_path)?;
+    let _lock = lock_beads_records(&issues_path)?;
+    let (shards, mut records) = BeadsShards::load(&issues_path)?;
     let mut found = false;
     let mut deleted_comment_id: Option<String> = None;
     let mut deleted_comment_author: Option<String> = None;
@@ -449,7 +464,7 @@ pub fn delete_beads_comment(
     if !found {
         return Err(KanbusError::IssueOperation("not found".to_string()));
     }
-    write_beads_records(&issues_path, &records)?;
+    shards.write(&records)?;
 
     let project_dir = load_project_directory(root)?;
     let comment_id = deleted_comment_id
@@ -471,8 +486,7 @@ pub fn delete_beads_comment(
     match write_events_batch(&events_dir, &[event]) {
         Ok(_paths) => {}
         Err(error) => {
-            fs::write(&issues_path, original_contents)
-                .map_err(|io_error| KanbusError::Io(io_error.to_string()))?;
+            shards.restore()?;
             return Err(error);
         }
     }
@@ -496,10 +510,9 @@ pub fn add_beads_dependency(
     if !issues_path.exists() {
         return Err(KanbusError::IssueOperation("no issues.jsonl".to_string()));
     }
-    let original_contents =
-        fs::read_to_string(&issues_path).map_err(|error| KanbusError::Io(error.to_string()))?;
 
-    let mut records = load_beads_records(&issues_path)?;
+    let _lock = lock_beads_records(&issues_path)?;
+    let (shards, mut records) = BeadsShards::load(&issues_path)?;
     let target_id = resolve_beads_identifier(&records, target)?;
     let source_index = resolve_beads_index(&records, identifier)?;
     let target_index = resolve_beads_index(&records, &target_id)?;
@@ -525,6 +538,11 @@ pub fn add_beads_dependency(
                 "circular dependency: cannot block on child".to_string(),
             ));
         }
+        let source_id = records[source_index]
+            .get("id")
+            .and_then(Value::as_str)
+            .unwrap_or(identifier);
+        ensure_no_blocking_cycle(&beads_blocking_edges(&records), source_id, &target_id)?;
     }
 
     let updated_at = Utc::now().to_rfc3339();
@@ -555,7 +573,7 @@ pub fn add_beads_dependency(
         record.insert("updated_at".to_string(), json!(updated_at));
     }
 
-    write_beads_records(&issues_path, &records)?;
+    shards.write(&records)?;
 
     let project_dir = load_project_directory(root)?;
     let occurred_at = now_timestamp();
@@ -571,14 +589,35 @@ pub fn add_beads_dependency(
     match write_events_batch(&events_dir, &[event]) {
         Ok(_paths) => {}
         Err(error) => {
-            fs::write(&issues_path, original_contents)
-                .map_err(|io_error| KanbusError::Io(io_error.to_string()))?;
+            shards.restore()?;
             return Err(error);
         }
     }
     Ok(())
 }
 
+fn beads_blocking_edges(records: &[Value]) -> HashMap<String, Vec<String>> {
+    let mut edges: HashMap<String, Vec<String>> = HashMap::new();
+    for record in records {
+        let Some(identifier) = record.get("id").and_then(Value::as_str) else {
+            continue;
+        };
+        let targets = record
+            .get("dependencies")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter(|entry| entry.get("type").and_then(Value::as_str) == Some("blocked-by"))
+            .filter_map(|entry| entry.get("depends_on_id").and_then(Value::as_str))
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        if !targets.is_empty() {
+            edges.insert(identifier.to_string(), targets);
+        }
+    }
+    edges
+}
+
 /// Remove a dependency from a Beads issue.
 pub fn remove_beads_dependency(
     root: &Path,
@@ -596,10 +635,9 @@ pub fn remove_beads_dependency(
     if !issues_path.exists() {
         return Err(KanbusError::IssueOperation("no issues.jsonl".to_string()));
     }
-    let original_contents =
-        fs::read_to_string(&issues_path).map_err(|error| KanbusError::Io(error.to_string()))?;
 
-    let mut records = load_beads_records(&issues_path)?;
+    let _lock = lock_beads_records(&issues_path)?;
+    let (shards, mut records) = BeadsShards::load(&issues_path)?;
     let target_id = resolve_beads_identifier(&records, target)?;
     let source_index = resolve_beads_index(&records, identifier)?;
 
@@ -621,7 +659,7 @@ pub fn remove_beads_dependency(
         }
     }
 
-    write_beads_records(&issues_path, &records)?;
+    shards.write(&records)?;
 
     let project_dir = load_project_directory(root)?;
     let occurred_at = now_timestamp();
@@ -637,8 +675,7 @@ pub fn remove_beads_dependency(
     match write_events_batch(&events_dir, &[event]) {
         Ok(_paths) => {}
         Err(error) => {
-            fs::write(&issues_path, original_contents)
-                .map_err(|io_error| KanbusError::Io(io_error.to_string()))?;
+            shards.restore()?;
             return Err(error);
         }
     }
@@ -669,11 +706,8 @@ pub fn update_beads_issue(
     if !issues_path.exists() {
         return Err(KanbusError::IssueOperation("no issues.jsonl".to_string()));
     }
-    let original_contents =
-        fs::read_to_string(&issues_path).map_err(|error| KanbusError::Io(error.to_string()))?;
-    let before_issue = load_beads_issue_by_id(root, identifier)?;
-
-    let mut records = load_beads_records(&issues_path)?;
+    let _lock = lock_beads_records(&issues_path)?;
+    let (shards, mut records) = BeadsShards::load(&issues_path)?;
     let mut exact_match_index = None;
     let mut partial_match_indices = Vec::new();
 
@@ -707,15 +741,17 @@ pub fn update_beads_issue(
         }
     };
 
+    let before_issue = beads_record_to_issue(&records, &records[match_index])?;
     let updated_at = Utc::now().to_rfc3339();
     let record = &mut records[match_index];
 
     let mut updated = false;
     if let Some(new_status) = status {
-        record
-            .as_object_mut()
-            .expect("beads record")
-            .insert("status".to_string(), json!(new_status));
+        let object = record.as_object_mut().expect("beads record");
+        object.insert("status".to_string(), json!(new_status));
+        if new_status != "closed" {
+            object.remove("closed_at");
+        }
         updated = true;
     }
     if let Some(new_priority) = priority {
@@ -796,9 +832,9 @@ pub fn update_beads_issue(
         .expect("beads record")
         .insert("updated_at".to_string(), json!(updated_at));
 
-    write_beads_records(&issues_path, &records)?;
+    shards.write(&records)?;
 
-    let updated_issue = load_beads_issue_by_id(root, identifier)?;
+    let updated_issue = beads_record_to_issue(&records, &records[match_index])?;
 
     let occurred_at = now_timestamp();
     let actor_id = get_current_user();
@@ -808,8 +844,7 @@ pub fn update_beads_issue(
     match write_events_batch(&events_dir, &events) {
         Ok(_paths) => {}
         Err(error) => {
-            fs::write(&issues_path, original_contents)
-                .map_err(|io_error| KanbusError::Io(io_error.to_string()))?;
+            shards.restore()?;
             return Err(error);
         }
     }
@@ -934,8 +969,80 @@ fn resolve_beads_identifier(records: &[Value], identifier: &str) -> Result<Strin
     }
 }
 
+/// Records read from every `.jsonl` shard in `.beads/`.
+///
+/// Each record is written back to the shard it was read from, and records
+/// without a shard (newly created ones) go to the primary `issues.jsonl`.
+struct BeadsShards {
+    paths: Vec<PathBuf>,
+    originals: Vec<String>,
+    shard_by_id: HashMap<String, usize>,
+}
+
+impl BeadsShards {
+    /// Load all shards next to the primary `issues.jsonl` at `primary`.
+    fn load(primary: &Path) -> Result<(Self, Vec<Value>), KanbusError> {
+        let beads_dir = primary.parent().unwrap_or_else(|| Path::new("."));
+        let paths = beads_shard_paths(beads_dir)?;
+        let mut originals = Vec::with_capacity(paths.len());
+        let mut shard_by_id = HashMap::new();
+        let mut records = Vec::new();
+        for (index, path) in paths.iter().enumerate() {
+            originals.push(
+                fs::read_to_string(path).map_err(|error| KanbusError::Io(error.to_string()))?,
+            );
+            for record in load_beads_records(path)? {
+                if let Some(identifier) = record.get("id").and_then(Value::as_str) {
+                    shard_by_id.insert(identifier.to_string(), index);
+                }
+                records.push(record);
+            }
+        }
+        let shards = Self {
+            paths,
+            originals,
+            shard_by_id,
+        };
+        Ok((shards, records))
+    }
+
+    /// Write records back to their shards, rewriting only shards that changed.
+    fn write(&self, records: &[Value]) -> Result<(), KanbusError> {
+        let mut grouped: Vec<Vec<Value>> = vec![Vec::new(); self.paths.len()];
+        for record in records {
+            let index = record
+                .get("id")
+                .and_then(Value::as_str)
+                .and_then(|identifier| self.shard_by_id.get(identifier))
+                .copied()
+                .unwrap_or(0);
+            grouped[index].push(record.clone());
+        }
+        for (index, shard_records) in grouped.iter().enumerate() {
+            let unchanged = load_beads_records_from_str(&self.originals[index])
+                .is_ok_and(|original| original == *shard_records);
+            if !unchanged {
+                write_beads_records(&self.paths[index], shard_records)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Restore every shard to the contents it had when loaded.
+    fn restore(&self) -> Result<(), KanbusError> {
+        for (path, original) in self.paths.iter().zip(&self.originals) {
+            fs::write(path, original).map_err(|error| KanbusError::Io(error.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
 fn load_beads_records(path: &Path) -> Result<Vec<Value>, KanbusError> {
     let contents = fs::read_to_string(path).map_err(|error| KanbusError::Io(error.to_string()))?;
+    load_beads_records_from_str(&contents)
+}
+
+fn load_beads_records_from_str(contents: &str) -> Result<Vec<Value>, KanbusError> {
     let mut records = Vec::new();
     for line in contents.lines() {
         if line.trim().is_empty() {
@@ -949,19 +1056,44 @@ fn load_beads_records(path: &Path) -> Result<Vec<Value>, KanbusError> {
 }
 
 fn write_beads_records(path: &Path, records: &[Value]) -> Result<(), KanbusError> {
-    let mut file = OpenOptions::new()
-        .write(true)
-        .truncate(true)
-        .open(path)
-        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    let directory = path.parent().unwrap_or_else(|| Path::new("."));
+    let mut file =
+        NamedTempFile::new_in(directory).map_err(|error| KanbusError::Io(error.to_string()))?;
     for record in records {
         let line =
             serde_json::to_string(record).map_err(|error| KanbusError::Io(error.to_string()))?;
         writeln!(file, "{}", line).map_err(|error| KanbusError::Io(error.to_string()))?;
     }
+    if let Ok(metadata) = fs::metadata(path) {
+        fs::set_permissions(file.path(), metadata.permissions())
+            .map_err(|error| KanbusError::Io(error.to_string()))?;
+    }
+    file.as_file()
+        .sync_all()
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    file.persist(path)
+        .map_err(|error| KanbusError::Io(error.error.to_string()))?;
     Ok(())
 }
 
+/// Hold an exclusive lock on the Beads issues file for a read-modify-write cycle.
+///
+/// The lock lives on a sibling `issues.jsonl.lock` file because atomic writes
+/// replace `issues.jsonl` itself. It is released when the returned file is dropped.
+fn lock_beads_records(path: &Path) -> Result<File, KanbusError> {
+    let mut lock_name = path.as_os_str().to_owned();
+    lock_name.push(".lock");
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(PathBuf::from(lock_name))
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    file.lock()
+        .map_err(|error| KanbusError::Io(format!("failed to lock beads issues: {error}")))?;
+    Ok(file)
+}
+
 /// Delete a Beads-compatible issue in .beads/issues.jsonl.
 pub fn delete_beads_issue(root: &Path, identifier: &str) -> Result<(), KanbusError> {
     let beads_dir = root.join(".beads");
@@ -974,15 +1106,16 @@ pub fn delete_beads_issue(root: &Path, identifier: &str) -> Result<(), KanbusErr
     if !issues_path.exists() {
         return Err(KanbusError::IssueOperation("no issues.jsonl".to_string()));
     }
-    let original_contents =
-        fs::read_to_string(&issues_path).map_err(|error| KanbusError::Io(error.to_string()))?;
-    let deleted_issue = load_beads_issue_by_id(root, identifier)?;
-    let mut records = load_beads_records(&issues_path)?;
-    let original_count = records.len();
-    records.retain(|record| record.get("id").and_then(|id| id.as_str()) != Some(identifier));
-    if records.len() == original_count {
+    let _lock = lock_beads_records(&issues_path)?;
+    let (shards, mut records) = BeadsShards::load(&issues_path)?;
+    let Some(deleted_record) = records
+        .iter()
+        .find(|record| record.get("id").and_then(|id| id.as_str()) == Some(identifier))
+    else {
         return Err(KanbusError::IssueOperation("not found".to_string()));
-    }
+    };
+    let deleted_issue = beads_record_to_issue(&records, deleted_record)?;
+    records.retain(|record| record.get("id").and_then(|id| id.as_str()) != Some(identifier));
     for record in &mut records {
         // Clear parent fields that reference the deleted issue
         if let Some(parent_value) = record.get("parent").and_then(Value::as_str) {
@@ -1008,7 +1141,7 @@ pub fn delete_beads_issue(root: &Path, identifier: &str) -> Result<(), KanbusErr
             }
         }
     }
-    write_beads_records(&issues_path, &records)?;
+    shards.write(&records)?;
 
     let project_dir = load_project_directory(root)?;
     let occurred_at = now_timestamp();
@@ -1024,8 +1157,7 @@ pub fn delete_beads_issue(root: &Path, identifier: &str) -> Result<(), KanbusErr
     match write_events_batch(&events_dir, &[event]) {
         Ok(_paths) => {}
         Err(error) => {
-            fs::write(&issues_path, original_contents)
-                .map_err(|io_error| KanbusError::Io(io_error.to_string()))?;
+            shards.restore()?;
             return Err(error);
         }
     }
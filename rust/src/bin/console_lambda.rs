@@ -20,6 +20,7 @@ use tokio::sync::Mutex;
 use tokio_stream::wrappers::IntervalStream;
 
 use kanbus::console_backend::{find_issue_matches, FileStore};
+use kanbus::ids::identifier_prefixes;
 
 const EFS_ROOT: &str = "/mnt/data";
 const DEFAULT_ASSETS_ROOT: &str = "/opt/apps/console/dist";
@@ -95,7 +96,11 @@ fn handle_issue(store: &FileStore, identifier: &str) -> Result<ResponseType, Err
             return error_response(error.to_string(), StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
-    let matches = find_issue_matches(&snapshot.issues, identifier, &snapshot.config.project_key);
+    let matches = find_issue_matches(
+        &snapshot.issues,
+        identifier,
+        &identifier_prefixes(&snapshot.config),
+    );
     if matches.is_empty() {
         return error_response("issue not found", StatusCode::NOT_FOUND);
     }
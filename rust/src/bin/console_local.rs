@@ -14,6 +14,7 @@ use std::time::Duration;
 
 use axum::body::Body;
 use axum::body::Bytes;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
 use axum::extract::{Path as AxumPath, Query, State};
 use axum::http::header::CONTENT_TYPE;
 use axum::http::StatusCode;
@@ -23,6 +24,7 @@ use axum::response::Response;
 use axum::routing::{get, post};
 use axum::Json;
 use axum::Router;
+use chrono::{DateTime, Utc};
 use futures_util::stream;
 use futures_util::stream::BoxStream;
 use futures_util::Stream;
@@ -38,8 +40,16 @@ use tower_http::cors::{Any, CorsLayer};
 use kanbus::console_backend::{find_issue_matches, FileStore};
 use kanbus::console_ui_state::{load_state, save_state, ConsoleUiState};
 use kanbus::daemon_paths::get_console_state_path;
+use kanbus::error::KanbusError;
 use kanbus::event_history::{load_issue_events, EventRecord};
+use kanbus::ids::identifier_prefixes;
+use kanbus::issue_creation::{create_issue, IssueCreationRequest};
+use kanbus::issue_update::{update_issue, IssueUpdateRequest};
+use kanbus::markdown_render::render_markdown;
+use kanbus::models::IssueData;
 use kanbus::notification_events::{NotificationEvent, UiControlAction};
+use kanbus::notification_publisher::get_notification_socket_path;
+use kanbus::queries::{filter_issues, IssueFilter};
 
 #[cfg(feature = "embed-assets")]
 use rust_embed::RustEmbed;
@@ -64,6 +74,93 @@ struct AppState {
     state_file_path: PathBuf,
 }
 
+/// Request body for creating an issue, mirroring `IssueCreationRequest`.
+#[derive(Debug, Deserialize)]
+struct CreateIssueBody {
+    title: String,
+    issue_type: Option<String>,
+    priority: Option<u8>,
+    assignee: Option<String>,
+    parent: Option<String>,
+    #[serde(default)]
+    priority_from_parent: bool,
+    #[serde(default)]
+    labels: Vec<String>,
+    description: Option<String>,
+    status: Option<String>,
+    #[serde(default)]
+    local: bool,
+    #[serde(default)]
+    allow_duplicate_title: bool,
+    due_at: Option<DateTime<Utc>>,
+    estimate_minutes: Option<u32>,
+}
+
+/// Request body for updating an issue; omitted fields are left unchanged.
+#[derive(Debug, Deserialize)]
+struct UpdateIssueBody {
+    title: Option<String>,
+    description: Option<String>,
+    status: Option<String>,
+    assignee: Option<String>,
+    priority: Option<u8>,
+    #[serde(default)]
+    add_labels: Vec<String>,
+    #[serde(default)]
+    remove_labels: Vec<String>,
+    set_labels: Option<String>,
+    parent: Option<String>,
+    due_at: Option<DateTime<Utc>>,
+    estimate_minutes: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssuesQuery {
+    status: Option<String>,
+    #[serde(rename = "type")]
+    issue_type: Option<String>,
+    assignee: Option<String>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+struct IssuesResponse {
+    issues: Vec<IssueData>,
+    total: usize,
+    offset: usize,
+}
+
+impl IssuesResponse {
+    /// Filter issues by the query and slice out the requested page.
+    ///
+    /// `total` counts every matching issue; a missing `limit` returns all of
+    /// them from `offset` onward.
+    fn from_query(issues: Vec<IssueData>, query: &IssuesQuery) -> Self {
+        let filtered = filter_issues(
+            issues,
+            &IssueFilter {
+                status: query.status.as_deref(),
+                issue_type: query.issue_type.as_deref(),
+                assignee: query.assignee.as_deref(),
+                ..IssueFilter::default()
+            },
+        );
+        let total = filtered.len();
+        let offset = query.offset.unwrap_or(0);
+        let issues = filtered
+            .into_iter()
+            .skip(offset)
+            .take(query.limit.unwrap_or(usize::MAX))
+            .collect();
+        Self {
+            issues,
+            total,
+            offset,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct IssueEventsQuery {
     limit: Option<usize>,
@@ -167,14 +264,20 @@ async fn main() {
     let app = Router::new()
         .route("/assets/*path", get(get_public_asset))
         .route("/api/config", get(get_config_root))
-        .route("/api/issues", get(get_issues_root))
-        .route("/api/issues/:id", get(get_issue_root))
+        .route("/api/issues", get(get_issues_root).post(post_issue_root))
+        .route(
+            "/api/issues/:id",
+            get(get_issue_root).patch(patch_issue_root),
+        )
         .route("/api/issues/:id/events", get(get_issue_events_root))
+        .route("/api/issues/:id/comments", get(get_issue_comments_root))
         .route("/api/events", get(get_events_root))
         .route("/api/events/realtime", get(get_realtime_events_root))
+        .route("/api/ws", get(get_websocket_root))
         .route("/api/notifications", post(post_notification_root))
         .route("/api/ui-state", get(get_ui_state_root))
         .route("/api/render/d2", post(post_render_d2))
+        .route("/api/render/markdown", post(post_render_markdown))
         .route("/api/telemetry/console", post(post_console_telemetry_root))
         .route(
             "/api/telemetry/console/events",
@@ -188,21 +291,36 @@ async fn main() {
         .route("/issues/:id", get(get_index_root))
         .route("/issues/:parent/:id", get(get_index_root))
         .route("/:account/:project/api/config", get(get_config))
-        .route("/:account/:project/api/issues", get(get_issues))
-        .route("/:account/:project/api/issues/:id", get(get_issue))
+        .route(
+            "/:account/:project/api/issues",
+            get(get_issues).post(post_issue),
+        )
+        .route(
+            "/:account/:project/api/issues/:id",
+            get(get_issue).patch(patch_issue),
+        )
         .route(
             "/:account/:project/api/issues/:id/events",
             get(get_issue_events),
         )
+        .route(
+            "/:account/:project/api/issues/:id/comments",
+            get(get_issue_comments),
+        )
         .route("/:account/:project/api/events", get(get_events))
         .route(
             "/:account/:project/api/events/realtime",
             get(get_realtime_events),
         )
+        .route("/:account/:project/api/ws", get(get_websocket))
         .route(
             "/:account/:project/api/notifications",
             post(post_notification),
         )
+        .route(
+            "/:account/:project/api/render/markdown",
+            post(post_render_markdown),
+        )
         .route(
             "/:account/:project/api/telemetry/console",
             post(post_console_telemetry),
@@ -353,15 +471,19 @@ async fn get_config_root(State(state): State<AppState>) -> Response {
 async fn get_issues(
     State(state): State<AppState>,
     AxumPath((account, project)): AxumPath<(String, String)>,
+    Query(query): Query<IssuesQuery>,
 ) -> Response {
     let store = store_for(&state, &account, &project);
     match store.build_snapshot() {
-        Ok(snapshot) => Json(snapshot.issues).into_response(),
+        Ok(snapshot) => Json(IssuesResponse::from_query(snapshot.issues, &query)).into_response(),
         Err(error) => error_response(error.to_string(), StatusCode::INTERNAL_SERVER_ERROR),
     }
 }
 
-async fn get_issues_root(State(state): State<AppState>) -> Response {
+async fn get_issues_root(
+    State(state): State<AppState>,
+    Query(query): Query<IssuesQuery>,
+) -> Response {
     let store = match store_for_root(&state) {
         Some(store) => store,
         None => {
@@ -372,7 +494,7 @@ async fn get_issues_root(State(state): State<AppState>) -> Response {
         }
     };
     match store.build_snapshot() {
-        Ok(snapshot) => Json(snapshot.issues).into_response(),
+        Ok(snapshot) => Json(IssuesResponse::from_query(snapshot.issues, &query)).into_response(),
         Err(error) => error_response(error.to_string(), StatusCode::INTERNAL_SERVER_ERROR),
     }
 }
@@ -388,7 +510,11 @@ async fn get_issue(
             return error_response(error.to_string(), StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
-    let matches = find_issue_matches(&snapshot.issues, &id, &snapshot.config.project_key);
+    let matches = find_issue_matches(
+        &snapshot.issues,
+        &id,
+        &identifier_prefixes(&snapshot.config),
+    );
     if matches.is_empty() {
         return error_response("issue not found", StatusCode::NOT_FOUND);
     }
@@ -414,7 +540,11 @@ async fn get_issue_root(State(state): State<AppState>, AxumPath(id): AxumPath<St
             return error_response(error.to_string(), StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
-    let matches = find_issue_matches(&snapshot.issues, &id, &snapshot.config.project_key);
+    let matches = find_issue_matches(
+        &snapshot.issues,
+        &id,
+        &identifier_prefixes(&snapshot.config),
+    );
     if matches.is_empty() {
         return error_response("issue not found", StatusCode::NOT_FOUND);
     }
@@ -424,6 +554,158 @@ async fn get_issue_root(State(state): State<AppState>, AxumPath(id): AxumPath<St
     Json(matches[0]).into_response()
 }
 
+async fn get_issue_comments(
+    State(state): State<AppState>,
+    AxumPath((account, project, id)): AxumPath<(String, String, String)>,
+) -> Response {
+    let store = store_for(&state, &account, &project);
+    issue_comments_response(&store, &id)
+}
+
+async fn get_issue_comments_root(
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<String>,
+) -> Response {
+    let store = match store_for_root(&state) {
+        Some(store) => store,
+        None => {
+            return error_response(
+                "multi-tenant mode requires /:account/:project",
+                StatusCode::BAD_REQUEST,
+            )
+        }
+    };
+    issue_comments_response(&store, &id)
+}
+
+fn issue_comments_response(store: &FileStore, id: &str) -> Response {
+    let snapshot = match store.build_snapshot() {
+        Ok(snapshot) => snapshot,
+        Err(error) => {
+            return error_response(error.to_string(), StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+    let matches = find_issue_matches(&snapshot.issues, id, &identifier_prefixes(&snapshot.config));
+    if matches.is_empty() {
+        return error_response("issue not found", StatusCode::NOT_FOUND);
+    }
+    if matches.len() > 1 {
+        return error_response("issue id is ambiguous", StatusCode::BAD_REQUEST);
+    }
+    Json(&matches[0].comments).into_response()
+}
+
+async fn post_issue(
+    State(state): State<AppState>,
+    AxumPath((account, project)): AxumPath<(String, String)>,
+    Json(body): Json<CreateIssueBody>,
+) -> Response {
+    match writable_store_for(&state, &account, &project) {
+        Some(store) => create_issue_response(store, body).await,
+        None => error_response("project not found", StatusCode::NOT_FOUND),
+    }
+}
+
+async fn post_issue_root(
+    State(state): State<AppState>,
+    Json(body): Json<CreateIssueBody>,
+) -> Response {
+    let store = match store_for_root(&state) {
+        Some(store) => store,
+        None => {
+            return error_response(
+                "multi-tenant mode requires /:account/:project",
+                StatusCode::BAD_REQUEST,
+            )
+        }
+    };
+    create_issue_response(store, body).await
+}
+
+async fn create_issue_response(store: FileStore, body: CreateIssueBody) -> Response {
+    let request = IssueCreationRequest {
+        root: store.root().to_path_buf(),
+        title: body.title,
+        issue_type: body.issue_type,
+        priority: body.priority,
+        assignee: body.assignee,
+        parent: body.parent,
+        priority_from_parent: body.priority_from_parent,
+        labels: body.labels,
+        description: body.description,
+        status: body.status,
+        local: body.local,
+        validate: true,
+        allow_duplicate_title: body.allow_duplicate_title,
+        due_at: body.due_at,
+        estimate_minutes: body.estimate_minutes,
+    };
+    let issue = match tokio::task::spawn_blocking(move || create_issue(&request)).await {
+        Ok(Ok(result)) => result.issue,
+        Ok(Err(error)) => return write_error_response(error),
+        Err(error) => {
+            return error_response(error.to_string(), StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+    (StatusCode::CREATED, Json(issue)).into_response()
+}
+
+async fn patch_issue(
+    State(state): State<AppState>,
+    AxumPath((account, project, id)): AxumPath<(String, String, String)>,
+    Json(body): Json<UpdateIssueBody>,
+) -> Response {
+    match writable_store_for(&state, &account, &project) {
+        Some(store) => update_issue_response(store, id, body).await,
+        None => error_response("project not found", StatusCode::NOT_FOUND),
+    }
+}
+
+async fn patch_issue_root(
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<String>,
+    Json(body): Json<UpdateIssueBody>,
+) -> Response {
+    let store = match store_for_root(&state) {
+        Some(store) => store,
+        None => {
+            return error_response(
+                "multi-tenant mode requires /:account/:project",
+                StatusCode::BAD_REQUEST,
+            )
+        }
+    };
+    update_issue_response(store, id, body).await
+}
+
+async fn update_issue_response(store: FileStore, id: String, body: UpdateIssueBody) -> Response {
+    let updated = tokio::task::spawn_blocking(move || {
+        update_issue(&IssueUpdateRequest {
+            title: body.title,
+            description: body.description,
+            status: body.status,
+            assignee: body.assignee,
+            priority: body.priority,
+            add_labels: body.add_labels,
+            remove_labels: body.remove_labels,
+            set_labels: body.set_labels,
+            parent: body.parent,
+            due_at: body.due_at,
+            estimate_minutes: body.estimate_minutes,
+            ..IssueUpdateRequest::new(store.root(), id)
+        })
+    })
+    .await;
+    let issue = match updated {
+        Ok(Ok(issue)) => issue,
+        Ok(Err(error)) => return write_error_response(error),
+        Err(error) => {
+            return error_response(error.to_string(), StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+    Json(issue).into_response()
+}
+
 async fn get_issue_events_root(
     State(state): State<AppState>,
     AxumPath(id): AxumPath<String>,
@@ -444,7 +726,11 @@ async fn get_issue_events_root(
             return error_response(error.to_string(), StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
-    let matches = find_issue_matches(&snapshot.issues, &id, &snapshot.config.project_key);
+    let matches = find_issue_matches(
+        &snapshot.issues,
+        &id,
+        &identifier_prefixes(&snapshot.config),
+    );
     if matches.is_empty() {
         return error_response("issue not found", StatusCode::NOT_FOUND);
     }
@@ -481,7 +767,11 @@ async fn get_issue_events(
             return error_response(error.to_string(), StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
-    let matches = find_issue_matches(&snapshot.issues, &id, &snapshot.config.project_key);
+    let matches = find_issue_matches(
+        &snapshot.issues,
+        &id,
+        &identifier_prefixes(&snapshot.config),
+    );
     if matches.is_empty() {
         return error_response("issue not found", StatusCode::NOT_FOUND);
     }
@@ -793,6 +1083,54 @@ async fn get_realtime_events(
     get_realtime_events_root(State(state)).await
 }
 
+async fn get_websocket_root(State(state): State<AppState>, upgrade: WebSocketUpgrade) -> Response {
+    let receiver = state.notification_tx.subscribe();
+    upgrade.on_upgrade(move |socket| forward_notifications(socket, receiver))
+}
+
+async fn get_websocket(
+    State(state): State<AppState>,
+    AxumPath((_account, _project)): AxumPath<(String, String)>,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    get_websocket_root(State(state), upgrade).await
+}
+
+/// Forward broadcast notifications to a WebSocket client as JSON text frames.
+///
+/// Runs until the client disconnects or the broadcast channel closes; the
+/// receiver is dropped on return, which releases the subscription.
+async fn forward_notifications(
+    mut socket: WebSocket,
+    mut receiver: broadcast::Receiver<NotificationEvent>,
+) {
+    loop {
+        tokio::select! {
+            event = receiver.recv() => match event {
+                Ok(notification) => {
+                    let Ok(data) = serde_json::to_string(&notification) else {
+                        continue;
+                    };
+                    if socket.send(Message::Text(data)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            message = socket.recv() => match message {
+                Some(Ok(Message::Ping(payload))) => {
+                    if socket.send(Message::Pong(payload)).await.is_err() {
+                        break;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
 async fn post_render_d2(body: Bytes) -> Response {
     // Check if d2 is installed
     let d2_available = Command::new("which")
@@ -933,6 +1271,17 @@ async fn post_render_d2(body: Bytes) -> Response {
     }
 }
 
+async fn post_render_markdown(body: Bytes) -> Response {
+    let request: JsonValue = match serde_json::from_slice(&body) {
+        Ok(json) => json,
+        Err(_) => return error_response("Invalid JSON", StatusCode::BAD_REQUEST),
+    };
+    let Some(source) = request.get("source").and_then(|value| value.as_str()) else {
+        return error_response("Missing 'source' field", StatusCode::BAD_REQUEST);
+    };
+    Json(serde_json::json!({ "html": render_markdown(source) })).into_response()
+}
+
 fn store_for(state: &AppState, account: &str, project: &str) -> FileStore {
     let root = if state.multi_tenant {
         FileStore::resolve_tenant_root(&state.base_root, account, project)
@@ -942,6 +1291,15 @@ fn store_for(state: &AppState, account: &str, project: &str) -> FileStore {
     FileStore::new(root)
 }
 
+/// Resolve the store for a write, requiring an initialized project in multi-tenant mode.
+fn writable_store_for(state: &AppState, account: &str, project: &str) -> Option<FileStore> {
+    let store = store_for(state, account, project);
+    if state.multi_tenant && store.load_config().is_err() {
+        return None;
+    }
+    Some(store)
+}
+
 fn store_for_root(state: &AppState) -> Option<FileStore> {
     if state.multi_tenant {
         return None;
@@ -954,6 +1312,17 @@ fn error_response(message: impl Into<String>, status: StatusCode) -> Response {
     (status, Json(payload)).into_response()
 }
 
+fn write_error_response(error: KanbusError) -> Response {
+    let status = match &error {
+        KanbusError::IssueOperation(message) if message == "not found" => StatusCode::NOT_FOUND,
+        KanbusError::Io(_) | KanbusError::IdGenerationFailed(_) => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+        _ => StatusCode::BAD_REQUEST,
+    };
+    error_response(error.to_string(), status)
+}
+
 fn snapshot_payload(store: &FileStore) -> (String, u64) {
     match store.build_snapshot() {
         Ok(snapshot) => {
@@ -1206,21 +1575,6 @@ fn serve_asset_from_filesystem(state: &AppState, asset_path: &str) -> Response {
         })
 }
 
-/// Get the Unix domain socket path for notifications based on project root.
-///
-/// Uses the same hash-based approach as the CLI publisher to ensure they connect to the same socket.
-fn get_notification_socket_path(root: &StdPath) -> PathBuf {
-    use sha2::{Digest, Sha256};
-
-    let canonical = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
-    let mut hasher = Sha256::new();
-    hasher.update(canonical.to_string_lossy().as_bytes());
-    let hash = format!("{:x}", hasher.finalize());
-    let socket_name = format!("kanbus-{}.sock", &hash[..12]);
-
-    std::env::temp_dir().join(socket_name)
-}
-
 /// Listen on Unix domain socket for notification events from CLI commands.
 #[cfg(unix)]
 async fn listen_on_socket(socket_path: PathBuf, state: AppState) -> io::Result<()> {
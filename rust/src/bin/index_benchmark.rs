@@ -42,9 +42,13 @@ fn create_issue(identifier: &str, now: DateTime<Utc>) -> IssueData {
         labels: vec!["benchmark".to_string()],
         dependencies,
         comments: Vec::new(),
+        links: Vec::new(),
         created_at: now,
         updated_at: now,
         closed_at: None,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom: BTreeMap::new(),
     }
 }
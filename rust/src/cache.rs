@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::KanbusError;
 use crate::index::IssueIndex;
+use crate::issue_files::list_issue_files;
 use crate::models::IssueData;
 
 /// Serialized cache representation for the issue index.
@@ -26,26 +27,17 @@ pub fn collect_issue_file_mtimes(
     issues_directory: &Path,
 ) -> Result<BTreeMap<String, f64>, KanbusError> {
     let mut mtimes = BTreeMap::new();
-    let entries =
-        std::fs::read_dir(issues_directory).map_err(|error| KanbusError::Io(error.to_string()))?;
-    for entry in entries {
-        let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
-        let path = entry.path();
-        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
-            continue;
-        }
-        let mtime = mtime_from_entry(&entry)?;
-        if let Some(name) = entry.file_name().to_str() {
+    for path in list_issue_files(issues_directory)? {
+        let mtime = mtime_from_path(&path)?;
+        if let Some(name) = path.file_name().and_then(|name| name.to_str()) {
             mtimes.insert(name.to_string(), mtime);
         }
     }
     Ok(mtimes)
 }
 
-fn mtime_from_entry(entry: &std::fs::DirEntry) -> Result<f64, KanbusError> {
-    let metadata = entry
-        .metadata()
-        .map_err(|error| KanbusError::Io(error.to_string()))?;
+pub(crate) fn mtime_from_path(path: &Path) -> Result<f64, KanbusError> {
+    let metadata = std::fs::metadata(path).map_err(|error| KanbusError::Io(error.to_string()))?;
     let modified = metadata
         .modified()
         .map_err(|error| KanbusError::Io(error.to_string()))?;
@@ -3,46 +3,87 @@
 use std::ffi::OsString;
 use std::path::Path;
 
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
 use clap::error::ErrorKind;
-use clap::{Parser, Subcommand};
-use std::collections::HashSet;
+use clap::{Args, Parser, Subcommand};
+use std::collections::{BTreeMap, HashSet};
 
 use crate::agents_management::ensure_agents_file;
 use crate::beads_write::{
     add_beads_comment, add_beads_dependency, create_beads_issue, delete_beads_comment,
     delete_beads_issue, remove_beads_dependency, update_beads_comment, update_beads_issue,
 };
+use crate::config_edit::{get_configuration_value, set_configuration_value};
 use crate::config_loader::load_project_configuration;
 use crate::console_snapshot::build_console_snapshot;
 use crate::console_telemetry::stream_console_telemetry;
 use crate::content_validation::validate_code_blocks;
 use crate::daemon_client::{request_shutdown, request_status};
+use crate::daemon_protocol::PROTOCOL_VERSION;
 use crate::daemon_server::run_daemon;
-use crate::dependencies::{add_dependency, list_ready_issues, remove_dependency};
-use crate::dependency_tree::{build_dependency_tree, render_dependency_tree};
+use crate::dependencies::{
+    add_dependency, claim_next_ready_issue, list_ready_issues, next_ready_issue, remove_dependency,
+};
+use crate::dependency_tree::{
+    build_dependency_tree, collapse_closed_subtrees, render_dependency_tree,
+};
 use crate::doctor::run_doctor;
+use crate::editor::compose_issue_text;
 use crate::error::KanbusError;
+use crate::event_history::{format_history_line, load_issue_history};
 use crate::file_io::{
     canonicalize_path, ensure_git_repository, get_configuration_path, initialize_project,
     resolve_root,
 };
+use crate::hierarchy::move_issue;
 use crate::ids::format_issue_key;
+use crate::issue_archive::{archive_issues, list_archived_issues, unarchive_issue};
 use crate::issue_close::close_issue;
-use crate::issue_comment::{add_comment, delete_comment, ensure_issue_comment_ids, update_comment};
+use crate::issue_comment::{
+    add_comment, add_reply, delete_comment, ensure_issue_comment_ids, list_comments_since,
+    set_comment_resolved, update_comment, update_last_comment,
+};
+use crate::issue_copy::copy_issue;
 use crate::issue_creation::{create_issue, IssueCreationRequest};
 use crate::issue_delete::delete_issue;
-use crate::issue_display::format_issue_for_display;
-use crate::issue_line::{compute_widths, format_issue_line};
-use crate::issue_listing::list_issues;
-use crate::issue_lookup::load_issue_from_project;
+use crate::issue_display::{
+    blocked_issues, collapse_resolved_comment, format_issue_for_display, format_issue_markdown,
+    format_issue_with_dependencies,
+};
+use crate::issue_export::{export_issues, render_json};
+use crate::issue_labels::{label_counts, rename_label};
+use crate::issue_line::{compute_widths, format_grouped_issue_lines, format_issue_line};
+use crate::issue_links::{add_issue_link, remove_issue_link};
+use crate::issue_listing::{
+    list_git_modified_issue_identifiers, list_issue_identifiers_changed_since, list_issues,
+    ListOptions,
+};
+use crate::issue_lookup::{
+    load_issue_from_project, most_recently_updated, resolve_identifier, LAST_ISSUE_TOKEN,
+};
+use crate::issue_reassign::reassign_issues;
+use crate::issue_reopen::{reopen_beads_issue, reopen_issue};
+use crate::issue_reorganize::reorganize_issues;
+use crate::issue_templates::{apply_template, list_templates, save_template, IssueTemplate};
+use crate::issue_time::{format_minutes, log_time};
 use crate::issue_transfer::{localize_issue, promote_issue};
-use crate::issue_update::update_issue;
+use crate::issue_update::{resolve_next_status, touch_issue, update_issue, IssueUpdateRequest};
 use crate::jira_sync::pull_from_jira;
-use crate::maintenance::{collect_project_stats, validate_project};
-use crate::migration::{load_beads_issue_by_id, load_beads_issues, migrate_from_beads};
+use crate::maintenance::{
+    collect_project_stats, collect_validation_issues, compute_burndown, configured_time_zone,
+    start_of_day, ValidationIssue, ValidationSeverity,
+};
+use crate::migration::{
+    load_beads_issue_by_id, load_beads_issues, merge_from_beads, migrate_from_beads,
+};
 use crate::models::IssueData;
-use crate::queries::{filter_issues, search_issues};
+use crate::queries::{
+    collect_descendant_identifiers, compare_priority, count_issues_by, filter_issues, parse_since,
+    rank_issues, search_issues, sort_issues, IssueFilter, PRIORITY_ORDER_ASCENDING,
+};
 use crate::users::get_current_user;
+use crate::watch::watch_notifications;
 use crate::wiki::{render_wiki_page, WikiRenderRequest};
 
 /// Kanbus CLI arguments.
@@ -69,6 +110,12 @@ pub struct Cli {
     /// Enable Beads compatibility mode (read .beads/issues.jsonl).
     #[arg(long)]
     beads: bool,
+    /// Suppress success messages on stdout (data output is still printed).
+    #[arg(long, global = true)]
+    quiet: bool,
+    /// Never pipe list or show output through a pager.
+    #[arg(long = "no-pager", global = true)]
+    no_pager: bool,
     #[command(subcommand)]
     command: Commands,
 }
@@ -80,6 +127,9 @@ enum Commands {
         /// Create project-local alongside project.
         #[arg(long)]
         local: bool,
+        /// Configuration preset: kanban, scrum, or bugtracker.
+        #[arg(long, value_name = "NAME")]
+        template: Option<String>,
     },
     /// Set up Kanbus helper files.
     Setup {
@@ -112,22 +162,51 @@ enum Commands {
         /// Parent issue identifier.
         #[arg(long)]
         parent: Option<String>,
+        /// Inherit the parent's priority unless --priority is given.
+        #[arg(long = "priority-from-parent", requires = "parent")]
+        priority_from_parent: bool,
         /// Issue labels.
         #[arg(long)]
         label: Vec<String>,
         /// Issue description.
         #[arg(long, num_args = 1..)]
         description: Option<Vec<String>>,
+        /// Compose the title and description in $EDITOR.
+        #[arg(long, conflicts_with = "description")]
+        edit: bool,
+        /// Initial status override (defaults to the configured initial_status).
+        #[arg(long = "start-status", value_name = "STATUS")]
+        start_status: Option<String>,
+        /// Due date (YYYY-MM-DD or RFC 3339 timestamp).
+        #[arg(long, value_name = "DATE")]
+        due: Option<String>,
+        /// Estimated effort in minutes.
+        #[arg(long, value_name = "MINUTES")]
+        estimate: Option<u32>,
         /// Create the issue in project-local.
         #[arg(long)]
         local: bool,
         /// Bypass validation checks.
         #[arg(long = "no-validate")]
         no_validate: bool,
+        /// Create the issue even if another issue has the same title.
+        #[arg(long = "allow-duplicate-title")]
+        allow_duplicate_title: bool,
         /// Automatically focus the issue in the console UI after creation.
         #[arg(long)]
         focus: bool,
     },
+    /// Copy an issue into a new issue with a fresh identifier.
+    Copy {
+        /// Issue identifier to copy.
+        identifier: String,
+        /// Title override for the copy.
+        #[arg(long, num_args = 1..)]
+        title: Option<Vec<String>>,
+        /// Link the copy to the original with a relates-to dependency.
+        #[arg(long)]
+        link: bool,
+    },
     /// Show an issue.
     Show {
         /// Issue identifier.
@@ -135,20 +214,38 @@ enum Commands {
         /// Emit JSON output.
         #[arg(long)]
         json: bool,
+        /// Emit a Markdown document.
+        #[arg(long, conflicts_with = "json")]
+        markdown: bool,
     },
-    /// Update an issue.
-    Update {
+    /// Show the change history of an issue.
+    History {
         /// Issue identifier.
         identifier: String,
+        /// Emit the ordered event records as JSON.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Update an issue.
+    Update {
+        /// Issue identifiers.
+        #[arg(required = true, num_args = 1.., value_name = "IDENTIFIER")]
+        identifiers: Vec<String>,
         /// Updated title.
         #[arg(long, num_args = 1..)]
         title: Option<Vec<String>>,
         /// Updated description.
         #[arg(long, num_args = 1..)]
         description: Option<Vec<String>>,
+        /// Append text to the existing description ('-' for stdin).
+        #[arg(long = "append-description", conflicts_with = "description")]
+        append_description: Option<String>,
         /// Updated status.
         #[arg(long)]
         status: Option<String>,
+        /// Advance to the single forward status allowed by the workflow.
+        #[arg(long = "status-next", conflicts_with = "status")]
+        status_next: bool,
         /// Updated priority.
         #[arg(long)]
         priority: Option<u8>,
@@ -167,6 +264,12 @@ enum Commands {
         /// Updated parent issue identifier.
         #[arg(long)]
         parent: Option<String>,
+        /// Updated due date (YYYY-MM-DD or RFC 3339 timestamp).
+        #[arg(long, value_name = "DATE")]
+        due: Option<String>,
+        /// Updated effort estimate in minutes.
+        #[arg(long, value_name = "MINUTES")]
+        estimate: Option<u32>,
         /// Claim the issue.
         #[arg(long)]
         claim: bool,
@@ -174,8 +277,14 @@ enum Commands {
         #[arg(long = "no-validate")]
         no_validate: bool,
     },
-    /// Close an issue.
+    /// Close one or more issues.
     Close {
+        /// Issue identifiers.
+        #[arg(required = true, num_args = 1.., value_name = "IDENTIFIER")]
+        identifiers: Vec<String>,
+    },
+    /// Reopen a closed issue.
+    Reopen {
         /// Issue identifier.
         identifier: String,
     },
@@ -184,6 +293,62 @@ enum Commands {
         /// Issue identifier.
         identifier: String,
     },
+    /// Bump an issue's updated timestamp without changing any fields.
+    Touch {
+        /// Issue identifier.
+        identifier: String,
+    },
+    /// Move closed issues into the project archive.
+    Archive {
+        /// Archive issues closed at least this many days ago.
+        #[arg(long = "older-than", value_name = "DAYS")]
+        older_than: u32,
+    },
+    /// Restore an archived issue.
+    Unarchive {
+        /// Issue identifier.
+        identifier: String,
+    },
+    /// Assign an issue to someone.
+    Assign {
+        /// Issue identifier.
+        identifier: String,
+        /// New assignee.
+        assignee: String,
+    },
+    /// Remove an issue's assignee.
+    Unassign {
+        /// Issue identifier.
+        identifier: String,
+    },
+    /// Log time spent on an issue.
+    #[command(name = "log-time")]
+    LogTime {
+        /// Issue identifier.
+        identifier: String,
+        /// Minutes of work to log.
+        minutes: u32,
+    },
+    /// Move an issue under a new parent, or clear its parent.
+    Move {
+        /// Issue identifier.
+        identifier: String,
+        /// New parent identifier; omit to clear the parent.
+        #[arg(long)]
+        parent: Option<String>,
+    },
+    /// Reassign all open issues from one assignee to another.
+    Reassign {
+        /// Current assignee.
+        #[arg(long)]
+        from: String,
+        /// New assignee.
+        #[arg(long)]
+        to: String,
+        /// Report matching issues without changing them.
+        #[arg(long = "dry-run")]
+        dry_run: bool,
+    },
     /// Add a comment to an issue.
     Comment {
         #[command(subcommand)]
@@ -196,10 +361,43 @@ enum Commands {
         /// Read comment body from file ('-' for stdin).
         #[arg(long = "body-file", value_name = "PATH")]
         body_file: Option<String>,
+        /// Mark a comment as resolved (full id or prefix).
+        #[arg(long, value_name = "COMMENT_ID", conflicts_with_all = ["unresolve", "body_file"])]
+        resolve: Option<String>,
+        /// Reopen a resolved comment (full id or prefix).
+        #[arg(long, value_name = "COMMENT_ID", conflicts_with = "body_file")]
+        unresolve: Option<String>,
+        /// Reply to a comment (full id or prefix), joining its thread.
+        #[arg(long = "reply-to", value_name = "COMMENT_ID", conflicts_with_all = ["resolve", "unresolve"])]
+        reply_to: Option<String>,
+        /// Record the comment at this RFC 3339 time instead of now.
+        #[arg(long, value_name = "TIMESTAMP")]
+        at: Option<String>,
+        /// Allow --at to be in the future.
+        #[arg(long = "allow-future", requires = "at")]
+        allow_future: bool,
+        /// Replace the text of your most recent comment on the issue.
+        #[arg(long = "edit-last", conflicts_with_all = ["resolve", "unresolve", "reply_to", "at"])]
+        edit_last: bool,
+        /// With --edit-last, edit your latest comment even if others commented after it.
+        #[arg(long, requires = "edit_last")]
+        force: bool,
         /// Bypass validation checks.
         #[arg(long = "no-validate")]
         no_validate: bool,
     },
+    /// List recent comments across all issues.
+    Comments {
+        /// First day of the window (YYYY-MM-DD).
+        #[arg(long, value_name = "DATE")]
+        since: String,
+        /// Only include comments by this author.
+        #[arg(long)]
+        author: Option<String>,
+        /// Emit JSON output.
+        #[arg(long)]
+        json: bool,
+    },
     /// List issues.
     ///
     /// Examples:
@@ -208,59 +406,134 @@ enum Commands {
     ///   kbs list --status open                      only open issues
     ///   kbs list --type task --status in_progress
     ///   kbs issues / kbs epics / kbs tasks / kbs bugs   shorthand aliases
-    List {
-        /// Status filter.
-        #[arg(long)]
-        status: Option<String>,
-        /// Type filter.
-        #[arg(long = "type")]
-        issue_type: Option<String>,
-        /// Assignee filter.
+    List(Box<ListArgs>),
+    /// Search issue titles, descriptions, and comments, best matches first.
+    Search {
+        /// Search terms.
+        #[arg(required = true)]
+        query: Vec<String>,
+        /// Maximum number of results.
         #[arg(long)]
-        assignee: Option<String>,
-        /// Label filter.
+        limit: Option<usize>,
+        /// Show each result's relevance score.
         #[arg(long)]
-        label: Option<String>,
-        /// Sort key.
+        verbose: bool,
+    },
+    /// Validate project integrity.
+    Validate {
+        /// Also report leaf issues (such as sub-tasks) that have no parent.
         #[arg(long)]
-        sort: Option<String>,
-        /// Search term.
+        orphans: bool,
+        /// Emit findings as a JSON list.
         #[arg(long)]
-        search: Option<String>,
-        /// Filter by project label.
-        #[arg(long = "project")]
-        project: Vec<String>,
-        /// Exclude local issues.
-        #[arg(long = "no-local")]
-        no_local: bool,
-        /// Show only local issues.
-        #[arg(long = "local-only")]
-        local_only: bool,
-        /// Plain, non-colorized output for machine parsing.
+        json: bool,
+        /// Exit with status 1 when any warning is reported.
         #[arg(long)]
-        porcelain: bool,
+        strict: bool,
     },
-    /// Validate project integrity.
-    Validate,
     /// Promote a local issue to shared.
     Promote {
         /// Issue identifier.
         identifier: String,
+        /// Replace a shared issue with the same identifier.
+        #[arg(long)]
+        force: bool,
     },
     /// Move a shared issue to project-local.
     Localize {
         /// Issue identifier.
         identifier: String,
+        /// Replace a project-local issue with the same identifier.
+        #[arg(long)]
+        force: bool,
     },
+    /// Move issue files to match the configured issue_layout.
+    Reorganize,
     /// Report project statistics.
-    Stats,
+    Stats {
+        /// Print the number of open issues at the end of each day.
+        #[arg(long, requires = "since")]
+        burndown: bool,
+        /// First day of the burndown series (YYYY-MM-DD).
+        #[arg(long, value_name = "DATE", requires = "burndown")]
+        since: Option<String>,
+        /// Last day of the burndown series (YYYY-MM-DD, defaults to today).
+        #[arg(long, value_name = "DATE", requires = "burndown")]
+        until: Option<String>,
+        /// Output format for --burndown: text or json.
+        #[arg(long, default_value = "text", requires = "burndown")]
+        format: String,
+        /// Break open and closed counts down per assignee.
+        #[arg(long = "by-assignee", conflicts_with = "burndown")]
+        by_assignee: bool,
+        /// Break open and closed counts down per label.
+        #[arg(long = "by-label", conflicts_with = "burndown")]
+        by_label: bool,
+        /// Emit the stats as JSON.
+        #[arg(long, conflicts_with = "burndown")]
+        json: bool,
+    },
+    /// Export issues in another format.
+    ///
+    /// Examples:
+    ///   kbs export --format ical > kanbus.ics     due-dated issues as a calendar feed
+    ///   kbs export --format json --flatten-custom  custom fields as top-level keys
+    Export {
+        /// Export format (csv, ical, json).
+        #[arg(long)]
+        format: String,
+        /// Hoist custom fields to top-level `custom_<key>` keys in JSON output.
+        #[arg(long = "flatten-custom")]
+        flatten_custom: bool,
+        /// Status filter.
+        #[arg(long)]
+        status: Option<String>,
+        /// Type filter.
+        #[arg(long = "type")]
+        issue_type: Option<String>,
+        /// Assignee filter.
+        #[arg(long)]
+        assignee: Option<String>,
+        /// Write the export to this file instead of stdout.
+        #[arg(long, value_name = "PATH")]
+        output: Option<String>,
+    },
     /// Manage issue dependencies.
     #[command(name = "dep", trailing_var_arg = true, allow_hyphen_values = true)]
     Dep {
-        /// Raw arguments: <id> <type> <target> | <id> remove <type> <target> | tree <id> [--depth N] [--format FORMAT]
+        /// Raw arguments: <id> <type> <target> | <id> remove <type> <target> | tree <id> [--depth N] [--format FORMAT] [--porcelain]
         #[arg(num_args = 1..)]
         args: Vec<String>,
     },
+    /// Manage external links on an issue.
+    Link {
+        #[command(subcommand)]
+        command: LinkCommands,
+    },
+    /// Manage labels across all issues.
+    Label {
+        #[command(subcommand)]
+        command: LabelCommands,
+    },
+    /// Read and edit project configuration values.
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+    /// Save and apply reusable issue templates.
+    Template {
+        #[command(subcommand)]
+        command: TemplateCommands,
+    },
+    /// Recommend the highest-priority ready issue.
+    Next {
+        /// Consider issues assigned to this user (defaults to the current user).
+        #[arg(long)]
+        assignee: Option<String>,
+        /// Assign the chosen issue to the user and mark it in progress.
+        #[arg(long)]
+        claim: bool,
+    },
     /// List issues that are ready (not blocked).
     Ready {
         /// Exclude local issues.
@@ -276,9 +549,29 @@ enum Commands {
         command: JiraCommands,
     },
     /// Migrate Beads issues into Kanbus.
-    Migrate,
+    Migrate {
+        /// Import into an existing project, skipping issues that already exist.
+        #[arg(long)]
+        merge: bool,
+        /// With --merge, overwrite existing issues with the Beads data.
+        #[arg(long = "update-existing", requires = "merge")]
+        update_existing: bool,
+    },
     /// Run environment diagnostics.
-    Doctor,
+    Doctor {
+        /// Remove event files that belong to issues which no longer exist.
+        #[arg(long)]
+        fix: bool,
+        /// Exit with status 1 when any warning is reported.
+        #[arg(long)]
+        strict: bool,
+    },
+    /// Show version information.
+    Version {
+        /// Emit version, daemon protocol version, and build details as JSON.
+        #[arg(long)]
+        json: bool,
+    },
     /// Run the daemon server.
     Daemon {
         /// Repository root path.
@@ -301,6 +594,15 @@ enum Commands {
     /// Stop the daemon process.
     #[command(name = "daemon-stop")]
     DaemonStop,
+    /// Print issue changes as they happen.
+    Watch {
+        /// Print each event as JSON.
+        #[arg(long)]
+        json: bool,
+        /// Exit after printing this many events.
+        #[arg(long = "max-events", value_parser = clap::value_parser!(u32).range(1..))]
+        max_events: Option<u32>,
+    },
 }
 
 fn is_help_request(kind: ErrorKind) -> bool {
@@ -388,6 +690,120 @@ fn cover_help_request() {
     let _ = is_help_request(ErrorKind::DisplayVersion);
 }
 
+/// Arguments for `list`, boxed to keep `Commands` small.
+#[derive(Debug, Args)]
+struct ListArgs {
+    /// Status filter.
+    #[arg(long)]
+    status: Option<String>,
+    /// Type filter.
+    #[arg(long = "type")]
+    issue_type: Option<String>,
+    /// Remove issues with this status (repeatable).
+    #[arg(long = "exclude-status", value_name = "STATUS")]
+    exclude_status: Vec<String>,
+    /// Remove issues of this type (repeatable).
+    #[arg(long = "exclude-type", value_name = "TYPE")]
+    exclude_type: Vec<String>,
+    /// Assignee filter.
+    #[arg(long)]
+    assignee: Option<String>,
+    /// Show issues assigned to anyone listed in this file (one assignee per line).
+    #[arg(
+        long = "assignee-file",
+        value_name = "PATH",
+        conflicts_with = "assignee"
+    )]
+    assignee_file: Option<String>,
+    /// Case-insensitive assignee substring filter (e.g. "@acme.com").
+    #[arg(long = "assignee-contains")]
+    assignee_contains: Option<String>,
+    /// Label filter.
+    #[arg(long)]
+    label: Option<String>,
+    /// Label prefix filter (e.g. "area:").
+    #[arg(long = "label-prefix")]
+    label_prefix: Option<String>,
+    /// Show only issues due before this date (YYYY-MM-DD).
+    #[arg(long = "due-before", value_name = "DATE")]
+    due_before: Option<String>,
+    /// Show only open issues whose due date has passed.
+    #[arg(long)]
+    overdue: bool,
+    /// Parent filter (direct children unless --recursive is given).
+    #[arg(long)]
+    parent: Option<String>,
+    /// Include every descendant of --parent.
+    #[arg(long, requires = "parent")]
+    recursive: bool,
+    /// Maximum levels below --parent with --recursive (1 = direct children).
+    #[arg(long, requires = "recursive")]
+    depth: Option<usize>,
+    /// Sort key.
+    #[arg(long)]
+    sort: Option<String>,
+    /// Search term.
+    #[arg(long)]
+    search: Option<String>,
+    /// Filter by project label.
+    #[arg(long = "project")]
+    project: Vec<String>,
+    /// Exclude local issues.
+    #[arg(long = "no-local")]
+    no_local: bool,
+    /// Show only local issues.
+    #[arg(long = "local-only")]
+    local_only: bool,
+    /// Include issues moved to the archive.
+    #[arg(long = "include-archived")]
+    include_archived: bool,
+    /// Show only issues with uncommitted changes in git.
+    #[arg(long = "modified-in-git")]
+    modified_in_git: bool,
+    /// Show only issues whose files changed since a git revision (tag, branch, or commit).
+    #[arg(long = "since-commit", value_name = "REF")]
+    since_commit: Option<String>,
+    /// Show only issues updated at or after an RFC 3339 timestamp or a duration ago (e.g. 24h, 7d).
+    #[arg(long, value_name = "WHEN")]
+    since: Option<String>,
+    /// Plain, non-colorized output for machine parsing.
+    #[arg(long)]
+    porcelain: bool,
+    /// Print the matching issues as a JSON array.
+    #[arg(long, conflicts_with_all = ["porcelain", "count_by", "group_by"])]
+    json: bool,
+    /// With --json, replace the nested custom map with top-level custom_<key> fields.
+    #[arg(long = "flatten-custom", requires = "json")]
+    flatten_custom: bool,
+    /// Read every issue file from disk instead of the issue cache or daemon.
+    #[arg(long = "no-cache")]
+    no_cache: bool,
+    /// Show open issues assigned to the current user.
+    #[arg(long)]
+    mine: bool,
+    /// Show only issues created by the current user.
+    #[arg(long = "created-by-me")]
+    created_by_me: bool,
+    /// Show only issues assigned to the current user.
+    #[arg(long = "assigned-to-me")]
+    assigned_to_me: bool,
+    /// Print issue counts per value of a field (status, type, assignee, priority, label).
+    #[arg(long = "count-by")]
+    count_by: Option<String>,
+    /// Output format for counts (text, json).
+    #[arg(long, default_value = "text", requires = "count_by")]
+    format: String,
+    /// Group output under status or category headers with issue counts.
+    #[arg(long = "group-by", value_name = "FIELD", conflicts_with = "count_by")]
+    group_by: Option<String>,
+    /// Exit with status 1 when any issue matches, reporting the matches.
+    #[arg(long = "fail-if-any", conflicts_with_all = ["fail_if_empty", "count_by"])]
+    fail_if_any: bool,
+    /// Exit with status 1 when no issue matches.
+    #[arg(long = "fail-if-empty", conflicts_with = "count_by")]
+    fail_if_empty: bool,
+}
+
 #[derive(Debug, Subcommand)]
 enum SetupCommands {
     /// Ensure AGENTS.md includes Kanbus guidance.
@@ -417,6 +833,95 @@ enum WikiCommands {
     },
 }
 
+#[derive(Debug, Subcommand)]
+enum LinkCommands {
+    /// Attach an external URL to an issue.
+    Add {
+        /// Issue identifier.
+        identifier: String,
+        /// Link URL.
+        url: String,
+        /// Display title for the link.
+        #[arg(long)]
+        title: Option<String>,
+    },
+    /// Remove an external URL from an issue.
+    Remove {
+        /// Issue identifier.
+        identifier: String,
+        /// Link URL.
+        url: String,
+    },
+    /// List the external links on an issue.
+    List {
+        /// Issue identifier.
+        identifier: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum LabelCommands {
+    /// List every label in use with its issue count.
+    List,
+    /// Rename a label on every issue that carries it.
+    Rename {
+        /// Current label.
+        from: String,
+        /// New label.
+        to: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum TemplateCommands {
+    /// Save an issue template.
+    Save {
+        /// Template name.
+        name: String,
+        /// Default issue title.
+        #[arg(long, num_args = 1..)]
+        title: Option<Vec<String>>,
+        /// Issue type.
+        #[arg(long = "type", value_name = "TYPE")]
+        issue_type: Option<String>,
+        /// Issue priority.
+        #[arg(long)]
+        priority: Option<u8>,
+        /// Issue labels.
+        #[arg(long)]
+        label: Vec<String>,
+        /// Issue description.
+        #[arg(long, num_args = 1..)]
+        description: Option<Vec<String>>,
+    },
+    /// Create an issue from a template.
+    Apply {
+        /// Template name.
+        name: String,
+        /// Issue title (defaults to the template title).
+        #[arg(num_args = 0.., value_name = "TITLE")]
+        title: Vec<String>,
+    },
+    /// List saved templates.
+    List,
+}
+
+#[derive(Debug, Subcommand)]
+enum ConfigCommands {
+    /// Print a configuration value.
+    Get {
+        /// Dotted configuration key, such as `default_priority`.
+        key: String,
+    },
+    /// Set a configuration value after validating the result.
+    Set {
+        /// Dotted configuration key, such as `default_priority`.
+        key: String,
+        /// New value, parsed as YAML.
+        value: String,
+    },
+}
+
 #[derive(Debug, Subcommand)]
 enum ConsoleCommands {
     /// Emit a JSON snapshot for the console.
@@ -495,6 +1000,7 @@ enum ConsoleCommands {
 #[derive(Debug, Subcommand)]
 enum CommentCommands {
     /// Update a comment by id prefix.
+    #[command(visible_alias = "edit")]
     Update {
         /// Issue identifier.
         identifier: String,
@@ -523,6 +1029,22 @@ enum CommentCommands {
 #[derive(Debug, Default)]
 pub struct CommandOutput {
     pub stdout: String,
+    /// Diagnostics printed on stderr alongside a partial or failing result.
+    pub stderr: String,
+    /// Whether the output may be shown through a pager.
+    pub pageable: bool,
+    /// Process exit code; nonzero when the command reports a failure on stdout.
+    pub exit_code: i32,
+}
+
+impl CommandOutput {
+    /// Successful output that prints `stdout`.
+    fn from_stdout(stdout: impl Into<String>) -> Self {
+        Self {
+            stdout: stdout.into(),
+            ..Self::default()
+        }
+    }
 }
 
 /// Run the CLI with explicit arguments.
@@ -532,19 +1054,49 @@ pub struct CommandOutput {
 /// * `args` - Command line arguments.
 /// * `cwd` - Working directory for the command.
 ///
+/// # Returns
+///
+/// The process exit code.
+///
 /// # Errors
 ///
 /// Returns `KanbusError` if execution fails.
-pub fn run_from_args<I, T>(args: I, cwd: &Path) -> Result<(), KanbusError>
+pub fn run_from_args<I, T>(args: I, cwd: &Path) -> Result<i32, KanbusError>
 where
     I: IntoIterator<Item = T>,
     T: Into<OsString> + Clone,
 {
-    let output = run_from_args_with_output(args, cwd)?;
-    if !output.stdout.is_empty() {
+    let output = run_from_args_with_output(args, cwd);
+    crate::notification_publisher::flush_notifications();
+    let output = output?;
+    if !output.stderr.is_empty() {
+        eprintln!("{}", output.stderr);
+    }
+    if output.pageable {
+        crate::pager::print_paged(&output.stdout);
+    } else if output.stdout.ends_with('\n') {
+        // Formats such as iCalendar terminate their own lines.
+        print!("{}", output.stdout);
+    } else if !output.stdout.is_empty() {
         println!("{}", output.stdout);
     }
-    Ok(())
+    Ok(output.exit_code)
+}
+
+/// Format an error for stderr.
+///
+/// With `KANBUS_JSON_ERRORS=1` the error is printed as
+/// `{"error": "...", "code": "..."}` so wrapping tools can match on the code.
+pub fn format_error(error: &KanbusError) -> String {
+    if std::env::var("KANBUS_JSON_ERRORS").as_deref() == Ok("1") {
+        format!(
+            "{{\"error\": {}, \"code\": \"{}\"}}",
+            serde_json::Value::String(error.to_string()),
+            error.code()
+        )
+    } else {
+        error.to_string()
+    }
 }
 
 /// Run the CLI with explicit arguments and capture stdout output.
@@ -566,12 +1118,16 @@ where
     cover_help_request();
     let args_vec: Vec<OsString> = args.into_iter().map(Into::into).collect();
     let beads_flag = args_vec.iter().any(|arg| arg == "--beads");
-    let cli = match Cli::try_parse_from(&args_vec) {
+    let args_vec = rewrite_version_json_args(args_vec);
+    let mut cli = match Cli::try_parse_from(&args_vec) {
         Ok(parsed) => parsed,
         Err(error) => {
             let rendered = error.render().to_string();
             if is_help_request(error.kind()) {
-                return Ok(CommandOutput { stdout: rendered });
+                return Ok(CommandOutput {
+                    stdout: rendered,
+                    ..CommandOutput::default()
+                });
             }
             return Err(KanbusError::IssueOperation(rendered));
         }
@@ -579,13 +1135,240 @@ where
     let root = resolve_root(cwd);
     let root = canonicalize_path(&root).unwrap_or(root);
     let (beads_mode, beads_forced) = resolve_beads_mode(&root, beads_flag)?;
-    let stdout = execute_command(cli.command, &root, beads_mode, beads_forced)?;
+    resolve_relative_identifiers(&mut cli.command, &root, beads_mode)?;
+    let suppress_output = cli.quiet && is_success_message_command(&cli.command);
+    let pageable =
+        !cli.no_pager && matches!(cli.command, Commands::List(_) | Commands::Show { .. });
+    if let Commands::Validate {
+        orphans,
+        json: true,
+        strict,
+    } = cli.command
+    {
+        return validation_report_output(&root, orphans, strict);
+    }
+    let output = execute_command(cli.command, &root, cwd, beads_mode, beads_forced)?;
+    if suppress_output {
+        return Ok(CommandOutput {
+            stderr: output.stderr,
+            exit_code: output.exit_code,
+            ..CommandOutput::default()
+        });
+    }
 
     Ok(CommandOutput {
-        stdout: stdout.unwrap_or_default(),
+        pageable: pageable && !output.stdout.is_empty(),
+        ..output
     })
 }
 
+/// Run `validate --json`, printing the findings on stdout even when they
+/// fail validation so the report stays machine-readable.
+fn validation_report_output(
+    root: &Path,
+    orphans: bool,
+    strict: bool,
+) -> Result<CommandOutput, KanbusError> {
+    let findings = collect_validation_issues(root, orphans)?;
+    let failed = validation_failed(&findings, strict);
+    let stdout = serde_json::to_string_pretty(&findings)
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    Ok(CommandOutput {
+        stdout,
+        exit_code: i32::from(failed),
+        ..CommandOutput::default()
+    })
+}
+
+/// Whether validation findings fail the run: any error, or any finding at
+/// all with `--strict`.
+fn validation_failed(findings: &[ValidationIssue], strict: bool) -> bool {
+    findings
+        .iter()
+        .any(|finding| strict || finding.severity == ValidationSeverity::Error)
+}
+
+/// Route `--version --json` to the `version --json` subcommand, since clap
+/// handles `--version` on its own and exits before flags are parsed.
+fn rewrite_version_json_args(args: Vec<OsString>) -> Vec<OsString> {
+    let requests_version = args
+        .get(1)
+        .is_some_and(|arg| arg == "--version" || arg == "-V");
+    if requests_version && args.iter().skip(2).any(|arg| arg == "--json") {
+        let mut rewritten: Vec<OsString> = args.into_iter().take(1).collect();
+        rewritten.push("version".into());
+        rewritten.push("--json".into());
+        return rewritten;
+    }
+    args
+}
+
+/// Replace relative identifier tokens such as `@last` in identifier arguments.
+///
+/// Only arguments that name an issue are resolved, so titles and comment
+/// text containing the token are left alone.
+fn resolve_relative_identifiers(
+    command: &mut Commands,
+    root: &Path,
+    beads_mode: bool,
+) -> Result<(), KanbusError> {
+    let mut identifiers = identifier_arguments(command);
+    if !identifiers
+        .iter()
+        .any(|arg| arg.as_str() == LAST_ISSUE_TOKEN)
+    {
+        return Ok(());
+    }
+    let resolved = if beads_mode {
+        let issues = load_beads_issues(&beads_root(root))?;
+        most_recently_updated(&issues)
+            .map(|issue| issue.identifier.clone())
+            .ok_or_else(|| {
+                KanbusError::IssueOperation(format!("no issues to resolve {LAST_ISSUE_TOKEN}"))
+            })?
+    } else {
+        resolve_identifier(root, LAST_ISSUE_TOKEN)?
+    };
+    for identifier in identifiers.iter_mut() {
+        if identifier.as_str() == LAST_ISSUE_TOKEN {
+            **identifier = resolved.clone();
+        }
+    }
+    Ok(())
+}
+
+/// Collect the arguments of a command that name an issue.
+fn identifier_arguments(command: &mut Commands) -> Vec<&mut String> {
+    match command {
+        Commands::Create { parent, .. } => parent.iter_mut().collect(),
+        Commands::List(args) => args.parent.iter_mut().collect(),
+        Commands::Update {
+            identifiers,
+            parent,
+            ..
+        } => identifiers.iter_mut().chain(parent.iter_mut()).collect(),
+        Commands::Move {
+            identifier, parent, ..
+        } => std::iter::once(identifier)
+            .chain(parent.iter_mut())
+            .collect(),
+        Commands::Close { identifiers } => identifiers.iter_mut().collect(),
+        Commands::Comment {
+            command: Some(command),
+            ..
+        } => match command {
+            CommentCommands::Update { identifier, .. }
+            | CommentCommands::Delete { identifier, .. }
+            | CommentCommands::EnsureIds { identifier } => vec![identifier],
+        },
+        Commands::Comment { identifier, .. } => identifier.iter_mut().collect(),
+        Commands::Copy { identifier, .. }
+        | Commands::Show { identifier, .. }
+        | Commands::History { identifier, .. }
+        | Commands::Reopen { identifier }
+        | Commands::Delete { identifier }
+        | Commands::Touch { identifier }
+        | Commands::Unarchive { identifier }
+        | Commands::Assign { identifier, .. }
+        | Commands::Unassign { identifier }
+        | Commands::LogTime { identifier, .. }
+        | Commands::Promote { identifier, .. }
+        | Commands::Localize { identifier, .. } => vec![identifier],
+        Commands::Link { command } => match command {
+            LinkCommands::Add { identifier, .. }
+            | LinkCommands::Remove { identifier, .. }
+            | LinkCommands::List { identifier } => vec![identifier],
+        },
+        Commands::Dep { args } => dependency_identifier_arguments(args),
+        _ => Vec::new(),
+    }
+}
+
+/// Collect the issue arguments of raw `dep` arguments: the source and target
+/// of `<id> <type> <target>` and `<id> remove <type> <target>`, or the root
+/// of `tree <id>`.
+fn dependency_identifier_arguments(args: &mut [String]) -> Vec<&mut String> {
+    let positions: &[usize] = match args.first().map(String::as_str) {
+        Some("tree") => &[1],
+        _ if args.get(1).map(String::as_str) == Some("remove") => &[0, 3],
+        _ => &[0, 2],
+    };
+    args.iter_mut()
+        .enumerate()
+        .filter(|(index, _)| positions.contains(index))
+        .map(|(_, arg)| arg)
+        .collect()
+}
+
+/// Apply an action to each issue, continuing past failures.
+///
+/// Prints one `<verb> <id>` line per successful issue on stdout. With a single
+/// identifier its error is returned unchanged; otherwise failures are listed
+/// in a summary on stderr and the command exits with status 1.
+fn run_for_each_issue(
+    identifiers: &[String],
+    verb: &str,
+    mut action: impl FnMut(&str) -> Result<(), KanbusError>,
+) -> Result<CommandOutput, KanbusError> {
+    let mut lines = Vec::new();
+    let mut failures = Vec::new();
+    for identifier in identifiers {
+        match action(identifier) {
+            Ok(()) => lines.push(format!("{verb} {}", format_issue_key(identifier, false))),
+            Err(error) if identifiers.len() == 1 => return Err(error),
+            Err(error) => failures.push(format!("  {identifier}: {error}")),
+        }
+    }
+    if failures.is_empty() {
+        return Ok(CommandOutput::from_stdout(lines.join("\n")));
+    }
+    let mut summary = vec![format!(
+        "{} of {} issues failed:",
+        failures.len(),
+        identifiers.len()
+    )];
+    summary.extend(failures);
+    Ok(CommandOutput {
+        stdout: lines.join("\n"),
+        stderr: summary.join("\n"),
+        exit_code: 1,
+        ..CommandOutput::default()
+    })
+}
+
+/// Whether a command prints a success message rather than requested data.
+fn is_success_message_command(command: &Commands) -> bool {
+    match command {
+        Commands::Init { .. }
+        | Commands::Create { .. }
+        | Commands::Copy { .. }
+        | Commands::Update { .. }
+        | Commands::Close { .. }
+        | Commands::Reopen { .. }
+        | Commands::Delete { .. }
+        | Commands::Touch { .. }
+        | Commands::Assign { .. }
+        | Commands::Unassign { .. }
+        | Commands::LogTime { .. }
+        | Commands::Archive { .. }
+        | Commands::Unarchive { .. }
+        | Commands::Comment { .. }
+        | Commands::Promote { .. }
+        | Commands::Localize { .. }
+        | Commands::Reorganize
+        | Commands::Move { .. }
+        | Commands::Migrate { .. } => true,
+        Commands::Reassign { dry_run, .. } => !dry_run,
+        Commands::Export { output, .. } => output.is_some(),
+        Commands::Link { command } => !matches!(command, LinkCommands::List { .. }),
+        Commands::Label { command } => matches!(command, LabelCommands::Rename { .. }),
+        Commands::Config { command } => matches!(command, ConfigCommands::Set { .. }),
+        Commands::Template { command } => !matches!(command, TemplateCommands::List),
+        Commands::Dep { args } => args.first().map(|arg| arg != "tree").unwrap_or(false),
+        _ => false,
+    }
+}
+
 fn resolve_beads_mode(root: &Path, beads_flag: bool) -> Result<(bool, bool), KanbusError> {
     if beads_flag {
         return Ok((true, true));
@@ -614,20 +1397,21 @@ fn beads_root(root: &Path) -> std::path::PathBuf {
 fn execute_command(
     command: Commands,
     root: &Path,
+    cwd: &Path,
     beads_mode: bool,
     _beads_forced: bool,
-) -> Result<Option<String>, KanbusError> {
+) -> Result<CommandOutput, KanbusError> {
     let root_for_beads = beads_root(root);
     match command {
-        Commands::Init { local } => {
+        Commands::Init { local, template } => {
             ensure_git_repository(root)?;
-            initialize_project(root, local)?;
-            Ok(None)
+            initialize_project(root, local, template.as_deref())?;
+            Ok(CommandOutput::default())
         }
         Commands::Setup { command } => match command {
             SetupCommands::Agents { force } => {
                 ensure_agents_file(root, force)?;
-                Ok(None)
+                Ok(CommandOutput::default())
             }
         },
         Commands::Create {
@@ -636,20 +1420,30 @@ fn execute_command(
             priority,
             assignee,
             parent,
+            priority_from_parent,
             label,
             description,
+            edit,
+            start_status,
+            due,
+            estimate,
             local,
             no_validate,
+            allow_duplicate_title,
             focus,
         } => {
-            let title_text = title.join(" ");
+            let (title_text, description_text) = if edit {
+                compose_issue_text(&title.join(" "))?
+            } else {
+                let description_text = description
+                    .as_ref()
+                    .map(|values| values.join(" "))
+                    .unwrap_or_default();
+                (title.join(" "), description_text)
+            };
             if title_text.trim().is_empty() {
                 return Err(KanbusError::IssueOperation("title is required".to_string()));
             }
-            let description_text = description
-                .as_ref()
-                .map(|values| values.join(" "))
-                .unwrap_or_default();
             if !no_validate && !description_text.is_empty() {
                 validate_code_blocks(&description_text)?;
             }
@@ -659,6 +1453,26 @@ fn execute_command(
                         "beads mode does not support local issues".to_string(),
                     ));
                 }
+                if start_status.is_some() {
+                    return Err(KanbusError::IssueOperation(
+                        "beads mode does not support --start-status".to_string(),
+                    ));
+                }
+                if priority_from_parent {
+                    return Err(KanbusError::IssueOperation(
+                        "beads mode does not support --priority-from-parent".to_string(),
+                    ));
+                }
+                if due.is_some() {
+                    return Err(KanbusError::IssueOperation(
+                        "beads mode does not support --due".to_string(),
+                    ));
+                }
+                if estimate.is_some() {
+                    return Err(KanbusError::IssueOperation(
+                        "beads mode does not support --estimate".to_string(),
+                    ));
+                }
                 let issue = create_beads_issue(
                     &root_for_beads,
                     &title_text,
@@ -689,7 +1503,7 @@ fn execute_command(
                 }
 
                 let use_color = should_use_color();
-                return Ok(Some(format_issue_for_display(
+                return Ok(CommandOutput::from_stdout(format_issue_for_display(
                     &issue, None, use_color, false,
                 )));
             }
@@ -700,14 +1514,22 @@ fn execute_command(
                 priority,
                 assignee,
                 parent,
+                priority_from_parent,
                 labels: label,
                 description: if description_text.is_empty() {
                     None
                 } else {
                     Some(description_text)
                 },
+                status: start_status,
                 local,
                 validate: !no_validate,
+                allow_duplicate_title,
+                due_at: due
+                    .as_deref()
+                    .map(|value| parse_due_timestamp(root, value))
+                    .transpose()?,
+                estimate_minutes: estimate,
             };
             let result = create_issue(&request)?;
             let configuration = result.configuration;
@@ -729,14 +1551,41 @@ fn execute_command(
             }
 
             let use_color = should_use_color();
-            Ok(Some(format_issue_for_display(
+            let display = format_issue_for_display(&issue, Some(&configuration), use_color, false);
+            if let Some(duplicate_identifier) = result.duplicate_of {
+                eprintln!(
+                    "Warning: duplicate title: \"{}\" already exists as {duplicate_identifier}",
+                    issue.title
+                );
+            }
+            Ok(CommandOutput::from_stdout(display))
+        }
+        Commands::Copy {
+            identifier,
+            title,
+            link,
+        } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support copy".to_string(),
+                ));
+            }
+            let title_text = title.as_ref().map(|values| values.join(" "));
+            let issue = copy_issue(root, &identifier, title_text.as_deref(), link)?;
+            let configuration = load_project_configuration(&get_configuration_path(root)?)?;
+            let use_color = should_use_color();
+            Ok(CommandOutput::from_stdout(format_issue_for_display(
                 &issue,
                 Some(&configuration),
                 use_color,
                 false,
             )))
         }
-        Commands::Show { identifier, json } => {
+        Commands::Show {
+            identifier,
+            json,
+            markdown,
+        } => {
             let (issue, configuration) = if beads_mode {
                 let mut beads_issue = load_beads_issue_by_id(&root_for_beads, &identifier)?;
                 // Normalize comment ids for display consistency
@@ -763,30 +1612,90 @@ fn execute_command(
                 }
                 (issue, Some(configuration))
             };
+            if markdown {
+                return Ok(CommandOutput::from_stdout(format_issue_markdown(
+                    &issue, false,
+                )));
+            }
+            // Reverse "blocks" links are not stored on the issue, so the JSON
+            // and text views list the project through the issue cache.
+            let all_issues = if beads_mode {
+                load_beads_issues(&root_for_beads)?
+            } else {
+                list_issues(root, &ListOptions::default())?
+            };
             if json {
+                let mut value = serde_json::to_value(&issue).expect("failed to serialize issue");
+                let blocks = blocked_issues(&issue, &all_issues)
+                    .into_iter()
+                    .map(|blocked| blocked.identifier.clone())
+                    .collect::<Vec<_>>();
+                if let serde_json::Value::Object(map) = &mut value {
+                    map.insert("blocks".to_string(), serde_json::json!(blocks));
+                }
                 let payload =
-                    serde_json::to_string_pretty(&issue).expect("failed to serialize issue");
-                return Ok(Some(payload));
+                    serde_json::to_string_pretty(&value).expect("failed to serialize issue");
+                return Ok(CommandOutput::from_stdout(payload));
+            }
+            let mut dependency_targets = BTreeMap::new();
+            for dependency in &issue.dependencies {
+                let target = if beads_mode {
+                    load_beads_issue_by_id(&root_for_beads, &dependency.target).ok()
+                } else {
+                    load_issue_from_project(root, &dependency.target)
+                        .ok()
+                        .map(|lookup| lookup.issue)
+                };
+                if let Some(target) = target {
+                    dependency_targets.insert(dependency.target.clone(), target);
+                }
             }
             let use_color = should_use_color();
-            Ok(Some(format_issue_for_display(
+            Ok(CommandOutput::from_stdout(format_issue_with_dependencies(
                 &issue,
                 configuration.as_ref(),
                 use_color,
                 false,
+                &dependency_targets,
+                Some(&all_issues),
             )))
         }
+        Commands::History { identifier, json } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support history".to_string(),
+                ));
+            }
+            let lookup = load_issue_from_project(root, &identifier)?;
+            let events = load_issue_history(&lookup.project_dir, &lookup.issue.identifier)?;
+            if json {
+                let payload = serde_json::to_string_pretty(&events)
+                    .map_err(|error| KanbusError::Io(error.to_string()))?;
+                return Ok(CommandOutput::from_stdout(payload));
+            }
+            Ok(CommandOutput::from_stdout(
+                events
+                    .iter()
+                    .map(format_history_line)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ))
+        }
         Commands::Update {
-            identifier,
+            identifiers,
             title,
             description,
+            append_description,
             status,
+            status_next,
             priority,
             assignee,
             add_labels,
             remove_labels,
             set_labels,
             parent,
+            due,
+            estimate,
             claim,
             no_validate,
         } => {
@@ -794,10 +1703,28 @@ fn execute_command(
                 .as_ref()
                 .map(|values| values.join(" "))
                 .unwrap_or_default();
-            let description_text = description
-                .as_ref()
-                .map(|values| values.join(" "))
-                .unwrap_or_default();
+            let appended_text = match append_description.as_deref() {
+                Some(value) => {
+                    let appended = if value == "-" {
+                        use std::io::{stdin, Read};
+                        let mut buffer = String::new();
+                        stdin().read_to_string(&mut buffer).map_err(|error| {
+                            KanbusError::Io(format!("failed to read stdin: {error}"))
+                        })?;
+                        buffer
+                    } else {
+                        value.to_string()
+                    };
+                    let appended = appended.trim_end().to_string();
+                    if appended.is_empty() {
+                        return Err(KanbusError::IssueOperation(
+                            "append text is required".to_string(),
+                        ));
+                    }
+                    Some(appended)
+                }
+                None => None,
+            };
             let assignee_value = if claim {
                 Some(get_current_user())
             } else {
@@ -808,82 +1735,333 @@ fn execute_command(
             } else {
                 Some(title_text.as_str())
             };
-            let description_value = if description_text.is_empty() {
-                None
-            } else {
-                Some(description_text.as_str())
-            };
-            if !no_validate {
-                if let Some(text) = description_value {
-                    validate_code_blocks(text)?;
-                }
-            }
             if beads_mode {
                 if parent.is_some() {
                     return Err(KanbusError::IssueOperation(
                         "parent update not supported in beads mode".to_string(),
                     ));
                 }
-                update_beads_issue(
-                    &root_for_beads,
-                    &identifier,
-                    status.as_deref(),
-                    priority,
-                    title_value,
-                    description_value,
-                    assignee_value.as_deref(),
-                    &add_labels,
-                    &remove_labels,
-                    set_labels.as_deref(),
-                )?;
+                if status_next {
+                    return Err(KanbusError::IssueOperation(
+                        "beads mode does not support --status-next".to_string(),
+                    ));
+                }
+                if due.is_some() {
+                    return Err(KanbusError::IssueOperation(
+                        "beads mode does not support --due".to_string(),
+                    ));
+                }
+                if estimate.is_some() {
+                    return Err(KanbusError::IssueOperation(
+                        "beads mode does not support --estimate".to_string(),
+                    ));
+                }
+            }
+            let due_at = if beads_mode {
+                None
             } else {
-                update_issue(
-                    root,
-                    &identifier,
-                    title_value,
-                    description_value,
-                    status.as_deref(),
-                    assignee_value.as_deref(),
-                    priority,
-                    claim,
-                    !no_validate,
-                    &add_labels,
-                    &remove_labels,
-                    set_labels.as_deref(),
-                    parent.as_deref(),
-                )?;
+                due.as_deref()
+                    .map(|value| parse_due_timestamp(root, value))
+                    .transpose()?
+            };
+            run_for_each_issue(&identifiers, "Updated", |identifier| {
+                let description_text = if let Some(appended) = appended_text.as_deref() {
+                    let existing = if beads_mode {
+                        load_beads_issue_by_id(&root_for_beads, identifier)?.description
+                    } else {
+                        load_issue_from_project(root, identifier)?.issue.description
+                    };
+                    if existing.is_empty() {
+                        appended.to_string()
+                    } else {
+                        format!("{}\n{appended}", existing.trim_end_matches('\n'))
+                    }
+                } else {
+                    description
+                        .as_ref()
+                        .map(|values| values.join(" "))
+                        .unwrap_or_default()
+                };
+                let description_value = if description_text.is_empty() {
+                    None
+                } else {
+                    Some(description_text.as_str())
+                };
+                if !no_validate {
+                    if let Some(text) = description_value {
+                        validate_code_blocks(text)?;
+                    }
+                }
+                if beads_mode {
+                    update_beads_issue(
+                        &root_for_beads,
+                        identifier,
+                        status.as_deref(),
+                        priority,
+                        title_value,
+                        description_value,
+                        assignee_value.as_deref(),
+                        &add_labels,
+                        &remove_labels,
+                        set_labels.as_deref(),
+                    )?;
+                } else {
+                    let status = if status_next {
+                        Some(resolve_next_status(root, identifier)?)
+                    } else {
+                        status.clone()
+                    };
+                    update_issue(&IssueUpdateRequest {
+                        title: title_value.map(str::to_string),
+                        description: description_value.map(str::to_string),
+                        status: status.clone(),
+                        assignee: assignee_value.clone(),
+                        priority,
+                        claim,
+                        validate: !no_validate,
+                        add_labels: add_labels.clone(),
+                        remove_labels: remove_labels.clone(),
+                        set_labels: set_labels.clone(),
+                        parent: parent.clone(),
+                        due_at,
+                        estimate_minutes: estimate,
+                        ..IssueUpdateRequest::new(root, identifier)
+                    })?;
+                }
+                Ok(())
+            })
+        }
+        Commands::Close { identifiers } => {
+            run_for_each_issue(&identifiers, "Closed", |identifier| {
+                if beads_mode {
+                    update_beads_issue(
+                        &root_for_beads,
+                        identifier,
+                        Some("closed"),
+                        None,
+                        None,
+                        None,
+                        None,
+                        &[],
+                        &[],
+                        None,
+                    )?;
+                } else {
+                    close_issue(root, identifier)?;
+                }
+                Ok(())
+            })
+        }
+        Commands::Reopen { identifier } => {
+            let issue = if beads_mode {
+                reopen_beads_issue(&root_for_beads, &identifier)?
+            } else {
+                reopen_issue(root, &identifier)?
+            };
+            Ok(CommandOutput::from_stdout(format!(
+                "Reopened {}",
+                format_issue_key(&issue.identifier, false)
+            )))
+        }
+        Commands::Delete { identifier } => {
+            if beads_mode {
+                delete_beads_issue(&root_for_beads, &identifier)?;
+            } else {
+                delete_issue(root, &identifier)?;
             }
             let formatted_identifier = format_issue_key(&identifier, false);
-            Ok(Some(format!("Updated {}", formatted_identifier)))
+            Ok(CommandOutput::from_stdout(format!(
+                "Deleted {}",
+                formatted_identifier
+            )))
         }
-        Commands::Close { identifier } => {
-            if beads_mode {
+        Commands::Assign {
+            identifier,
+            assignee,
+        } => {
+            let assignee = assignee.trim();
+            if assignee.is_empty() {
+                return Err(KanbusError::IssueOperation(
+                    "assignee must not be empty".to_string(),
+                ));
+            }
+            let resolved = if beads_mode {
                 update_beads_issue(
                     &root_for_beads,
                     &identifier,
-                    Some("closed"),
                     None,
                     None,
                     None,
                     None,
+                    Some(assignee),
                     &[],
                     &[],
                     None,
                 )?;
+                identifier
             } else {
-                close_issue(root, &identifier)?;
+                update_issue(&IssueUpdateRequest {
+                    assignee: Some(assignee.to_string()),
+                    ..IssueUpdateRequest::new(root, identifier.clone())
+                })?
+                .identifier
+            };
+            Ok(CommandOutput::from_stdout(format!(
+                "Assigned {} to {assignee}",
+                format_issue_key(&resolved, false)
+            )))
+        }
+        Commands::Unassign { identifier } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support unassign".to_string(),
+                ));
             }
-            let formatted_identifier = format_issue_key(&identifier, false);
-            Ok(Some(format!("Closed {}", formatted_identifier)))
+            let issue = update_issue(&IssueUpdateRequest {
+                clear_assignee: true,
+                ..IssueUpdateRequest::new(root, identifier.clone())
+            })?;
+            Ok(CommandOutput::from_stdout(format!(
+                "Unassigned {}",
+                format_issue_key(&issue.identifier, false)
+            )))
         }
-        Commands::Delete { identifier } => {
+        Commands::LogTime {
+            identifier,
+            minutes,
+        } => {
             if beads_mode {
-                delete_beads_issue(&root_for_beads, &identifier)?;
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support log-time".to_string(),
+                ));
+            }
+            let issue = log_time(root, &identifier, minutes)?;
+            Ok(CommandOutput::from_stdout(format!(
+                "Logged {} on {}",
+                format_minutes(minutes),
+                format_issue_key(&issue.identifier, false)
+            )))
+        }
+        Commands::Touch { identifier } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support touch".to_string(),
+                ));
+            }
+            let issue = touch_issue(root, &identifier)?;
+            Ok(CommandOutput::from_stdout(format!(
+                "Touched {}",
+                format_issue_key(&issue.identifier, false)
+            )))
+        }
+        Commands::Archive { older_than } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support archive".to_string(),
+                ));
+            }
+            let identifiers = archive_issues(root, older_than)?;
+            let mut lines = identifiers
+                .iter()
+                .map(|identifier| format!("Archived {}", format_issue_key(identifier, false)))
+                .collect::<Vec<_>>();
+            lines.push(format!("archived {} issues", identifiers.len()));
+            Ok(CommandOutput::from_stdout(lines.join("\n")))
+        }
+        Commands::Unarchive { identifier } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support archive".to_string(),
+                ));
+            }
+            let issue = unarchive_issue(root, &identifier)?;
+            Ok(CommandOutput::from_stdout(format!(
+                "Unarchived {}",
+                format_issue_key(&issue.identifier, false)
+            )))
+        }
+        Commands::Move { identifier, parent } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support move".to_string(),
+                ));
+            }
+            let issue = move_issue(root, &identifier, parent.as_deref())?;
+            let message = match &issue.parent {
+                Some(parent) => format!(
+                    "Moved {} under {}",
+                    format_issue_key(&issue.identifier, false),
+                    format_issue_key(parent, false)
+                ),
+                None => format!(
+                    "Cleared parent of {}",
+                    format_issue_key(&issue.identifier, false)
+                ),
+            };
+            Ok(CommandOutput::from_stdout(message))
+        }
+        Commands::Reassign { from, to, dry_run } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support reassign".to_string(),
+                ));
+            }
+            let identifiers = reassign_issues(root, &from, &to, dry_run)?;
+            let verb = if dry_run {
+                "would reassign"
             } else {
-                delete_issue(root, &identifier)?;
+                "reassigned"
+            };
+            let mut lines = identifiers
+                .iter()
+                .map(|identifier| {
+                    format!(
+                        "{verb} {}: {from} -> {to}",
+                        format_issue_key(identifier, false)
+                    )
+                })
+                .collect::<Vec<_>>();
+            lines.push(format!("{verb} {} issues", identifiers.len()));
+            Ok(CommandOutput::from_stdout(lines.join("\n")))
+        }
+        Commands::Comments {
+            since,
+            author,
+            json,
+        } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support comments digest".to_string(),
+                ));
             }
-            let formatted_identifier = format_issue_key(&identifier, false);
-            Ok(Some(format!("Deleted {}", formatted_identifier)))
+            let since = parse_calendar_date(&since)?;
+            let entries = list_comments_since(root, since, author.as_deref())?;
+            if json {
+                return serde_json::to_string_pretty(&entries)
+                    .map(CommandOutput::from_stdout)
+                    .map_err(|error| KanbusError::Io(error.to_string()));
+            }
+            Ok(CommandOutput::from_stdout(
+                entries
+                    .iter()
+                    .filter(|entry| !(entry.resolved && entry.reply_to.is_some()))
+                    .map(|entry| {
+                        let text = if entry.resolved {
+                            collapse_resolved_comment(&entry.text, false)
+                        } else {
+                            entry.text.clone()
+                        };
+                        format!(
+                            "{} {} {}: {}",
+                            entry.created_at.to_rfc3339(),
+                            entry.issue_id,
+                            entry.author,
+                            text
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ))
         }
         Commands::Comment {
             command,
@@ -891,6 +2069,13 @@ fn execute_command(
             text,
             no_validate,
             body_file,
+            resolve,
+            unresolve,
+            reply_to,
+            at,
+            allow_future,
+            edit_last,
+            force,
         } => match command {
             Some(CommentCommands::Update {
                 identifier,
@@ -911,7 +2096,7 @@ fn execute_command(
                 } else {
                     update_comment(root, &identifier, &comment_id, &text_value)?;
                 }
-                Ok(None)
+                Ok(CommandOutput::default())
             }
             Some(CommentCommands::Delete {
                 identifier,
@@ -922,7 +2107,7 @@ fn execute_command(
                 } else {
                     delete_comment(root, &identifier, &comment_id)?;
                 }
-                Ok(None)
+                Ok(CommandOutput::default())
             }
             Some(CommentCommands::EnsureIds { identifier }) => {
                 if beads_mode {
@@ -931,7 +2116,7 @@ fn execute_command(
                     ));
                 }
                 ensure_issue_comment_ids(root, &identifier)?;
-                Ok(None)
+                Ok(CommandOutput::default())
             }
             None => {
                 let Some(identifier) = identifier else {
@@ -939,6 +2124,26 @@ fn execute_command(
                         "issue identifier is required".to_string(),
                     ));
                 };
+                let resolution = match (resolve, unresolve) {
+                    (Some(comment_id), _) => Some((comment_id, true)),
+                    (None, Some(comment_id)) => Some((comment_id, false)),
+                    (None, None) => None,
+                };
+                if let Some((comment_id, resolved)) = resolution {
+                    if !text.is_empty() {
+                        return Err(KanbusError::IssueOperation(
+                            "comment text cannot be combined with --resolve or --unresolve"
+                                .to_string(),
+                        ));
+                    }
+                    if beads_mode {
+                        return Err(KanbusError::IssueOperation(
+                            "beads mode does not support comment resolution".to_string(),
+                        ));
+                    }
+                    set_comment_resolved(root, &identifier, &comment_id, resolved)?;
+                    return Ok(CommandOutput::default());
+                }
                 let text_value = if let Some(path) = body_file.as_deref() {
                     if path == "-" {
                         use std::io::{stdin, Read};
@@ -963,52 +2168,162 @@ fn execute_command(
                 if !no_validate {
                     validate_code_blocks(&text_value)?;
                 }
-                if beads_mode {
+                if edit_last {
+                    if beads_mode {
+                        return Err(KanbusError::IssueOperation(
+                            "beads mode does not support --edit-last".to_string(),
+                        ));
+                    }
+                    update_last_comment(
+                        root,
+                        &identifier,
+                        &get_current_user(),
+                        &text_value,
+                        force,
+                    )?;
+                    return Ok(CommandOutput::default());
+                }
+                let created_at = match at.as_deref() {
+                    Some(value) => Some(parse_comment_timestamp(value, allow_future)?),
+                    None => None,
+                };
+                if let Some(reply_to) = reply_to {
+                    if beads_mode {
+                        return Err(KanbusError::IssueOperation(
+                            "beads mode does not support comment replies".to_string(),
+                        ));
+                    }
+                    add_reply(
+                        root,
+                        &identifier,
+                        &get_current_user(),
+                        &text_value,
+                        created_at,
+                        &reply_to,
+                    )?;
+                } else if beads_mode {
                     add_beads_comment(
                         &root_for_beads,
                         &identifier,
                         &get_current_user(),
                         &text_value,
+                        created_at,
                     )?;
                 } else {
-                    add_comment(root, &identifier, &get_current_user(), &text_value)?;
+                    add_comment(
+                        root,
+                        &identifier,
+                        &get_current_user(),
+                        &text_value,
+                        created_at,
+                    )?;
                 }
-                Ok(None)
+                Ok(CommandOutput::default())
             }
         },
-        Commands::Promote { identifier } => {
-            promote_issue(root, &identifier)?;
-            Ok(None)
+        Commands::Promote { identifier, force } => {
+            promote_issue(root, &identifier, force)?;
+            Ok(CommandOutput::default())
         }
-        Commands::Localize { identifier } => {
-            localize_issue(root, &identifier)?;
-            Ok(None)
+        Commands::Localize { identifier, force } => {
+            localize_issue(root, &identifier, force)?;
+            Ok(CommandOutput::default())
         }
-        Commands::List {
-            status,
-            issue_type,
-            assignee,
-            label,
-            sort,
-            search,
-            project,
-            no_local,
-            local_only,
-            porcelain,
-        } => {
-            let issues = if beads_mode {
+        Commands::List(args) => {
+            let ListArgs {
+                status,
+                issue_type,
+                exclude_status,
+                exclude_type,
+                assignee,
+                assignee_file,
+                assignee_contains,
+                label,
+                label_prefix,
+                due_before,
+                overdue,
+                parent,
+                recursive,
+                depth,
+                sort,
+                search,
+                project,
+                no_local,
+                local_only,
+                include_archived,
+                modified_in_git,
+                since_commit,
+                since,
+                porcelain,
+                json,
+                flatten_custom,
+                no_cache,
+                mine,
+                created_by_me,
+                assigned_to_me,
+                count_by,
+                format,
+                group_by,
+                fail_if_any,
+                fail_if_empty,
+            } = *args;
+            if depth == Some(0) {
+                return Err(KanbusError::IssueOperation(
+                    "depth must be at least 1".to_string(),
+                ));
+            }
+            let assignee = if mine {
+                assignee.or_else(|| Some(get_current_user()))
+            } else {
+                assignee
+            };
+            let updated_cutoff = since
+                .as_deref()
+                .map(|value| parse_since(value, Utc::now()))
+                .transpose()?;
+            let due_cutoff = if beads_mode {
+                None
+            } else {
+                due_before
+                    .as_deref()
+                    .map(|value| parse_due_before(root, value))
+                    .transpose()?
+            };
+            let mut issues = if beads_mode {
                 if local_only || no_local {
                     return Err(KanbusError::IssueOperation(
                         "beads mode does not support local filtering".to_string(),
                     ));
                 }
+                if include_archived {
+                    return Err(KanbusError::IssueOperation(
+                        "beads mode does not support archive".to_string(),
+                    ));
+                }
+                if modified_in_git || since_commit.is_some() {
+                    return Err(KanbusError::IssueOperation(
+                        "beads mode does not support git filters".to_string(),
+                    ));
+                }
+                if due_before.is_some() || overdue {
+                    return Err(KanbusError::IssueOperation(
+                        "beads mode does not support due date filters".to_string(),
+                    ));
+                }
                 let issues = load_beads_issues(&root_for_beads)?;
                 let filtered = filter_issues(
                     issues,
-                    status.as_deref(),
-                    issue_type.as_deref(),
-                    assignee.as_deref(),
-                    label.as_deref(),
+                    &IssueFilter {
+                        status: status.as_deref(),
+                        issue_type: issue_type.as_deref(),
+                        assignee: assignee.as_deref(),
+                        assignee_contains: assignee_contains.as_deref(),
+                        label: label.as_deref(),
+                        label_prefix: label_prefix.as_deref(),
+                        exclude_statuses: &exclude_status,
+                        exclude_types: &exclude_type,
+                        ..IssueFilter::default()
+                    },
                 );
                 let mut searched = search_issues(filtered, search.as_deref());
                 // Beads fixtures include closed issues; align with Kanbus list default by hiding
@@ -1016,27 +2331,155 @@ fn execute_command(
                 if status.is_none() {
                     searched.retain(|issue| !issue.status.eq_ignore_ascii_case("closed"));
                 }
+                let priority_order = get_configuration_path(root)
+                    .and_then(|path| load_project_configuration(&path))
+                    .map(|configuration| configuration.priority_order)
+                    .unwrap_or_else(|_| PRIORITY_ORDER_ASCENDING.to_string());
                 searched.sort_by(|a, b| {
-                    a.priority
-                        .cmp(&b.priority)
+                    compare_priority(a.priority, b.priority, &priority_order)
                         .then_with(|| sort_timestamp(b).total_cmp(&sort_timestamp(a)))
                         .then(a.identifier.cmp(&b.identifier))
                 });
                 searched
             } else {
-                list_issues(
+                let mut issues = list_issues(
                     root,
-                    status.as_deref(),
-                    issue_type.as_deref(),
-                    assignee.as_deref(),
-                    label.as_deref(),
-                    sort.as_deref(),
-                    search.as_deref(),
-                    &project,
-                    !no_local,
-                    local_only,
-                )?
+                    &ListOptions {
+                        status: status.as_deref(),
+                        issue_type: issue_type.as_deref(),
+                        assignee: assignee.as_deref(),
+                        label: label.as_deref(),
+                        sort: sort.as_deref(),
+                        search: search.as_deref(),
+                        project_filter: &project,
+                        include_local: !no_local,
+                        local_only,
+                        use_cache: !no_cache,
+                    },
+                )?;
+                if include_archived {
+                    let archived = filter_issues(
+                        list_archived_issues(root)?,
+                        &IssueFilter {
+                            status: status.as_deref(),
+                            issue_type: issue_type.as_deref(),
+                            assignee: assignee.as_deref(),
+                            label: label.as_deref(),
+                            ..IssueFilter::default()
+                        },
+                    );
+                    issues.extend(search_issues(archived, search.as_deref()));
+                    let priority_order = get_configuration_path(root)
+                        .and_then(|path| load_project_configuration(&path))
+                        .map(|configuration| configuration.priority_order)
+                        .unwrap_or_else(|_| PRIORITY_ORDER_ASCENDING.to_string());
+                    issues = sort_issues(issues, sort.as_deref(), &priority_order)?;
+                }
+                issues
             };
+            if mine && status.is_none() {
+                issues.retain(|issue| !issue.status.eq_ignore_ascii_case("closed"));
+            }
+            if let Some(path) = assignee_file.as_deref() {
+                let contents = std::fs::read_to_string(cwd.join(path)).map_err(|error| {
+                    KanbusError::Io(format!("failed to read assignee file: {error}"))
+                })?;
+                let roster: HashSet<&str> = contents
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .collect();
+                issues.retain(|issue| {
+                    issue
+                        .assignee
+                        .as_deref()
+                        .is_some_and(|assignee| roster.contains(assignee))
+                });
+            }
+            if created_by_me || assigned_to_me {
+                let current_user = get_current_user();
+                issues.retain(|issue| {
+                    (!created_by_me || issue.creator.as_deref() == Some(current_user.as_str()))
+                        && (!assigned_to_me
+                            || issue.assignee.as_deref() == Some(current_user.as_str()))
+                });
+            }
+            if !beads_mode
+                && (label_prefix.is_some()
+                    || assignee_contains.is_some()
+                    || due_cutoff.is_some()
+                    || overdue
+                    || !exclude_status.is_empty()
+                    || !exclude_type.is_empty())
+            {
+                issues = filter_issues(
+                    issues,
+                    &IssueFilter {
+                        assignee_contains: assignee_contains.as_deref(),
+                        label_prefix: label_prefix.as_deref(),
+                        due_before: due_cutoff,
+                        overdue,
+                        exclude_statuses: &exclude_status,
+                        exclude_types: &exclude_type,
+                        ..IssueFilter::default()
+                    },
+                );
+            }
+            if let Some(cutoff) = updated_cutoff {
+                issues.retain(|issue| issue.updated_at >= cutoff);
+            }
+            if modified_in_git {
+                let modified = list_git_modified_issue_identifiers(root)?;
+                issues.retain(|issue| modified.contains(&issue.identifier));
+            }
+            if let Some(reference) = since_commit.as_deref() {
+                let changed = list_issue_identifiers_changed_since(root, reference)?;
+                issues.retain(|issue| changed.contains(&issue.identifier));
+            }
+            if let Some(parent) = parent.as_deref() {
+                let (parent_identifier, hierarchy) = if beads_mode {
+                    (
+                        load_beads_issue_by_id(&root_for_beads, parent)?.identifier,
+                        load_beads_issues(&root_for_beads)?,
+                    )
+                } else {
+                    (
+                        load_issue_from_project(root, parent)?.issue.identifier,
+                        list_issues(
+                            root,
+                            &ListOptions {
+                                project_filter: &project,
+                                include_local: !no_local,
+                                local_only,
+                                use_cache: !no_cache,
+                                ..ListOptions::default()
+                            },
+                        )?,
+                    )
+                };
+                let max_depth = if recursive { depth } else { Some(1) };
+                let descendants =
+                    collect_descendant_identifiers(&hierarchy, &parent_identifier, max_depth);
+                issues.retain(|issue| descendants.contains(&issue.identifier));
+            }
+            if let Some(field) = count_by.as_deref() {
+                let counts = count_issues_by(&issues, field)?;
+                return match format.as_str() {
+                    "text" => Ok(CommandOutput::from_stdout(
+                        counts
+                            .iter()
+                            .map(|entry| format!("{}: {}", entry.key, entry.count))
+                            .collect::<Vec<_>>()
+                            .join("\n"),
+                    )),
+                    "json" => serde_json::to_string_pretty(&counts)
+                        .map(CommandOutput::from_stdout)
+                        .map_err(|error| KanbusError::Io(error.to_string())),
+                    _ => Err(KanbusError::IssueOperation(format!(
+                        "unsupported list format: {format}"
+                    ))),
+                };
+            }
             let configuration = if beads_mode {
                 None
             } else {
@@ -1075,14 +2518,102 @@ fn execute_command(
                     )
                 })
                 .collect::<Vec<_>>();
-            Ok(Some(lines.join("\n")))
+            let stdout = if json {
+                render_json(&issues, flatten_custom)?
+            } else if let Some(group_by) = group_by {
+                format_grouped_issue_lines(
+                    &issues,
+                    &group_by,
+                    porcelain,
+                    project_context,
+                    configuration.as_ref(),
+                    None,
+                )?
+            } else {
+                lines.join("\n")
+            };
+            let failure = if fail_if_any && !issues.is_empty() {
+                Some(format!("{} matching issues (--fail-if-any)", issues.len()))
+            } else if fail_if_empty && issues.is_empty() {
+                Some("no matching issues (--fail-if-empty)".to_string())
+            } else {
+                None
+            };
+            Ok(match failure {
+                Some(stderr) => CommandOutput {
+                    stdout,
+                    stderr,
+                    exit_code: 1,
+                    ..CommandOutput::default()
+                },
+                None => CommandOutput::from_stdout(stdout),
+            })
         }
-        Commands::Validate => {
-            validate_project(root)?;
-            Ok(None)
+        // `validate --json` is answered by `validation_report_output`.
+        Commands::Validate {
+            orphans, strict, ..
+        } => {
+            let findings = collect_validation_issues(root, orphans)?;
+            let failed = validation_failed(&findings, strict);
+            let lines = findings
+                .iter()
+                .map(|finding| match finding.severity {
+                    ValidationSeverity::Error => finding.describe(),
+                    ValidationSeverity::Warning => format!("warning: {}", finding.describe()),
+                })
+                .collect::<Vec<_>>();
+            if failed {
+                return Err(KanbusError::IssueOperation(format!(
+                    "validation failed:\n{}",
+                    lines.join("\n")
+                )));
+            }
+            Ok(CommandOutput::from_stdout(lines.join("\n")))
         }
-        Commands::Stats => {
-            let stats = collect_project_stats(root)?;
+        Commands::Stats {
+            burndown,
+            since,
+            until,
+            format,
+            by_assignee,
+            by_label,
+            json,
+        } => {
+            if burndown {
+                let since = parse_calendar_date(since.as_deref().unwrap_or_default())?;
+                let until = match until.as_deref() {
+                    Some(value) => parse_calendar_date(value)?,
+                    None => Utc::now().date_naive(),
+                };
+                let series = compute_burndown(root, since, until)?;
+                return match format.as_str() {
+                    "text" => Ok(CommandOutput::from_stdout(
+                        series
+                            .iter()
+                            .map(|(day, open)| format!("{day}: {open}"))
+                            .collect::<Vec<_>>()
+                            .join("\n"),
+                    )),
+                    "json" => {
+                        let map: serde_json::Map<String, serde_json::Value> = series
+                            .iter()
+                            .map(|(day, open)| (day.to_string(), serde_json::json!(open)))
+                            .collect();
+                        serde_json::to_string_pretty(&map)
+                            .map(CommandOutput::from_stdout)
+                            .map_err(|error| KanbusError::Io(error.to_string()))
+                    }
+                    other => Err(KanbusError::IssueOperation(format!(
+                        "unsupported burndown format: {other}"
+                    ))),
+                };
+            }
+            let stats = collect_project_stats(root, by_assignee, by_label)?;
+            if json {
+                return serde_json::to_string_pretty(&stats)
+                    .map(CommandOutput::from_stdout)
+                    .map_err(|error| KanbusError::Io(error.to_string()));
+            }
             let mut lines = Vec::new();
             lines.push(format!("total issues: {}", stats.total));
             lines.push(format!("open issues: {}", stats.open_count));
@@ -1090,7 +2621,68 @@ fn execute_command(
             for (issue_type, count) in stats.type_counts {
                 lines.push(format!("type: {issue_type}: {count}"));
             }
-            Ok(Some(lines.join("\n")))
+            lines.push(format!(
+                "open estimated minutes: {}",
+                stats.open_estimate_minutes
+            ));
+            lines.push(format!("open spent minutes: {}", stats.open_spent_minutes));
+            for (heading, groups) in [
+                ("assignees", &stats.assignee_counts),
+                ("labels", &stats.label_counts),
+            ] {
+                let Some(groups) = groups else {
+                    continue;
+                };
+                lines.push(format!("{heading}:"));
+                for (group, counts) in groups {
+                    lines.push(format!(
+                        "  {group}: {} open, {} closed",
+                        counts.open, counts.closed
+                    ));
+                }
+            }
+            Ok(CommandOutput::from_stdout(lines.join("\n")))
+        }
+        Commands::Reorganize => {
+            let result = reorganize_issues(root)?;
+            Ok(CommandOutput::from_stdout(format!(
+                "reorganized {} issues",
+                result.moved_count
+            )))
+        }
+        Commands::Export {
+            format,
+            flatten_custom,
+            status,
+            issue_type,
+            assignee,
+            output,
+        } => {
+            if flatten_custom && format != "json" {
+                return Err(KanbusError::IssueOperation(
+                    "--flatten-custom requires --format json".to_string(),
+                ));
+            }
+            let exported = export_issues(
+                root,
+                &format,
+                flatten_custom,
+                status.as_deref(),
+                issue_type.as_deref(),
+                assignee.as_deref(),
+            )?;
+            let Some(path) = output else {
+                return Ok(CommandOutput::from_stdout(exported));
+            };
+            let contents = if exported.ends_with('\n') {
+                exported
+            } else {
+                format!("{exported}\n")
+            };
+            std::fs::write(cwd.join(&path), contents).map_err(|error| {
+                KanbusError::Io(format!("failed to write export file: {error}"))
+            })?;
+            Ok(CommandOutput::from_stdout(format!("Exported to {path}")))
         }
         Commands::Dep { args } => {
             if args.is_empty() {
@@ -1099,7 +2691,7 @@ fn execute_command(
                 ));
             }
 
-            // Tree handling: kanbus dep tree <id> [--depth N] [--format FORMAT]
+            // Tree handling: kanbus dep tree <id> [--depth N] [--format FORMAT] [--porcelain] [--collapse-closed]
             if args[0] == "tree" {
                 if args.len() < 2 {
                     return Err(KanbusError::IssueOperation(
@@ -1109,6 +2701,7 @@ fn execute_command(
                 let identifier = args[1].clone();
                 let mut depth: Option<usize> = None;
                 let mut format = "text".to_string();
+                let mut collapse_closed = false;
                 let mut index = 2;
                 while index < args.len() {
                     match args[index].as_str() {
@@ -1126,14 +2719,30 @@ fn execute_command(
                             format = args[index + 1].clone();
                             index += 2;
                         }
+                        "--porcelain" => {
+                            format = "porcelain".to_string();
+                            index += 1;
+                        }
+                        "--collapse-closed" => {
+                            collapse_closed = true;
+                            index += 1;
+                        }
                         _ => {
                             index += 1;
                         }
                     }
                 }
-                let tree = build_dependency_tree(root, &identifier, depth)?;
+                if collapse_closed && !matches!(format.as_str(), "text" | "dot") {
+                    return Err(KanbusError::IssueOperation(
+                        "--collapse-closed supports text and dot formats".to_string(),
+                    ));
+                }
+                let mut tree = build_dependency_tree(root, &identifier, depth)?;
+                if collapse_closed {
+                    collapse_closed_subtrees(&mut tree);
+                }
                 let output = render_dependency_tree(&tree, &format, None)?;
-                return Ok(Some(output));
+                return Ok(CommandOutput::from_stdout(output));
             }
 
             if args.len() < 2 {
@@ -1177,7 +2786,73 @@ fn execute_command(
             } else {
                 add_dependency(root, identifier, &target, &dependency_type)?;
             }
-            Ok(None)
+            Ok(CommandOutput::default())
+        }
+        Commands::Search {
+            query,
+            limit,
+            verbose,
+        } => {
+            let (issues, configuration) = if beads_mode {
+                (load_beads_issues(&root_for_beads)?, None)
+            } else {
+                (
+                    list_issues(root, &ListOptions::default())?,
+                    Some(load_project_configuration(&get_configuration_path(root)?)?),
+                )
+            };
+            let mut ranked = rank_issues(issues, &query.join(" "));
+            if let Some(limit) = limit {
+                ranked.truncate(limit);
+            }
+            let project_context = !beads_mode
+                && !ranked
+                    .iter()
+                    .any(|(issue, _)| issue.custom.contains_key("project_path"));
+            let matches: Vec<IssueData> = ranked.iter().map(|(issue, _)| issue.clone()).collect();
+            let widths = compute_widths(&matches, project_context);
+            let lines = ranked
+                .iter()
+                .map(|(issue, score)| {
+                    let line = format_issue_line(
+                        issue,
+                        Some(&widths),
+                        false,
+                        project_context,
+                        configuration.as_ref(),
+                        None,
+                    );
+                    if verbose {
+                        format!("{line}  (score {score})")
+                    } else {
+                        line
+                    }
+                })
+                .collect::<Vec<_>>();
+            Ok(CommandOutput::from_stdout(lines.join("\n")))
+        }
+        Commands::Next { assignee, claim } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support next".to_string(),
+                ));
+            }
+            let assignee = assignee.unwrap_or_else(get_current_user);
+            let next = if claim {
+                claim_next_ready_issue(root, &assignee)?
+            } else {
+                next_ready_issue(root, &assignee)?
+            };
+            let Some(issue) = next else {
+                return Ok(CommandOutput::from_stdout("No ready issues".to_string()));
+            };
+            let configuration = load_project_configuration(&get_configuration_path(root)?)?;
+            Ok(CommandOutput::from_stdout(format_issue_for_display(
+                &issue,
+                Some(&configuration),
+                should_use_color(),
+                false,
+            )))
         }
         Commands::Ready {
             no_local,
@@ -1200,7 +2875,7 @@ fn execute_command(
             for issue in issues {
                 lines.push(format_ready_line(&issue));
             }
-            Ok(Some(lines.join("\n")))
+            Ok(CommandOutput::from_stdout(lines.join("\n")))
         }
         Commands::Jira { command } => match command {
             JiraCommands::Pull { dry_run } => {
@@ -1219,23 +2894,202 @@ fn execute_command(
                 }
                 let result =
                     pull_from_jira(root, jira_config, &jira_configuration.project_key, dry_run)?;
-                Ok(Some(format!(
+                Ok(CommandOutput::from_stdout(format!(
                     "pulled {} new, updated {} existing",
                     result.pulled, result.updated
                 )))
             }
         },
-        Commands::Migrate => {
+        Commands::Migrate {
+            merge,
+            update_existing,
+        } => {
+            if merge {
+                let result = merge_from_beads(&root_for_beads, update_existing)?;
+                return Ok(CommandOutput::from_stdout(format!(
+                    "created {} issues, updated {}, skipped {}",
+                    result.created_count, result.updated_count, result.skipped_count
+                )));
+            }
             let result = migrate_from_beads(&root_for_beads)?;
-            Ok(Some(format!("migrated {} issues", result.issue_count)))
+            Ok(CommandOutput::from_stdout(format!(
+                "migrated {} issues",
+                result.issue_count
+            )))
         }
-        Commands::Doctor => {
-            let result = run_doctor(root)?;
-            Ok(Some(format!("ok {}", result.project_dir.display())))
+        Commands::Doctor { fix, strict } => {
+            let result = run_doctor(root, fix)?;
+            let mut lines = vec![format!("ok {}", result.project_dir.display())];
+            lines.extend(
+                result
+                    .warnings
+                    .iter()
+                    .map(|warning| format!("warning: {warning}")),
+            );
+            lines.extend(result.fixes.iter().map(|fix| format!("fixed: {fix}")));
+            if strict && !result.warnings.is_empty() {
+                return Err(KanbusError::IssueOperation(format!(
+                    "{}\n{} warnings (--strict)",
+                    lines.join("\n"),
+                    result.warnings.len()
+                )));
+            }
+            Ok(CommandOutput::from_stdout(lines.join("\n")))
+        }
+        Commands::Version { json } => {
+            if !json {
+                return Ok(CommandOutput::from_stdout(format!(
+                    "kbs {}",
+                    env!("GIT_VERSION")
+                )));
+            }
+            let payload = serde_json::json!({
+                "version": env!("GIT_VERSION"),
+                "protocol_version": PROTOCOL_VERSION,
+                "build": {
+                    "git_sha": env!("GIT_SHA"),
+                    "profile": if cfg!(debug_assertions) { "debug" } else { "release" },
+                },
+            });
+            serde_json::to_string_pretty(&payload)
+                .map(CommandOutput::from_stdout)
+                .map_err(|error| KanbusError::Io(error.to_string()))
         }
         Commands::Daemon { root } => {
             run_daemon(Path::new(&root))?;
-            Ok(None)
+            Ok(CommandOutput::default())
+        }
+        Commands::Link { command } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support links".to_string(),
+                ));
+            }
+            match command {
+                LinkCommands::Add {
+                    identifier,
+                    url,
+                    title,
+                } => {
+                    add_issue_link(root, &identifier, &url, title.as_deref())?;
+                    Ok(CommandOutput::from_stdout(format!(
+                        "Linked {} to {url}",
+                        format_issue_key(&identifier, false)
+                    )))
+                }
+                LinkCommands::Remove { identifier, url } => {
+                    remove_issue_link(root, &identifier, &url)?;
+                    Ok(CommandOutput::from_stdout(format!(
+                        "Unlinked {url} from {}",
+                        format_issue_key(&identifier, false)
+                    )))
+                }
+                LinkCommands::List { identifier } => {
+                    let issue = load_issue_from_project(root, &identifier)?.issue;
+                    let lines = issue
+                        .links
+                        .iter()
+                        .map(|link| match &link.title {
+                            Some(title) => format!("{title} <{}>", link.url),
+                            None => link.url.clone(),
+                        })
+                        .collect::<Vec<_>>();
+                    Ok(CommandOutput::from_stdout(lines.join("\n")))
+                }
+            }
+        }
+        Commands::Label { command } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support labels".to_string(),
+                ));
+            }
+            match command {
+                LabelCommands::List => {
+                    let lines = label_counts(root)?
+                        .into_iter()
+                        .map(|(label, count)| format!("{label} ({count})"))
+                        .collect::<Vec<_>>();
+                    Ok(CommandOutput::from_stdout(lines.join("\n")))
+                }
+                LabelCommands::Rename { from, to } => {
+                    let identifiers = rename_label(root, &from, &to)?;
+                    let mut lines = identifiers
+                        .iter()
+                        .map(|identifier| {
+                            format!(
+                                "relabeled {}: {from} -> {to}",
+                                format_issue_key(identifier, false)
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    lines.push(format!("relabeled {} issues", identifiers.len()));
+                    Ok(CommandOutput::from_stdout(lines.join("\n")))
+                }
+            }
+        }
+        Commands::Config { command } => match command {
+            ConfigCommands::Get { key } => Ok(CommandOutput::from_stdout(get_configuration_value(
+                root, &key,
+            )?)),
+            ConfigCommands::Set { key, value } => {
+                let change = set_configuration_value(root, &key, &value)?;
+                if change.rewritten {
+                    eprintln!(
+                        "Warning: {key} could not be edited in place; rewrote the configuration file without its comments"
+                    );
+                }
+                Ok(CommandOutput::from_stdout(format!(
+                    "Set {key}: {} -> {}",
+                    change.previous, change.current
+                )))
+            }
+        },
+        Commands::Template { command } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support templates".to_string(),
+                ));
+            }
+            match command {
+                TemplateCommands::Save {
+                    name,
+                    title,
+                    issue_type,
+                    priority,
+                    label,
+                    description,
+                } => {
+                    let template = IssueTemplate {
+                        title: title.map(|words| words.join(" ")),
+                        issue_type,
+                        priority,
+                        labels: label,
+                        description: description.map(|words| words.join(" ")),
+                    };
+                    save_template(root, &name, &template)?;
+                    Ok(CommandOutput::from_stdout(format!("saved template {name}")))
+                }
+                TemplateCommands::Apply { name, title } => {
+                    let title_text = title.join(" ");
+                    let override_title = if title_text.is_empty() {
+                        None
+                    } else {
+                        Some(title_text.as_str())
+                    };
+                    let result = apply_template(root, &name, override_title)?;
+                    let use_color = should_use_color();
+                    Ok(CommandOutput::from_stdout(format_issue_for_display(
+                        &result.issue,
+                        Some(&result.configuration),
+                        use_color,
+                        false,
+                    )))
+                }
+                TemplateCommands::List => {
+                    Ok(CommandOutput::from_stdout(list_templates(root)?.join("\n")))
+                }
+            }
         }
         Commands::Wiki { command } => match command {
             WikiCommands::Render { page } => {
@@ -1244,7 +3098,7 @@ fn execute_command(
                     page_path: Path::new(&page).to_path_buf(),
                 };
                 let output = render_wiki_page(&request)?;
-                Ok(Some(output))
+                Ok(CommandOutput::from_stdout(output))
             }
         },
         Commands::Console { command } => match command {
@@ -1252,11 +3106,11 @@ fn execute_command(
                 let snapshot = build_console_snapshot(root)?;
                 let payload = serde_json::to_string_pretty(&snapshot)
                     .map_err(|error| KanbusError::Io(error.to_string()))?;
-                Ok(Some(payload))
+                Ok(CommandOutput::from_stdout(payload))
             }
             ConsoleCommands::Log { output, url } => {
                 stream_console_telemetry(root, output, url)?;
-                Ok(None)
+                Ok(CommandOutput::default())
             }
             ConsoleCommands::Focus {
                 identifier,
@@ -1289,7 +3143,7 @@ fn execute_command(
                 } else {
                     format!("Focused on issue {}", issue_id)
                 };
-                Ok(Some(msg))
+                Ok(CommandOutput::from_stdout(msg))
             }
             ConsoleCommands::Unfocus => {
                 use crate::notification_events::{NotificationEvent, UiControlAction};
@@ -1302,7 +3156,9 @@ fn execute_command(
                 // Best-effort notification - don't fail if console server is down
                 let _ = publish_notification(root, event);
 
-                Ok(Some("Cleared focus filter".to_string()))
+                Ok(CommandOutput::from_stdout(
+                    "Cleared focus filter".to_string(),
+                ))
             }
             ConsoleCommands::View { mode } => {
                 use crate::notification_events::{NotificationEvent, UiControlAction};
@@ -1325,7 +3181,10 @@ fn execute_command(
                 // Best-effort notification - don't fail if console server is down
                 let _ = publish_notification(root, event);
 
-                Ok(Some(format!("Switched to {} view", mode)))
+                Ok(CommandOutput::from_stdout(format!(
+                    "Switched to {} view",
+                    mode
+                )))
             }
             ConsoleCommands::Search { query, clear } => {
                 use crate::notification_events::{NotificationEvent, UiControlAction};
@@ -1354,7 +3213,7 @@ fn execute_command(
                 } else {
                     format!("Set search query to: {}", search_query)
                 };
-                Ok(Some(msg))
+                Ok(CommandOutput::from_stdout(msg))
             }
             ConsoleCommands::Maximize => {
                 use crate::notification_events::{NotificationEvent, UiControlAction};
@@ -1366,7 +3225,7 @@ fn execute_command(
 
                 let _ = publish_notification(root, event);
                 println!("Maximized detail panel");
-                Ok(None)
+                Ok(CommandOutput::default())
             }
             ConsoleCommands::Restore => {
                 use crate::notification_events::{NotificationEvent, UiControlAction};
@@ -1378,7 +3237,7 @@ fn execute_command(
 
                 let _ = publish_notification(root, event);
                 println!("Restored detail panel");
-                Ok(None)
+                Ok(CommandOutput::default())
             }
             ConsoleCommands::CloseDetail => {
                 use crate::notification_events::{NotificationEvent, UiControlAction};
@@ -1390,7 +3249,7 @@ fn execute_command(
 
                 let _ = publish_notification(root, event);
                 println!("Closed detail panel");
-                Ok(None)
+                Ok(CommandOutput::default())
             }
             ConsoleCommands::ToggleSettings => {
                 use crate::notification_events::{NotificationEvent, UiControlAction};
@@ -1402,7 +3261,7 @@ fn execute_command(
 
                 let _ = publish_notification(root, event);
                 println!("Toggled settings panel");
-                Ok(None)
+                Ok(CommandOutput::default())
             }
             ConsoleCommands::SetSetting { key, value } => {
                 use crate::notification_events::{NotificationEvent, UiControlAction};
@@ -1417,7 +3276,7 @@ fn execute_command(
 
                 let _ = publish_notification(root, event);
                 println!("Set {} = {}", key, value);
-                Ok(None)
+                Ok(CommandOutput::default())
             }
             ConsoleCommands::CollapseColumn { column } => {
                 use crate::notification_events::{NotificationEvent, UiControlAction};
@@ -1431,7 +3290,7 @@ fn execute_command(
 
                 let _ = publish_notification(root, event);
                 println!("Collapsed column: {}", column);
-                Ok(None)
+                Ok(CommandOutput::default())
             }
             ConsoleCommands::ExpandColumn { column } => {
                 use crate::notification_events::{NotificationEvent, UiControlAction};
@@ -1445,7 +3304,7 @@ fn execute_command(
 
                 let _ = publish_notification(root, event);
                 println!("Expanded column: {}", column);
-                Ok(None)
+                Ok(CommandOutput::default())
             }
             ConsoleCommands::Select { identifier } => {
                 use crate::notification_events::{NotificationEvent, UiControlAction};
@@ -1468,7 +3327,7 @@ fn execute_command(
 
                 let _ = publish_notification(root, event);
                 println!("Selected issue {}", issue_id);
-                Ok(None)
+                Ok(CommandOutput::default())
             }
             ConsoleCommands::Status => {
                 let root_clone = root.to_path_buf();
@@ -1486,7 +3345,7 @@ fn execute_command(
                     }
                     Err(_) => "Console server is not running.".to_string(),
                 };
-                Ok(Some(output))
+                Ok(CommandOutput::from_stdout(output))
             }
             ConsoleCommands::Get { field } => {
                 let field = field.as_str();
@@ -1518,9 +3377,11 @@ fn execute_command(
                                 .to_string(),
                             _ => unreachable!("field validated"),
                         };
-                        Ok(Some(value))
+                        Ok(CommandOutput::from_stdout(value))
                     }
-                    Err(_) => Ok(Some("Console server is not running.".to_string())),
+                    Err(_) => Ok(CommandOutput::from_stdout(
+                        "Console server is not running.".to_string(),
+                    )),
                 }
             }
         },
@@ -1528,23 +3389,44 @@ fn execute_command(
             let status = request_status(root).map_err(format_daemon_project_error)?;
             let payload = serde_json::to_string_pretty(&status)
                 .map_err(|error| KanbusError::Io(error.to_string()))?;
-            Ok(Some(payload))
+            Ok(CommandOutput::from_stdout(payload))
         }
         Commands::DaemonStop => {
             let status = request_shutdown(root).map_err(format_daemon_project_error)?;
             let payload = serde_json::to_string_pretty(&status)
                 .map_err(|error| KanbusError::Io(error.to_string()))?;
-            Ok(Some(payload))
+            Ok(CommandOutput::from_stdout(payload))
+        }
+        Commands::Watch { json, max_events } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "beads mode does not support watch".to_string(),
+                ));
+            }
+            // Events are streamed as they arrive rather than collected.
+            watch_notifications(
+                root,
+                json,
+                max_events.map(|count| count as usize),
+                &mut std::io::stdout(),
+                &mut std::io::stderr(),
+            )
+            .map_err(format_daemon_project_error)?;
+            Ok(CommandOutput::default())
         }
     }
 }
 
 /// Run the CLI using process arguments and current directory.
 ///
+/// # Returns
+///
+/// The process exit code.
+///
 /// # Errors
 ///
 /// Returns `KanbusError` if execution fails.
-pub fn run_from_env() -> Result<(), KanbusError> {
+pub fn run_from_env() -> Result<i32, KanbusError> {
     let args = rewrite_alias_args(std::env::args_os());
     run_from_args(args, Path::new("."))
 }
@@ -1599,6 +3481,51 @@ fn sort_timestamp(issue: &IssueData) -> f64 {
     timestamp.timestamp() as f64
 }
 
+fn parse_comment_timestamp(value: &str, allow_future: bool) -> Result<DateTime<Utc>, KanbusError> {
+    let timestamp = DateTime::parse_from_rfc3339(value)
+        .map_err(|_| KanbusError::IssueOperation(format!("invalid timestamp: {value}")))?
+        .with_timezone(&Utc);
+    if !allow_future && timestamp > Utc::now() {
+        return Err(KanbusError::IssueOperation(
+            "comment timestamp is in the future (use --allow-future)".to_string(),
+        ));
+    }
+    Ok(timestamp)
+}
+
+fn parse_calendar_date(value: &str) -> Result<NaiveDate, KanbusError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        KanbusError::IssueOperation(format!("invalid date: {value} (expected YYYY-MM-DD)"))
+    })
+}
+
+/// Parse a `--due` value.
+///
+/// A plain date means midnight at the start of that day in the configured
+/// `time_zone` (UTC when unset).
+fn parse_due_timestamp(root: &Path, value: &str) -> Result<DateTime<Utc>, KanbusError> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+    let day = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        KanbusError::IssueOperation(format!(
+            "invalid due date: {value} (expected YYYY-MM-DD or RFC 3339 timestamp)"
+        ))
+    })?;
+    Ok(start_of_day(project_time_zone(root)?, day))
+}
+
+/// Return the instant a `--due-before` date starts in the configured `time_zone`.
+fn parse_due_before(root: &Path, value: &str) -> Result<DateTime<Utc>, KanbusError> {
+    let day = parse_calendar_date(value)?;
+    Ok(start_of_day(project_time_zone(root)?, day))
+}
+
+fn project_time_zone(root: &Path) -> Result<Option<Tz>, KanbusError> {
+    let configuration = load_project_configuration(&get_configuration_path(root)?)?;
+    configured_time_zone(&configuration)
+}
+
 fn format_ready_line(issue: &IssueData) -> String {
     let prefix = issue
         .custom
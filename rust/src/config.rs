@@ -8,6 +8,23 @@ use crate::models::{
     CategoryDefinition, PriorityDefinition, ProjectConfiguration, StatusDefinition,
 };
 
+/// Preset configurations available to `kanbus init --template`.
+pub const CONFIGURATION_TEMPLATES: [&str; 3] = ["kanban", "scrum", "bugtracker"];
+
+/// Hierarchy used by the `scrum` configuration template.
+pub const SCRUM_HIERARCHY: [&str; 4] = ["initiative", "epic", "story", "subtask"];
+
+/// Color-blind-safe status colors, assigned in status order when
+/// `accessible_colors` is enabled.
+pub const ACCESSIBLE_STATUS_PALETTE: [&str; 6] = [
+    "bright_blue",
+    "yellow",
+    "bright_cyan",
+    "magenta",
+    "bright_white",
+    "blue",
+];
+
 /// Return the default project configuration.
 pub fn default_project_configuration() -> ProjectConfiguration {
     let mut workflows = BTreeMap::new();
@@ -195,6 +212,7 @@ pub fn default_project_configuration() -> ProjectConfiguration {
         initial_status: "open".to_string(),
         priorities,
         default_priority: 2,
+        priority_import_aliases: BTreeMap::new(),
         assignee: None,
         time_zone: None,
         statuses: vec![
@@ -245,22 +263,208 @@ pub fn default_project_configuration() -> ProjectConfiguration {
             ("chore".to_string(), "green".to_string()),
             ("event".to_string(), "bright_blue".to_string()),
         ]),
+        type_prefixes: BTreeMap::new(),
+        label_colors: BTreeMap::new(),
+        accessible_colors: false,
         beads_compatibility: false,
+        comment_on_transition: false,
+        issue_layout: "flat".to_string(),
+        issue_format: "json".to_string(),
+        priority_order: "ascending".to_string(),
+        max_description_length: None,
+        auto_claim_on_status: Vec::new(),
+        notification_socket_path: None,
+        warn_on_duplicate_title: true,
         jira: None,
     }
 }
 
-/// Write the default configuration to disk.
+/// Return the preset configuration for a template name.
+///
+/// Presets start from the default configuration and replace the hierarchy,
+/// types, statuses, and default workflow.
+///
+/// # Arguments
+///
+/// * `name` - Template name, one of `CONFIGURATION_TEMPLATES`.
+///
+/// # Errors
+///
+/// Returns `KanbusError::Initialization` if the template is unknown.
+pub fn template_project_configuration(name: &str) -> Result<ProjectConfiguration, KanbusError> {
+    let mut configuration = default_project_configuration();
+    let default_workflow: &[(&str, &[(&str, &str)])] = match name {
+        "kanban" => {
+            configuration.statuses = vec![
+                status("open", "To Do", "To do", false),
+                status("in_progress", "Doing", "In progress", false),
+                status("review", "Review", "In progress", false),
+                status("closed", "Done", "Done", true),
+            ];
+            &[
+                ("open", &[("in_progress", "Start work"), ("closed", "Drop")]),
+                (
+                    "in_progress",
+                    &[
+                        ("review", "Request review"),
+                        ("open", "Pause"),
+                        ("closed", "Complete"),
+                    ],
+                ),
+                (
+                    "review",
+                    &[("in_progress", "Request changes"), ("closed", "Approve")],
+                ),
+                ("closed", &[("open", "Reopen")]),
+            ]
+        }
+        "scrum" => {
+            configuration.hierarchy = SCRUM_HIERARCHY.iter().map(|t| t.to_string()).collect();
+            configuration.types = vec!["task".to_string(), "bug".to_string(), "spike".to_string()];
+            configuration.statuses = vec![
+                status("backlog", "Product Backlog", "To do", true),
+                status("open", "Sprint Backlog", "To do", false),
+                status("in_progress", "In Sprint", "In progress", false),
+                status("review", "Sprint Review", "In progress", false),
+                status("closed", "Done", "Done", true),
+            ];
+            &[
+                (
+                    "backlog",
+                    &[("open", "Plan into sprint"), ("closed", "Drop")],
+                ),
+                (
+                    "open",
+                    &[
+                        ("in_progress", "Start work"),
+                        ("backlog", "Return to backlog"),
+                        ("closed", "Drop"),
+                    ],
+                ),
+                (
+                    "in_progress",
+                    &[
+                        ("review", "Ready for review"),
+                        ("open", "Pause"),
+                        ("closed", "Complete"),
+                    ],
+                ),
+                ("review", &[("in_progress", "Rework"), ("closed", "Accept")]),
+                ("closed", &[("open", "Reopen")]),
+            ]
+        }
+        "bugtracker" => {
+            configuration.types = vec![
+                "bug".to_string(),
+                "feature".to_string(),
+                "chore".to_string(),
+            ];
+            configuration.statuses = vec![
+                status("open", "New", "To do", false),
+                status("triaged", "Triaged", "To do", false),
+                status("in_progress", "Fixing", "In progress", false),
+                status("resolved", "Resolved", "In progress", false),
+                status("closed", "Closed", "Done", true),
+            ];
+            &[
+                (
+                    "open",
+                    &[
+                        ("triaged", "Triage"),
+                        ("in_progress", "Start fix"),
+                        ("closed", "Won't fix"),
+                    ],
+                ),
+                (
+                    "triaged",
+                    &[
+                        ("in_progress", "Start fix"),
+                        ("open", "Untriage"),
+                        ("closed", "Won't fix"),
+                    ],
+                ),
+                (
+                    "in_progress",
+                    &[
+                        ("resolved", "Resolve"),
+                        ("triaged", "Pause"),
+                        ("closed", "Close"),
+                    ],
+                ),
+                (
+                    "resolved",
+                    &[("closed", "Verify"), ("in_progress", "Reopen fix")],
+                ),
+                ("closed", &[("open", "Reopen")]),
+            ]
+        }
+        _ => {
+            return Err(KanbusError::Initialization(format!(
+                "unknown template '{name}' (valid templates: {})",
+                CONFIGURATION_TEMPLATES.join(", ")
+            )))
+        }
+    };
+    let mut workflow = BTreeMap::new();
+    let mut labels = BTreeMap::new();
+    for (from, transitions) in default_workflow {
+        workflow.insert(
+            from.to_string(),
+            transitions.iter().map(|(to, _)| to.to_string()).collect(),
+        );
+        labels.insert(
+            from.to_string(),
+            transitions
+                .iter()
+                .map(|(to, label)| (to.to_string(), label.to_string()))
+                .collect(),
+        );
+    }
+    configuration
+        .workflows
+        .insert("default".to_string(), workflow);
+    configuration
+        .transition_labels
+        .insert("default".to_string(), labels);
+    for (issue_type, color) in [
+        ("subtask", "bright_cyan"),
+        ("spike", "cyan"),
+        ("feature", "yellow"),
+    ] {
+        configuration
+            .type_colors
+            .entry(issue_type.to_string())
+            .or_insert_with(|| color.to_string());
+    }
+    Ok(configuration)
+}
+
+fn status(key: &str, name: &str, category: &str, collapsed: bool) -> StatusDefinition {
+    StatusDefinition {
+        key: key.to_string(),
+        name: name.to_string(),
+        category: category.to_string(),
+        color: None,
+        collapsed,
+    }
+}
+
+/// Write the default configuration, or a template preset, to disk.
 ///
 /// # Arguments
 ///
 /// * `path` - Path to the kanbus.yml file.
+/// * `template` - Optional template name from `CONFIGURATION_TEMPLATES`.
 ///
 /// # Errors
 ///
-/// Returns `KanbusError::Io` if writing fails.
-pub fn write_default_configuration(path: &Path) -> Result<(), KanbusError> {
-    let configuration = default_project_configuration();
+/// Returns `KanbusError::Initialization` if the template is unknown, or
+/// `KanbusError::Io` if writing fails.
+pub fn write_default_configuration(path: &Path, template: Option<&str>) -> Result<(), KanbusError> {
+    let configuration = match template {
+        Some(name) => template_project_configuration(name)?,
+        None => default_project_configuration(),
+    };
     let contents = serde_yaml::to_string(&configuration)
         .map_err(|error| KanbusError::Io(error.to_string()))?;
     std::fs::write(path, contents).map_err(|error| KanbusError::Io(error.to_string()))
@@ -0,0 +1,301 @@
+//! Reading and editing individual configuration values.
+
+use std::fs;
+use std::path::Path;
+
+use serde_yaml::{Mapping, Value};
+
+use crate::config_loader::{load_project_configuration, parse_project_configuration};
+use crate::error::KanbusError;
+use crate::file_io::get_configuration_path;
+
+/// Result of setting a configuration value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigurationChange {
+    pub previous: String,
+    pub current: String,
+    /// Whether the file had to be re-serialized, dropping its comments and
+    /// formatting, because the key could not be edited in place.
+    pub rewritten: bool,
+}
+
+/// Read a configuration value by dotted key, including defaults.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `key` - Dotted key such as `default_priority` or `label_colors.bug`.
+///
+/// # Returns
+/// The value rendered as YAML.
+///
+/// # Errors
+/// Returns `KanbusError::Configuration` if the key is unknown or the
+/// configuration cannot be loaded.
+pub fn get_configuration_value(root: &Path, key: &str) -> Result<String, KanbusError> {
+    let effective = load_effective_value(root)?;
+    lookup(&effective, key)
+        .map(render_value)
+        .ok_or_else(|| unknown_key(key))
+}
+
+/// Set a configuration value by dotted key, refusing to save an invalid file.
+///
+/// The value is parsed as YAML, so `3`, `true`, and `[a, b]` keep their types.
+/// Only the lines holding the key are edited, so comments and formatting
+/// elsewhere in the file survive. When the key sits inside a flow collection
+/// such as `{bug: red}` the whole file is re-serialized instead and the
+/// returned change is marked `rewritten`.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `key` - Dotted key such as `default_priority` or `label_colors.bug`.
+/// * `value` - New value as YAML text.
+///
+/// # Errors
+/// Returns `KanbusError::Configuration` if the key is unknown or the updated
+/// configuration fails validation.
+pub fn set_configuration_value(
+    root: &Path,
+    key: &str,
+    value: &str,
+) -> Result<ConfigurationChange, KanbusError> {
+    let effective = load_effective_value(root)?;
+    let segments: Vec<&str> = key.split('.').collect();
+    let (last, parents) = segments
+        .split_last()
+        .filter(|(last, _)| !last.is_empty())
+        .ok_or_else(|| unknown_key(key))?;
+    let previous = match lookup(&effective, key) {
+        Some(existing) => render_value(existing),
+        None if lookup(&effective, &parents.join("."))
+            .is_some_and(|parent| parent.is_mapping() || parent.is_null()) =>
+        {
+            "null".to_string()
+        }
+        None => return Err(unknown_key(key)),
+    };
+    let new_value: Value = serde_yaml::from_str(value)
+        .map_err(|error| KanbusError::Configuration(format!("invalid value: {error}")))?;
+
+    let path = get_configuration_path(root)?;
+    let contents = fs::read_to_string(&path).map_err(|error| KanbusError::Io(error.to_string()))?;
+    let mut document = match serde_yaml::from_str::<Value>(&contents) {
+        Ok(Value::Mapping(mapping)) => mapping,
+        Ok(_) => Mapping::new(),
+        Err(error) => return Err(KanbusError::Configuration(error.to_string())),
+    };
+    let mut mapping = &mut document;
+    for segment in parents {
+        let entry = mapping
+            .entry(mapping_key(mapping, segment))
+            .or_insert_with(|| Value::Mapping(Mapping::new()));
+        if !entry.is_mapping() {
+            *entry = Value::Mapping(Mapping::new());
+        }
+        mapping = entry.as_mapping_mut().expect("mapping");
+    }
+    mapping.insert(mapping_key(mapping, last), new_value.clone());
+    let document = Value::Mapping(document);
+
+    let edited = edit_in_place(&contents, &segments, &new_value).filter(|edited| {
+        serde_yaml::from_str::<Value>(edited).is_ok_and(|parsed| parsed == document)
+    });
+    let rewritten = edited.is_none();
+    let updated = match edited {
+        Some(edited) => edited,
+        None => {
+            serde_yaml::to_string(&document).map_err(|error| KanbusError::Io(error.to_string()))?
+        }
+    };
+    parse_project_configuration(&path, &updated).map_err(|error| {
+        KanbusError::Configuration(format!("refusing to save invalid configuration: {error}"))
+    })?;
+    fs::write(&path, updated).map_err(|error| KanbusError::Io(error.to_string()))?;
+    Ok(ConfigurationChange {
+        previous,
+        current: render_value(&new_value),
+        rewritten,
+    })
+}
+
+/// Replace or insert the value at `segments` by editing only the lines that
+/// hold it, or `None` when the surrounding YAML is not in block style.
+fn edit_in_place(contents: &str, segments: &[&str], value: &Value) -> Option<String> {
+    let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    let (mut start, mut end, mut indent) = (0, lines.len(), 0);
+    for (depth, segment) in segments.iter().enumerate() {
+        let is_last = depth + 1 == segments.len();
+        let Some(line) = (start..end).find(|&line| key_at(&lines[line], indent, segment)) else {
+            let child_lines = if is_last {
+                render_entry(segment, value, indent)
+            } else {
+                let mut nested = vec![format!("{}{segment}:", " ".repeat(indent))];
+                let mut child_indent = indent + 2;
+                for (offset, parent) in segments[depth + 1..].iter().enumerate() {
+                    if depth + 2 + offset == segments.len() {
+                        nested.extend(render_entry(parent, value, child_indent));
+                    } else {
+                        nested.push(format!("{}{parent}:", " ".repeat(child_indent)));
+                        child_indent += 2;
+                    }
+                }
+                nested
+            };
+            let insert_at = last_content_line(&lines, start, end).map_or(start, |line| line + 1);
+            lines.splice(insert_at..insert_at, child_lines);
+            return Some(join_lines(&lines, contents));
+        };
+        let block_end = block_end(&lines, line, indent);
+        let (key_text, inline, comment) = split_entry(&lines[line], indent)?;
+        if is_last {
+            let mut replacement = render_entry(&key_text, value, indent);
+            if let Some(comment) = comment {
+                replacement[0].push_str(&comment);
+            }
+            lines.splice(line..block_end, replacement);
+            return Some(join_lines(&lines, contents));
+        }
+        match inline.as_str() {
+            "" => {}
+            "{}" | "null" | "~" => {
+                lines[line] = format!(
+                    "{}{key_text}:{}",
+                    " ".repeat(indent),
+                    comment.unwrap_or_default()
+                );
+            }
+            _ => return None,
+        }
+        let child_indent = (line + 1..block_end)
+            .find(|&child| is_content(&lines[child]))
+            .map_or(indent + 2, |child| indentation(&lines[child]));
+        if child_indent <= indent {
+            return None;
+        }
+        (start, end, indent) = (line + 1, block_end, child_indent);
+    }
+    None
+}
+
+/// Render `key: value` at `indent`, putting collections on their own lines
+/// in the layout `kanbus init` writes: mappings nested two spaces deeper,
+/// sequence items level with their key.
+fn render_entry(key: &str, value: &Value, indent: usize) -> Vec<String> {
+    let padding = " ".repeat(indent);
+    let rendered = render_value(value);
+    let item_padding = match value {
+        Value::Mapping(mapping) if !mapping.is_empty() => " ".repeat(indent + 2),
+        Value::Sequence(sequence) if !sequence.is_empty() => padding.clone(),
+        _ => return vec![format!("{padding}{key}: {rendered}")],
+    };
+    std::iter::once(format!("{padding}{key}:"))
+        .chain(rendered.lines().map(|line| format!("{item_padding}{line}")))
+        .collect()
+}
+
+/// Whether `line` starts the mapping entry `key` at exactly `indent`.
+fn key_at(line: &str, indent: usize, key: &str) -> bool {
+    is_content(line)
+        && indentation(line) == indent
+        && split_entry(line, indent).is_some_and(|(found, _, _)| {
+            found == key || found.trim_matches(|quote| quote == '"' || quote == '\'') == key
+        })
+}
+
+/// Split a mapping entry line into its key, inline value, and trailing
+/// comment (with its leading whitespace).
+fn split_entry(line: &str, indent: usize) -> Option<(String, String, Option<String>)> {
+    let body = &line[indent..];
+    let colon = body
+        .find(": ")
+        .or_else(|| body.strip_suffix(':').map(str::len))?;
+    let key = body[..colon].to_string();
+    let rest = &body[colon + 1..];
+    let comment_start = rest.find(" #").filter(|&index| {
+        let before = &rest[..index];
+        before.matches('"').count().is_multiple_of(2)
+            && before.matches('\'').count().is_multiple_of(2)
+    });
+    let (inline, comment) = match comment_start {
+        Some(index) => {
+            let value_end = rest[..index].trim_end().len();
+            (&rest[..value_end], Some(rest[value_end..].to_string()))
+        }
+        None => (rest, None),
+    };
+    Some((key, inline.trim().to_string(), comment))
+}
+
+/// Index one past the last line belonging to the entry at `line`.
+fn block_end(lines: &[String], line: usize, indent: usize) -> usize {
+    let mut end = line + 1;
+    for (index, candidate) in lines.iter().enumerate().skip(line + 1) {
+        if !is_content(candidate) {
+            continue;
+        }
+        let candidate_indent = indentation(candidate);
+        let nested_sequence = candidate_indent == indent && candidate[indent..].starts_with("- ");
+        if candidate_indent <= indent && !nested_sequence {
+            break;
+        }
+        end = index + 1;
+    }
+    end
+}
+
+fn last_content_line(lines: &[String], start: usize, end: usize) -> Option<usize> {
+    (start..end).rev().find(|&line| is_content(&lines[line]))
+}
+
+fn is_content(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    !trimmed.is_empty() && !trimmed.starts_with('#')
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+fn join_lines(lines: &[String], original: &str) -> String {
+    let mut joined = lines.join("\n");
+    if original.ends_with('\n') || original.is_empty() {
+        joined.push('\n');
+    }
+    joined
+}
+
+fn load_effective_value(root: &Path) -> Result<Value, KanbusError> {
+    let configuration = load_project_configuration(&get_configuration_path(root)?)?;
+    serde_yaml::to_value(&configuration).map_err(|error| KanbusError::Io(error.to_string()))
+}
+
+fn lookup<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    key.split('.').try_fold(value, |current, segment| {
+        let mapping = current.as_mapping()?;
+        mapping.get(segment).or_else(|| {
+            let number: u64 = segment.parse().ok()?;
+            mapping.get(Value::from(number))
+        })
+    })
+}
+
+/// Return the existing key matching `segment`, which may be numeric as in
+/// `priorities.1.name`, or a new string key.
+fn mapping_key(mapping: &Mapping, segment: &str) -> Value {
+    segment
+        .parse::<u64>()
+        .ok()
+        .map(Value::from)
+        .filter(|key| mapping.contains_key(key))
+        .unwrap_or_else(|| Value::String(segment.to_string()))
+}
+
+fn render_value(value: &Value) -> String {
+    serde_yaml::to_string(value)
+        .map(|text| text.trim_end().to_string())
+        .unwrap_or_default()
+}
+
+fn unknown_key(key: &str) -> KanbusError {
+    KanbusError::Configuration(format!("unknown configuration key: {key}"))
+}
@@ -6,9 +6,14 @@ use std::path::Path;
 
 use serde_yaml::{Mapping, Value};
 
-use crate::config::default_project_configuration;
+use crate::config::{default_project_configuration, ACCESSIBLE_STATUS_PALETTE};
 use crate::error::KanbusError;
+use crate::issue_display::parse_color;
+use crate::issue_files::{ISSUE_FORMATS, ISSUE_LAYOUTS};
 use crate::models::ProjectConfiguration;
+use crate::queries::PRIORITY_ORDERS;
+
+const BOOLEAN_OPTIONS: &[&str] = &["warn_on_duplicate_title"];
 
 /// Load a project configuration from disk.
 ///
@@ -20,31 +25,108 @@ use crate::models::ProjectConfiguration;
 ///
 /// Returns `KanbusError::Configuration` if the configuration is invalid.
 pub fn load_project_configuration(path: &Path) -> Result<ProjectConfiguration, KanbusError> {
+    let contents = read_configuration_file(path)?;
+    parse_project_configuration(path, &contents)
+}
+
+/// Load a project configuration and the warnings produced while resolving it.
+///
+/// Warnings describe configured values that loading replaced, such as status
+/// colors overridden by `accessible_colors`. They are reported by `doctor`.
+///
+/// # Arguments
+///
+/// * `path` - Path to the configuration file.
+///
+/// # Errors
+///
+/// Returns `KanbusError::Configuration` if the configuration is invalid.
+pub fn load_project_configuration_with_warnings(
+    path: &Path,
+) -> Result<(ProjectConfiguration, Vec<String>), KanbusError> {
+    let contents = read_configuration_file(path)?;
+    resolve_project_configuration(path, &contents)
+}
+
+fn read_configuration_file(path: &Path) -> Result<String, KanbusError> {
     let dotenv_path = path.parent().unwrap_or(Path::new(".")).join(".env");
     load_dotenv(&dotenv_path);
-    let contents = fs::read_to_string(path).map_err(|error| {
+    fs::read_to_string(path).map_err(|error| {
         if error.kind() == std::io::ErrorKind::NotFound {
             KanbusError::Configuration("configuration file not found".to_string())
         } else {
             KanbusError::Io(error.to_string())
         }
-    })?;
+    })
+}
 
-    let raw_value = load_configuration_value(&contents)?;
+/// Parse and validate configuration text as if it were stored at `path`.
+///
+/// Defaults and `.kanbus.override.yml` overrides are applied relative to
+/// `path`, exactly as [`load_project_configuration`] does.
+///
+/// # Arguments
+///
+/// * `path` - Path the configuration is (or will be) stored at.
+/// * `contents` - Configuration YAML.
+///
+/// # Errors
+///
+/// Returns `KanbusError::Configuration` if the configuration is invalid.
+pub fn parse_project_configuration(
+    path: &Path,
+    contents: &str,
+) -> Result<ProjectConfiguration, KanbusError> {
+    resolve_project_configuration(path, contents).map(|(configuration, _)| configuration)
+}
+
+fn resolve_project_configuration(
+    path: &Path,
+    contents: &str,
+) -> Result<(ProjectConfiguration, Vec<String>), KanbusError> {
+    let raw_value = load_configuration_value(path, contents)?;
     let mut merged_value = merge_with_defaults(raw_value)?;
     let overrides = load_override_configuration(path.parent().unwrap_or(Path::new(".")))?;
     merged_value = apply_overrides(merged_value, overrides);
     reject_legacy_fields(&merged_value)?;
+    validate_boolean_options(&merged_value)?;
     normalize_virtual_projects(&mut merged_value);
-    let configuration: ProjectConfiguration = serde_yaml::from_value(Value::Mapping(merged_value))
-        .map_err(|error| KanbusError::Configuration(map_configuration_error(&error)))?;
+    let mut configuration: ProjectConfiguration =
+        serde_yaml::from_value(Value::Mapping(merged_value))
+            .map_err(|error| KanbusError::Configuration(map_configuration_error(&error)))?;
 
     let errors = validate_project_configuration(&configuration);
     if !errors.is_empty() {
         return Err(KanbusError::Configuration(errors.join("; ")));
     }
+    let warnings = if configuration.accessible_colors {
+        apply_accessible_status_colors(&mut configuration)
+    } else {
+        Vec::new()
+    };
 
-    Ok(configuration)
+    Ok((configuration, warnings))
+}
+
+/// Assign each status a color from the color-blind-safe palette, returning a
+/// warning for every configured color that is replaced.
+fn apply_accessible_status_colors(configuration: &mut ProjectConfiguration) -> Vec<String> {
+    let mut warnings = Vec::new();
+    for (index, status) in configuration.statuses.iter_mut().enumerate() {
+        let color = ACCESSIBLE_STATUS_PALETTE[index % ACCESSIBLE_STATUS_PALETTE.len()];
+        if let Some(existing) = status
+            .color
+            .as_deref()
+            .filter(|existing| *existing != color)
+        {
+            warnings.push(format!(
+                "accessible_colors overrides color '{existing}' for status '{}'",
+                status.key
+            ));
+        }
+        status.color = Some(color.to_string());
+    }
+    warnings
 }
 
 fn load_dotenv(path: &Path) {
@@ -112,6 +194,69 @@ pub fn validate_project_configuration(configuration: &ProjectConfiguration) -> V
         }
     }
 
+    if !ISSUE_LAYOUTS.contains(&configuration.issue_layout.as_str()) {
+        errors.push(format!(
+            "issue_layout must be one of: {}",
+            ISSUE_LAYOUTS.join(", ")
+        ));
+    }
+
+    if !ISSUE_FORMATS.contains(&configuration.issue_format.as_str()) {
+        errors.push(format!(
+            "issue_format must be one of: {}",
+            ISSUE_FORMATS.join(", ")
+        ));
+    }
+
+    if !PRIORITY_ORDERS.contains(&configuration.priority_order.as_str()) {
+        errors.push(format!(
+            "priority_order must be one of: {}",
+            PRIORITY_ORDERS.join(", ")
+        ));
+    }
+
+    for (label, color) in &configuration.label_colors {
+        if parse_color(color).is_none() {
+            errors.push(format!("unknown color '{color}' for label '{label}'"));
+        }
+    }
+
+    for (issue_type, prefix) in &configuration.type_prefixes {
+        if !configuration.hierarchy.contains(issue_type)
+            && !configuration.types.contains(issue_type)
+        {
+            errors.push(format!(
+                "type_prefixes references unknown type '{issue_type}'"
+            ));
+        }
+        // Prefixes end at the first dash, and dots separate sub-issue suffixes.
+        if prefix.is_empty()
+            || !prefix
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || character == '_')
+        {
+            errors.push(format!(
+                "invalid prefix '{prefix}' for type '{issue_type}': use letters, digits, or underscores"
+            ));
+        }
+    }
+
+    for (alias, target) in &configuration.priority_import_aliases {
+        if !configuration
+            .priorities
+            .values()
+            .any(|priority| &priority.name == target)
+        {
+            errors.push(format!(
+                "priority_import_aliases maps '{alias}' to unknown priority '{target}'"
+            ));
+        }
+    }
+
+    if configuration.max_description_length == Some(0) {
+        errors.push("max_description_length must be greater than 0".to_string());
+    }
+
     if configuration.hierarchy.is_empty() {
         errors.push("hierarchy must not be empty".to_string());
     }
@@ -124,7 +269,14 @@ pub fn validate_project_configuration(configuration: &ProjectConfiguration) -> V
         "issue".to_string(),
         "subtask".to_string(),
     ];
-    if configuration.hierarchy != default_hierarchy && configuration.hierarchy != python_hierarchy {
+    let scrum_hierarchy: Vec<String> = crate::config::SCRUM_HIERARCHY
+        .iter()
+        .map(|item| item.to_string())
+        .collect();
+    if configuration.hierarchy != default_hierarchy
+        && configuration.hierarchy != python_hierarchy
+        && configuration.hierarchy != scrum_hierarchy
+    {
         errors.push("hierarchy is fixed".to_string());
     }
 
@@ -222,6 +374,15 @@ pub fn validate_project_configuration(configuration: &ProjectConfiguration) -> V
         ));
     }
 
+    for status in &configuration.auto_claim_on_status {
+        if !valid_statuses.contains(status) {
+            errors.push(format!(
+                "auto_claim_on_status references undefined status '{}'",
+                status
+            ));
+        }
+    }
+
     // Validate that all workflow states exist in statuses
     for (workflow_name, workflow) in &configuration.workflows {
         for (from_status, transitions) in workflow {
@@ -309,6 +470,17 @@ fn reject_legacy_fields(mapping: &Mapping) -> Result<(), KanbusError> {
     Ok(())
 }
 
+fn validate_boolean_options(mapping: &Mapping) -> Result<(), KanbusError> {
+    for key in BOOLEAN_OPTIONS {
+        if !matches!(mapping.get(*key), Some(Value::Bool(_))) {
+            return Err(KanbusError::Configuration(format!(
+                "{key} must be true or false"
+            )));
+        }
+    }
+    Ok(())
+}
+
 fn map_configuration_error(error: &serde_yaml::Error) -> String {
     let message = error.to_string();
     if message.contains("unknown field") {
@@ -340,15 +512,43 @@ fn merge_with_defaults(value: Value) -> Result<Mapping, KanbusError> {
     Ok(defaults)
 }
 
-fn load_configuration_value(contents: &str) -> Result<Value, KanbusError> {
+fn load_configuration_value(path: &Path, contents: &str) -> Result<Value, KanbusError> {
     if contents.trim().is_empty() {
         return Ok(Value::Mapping(Mapping::new()));
     }
-    let raw_value: Value = serde_yaml::from_str(contents)
-        .map_err(|error| KanbusError::Configuration(map_configuration_error(&error)))?;
+    let raw_value: Value = serde_yaml::from_str(contents).map_err(|error| {
+        KanbusError::Configuration(format_yaml_syntax_error(path, contents, &error))
+    })?;
     Ok(raw_value)
 }
 
+/// Describe a YAML syntax error with the file path and offending line.
+fn format_yaml_syntax_error(path: &Path, contents: &str, error: &serde_yaml::Error) -> String {
+    let Some(location) = error.location() else {
+        return format!("{}: invalid YAML: {error}", path.display());
+    };
+    // Errors at end of input (e.g. an unclosed bracket) point past the last line,
+    // so report the last line of the file instead.
+    let lines: Vec<&str> = contents.lines().collect();
+    let (line_number, column) = if location.line() > lines.len() {
+        let last = lines.len().max(1);
+        let width = lines.last().map(|line| line.chars().count()).unwrap_or(0);
+        (last, width + 1)
+    } else {
+        (location.line(), location.column())
+    };
+    let mut message = format!(
+        "{}:{}:{}: invalid YAML: {error}",
+        path.display(),
+        line_number,
+        column
+    );
+    if let Some(line) = lines.get(line_number.saturating_sub(1)) {
+        message.push_str(&format!("\n  {line_number} | {line}"));
+    }
+    message
+}
+
 fn load_override_configuration(root: &Path) -> Result<Mapping, KanbusError> {
     let override_path = root.join(".kanbus.override.yml");
     if !override_path.exists() {
@@ -359,8 +559,11 @@ fn load_override_configuration(root: &Path) -> Result<Mapping, KanbusError> {
     if contents.trim().is_empty() {
         return Ok(Mapping::new());
     }
-    let raw_value: Value = serde_yaml::from_str(&contents).map_err(|_error| {
-        KanbusError::Configuration("override configuration is invalid".to_string())
+    let raw_value: Value = serde_yaml::from_str(&contents).map_err(|error| {
+        KanbusError::Configuration(format!(
+            "override configuration is invalid: {}",
+            format_yaml_syntax_error(&override_path, &contents, &error)
+        ))
     })?;
     match raw_value {
         Value::Mapping(mapping) => Ok(mapping),
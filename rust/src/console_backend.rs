@@ -11,6 +11,8 @@ use crate::error::KanbusError;
 use crate::file_io::{
     find_project_local_directory, get_configuration_path, resolve_labeled_projects,
 };
+use crate::issue_creation::short_id_matches;
+use crate::issue_files::{list_issue_files, parse_issue};
 use crate::migration::load_beads_issues;
 use crate::models::{IssueData, ProjectConfiguration};
 
@@ -128,12 +130,13 @@ impl FileStore {
 
 /// Resolve issues by full or short identifier.
 ///
-/// Short identifiers are `{project_key}-{prefix}` where `prefix` is up to 6
-/// characters from the UUID segment after the dash.
+/// Short identifiers are `{key}-{prefix}` where `key` is one of the project's
+/// identifier `prefixes` and `prefix` is up to 6 characters from the UUID
+/// segment after the dash.
 pub fn find_issue_matches<'a>(
     issues: &'a [IssueData],
     identifier: &str,
-    project_key: &str,
+    prefixes: &[String],
 ) -> Vec<&'a IssueData> {
     let mut matches = Vec::new();
     for issue in issues {
@@ -141,46 +144,19 @@ pub fn find_issue_matches<'a>(
             matches.push(issue);
             continue;
         }
-        if short_id_matches(identifier, project_key, &issue.identifier) {
+        if short_id_matches(identifier, prefixes, &issue.identifier) {
             matches.push(issue);
         }
     }
     matches
 }
 
-fn short_id_matches(candidate: &str, project_key: &str, full_id: &str) -> bool {
-    if !candidate.starts_with(project_key) {
-        return false;
-    }
-    let mut parts = candidate.splitn(2, '-');
-    let prefix_key = parts.next().unwrap_or("");
-    let prefix = parts.next().unwrap_or("");
-    if prefix_key != project_key {
-        return false;
-    }
-    if prefix.is_empty() || prefix.len() > 6 {
-        return false;
-    }
-    let mut full_parts = full_id.splitn(2, '-');
-    let full_key = full_parts.next().unwrap_or("");
-    let full_suffix = full_parts.next().unwrap_or("");
-    if full_key != project_key {
-        return false;
-    }
-    full_suffix.starts_with(prefix)
-}
-
 fn load_issues_from_dir(issues_dir: &Path) -> Result<Vec<IssueData>, KanbusError> {
     let mut issues = Vec::new();
-    for entry in fs::read_dir(issues_dir).map_err(|error| KanbusError::Io(error.to_string()))? {
-        let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
-        let path = entry.path();
-        if path.extension().and_then(|value| value.to_str()) != Some("json") {
-            continue;
-        }
+    for path in list_issue_files(issues_dir)? {
         let bytes = fs::read(&path)
             .map_err(|_error| KanbusError::IssueOperation("issue file is invalid".to_string()))?;
-        let issue: IssueData = serde_json::from_slice(&bytes)
+        let issue = parse_issue(&path, &bytes)
             .map_err(|_error| KanbusError::IssueOperation("issue file is invalid".to_string()))?;
         issues.push(issue);
     }
@@ -1,11 +1,12 @@
 //! Daemon client utilities for index access.
 
+use std::cell::Cell;
 use std::collections::BTreeMap;
 use std::env;
 use std::io::{BufRead, BufReader, Write};
 #[cfg(unix)]
 use std::os::unix::net::UnixStream;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::sync::{Mutex, OnceLock};
 use std::time::Duration;
@@ -17,6 +18,25 @@ use crate::daemon_paths::get_daemon_socket_path;
 use crate::daemon_protocol::{ErrorEnvelope, RequestEnvelope, ResponseEnvelope, PROTOCOL_VERSION};
 use crate::error::KanbusError;
 
+/// Environment variable overriding how many times a failed connection is retried.
+pub const DAEMON_RETRIES_ENV: &str = "KANBUS_DAEMON_RETRIES";
+/// Environment variable overriding how long a request may wait for a reply.
+pub const DAEMON_TIMEOUT_ENV: &str = "KANBUS_DAEMON_TIMEOUT_MS";
+
+const DEFAULT_DAEMON_RETRIES: u32 = 10;
+const DEFAULT_DAEMON_TIMEOUT_MS: u64 = 10_000;
+const DAEMON_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+/// Daemon connection kept open by `forward_notification` between events.
+#[cfg(unix)]
+struct PublishConnection {
+    socket_path: PathBuf,
+    reader: BufReader<UnixStream>,
+}
+
+#[cfg(unix)]
+static PUBLISH_CONNECTION: Mutex<Option<PublishConnection>> = Mutex::new(None);
+
 /// Test-only response override for daemon client requests.
 #[derive(Clone, Debug)]
 pub enum TestDaemonResponse {
@@ -80,8 +100,25 @@ fn is_test_spawn_disabled() -> bool {
     *guard
 }
 
+thread_local! {
+    static SERVING_DAEMON: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Mark the current thread as the daemon's request loop.
+///
+/// The daemon answers one connection at a time, so library calls it makes
+/// while handling a request must not connect back to it.
+pub(crate) fn mark_daemon_thread() {
+    SERVING_DAEMON.with(|serving| serving.set(true));
+}
+
 /// Return whether daemon mode is enabled.
+///
+/// Always false on the daemon's own request loop.
 pub fn is_daemon_enabled() -> bool {
+    if SERVING_DAEMON.with(Cell::get) {
+        return false;
+    }
     let value = env::var("KANBUS_NO_DAEMON")
         .unwrap_or_default()
         .to_lowercase();
@@ -149,6 +186,137 @@ pub fn request_status(root: &Path) -> Result<BTreeMap<String, Value>, KanbusErro
     Ok(response.result.unwrap_or_default())
 }
 
+/// Ask an already running daemon for its protocol version.
+///
+/// Unlike the other requests this never spawns a daemon; `None` means no
+/// daemon is reachable (or daemon mode is disabled).
+///
+/// # Arguments
+/// * `root` - Repository root path.
+///
+/// # Errors
+/// Returns `KanbusError` if the daemon socket path cannot be resolved.
+pub fn probe_daemon_protocol_version(root: &Path) -> Result<Option<String>, KanbusError> {
+    if !is_daemon_enabled() {
+        return Ok(None);
+    }
+    let socket_path = get_daemon_socket_path(root)?;
+    if !socket_path.exists() && !has_test_daemon_response() {
+        return Ok(None);
+    }
+    let request = RequestEnvelope {
+        protocol_version: PROTOCOL_VERSION.to_string(),
+        request_id: format!("req-{}", Uuid::new_v4().simple()),
+        action: "ping".to_string(),
+        payload: BTreeMap::new(),
+    };
+    Ok(send_request(&socket_path, &request)
+        .ok()
+        .map(|response| response.protocol_version))
+}
+
+/// Relay a notification event to a running daemon's subscribers.
+///
+/// Like `probe_daemon_protocol_version` this never spawns a daemon: without
+/// a running daemon nobody is subscribed, so the event is dropped. The
+/// connection stays open for the next event this process publishes and is
+/// reopened once if the daemon has dropped it.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `event` - Serialized `NotificationEvent`.
+///
+/// # Errors
+/// Returns `KanbusError` if a running daemon cannot be reached or rejects
+/// the event.
+#[cfg(unix)]
+pub fn forward_notification(root: &Path, event: &Value) -> Result<(), KanbusError> {
+    if !is_daemon_enabled() {
+        return Ok(());
+    }
+    let socket_path = get_daemon_socket_path(root)?;
+    if !socket_path.exists() {
+        return Ok(());
+    }
+    let mut payload = BTreeMap::new();
+    payload.insert("event".to_string(), event.clone());
+    let request = RequestEnvelope {
+        protocol_version: PROTOCOL_VERSION.to_string(),
+        request_id: format!("req-{}", Uuid::new_v4().simple()),
+        action: "notifications.publish".to_string(),
+        payload,
+    };
+    let mut connection = PUBLISH_CONNECTION
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(open) = connection
+        .as_mut()
+        .filter(|open| open.socket_path == socket_path)
+    {
+        if let Ok(response) = exchange(&mut open.reader, &request, daemon_timeout()?) {
+            return check_response(response).map(|_| ());
+        }
+    }
+    *connection = None;
+    let (response, reader) = open_request(&socket_path, &request)?;
+    check_response(response)?;
+    *connection = Some(PublishConnection {
+        socket_path,
+        reader,
+    });
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn forward_notification(_root: &Path, _event: &Value) -> Result<(), KanbusError> {
+    Ok(())
+}
+
+/// Subscribe to notification events, spawning the daemon if needed.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+///
+/// # Returns
+/// A reader yielding one serialized `NotificationEvent` per line. Blank
+/// lines are heartbeats and carry no event. The stream ends when the daemon
+/// stops.
+///
+/// # Errors
+/// Returns `KanbusError` if daemon mode is disabled or the daemon cannot be
+/// reached.
+pub fn subscribe_notifications(root: &Path) -> Result<Box<dyn BufRead + Send>, KanbusError> {
+    // A status request starts the daemon, or replaces a stale one, first.
+    request_status(root)?;
+    let socket_path = get_daemon_socket_path(root)?;
+    let request = RequestEnvelope {
+        protocol_version: PROTOCOL_VERSION.to_string(),
+        request_id: format!("req-{}", Uuid::new_v4().simple()),
+        action: "notifications.subscribe".to_string(),
+        payload: BTreeMap::new(),
+    };
+    let (response, reader) = open_request(&socket_path, &request)?;
+    check_response(response)?;
+    #[cfg(unix)]
+    reader
+        .get_ref()
+        .set_read_timeout(None)
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    Ok(Box::new(reader))
+}
+
+fn check_response(response: ResponseEnvelope) -> Result<BTreeMap<String, Value>, KanbusError> {
+    if response.status != "ok" {
+        let error = response.error.unwrap_or(ErrorEnvelope {
+            code: "internal_error".to_string(),
+            message: "daemon error".to_string(),
+            details: BTreeMap::new(),
+        });
+        return Err(KanbusError::IssueOperation(error.message));
+    }
+    Ok(response.result.unwrap_or_default())
+}
+
 /// Request daemon shutdown.
 pub fn request_shutdown(root: &Path) -> Result<BTreeMap<String, Value>, KanbusError> {
     if !is_daemon_enabled() {
@@ -173,11 +341,43 @@ pub fn request_shutdown(root: &Path) -> Result<BTreeMap<String, Value>, KanbusEr
     Ok(response.result.unwrap_or_default())
 }
 
+/// Return the number of reconnect attempts after a failed connection.
+///
+/// # Errors
+/// Returns `KanbusError::Configuration` if `KANBUS_DAEMON_RETRIES` is not a
+/// non-negative integer.
+pub fn daemon_retry_count() -> Result<u32, KanbusError> {
+    match env::var(DAEMON_RETRIES_ENV) {
+        Ok(value) => value.trim().parse().map_err(|_| {
+            KanbusError::Configuration(format!("invalid {DAEMON_RETRIES_ENV}: {value}"))
+        }),
+        Err(_) => Ok(DEFAULT_DAEMON_RETRIES),
+    }
+}
+
+/// Return how long a daemon request may wait for its reply.
+///
+/// # Errors
+/// Returns `KanbusError::Configuration` if `KANBUS_DAEMON_TIMEOUT_MS` is not a
+/// positive integer.
+pub fn daemon_timeout() -> Result<Duration, KanbusError> {
+    match env::var(DAEMON_TIMEOUT_ENV) {
+        Ok(value) => match value.trim().parse::<u64>() {
+            Ok(millis) if millis > 0 => Ok(Duration::from_millis(millis)),
+            _ => Err(KanbusError::Configuration(format!(
+                "invalid {DAEMON_TIMEOUT_ENV}: {value}"
+            ))),
+        },
+        Err(_) => Ok(Duration::from_millis(DEFAULT_DAEMON_TIMEOUT_MS)),
+    }
+}
+
 fn request_with_recovery(
     socket_path: &Path,
     request: &RequestEnvelope,
     root: &Path,
 ) -> Result<ResponseEnvelope, KanbusError> {
+    let retries = daemon_retry_count()?;
     match send_request(socket_path, request) {
         Ok(response) => Ok(response),
         Err(error) => {
@@ -190,7 +390,7 @@ fn request_with_recovery(
             }
             spawn_daemon(root)?;
             let mut last_error = error;
-            for _ in 0..10 {
+            for _ in 0..retries {
                 match send_request(socket_path, request) {
                     Ok(response) => return Ok(response),
                     Err(err) => {
@@ -198,7 +398,7 @@ fn request_with_recovery(
                             return Err(err);
                         }
                         last_error = err;
-                        std::thread::sleep(Duration::from_millis(50));
+                        std::thread::sleep(DAEMON_RETRY_DELAY);
                     }
                 }
             }
@@ -223,21 +423,59 @@ fn send_request(
             TestDaemonResponse::Envelope(envelope) => Ok(envelope),
         };
     }
-    let mut stream =
+    open_request(socket_path, request).map(|(response, _)| response)
+}
+
+/// Send a request on a new connection and read the reply, returning the
+/// connection as well for requests that stream further lines.
+#[cfg(unix)]
+fn open_request(
+    socket_path: &Path,
+    request: &RequestEnvelope,
+) -> Result<(ResponseEnvelope, BufReader<UnixStream>), KanbusError> {
+    let timeout = daemon_timeout()?;
+    let stream =
         UnixStream::connect(socket_path).map_err(|error| KanbusError::Io(error.to_string()))?;
+    stream
+        .set_read_timeout(Some(timeout))
+        .and_then(|_| stream.set_write_timeout(Some(timeout)))
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    let mut reader = BufReader::new(stream);
+    let response = exchange(&mut reader, request, timeout)?;
+    Ok((response, reader))
+}
+
+/// Write one request line on an open connection and read its reply line.
+#[cfg(unix)]
+fn exchange(
+    reader: &mut BufReader<UnixStream>,
+    request: &RequestEnvelope,
+    timeout: Duration,
+) -> Result<ResponseEnvelope, KanbusError> {
     let payload =
         serde_json::to_string(request).map_err(|error| KanbusError::Io(error.to_string()))?;
+    let stream = reader.get_mut();
     stream
         .write_all(payload.as_bytes())
         .map_err(|error| KanbusError::Io(error.to_string()))?;
     stream
         .write_all(b"\n")
         .map_err(|error| KanbusError::Io(error.to_string()))?;
-    let mut reader = BufReader::new(stream);
     let mut line = String::new();
-    reader
-        .read_line(&mut line)
-        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    reader.read_line(&mut line).map_err(|error| {
+        if matches!(
+            error.kind(),
+            std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+        ) {
+            // A busy daemon is not a stale socket; do not trigger recovery.
+            KanbusError::IssueOperation(format!(
+                "daemon did not respond within {} ms",
+                timeout.as_millis()
+            ))
+        } else {
+            KanbusError::Io(error.to_string())
+        }
+    })?;
     if line.trim().is_empty() {
         return Err(KanbusError::IssueOperation(
             "empty daemon response".to_string(),
@@ -256,6 +494,16 @@ fn send_request(
     ))
 }
 
+#[cfg(not(unix))]
+fn open_request(
+    _socket_path: &Path,
+    _request: &RequestEnvelope,
+) -> Result<(ResponseEnvelope, std::io::Empty), KanbusError> {
+    Err(KanbusError::IssueOperation(
+        "daemon not supported on this platform".to_string(),
+    ))
+}
+
 #[cfg(unix)]
 fn spawn_daemon(root: &Path) -> Result<(), KanbusError> {
     if is_test_spawn_disabled() {
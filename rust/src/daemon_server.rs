@@ -1,11 +1,28 @@
 //! Daemon server for just-in-time index access.
 
 use std::collections::BTreeMap;
+#[cfg(unix)]
+use std::io::ErrorKind;
 use std::io::{BufRead, BufReader, Write};
 #[cfg(unix)]
 use std::os::unix::net::{UnixListener, UnixStream};
 use std::path::Path;
+#[cfg(unix)]
+use std::path::PathBuf;
+#[cfg(unix)]
+use std::sync::mpsc;
+#[cfg(unix)]
+use std::sync::Arc;
+use std::sync::Mutex;
+#[cfg(unix)]
+use std::thread;
+use std::time::{Duration, Instant};
 
+use chrono::{DateTime, Utc};
+#[cfg(unix)]
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use serde_json::Value;
 
 use crate::cache::{collect_issue_file_mtimes, load_cache_if_valid, write_cache};
@@ -16,8 +33,194 @@ use crate::daemon_protocol::{
 };
 use crate::error::KanbusError;
 use crate::file_io::load_project_directory;
-use crate::index::build_index_from_directory;
+use crate::index::{build_index_from_issues, issue_file_cache_path, load_issues_with_cache};
+use crate::issue_close::close_issue;
+use crate::issue_creation::{create_issue, IssueCreationRequest};
+use crate::issue_update::{update_issue, IssueUpdateRequest};
 use crate::models::IssueData;
+#[cfg(unix)]
+use crate::notification_events::NotificationEvent;
+
+/// How long a stopping daemon keeps answering clients that were already queued.
+#[cfg(unix)]
+const SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// How long a notification write may block before the subscriber is dropped.
+#[cfg(unix)]
+const SUBSCRIBER_WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Environment variable overriding how long an idle daemon waits before exiting.
+pub const DAEMON_IDLE_ENV: &str = "KANBUS_DAEMON_IDLE_SECS";
+
+const DEFAULT_DAEMON_IDLE_SECS: u64 = 600;
+#[cfg(unix)]
+const IDLE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Quiet period that coalesces a burst of file changes into one index refresh.
+#[cfg(unix)]
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Serializes index rebuilds between request handling and the file watcher.
+static INDEX_LOCK: Mutex<()> = Mutex::new(());
+
+/// Actions that write an issue and return it in the `issue` result field.
+const ISSUE_ACTIONS: [&str; 3] = ["issue.create", "issue.update", "issue.close"];
+
+/// Payload of an `issue.create` request, mirroring `IssueCreationRequest`.
+#[derive(Debug, Deserialize)]
+struct CreateIssuePayload {
+    title: String,
+    issue_type: Option<String>,
+    priority: Option<u8>,
+    assignee: Option<String>,
+    parent: Option<String>,
+    #[serde(default)]
+    priority_from_parent: bool,
+    #[serde(default)]
+    labels: Vec<String>,
+    description: Option<String>,
+    status: Option<String>,
+    #[serde(default)]
+    local: bool,
+    #[serde(default)]
+    allow_duplicate_title: bool,
+    due_at: Option<DateTime<Utc>>,
+    estimate_minutes: Option<u32>,
+}
+
+/// Payload of an `issue.update` request; omitted fields are left unchanged.
+#[derive(Debug, Deserialize)]
+struct UpdateIssuePayload {
+    identifier: String,
+    title: Option<String>,
+    description: Option<String>,
+    status: Option<String>,
+    assignee: Option<String>,
+    priority: Option<u8>,
+    #[serde(default)]
+    add_labels: Vec<String>,
+    #[serde(default)]
+    remove_labels: Vec<String>,
+    set_labels: Option<String>,
+    parent: Option<String>,
+    due_at: Option<DateTime<Utc>>,
+    estimate_minutes: Option<u32>,
+}
+
+/// Payload of an `issue.close` request.
+#[derive(Debug, Deserialize)]
+struct CloseIssuePayload {
+    identifier: String,
+}
+
+/// Return how long the daemon may go without requests before it shuts down.
+///
+/// # Errors
+/// Returns `KanbusError::Configuration` if `KANBUS_DAEMON_IDLE_SECS` is not a
+/// positive integer.
+pub fn daemon_idle_timeout() -> Result<Duration, KanbusError> {
+    match std::env::var(DAEMON_IDLE_ENV) {
+        Ok(value) => match value.trim().parse::<u64>() {
+            Ok(seconds) if seconds > 0 => Ok(Duration::from_secs(seconds)),
+            _ => Err(KanbusError::Configuration(format!(
+                "invalid {DAEMON_IDLE_ENV}: {value}"
+            ))),
+        },
+        Err(_) => Ok(Duration::from_secs(DEFAULT_DAEMON_IDLE_SECS)),
+    }
+}
+
+/// Connections subscribed to notification events, such as `kanbus watch`.
+#[cfg(unix)]
+#[derive(Default)]
+struct Subscribers {
+    streams: Mutex<Vec<UnixStream>>,
+}
+
+#[cfg(unix)]
+impl Subscribers {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<UnixStream>> {
+        self.streams
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn add(&self, stream: UnixStream) {
+        self.lock().push(stream);
+    }
+
+    /// Write one line to every subscriber, dropping those that have gone
+    /// away, and return how many remain.
+    fn broadcast(&self, line: &str) -> usize {
+        let mut streams = self.lock();
+        streams.retain_mut(|stream| {
+            stream
+                .write_all(line.as_bytes())
+                .and_then(|_| stream.write_all(b"\n"))
+                .is_ok()
+        });
+        streams.len()
+    }
+}
+
+/// Tracks request activity so the watchdog can stop an idle daemon.
+struct IdleTracker {
+    timeout: Duration,
+    state: Mutex<IdleState>,
+}
+
+struct IdleState {
+    last_activity: Instant,
+    in_flight: bool,
+    expired: bool,
+    stopped: bool,
+}
+
+impl IdleTracker {
+    fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            state: Mutex::new(IdleState {
+                last_activity: Instant::now(),
+                in_flight: false,
+                expired: false,
+                stopped: false,
+            }),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, IdleState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn begin_request(&self) {
+        let mut state = self.lock();
+        state.in_flight = true;
+        state.last_activity = Instant::now();
+    }
+
+    fn end_request(&self) {
+        let mut state = self.lock();
+        state.in_flight = false;
+        state.last_activity = Instant::now();
+    }
+
+    /// Time left before an idle shutdown, counted from the last request.
+    fn remaining(&self) -> Duration {
+        self.timeout
+            .saturating_sub(self.lock().last_activity.elapsed())
+    }
+
+    fn is_expired(&self) -> bool {
+        self.lock().expired
+    }
+
+    fn stop(&self) {
+        self.lock().stopped = true;
+    }
+}
 
 /// Run the daemon server for a repository root.
 ///
@@ -28,6 +231,7 @@ use crate::models::IssueData;
 /// Returns `KanbusError` if the daemon fails to bind or serve requests.
 #[cfg(unix)]
 pub fn run_daemon(root: &Path) -> Result<(), KanbusError> {
+    let idle_timeout = daemon_idle_timeout()?;
     let socket_path = get_daemon_socket_path(root)?;
     let socket_dir = socket_path
         .parent()
@@ -39,16 +243,181 @@ pub fn run_daemon(root: &Path) -> Result<(), KanbusError> {
 
     let listener =
         UnixListener::bind(&socket_path).map_err(|error| KanbusError::Io(error.to_string()))?;
+    crate::daemon_client::mark_daemon_thread();
     warm_cache(root)?;
+    let tracker = Arc::new(IdleTracker::new(idle_timeout));
+    let subscribers = Arc::new(Subscribers::default());
+    let watcher = match spawn_index_watcher(root, Arc::clone(&subscribers)) {
+        Ok(watcher) => Some(watcher),
+        Err(error) => {
+            eprintln!("Warning: index watcher unavailable, serving without it: {error}");
+            None
+        }
+    };
+    spawn_idle_watchdog(
+        Arc::clone(&tracker),
+        Arc::clone(&subscribers),
+        socket_path.clone(),
+    );
+    let served = serve(root, &listener, &tracker, &subscribers);
+    tracker.stop();
+    drop(watcher);
+    served?;
+    shutdown(root, listener, &socket_path)
+}
+
+#[cfg(unix)]
+fn serve(
+    root: &Path,
+    listener: &UnixListener,
+    tracker: &IdleTracker,
+    subscribers: &Arc<Subscribers>,
+) -> Result<(), KanbusError> {
     for stream in listener.incoming() {
         let stream = stream.map_err(|error| KanbusError::Io(error.to_string()))?;
-        if handle_stream(root, stream)? {
+        tracker.begin_request();
+        let handled = handle_stream(root, stream, Some(tracker), Some(subscribers));
+        tracker.end_request();
+        if handled? || tracker.is_expired() {
             break;
         }
     }
     Ok(())
 }
 
+/// Watch for idle periods and wake the accept loop once the daemon has gone
+/// `timeout` without a request. A request in flight is never interrupted,
+/// and a daemon with live notification subscribers stays up: each expiry
+/// sends them a blank heartbeat line and restarts the idle period.
+#[cfg(unix)]
+fn spawn_idle_watchdog(
+    tracker: Arc<IdleTracker>,
+    subscribers: Arc<Subscribers>,
+    socket_path: PathBuf,
+) {
+    thread::spawn(move || loop {
+        let wait = {
+            let mut state = tracker.lock();
+            if state.stopped {
+                return;
+            }
+            let idle = state.last_activity.elapsed();
+            if !state.in_flight && idle >= tracker.timeout {
+                if subscribers.broadcast("") > 0 {
+                    state.last_activity = Instant::now();
+                    Some(tracker.timeout)
+                } else {
+                    state.expired = true;
+                    None
+                }
+            } else {
+                Some(tracker.timeout.saturating_sub(idle))
+            }
+        };
+        match wait {
+            Some(wait) => thread::sleep(wait.max(IDLE_POLL_INTERVAL)),
+            None => {
+                // The accept loop is blocked; a connection lets it see the expiry.
+                let _ = UnixStream::connect(&socket_path);
+                return;
+            }
+        }
+    });
+}
+
+/// Watch the project's `issues/` and `events/` directories and refresh the
+/// index when they change, then tell subscribers with an `index.changed`
+/// notification. The whole project directory is watched so both directories
+/// are picked up even when they are created after the daemon starts. The
+/// watcher thread exits once the returned watcher is dropped. Callers treat
+/// a failure here as non-fatal and keep serving without change
+/// notifications.
+#[cfg(unix)]
+fn spawn_index_watcher(
+    root: &Path,
+    subscribers: Arc<Subscribers>,
+) -> Result<RecommendedWatcher, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let watched_dirs = [project_dir.join("issues"), project_dir.join("events")];
+    let (sender, receiver) = mpsc::channel::<notify::Result<notify::Event>>();
+    let mut watcher =
+        notify::recommended_watcher(sender).map_err(|error| KanbusError::Io(error.to_string()))?;
+    watcher
+        .watch(&project_dir, RecursiveMode::Recursive)
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    let root = root.to_path_buf();
+    let changed_line = serde_json::to_string(&NotificationEvent::IndexChanged)
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    thread::spawn(move || {
+        crate::daemon_client::mark_daemon_thread();
+        while let Ok(first) = receiver.recv() {
+            let mut changed = touches_watched_dirs(&first, &watched_dirs);
+            while let Ok(next) = receiver.recv_timeout(WATCH_DEBOUNCE) {
+                changed |= touches_watched_dirs(&next, &watched_dirs);
+            }
+            if !changed {
+                continue;
+            }
+            // A file caught mid-write fails to parse; the stale cache no longer
+            // matches disk, so the next change or request rebuilds it.
+            let _ = warm_cache(&root);
+            subscribers.broadcast(&changed_line);
+        }
+    });
+    Ok(watcher)
+}
+
+#[cfg(unix)]
+fn touches_watched_dirs(event: &notify::Result<notify::Event>, watched_dirs: &[PathBuf]) -> bool {
+    let Ok(event) = event else {
+        return false;
+    };
+    if matches!(event.kind, EventKind::Access(_)) {
+        return false;
+    }
+    event
+        .paths
+        .iter()
+        .any(|path| watched_dirs.iter().any(|dir| path.starts_with(dir)))
+}
+
+/// Stop serving: answer clients already queued on the socket, bring the
+/// index cache in line with disk, and remove the socket file.
+#[cfg(unix)]
+fn shutdown(root: &Path, listener: UnixListener, socket_path: &Path) -> Result<(), KanbusError> {
+    let drained = drain_pending_connections(root, &listener);
+    drop(listener);
+    let flushed = warm_cache(root);
+    if socket_path.exists() {
+        std::fs::remove_file(socket_path).map_err(|error| KanbusError::Io(error.to_string()))?;
+    }
+    drained.and(flushed)
+}
+
+#[cfg(unix)]
+fn drain_pending_connections(root: &Path, listener: &UnixListener) -> Result<(), KanbusError> {
+    listener
+        .set_nonblocking(true)
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    let deadline = Instant::now() + SHUTDOWN_DRAIN_TIMEOUT;
+    while Instant::now() < deadline {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                stream
+                    .set_nonblocking(false)
+                    .map_err(|error| KanbusError::Io(error.to_string()))?;
+                stream
+                    .set_read_timeout(Some(SHUTDOWN_DRAIN_TIMEOUT))
+                    .map_err(|error| KanbusError::Io(error.to_string()))?;
+                let _ = handle_stream(root, stream, None, None);
+            }
+            Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+            Err(error) => return Err(KanbusError::Io(error.to_string())),
+        }
+    }
+    Ok(())
+}
+
 #[cfg(not(unix))]
 pub fn run_daemon(_root: &Path) -> Result<(), KanbusError> {
     Err(KanbusError::IssueOperation(
@@ -62,7 +431,12 @@ fn warm_cache(root: &Path) -> Result<(), KanbusError> {
 }
 
 #[cfg(unix)]
-fn handle_stream(root: &Path, stream: UnixStream) -> Result<bool, KanbusError> {
+fn handle_stream(
+    root: &Path,
+    stream: UnixStream,
+    tracker: Option<&IdleTracker>,
+    subscribers: Option<&Arc<Subscribers>>,
+) -> Result<bool, KanbusError> {
     let mut reader = BufReader::new(
         stream
             .try_clone()
@@ -77,8 +451,25 @@ fn handle_stream(root: &Path, stream: UnixStream) -> Result<bool, KanbusError> {
         return Ok(false);
     }
     let mut stream = stream;
+    let mut subscribe = false;
+    let mut publish = false;
+    let mut relayed_event = None;
     let (response, should_shutdown) = match serde_json::from_str::<RequestEnvelope>(&line) {
-        Ok(request) => handle_request(root, request),
+        Ok(request) => {
+            let action = request.action.clone();
+            let payload = request.payload.clone();
+            let (response, should_shutdown) = handle_request(root, request, tracker);
+            if response.status == "ok" {
+                subscribe = action == "notifications.subscribe";
+                publish = action == "notifications.publish";
+                relayed_event = if publish {
+                    payload.get("event").cloned()
+                } else {
+                    issue_action_event(&action, &payload, &response)
+                };
+            }
+            (response, should_shutdown)
+        }
         Err(error) => (
             ResponseEnvelope {
                 protocol_version: PROTOCOL_VERSION.to_string(),
@@ -102,17 +493,72 @@ fn handle_stream(root: &Path, stream: UnixStream) -> Result<bool, KanbusError> {
     stream
         .write_all(b"\n")
         .map_err(|error| KanbusError::Io(error.to_string()))?;
+    if let Some(subscribers) = subscribers {
+        if subscribe {
+            // A stalled subscriber must not block the accept loop.
+            stream
+                .set_write_timeout(Some(SUBSCRIBER_WRITE_TIMEOUT))
+                .map_err(|error| KanbusError::Io(error.to_string()))?;
+            subscribers.add(stream);
+        } else if let Some(event) = relayed_event {
+            subscribers.broadcast(&event.to_string());
+            if publish {
+                spawn_publisher(root.to_path_buf(), reader, stream, Arc::clone(subscribers));
+            }
+        }
+    }
     Ok(should_shutdown)
 }
 
-fn handle_request(root: &Path, request: RequestEnvelope) -> (ResponseEnvelope, bool) {
+/// Keep relaying `notifications.publish` requests sent on a publisher's
+/// connection, so a process publishing several events reuses one socket
+/// without holding up the accept loop. Any other request ends the connection.
+#[cfg(unix)]
+fn spawn_publisher(
+    root: PathBuf,
+    mut reader: BufReader<UnixStream>,
+    mut stream: UnixStream,
+    subscribers: Arc<Subscribers>,
+) {
+    thread::spawn(move || {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if !matches!(reader.read_line(&mut line), Ok(count) if count > 0) {
+                return;
+            }
+            let Ok(request) = serde_json::from_str::<RequestEnvelope>(&line) else {
+                return;
+            };
+            if request.action != "notifications.publish" {
+                return;
+            }
+            let event = request.payload.get("event").cloned();
+            let (response, _) = handle_request(&root, request, None);
+            let Ok(payload) = serde_json::to_string(&response) else {
+                return;
+            };
+            if stream
+                .write_all(payload.as_bytes())
+                .and_then(|_| stream.write_all(b"\n"))
+                .is_err()
+            {
+                return;
+            }
+            if let Some(event) = event.filter(|_| response.status == "ok") {
+                subscribers.broadcast(&event.to_string());
+            }
+        }
+    });
+}
+
+fn handle_request(
+    root: &Path,
+    request: RequestEnvelope,
+    tracker: Option<&IdleTracker>,
+) -> (ResponseEnvelope, bool) {
     if let Err(error) = validate_protocol_compatibility(&request.protocol_version, PROTOCOL_VERSION)
     {
-        let code = if error.to_string() == "protocol version unsupported" {
-            "protocol_version_unsupported"
-        } else {
-            "protocol_version_mismatch"
-        };
         return (
             ResponseEnvelope {
                 protocol_version: PROTOCOL_VERSION.to_string(),
@@ -120,7 +566,7 @@ fn handle_request(root: &Path, request: RequestEnvelope) -> (ResponseEnvelope, b
                 status: "error".to_string(),
                 result: None,
                 error: Some(ErrorEnvelope {
-                    code: code.to_string(),
+                    code: error.code().to_string(),
                     message: error.to_string(),
                     details: BTreeMap::new(),
                 }),
@@ -132,6 +578,12 @@ fn handle_request(root: &Path, request: RequestEnvelope) -> (ResponseEnvelope, b
     if request.action == "ping" {
         let mut result = BTreeMap::new();
         result.insert("status".to_string(), Value::String("ok".to_string()));
+        if let Some(tracker) = tracker {
+            result.insert(
+                "idle_shutdown_in_seconds".to_string(),
+                Value::from(tracker.remaining().as_millis().div_ceil(1000) as u64),
+            );
+        }
         return (
             ResponseEnvelope {
                 protocol_version: PROTOCOL_VERSION.to_string(),
@@ -159,6 +611,80 @@ fn handle_request(root: &Path, request: RequestEnvelope) -> (ResponseEnvelope, b
         );
     }
 
+    if request.action == "notifications.subscribe" {
+        let mut result = BTreeMap::new();
+        result.insert(
+            "status".to_string(),
+            Value::String("subscribed".to_string()),
+        );
+        return (
+            ResponseEnvelope {
+                protocol_version: PROTOCOL_VERSION.to_string(),
+                request_id: request.request_id,
+                status: "ok".to_string(),
+                result: Some(result),
+                error: None,
+            },
+            false,
+        );
+    }
+
+    if request.action == "notifications.publish" {
+        if !request.payload.get("event").is_some_and(Value::is_object) {
+            return (
+                ResponseEnvelope {
+                    protocol_version: PROTOCOL_VERSION.to_string(),
+                    request_id: request.request_id,
+                    status: "error".to_string(),
+                    result: None,
+                    error: Some(ErrorEnvelope {
+                        code: "invalid_request".to_string(),
+                        message: "notification event is required".to_string(),
+                        details: BTreeMap::new(),
+                    }),
+                },
+                false,
+            );
+        }
+        let mut result = BTreeMap::new();
+        result.insert("status".to_string(), Value::String("ok".to_string()));
+        return (
+            ResponseEnvelope {
+                protocol_version: PROTOCOL_VERSION.to_string(),
+                request_id: request.request_id,
+                status: "ok".to_string(),
+                result: Some(result),
+                error: None,
+            },
+            false,
+        );
+    }
+
+    if ISSUE_ACTIONS.contains(&request.action.as_str()) {
+        let (status, result, error) =
+            match run_issue_action(root, &request.action, &request.payload) {
+                Ok(issue) => {
+                    let mut result = BTreeMap::new();
+                    result.insert(
+                        "issue".to_string(),
+                        serde_json::to_value(issue).unwrap_or(Value::Null),
+                    );
+                    ("ok", Some(result), None)
+                }
+                Err(error) => ("error", None, Some(error)),
+            };
+        return (
+            ResponseEnvelope {
+                protocol_version: PROTOCOL_VERSION.to_string(),
+                request_id: request.request_id,
+                status: status.to_string(),
+                result,
+                error,
+            },
+            false,
+        );
+    }
+
     if request.action == "index.list" {
         match load_index(root) {
             Ok(issues) => {
@@ -187,7 +713,7 @@ fn handle_request(root: &Path, request: RequestEnvelope) -> (ResponseEnvelope, b
                         status: "error".to_string(),
                         result: None,
                         error: Some(ErrorEnvelope {
-                            code: "internal_error".to_string(),
+                            code: error.code().to_string(),
                             message: error.to_string(),
                             details: BTreeMap::new(),
                         }),
@@ -216,6 +742,119 @@ fn handle_request(root: &Path, request: RequestEnvelope) -> (ResponseEnvelope, b
     )
 }
 
+fn run_issue_action(
+    root: &Path,
+    action: &str,
+    payload: &BTreeMap<String, Value>,
+) -> Result<IssueData, ErrorEnvelope> {
+    let issue = match action {
+        "issue.create" => {
+            let payload: CreateIssuePayload = parse_payload(payload)?;
+            let request = IssueCreationRequest {
+                root: root.to_path_buf(),
+                title: payload.title,
+                issue_type: payload.issue_type,
+                priority: payload.priority,
+                assignee: payload.assignee,
+                parent: payload.parent,
+                priority_from_parent: payload.priority_from_parent,
+                labels: payload.labels,
+                description: payload.description,
+                status: payload.status,
+                local: payload.local,
+                validate: true,
+                allow_duplicate_title: payload.allow_duplicate_title,
+                due_at: payload.due_at,
+                estimate_minutes: payload.estimate_minutes,
+            };
+            create_issue(&request).map(|result| result.issue)
+        }
+        "issue.update" => {
+            let payload: UpdateIssuePayload = parse_payload(payload)?;
+            update_issue(&IssueUpdateRequest {
+                title: payload.title,
+                description: payload.description,
+                status: payload.status,
+                assignee: payload.assignee,
+                priority: payload.priority,
+                add_labels: payload.add_labels,
+                remove_labels: payload.remove_labels,
+                set_labels: payload.set_labels,
+                parent: payload.parent,
+                due_at: payload.due_at,
+                estimate_minutes: payload.estimate_minutes,
+                ..IssueUpdateRequest::new(root, payload.identifier)
+            })
+        }
+        _ => {
+            let payload: CloseIssuePayload = parse_payload(payload)?;
+            close_issue(root, &payload.identifier)
+        }
+    };
+    issue.map_err(|error| ErrorEnvelope {
+        code: error.code().to_string(),
+        message: error.to_string(),
+        details: BTreeMap::new(),
+    })
+}
+
+fn parse_payload<T: DeserializeOwned>(
+    payload: &BTreeMap<String, Value>,
+) -> Result<T, ErrorEnvelope> {
+    let object = payload
+        .iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    serde_json::from_value(Value::Object(object)).map_err(|error| ErrorEnvelope {
+        code: "invalid_request".to_string(),
+        message: error.to_string(),
+        details: BTreeMap::new(),
+    })
+}
+
+/// Build the notification relayed to subscribers after a successful issue
+/// action, since writes made by the daemon cannot forward to the daemon.
+#[cfg(unix)]
+fn issue_action_event(
+    action: &str,
+    payload: &BTreeMap<String, Value>,
+    response: &ResponseEnvelope,
+) -> Option<Value> {
+    let issue_value = response.result.as_ref()?.get("issue")?.clone();
+    let issue_data: IssueData = serde_json::from_value(issue_value).ok()?;
+    let issue_id = issue_data.identifier.clone();
+    let event = match action {
+        "issue.create" => NotificationEvent::IssueCreated {
+            issue_id,
+            issue_data,
+        },
+        "issue.update" => {
+            let mut fields_changed: Vec<String> = payload
+                .keys()
+                .filter(|key| key.as_str() != "identifier")
+                .map(|key| match key.as_str() {
+                    "add_labels" | "remove_labels" | "set_labels" => "labels".to_string(),
+                    _ => key.clone(),
+                })
+                .collect();
+            fields_changed.sort();
+            fields_changed.dedup();
+            NotificationEvent::IssueUpdated {
+                issue_id,
+                fields_changed,
+                issue_data,
+            }
+        }
+        "issue.close" => NotificationEvent::IssueUpdated {
+            issue_id,
+            fields_changed: vec!["status".to_string()],
+            issue_data,
+        },
+        _ => return None,
+    };
+    serde_json::to_value(event).ok()
+}
+
 /// Handle a daemon request without opening a socket.
 ///
 /// # Arguments
@@ -225,10 +864,13 @@ fn handle_request(root: &Path, request: RequestEnvelope) -> (ResponseEnvelope, b
 /// # Returns
 /// Response envelope for the request.
 pub fn handle_request_for_testing(root: &Path, request: RequestEnvelope) -> ResponseEnvelope {
-    handle_request(root, request).0
+    handle_request(root, request, None).0
 }
 
 fn load_index(root: &Path) -> Result<Vec<IssueData>, KanbusError> {
+    let _guard = INDEX_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
     let project_dir = load_project_directory(root)?;
     let issues_dir = project_dir.join("issues");
     let cache_path = get_index_cache_path(root)?;
@@ -239,7 +881,8 @@ fn load_index(root: &Path) -> Result<Vec<IssueData>, KanbusError> {
             .map(|issue| issue.as_ref().clone())
             .collect());
     }
-    let index = build_index_from_directory(&issues_dir)?;
+    let issues = load_issues_with_cache(&issues_dir, &issue_file_cache_path(&project_dir))?;
+    let index = build_index_from_issues(issues);
     let mtimes = collect_issue_file_mtimes(&issues_dir)?;
     write_cache(&index, &cache_path, &mtimes)?;
     Ok(index
@@ -1,8 +1,9 @@
 //! Dependency management utilities.
 
-use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
 
+use crate::config_loader::load_project_configuration;
 use crate::error::KanbusError;
 use crate::event_history::{
     dependency_payload, events_dir_for_issue_path, now_timestamp, write_events_batch, EventRecord,
@@ -10,21 +11,113 @@ use crate::event_history::{
 };
 use crate::file_io::{
     discover_kanbus_projects, discover_project_directories, find_project_local_directory,
-    load_project_directory,
+    get_configuration_path, load_project_directory, lock_project_cache_file,
+    resolve_labeled_projects,
 };
-use crate::issue_files::{read_issue_from_file, write_issue_to_file};
-use crate::issue_lookup::{load_issue_from_project, IssueLookupResult};
+use crate::issue_files::{list_issue_files, read_issue_from_file, write_issue_to_file};
+use crate::issue_lookup::{
+    load_issue_from_project, load_issue_from_project_directory, IssueLookupResult,
+};
+use crate::issue_update::{update_issue, IssueUpdateRequest};
 use crate::models::{DependencyLink, IssueData};
+use crate::queries::compare_priority;
 use crate::users::get_current_user;
 
 const ALLOWED_DEPENDENCY_TYPES: [&str; 2] = ["blocked-by", "relates-to"];
 
+/// Separator between the project label and the issue identifier of a
+/// dependency target in another project, as in `alpha:alpha-task01`.
+pub const PROJECT_TARGET_SEPARATOR: char = ':';
+
+/// Shared issues of every configured project, keyed so dependency links can be
+/// followed across projects.
+///
+/// Issues in the primary project are keyed by identifier; issues in virtual
+/// projects are keyed as `<label>:<identifier>`.
+pub(crate) struct ProjectIssueIndex {
+    primary_label: String,
+    projects: Vec<(String, PathBuf)>,
+    issues: BTreeMap<String, (String, IssueData)>,
+}
+
+impl ProjectIssueIndex {
+    /// Load the shared issues of the primary and virtual projects.
+    ///
+    /// # Errors
+    /// Returns `KanbusError` if configuration or issue files cannot be read.
+    pub(crate) fn load(root: &Path) -> Result<Self, KanbusError> {
+        let configuration = load_project_configuration(&get_configuration_path(root)?)?;
+        let mut index = Self {
+            primary_label: configuration.project_key,
+            projects: Vec::new(),
+            issues: BTreeMap::new(),
+        };
+        for project in resolve_labeled_projects(root)? {
+            let project_dir = project
+                .project_dir
+                .canonicalize()
+                .map_err(|error| KanbusError::Io(error.to_string()))?;
+            let issues_dir = project_dir.join("issues");
+            if issues_dir.is_dir() {
+                for path in list_issue_files(&issues_dir)? {
+                    let issue = read_issue_from_file(&path)?;
+                    let key = index.key(&project.label, &issue.identifier);
+                    index.issues.insert(key, (project.label.clone(), issue));
+                }
+            }
+            index.projects.push((project.label, project_dir));
+        }
+        Ok(index)
+    }
+
+    /// Return the index key of a dependency target stored on an issue in the
+    /// project labeled `owner_label`.
+    pub(crate) fn key(&self, owner_label: &str, target: &str) -> String {
+        let (label, identifier) = target
+            .split_once(PROJECT_TARGET_SEPARATOR)
+            .unwrap_or((owner_label, target));
+        if label == self.primary_label {
+            identifier.to_string()
+        } else {
+            format!("{label}{PROJECT_TARGET_SEPARATOR}{identifier}")
+        }
+    }
+
+    /// Return the label of a project directory, or the primary label when the
+    /// directory is not a configured project.
+    pub(crate) fn label_for(&self, project_dir: &Path) -> &str {
+        self.projects
+            .iter()
+            .find(|(_, dir)| dir == project_dir)
+            .map(|(label, _)| label.as_str())
+            .unwrap_or(self.primary_label.as_str())
+    }
+
+    /// Look up an issue and its project label by index key.
+    pub(crate) fn get(&self, key: &str) -> Option<&(String, IssueData)> {
+        self.issues.get(key)
+    }
+
+    fn project_dir(&self, label: &str) -> Result<&Path, KanbusError> {
+        self.projects
+            .iter()
+            .find(|(candidate, _)| candidate == label)
+            .map(|(_, dir)| dir.as_path())
+            .ok_or_else(|| KanbusError::IssueOperation(format!("unknown project: {label}")))
+    }
+}
+
 /// Add a dependency to an issue.
 ///
+/// The read-validate-write sequence runs under a project-level lock so that
+/// concurrent adds cannot jointly form a cycle that neither detected alone.
+/// A target in another configured project is stored as `<label>:<identifier>`.
+///
 /// # Arguments
 /// * `root` - Repository root path.
 /// * `source_id` - Issue identifier to update.
-/// * `target_id` - Dependency target issue identifier.
+/// * `target_id` - Dependency target issue identifier, optionally qualified as
+///   `<label>:<identifier>`.
 /// * `dependency_type` - Dependency type to add.
 ///
 /// # Returns
@@ -39,11 +132,33 @@ pub fn add_dependency(
     dependency_type: &str,
 ) -> Result<IssueData, KanbusError> {
     validate_dependency_type(dependency_type)?;
+    let _lock = lock_project_cache_file(&load_project_directory(root)?, "dependencies.lock")?;
+    let index = ProjectIssueIndex::load(root)?;
     let source_lookup = load_issue_from_project(root, source_id)?;
-    let target_lookup = load_issue_from_project(root, target_id)?;
+    let target_lookup = match target_id.split_once(PROJECT_TARGET_SEPARATOR) {
+        Some((label, identifier)) => {
+            load_issue_from_project_directory(index.project_dir(label)?, identifier)?
+        }
+        None => load_issue_from_project(root, target_id)?,
+    };
+    let same_project = source_lookup.project_dir == target_lookup.project_dir;
+    let source_label = index.label_for(&source_lookup.project_dir);
+    // Compare and store full identifiers so short ids cannot hide a cycle;
+    // targets in another project carry that project's label.
+    let source_id = source_lookup.issue.identifier.as_str();
+    let target_id = if same_project {
+        target_lookup.issue.identifier.clone()
+    } else {
+        format!(
+            "{}{PROJECT_TARGET_SEPARATOR}{}",
+            index.label_for(&target_lookup.project_dir),
+            target_lookup.issue.identifier
+        )
+    };
+    let target_id = target_id.as_str();
 
     // Prevent blocked-by relationships that mirror parent-child edges (cycle-like).
-    if dependency_type == "blocked-by" {
+    if dependency_type == "blocked-by" && same_project {
         if source_lookup.issue.parent.as_deref() == Some(target_id) {
             return Err(KanbusError::IssueOperation(
                 "circular dependency: cannot block on parent".to_string(),
@@ -57,7 +172,11 @@ pub fn add_dependency(
     }
 
     if dependency_type == "blocked-by" {
-        ensure_no_cycle(root, source_id, target_id)?;
+        ensure_no_blocking_cycle(
+            &blocking_edges(&index),
+            &index.key(source_label, source_id),
+            &index.key(source_label, target_id),
+        )?;
     }
 
     if has_dependency(&source_lookup.issue, target_id, dependency_type) {
@@ -227,6 +346,83 @@ pub fn list_ready_issues(
     Ok(ready)
 }
 
+/// Pick the ready issue to work on next.
+///
+/// Candidates are ready issues that are unassigned or assigned to
+/// `assignee`. The highest priority (by the configured `priority_order`)
+/// wins, with the oldest issue breaking ties.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `assignee` - User whose issues are considered alongside unassigned ones.
+///
+/// # Returns
+/// The recommended issue, or `None` if nothing is ready.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if listing fails.
+pub fn next_ready_issue(root: &Path, assignee: &str) -> Result<Option<IssueData>, KanbusError> {
+    Ok(next_ready_candidates(root, assignee)?.into_iter().next())
+}
+
+/// Pick the ready issue to work on next and assign it to `assignee`.
+///
+/// Selection and assignment happen under a project lock, and each candidate
+/// is re-read inside the lock, so concurrent claims never take the same issue.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `assignee` - User claiming the issue.
+///
+/// # Returns
+/// The claimed issue, or `None` if nothing is ready.
+///
+/// # Errors
+/// Returns `KanbusError` if listing, locking, or updating fails.
+pub fn claim_next_ready_issue(
+    root: &Path,
+    assignee: &str,
+) -> Result<Option<IssueData>, KanbusError> {
+    let _lock = lock_project_cache_file(&load_project_directory(root)?, "claim.lock")?;
+    for candidate in next_ready_candidates(root, assignee)? {
+        let current = load_issue_from_project(root, &candidate.identifier)?.issue;
+        if current
+            .assignee
+            .as_deref()
+            .is_some_and(|current| current != assignee)
+        {
+            continue;
+        }
+        let claimed = update_issue(&IssueUpdateRequest {
+            assignee: Some(assignee.to_string()),
+            claim: true,
+            ..IssueUpdateRequest::new(root, current.identifier)
+        })?;
+        return Ok(Some(claimed));
+    }
+    Ok(None)
+}
+
+/// Ready issues open to `assignee`, best candidate first.
+fn next_ready_candidates(root: &Path, assignee: &str) -> Result<Vec<IssueData>, KanbusError> {
+    let configuration = load_project_configuration(&get_configuration_path(root)?)?;
+    let mut candidates: Vec<IssueData> = list_ready_issues(root, true, false)?
+        .into_iter()
+        .filter(|issue| {
+            issue
+                .assignee
+                .as_deref()
+                .is_none_or(|current| current == assignee)
+        })
+        .collect();
+    candidates.sort_by(|left, right| {
+        compare_priority(left.priority, right.priority, &configuration.priority_order)
+            .then(left.created_at.cmp(&right.created_at))
+            .then(left.identifier.cmp(&right.identifier))
+    });
+    Ok(candidates)
+}
+
 fn load_ready_issues_for_project(
     root: &Path,
     project_dir: &Path,
@@ -274,14 +470,7 @@ fn tag_issue_project(issue: &mut IssueData, root: &Path, project_dir: &Path) {
 
 fn load_issues_from_directory(issues_dir: &Path) -> Result<Vec<IssueData>, KanbusError> {
     let mut issues = Vec::new();
-    for entry in
-        std::fs::read_dir(issues_dir).map_err(|error| KanbusError::Io(error.to_string()))?
-    {
-        let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
-        let path = entry.path();
-        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
-            continue;
-        }
+    for path in list_issue_files(issues_dir)? {
         issues.push(read_issue_from_file(&path)?);
     }
     issues.sort_by(|left, right| left.identifier.cmp(&right.identifier));
@@ -310,77 +499,79 @@ fn has_dependency(issue: &IssueData, target_id: &str, dependency_type: &str) ->
     })
 }
 
-fn ensure_no_cycle(root: &Path, source_id: &str, target_id: &str) -> Result<(), KanbusError> {
-    let mut graph = build_dependency_graph(root)?;
-    graph
-        .edges
-        .entry(source_id.to_string())
-        .or_default()
-        .push(target_id.to_string());
-    if detect_cycle(&graph, source_id) {
-        return Err(KanbusError::IssueOperation("cycle detected".to_string()));
-    }
-    Ok(())
+/// Reject a `blocked-by` edge that would close a cycle.
+///
+/// Walks the existing `blocked-by` graph from `target_id`; if it reaches
+/// `source_id`, the error names the whole chain, starting and ending with
+/// `source_id`.
+///
+/// # Arguments
+/// * `edges` - Existing `blocked-by` edges keyed by blocked issue.
+/// * `source_id` - Issue that would become blocked.
+/// * `target_id` - Issue it would be blocked by.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the edge would form a cycle.
+pub(crate) fn ensure_no_blocking_cycle(
+    edges: &HashMap<String, Vec<String>>,
+    source_id: &str,
+    target_id: &str,
+) -> Result<(), KanbusError> {
+    let Some(path) = find_blocking_path(edges, target_id, source_id) else {
+        return Ok(());
+    };
+    let chain = std::iter::once(source_id)
+        .chain(path.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join(" -> ");
+    Err(KanbusError::IssueOperation(format!(
+        "circular dependency detected: {chain}"
+    )))
 }
 
-struct DependencyGraph {
-    edges: HashMap<String, Vec<String>>,
+/// Find the shortest chain of `blocked-by` edges from `start` to `goal`.
+fn find_blocking_path(
+    edges: &HashMap<String, Vec<String>>,
+    start: &str,
+    goal: &str,
+) -> Option<Vec<String>> {
+    let mut previous: HashMap<&str, &str> = HashMap::new();
+    let mut visited: HashSet<&str> = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if node == goal {
+            let mut path = vec![node.to_string()];
+            let mut current = node;
+            while let Some(&parent) = previous.get(current) {
+                path.push(parent.to_string());
+                current = parent;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for neighbor in edges.get(node).into_iter().flatten() {
+            if visited.insert(neighbor.as_str()) {
+                previous.insert(neighbor.as_str(), node);
+                queue.push_back(neighbor.as_str());
+            }
+        }
+    }
+    None
 }
 
-fn build_dependency_graph(root: &Path) -> Result<DependencyGraph, KanbusError> {
-    let project_dir = load_project_directory(root)?;
-    let issues_dir = project_dir.join("issues");
+/// Collect `blocked-by` edges across projects, keyed like the index.
+fn blocking_edges(index: &ProjectIssueIndex) -> HashMap<String, Vec<String>> {
     let mut edges: HashMap<String, Vec<String>> = HashMap::new();
-    for entry in
-        std::fs::read_dir(&issues_dir).map_err(|error| KanbusError::Io(error.to_string()))?
-    {
-        let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
-        let path = entry.path();
-        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
-            continue;
-        }
-        let issue = read_issue_from_file(&path)?;
+    for (key, (label, issue)) in &index.issues {
         let blocked_targets: Vec<String> = issue
             .dependencies
             .iter()
             .filter(|dependency| dependency.dependency_type == "blocked-by")
-            .map(|dependency| dependency.target.clone())
+            .map(|dependency| index.key(label, &dependency.target))
             .collect();
         if !blocked_targets.is_empty() {
-            edges.insert(issue.identifier.clone(), blocked_targets);
-        }
-    }
-    Ok(DependencyGraph { edges })
-}
-
-fn detect_cycle(graph: &DependencyGraph, start: &str) -> bool {
-    let mut visited: HashSet<String> = HashSet::new();
-    let mut stack: HashSet<String> = HashSet::new();
-
-    fn visit(
-        node: &str,
-        graph: &DependencyGraph,
-        visited: &mut HashSet<String>,
-        stack: &mut HashSet<String>,
-    ) -> bool {
-        if stack.contains(node) {
-            return true;
+            edges.insert(key.clone(), blocked_targets);
         }
-        if visited.contains(node) {
-            return false;
-        }
-        visited.insert(node.to_string());
-        stack.insert(node.to_string());
-        if let Some(neighbors) = graph.edges.get(node) {
-            for neighbor in neighbors {
-                if visit(neighbor, graph, visited, stack) {
-                    return true;
-                }
-            }
-        }
-        stack.remove(node);
-        false
     }
-
-    visit(start, graph, &mut visited, &mut stack)
+    edges
 }
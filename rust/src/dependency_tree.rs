@@ -1,14 +1,15 @@
 //! Dependency tree rendering utilities.
 
-use std::collections::{BTreeMap, HashSet};
-use std::fs;
+use std::collections::HashSet;
 use std::path::Path;
 
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::Serialize;
+use serde_json::json;
 
+use crate::dependencies::ProjectIssueIndex;
 use crate::error::KanbusError;
-use crate::file_io::load_project_directory;
-use crate::issue_files::read_issue_from_file;
+use crate::issue_lookup::load_issue_from_project;
 use crate::models::{DependencyLink, IssueData};
 
 const MAX_TREE_NODES: usize = 25;
@@ -19,13 +20,37 @@ pub struct DependencyTreeNode {
     #[serde(rename = "id")]
     pub identifier: String,
     pub title: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Type of the edge leading to this node; `null` for the tree root.
     pub dependency_type: Option<String>,
     pub dependencies: Vec<DependencyTreeNode>,
+    #[serde(skip)]
+    pub status: String,
+    /// Number of closed issues folded into this placeholder node.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collapsed_closed: Option<usize>,
+}
+
+/// Dependency tree with traversal metadata.
+#[derive(Debug, Clone)]
+pub struct DependencyTree {
+    pub root: DependencyTreeNode,
+    pub max_depth_reached: usize,
+    pub truncated: bool,
+    pub generated_at: DateTime<Utc>,
+}
+
+#[derive(Default)]
+struct TraversalState {
+    visited: HashSet<String>,
+    max_depth_reached: usize,
+    truncated: bool,
 }
 
 /// Build a dependency tree for the given issue.
 ///
+/// Targets in other configured projects are followed into those projects and
+/// shown as `<label>:<identifier>`.
+///
 /// # Arguments
 /// * `root` - Repository root path.
 /// * `identifier` - Issue identifier to start from.
@@ -37,117 +62,176 @@ pub fn build_dependency_tree(
     root: &Path,
     identifier: &str,
     max_depth: Option<usize>,
-) -> Result<DependencyTreeNode, KanbusError> {
-    let project_dir = load_project_directory(root)?;
-    let issues_dir = project_dir.join("issues");
-    let issues = load_issues(&issues_dir)?;
-    let issue = issues
-        .get(identifier)
+) -> Result<DependencyTree, KanbusError> {
+    let index = ProjectIssueIndex::load(root)?;
+    let lookup = load_issue_from_project(root, identifier)?;
+    let key = index.key(
+        index.label_for(&lookup.project_dir),
+        &lookup.issue.identifier,
+    );
+    let (label, issue) = index
+        .get(&key)
         .ok_or_else(|| KanbusError::IssueOperation("not found".to_string()))?;
 
-    build_node(issue, &issues, max_depth, 0, &mut HashSet::new(), None)
+    let mut state = TraversalState::default();
+    let root_node = build_node(&key, label, issue, &index, max_depth, 0, &mut state, None)?;
+    Ok(DependencyTree {
+        root: root_node,
+        max_depth_reached: state.max_depth_reached,
+        truncated: state.truncated,
+        generated_at: Utc::now(),
+    })
 }
 
 /// Render a dependency tree in the requested format.
 ///
 /// # Arguments
-/// * `node` - Dependency tree root.
-/// * `output_format` - Output format (text, json, dot).
+/// * `tree` - Dependency tree with metadata.
+/// * `output_format` - Output format (text, json, dot, porcelain).
 /// * `max_nodes` - Maximum nodes to render for text output.
 ///
 /// # Errors
 /// Returns `KanbusError::IssueOperation` if format is unsupported.
 pub fn render_dependency_tree(
-    node: &DependencyTreeNode,
+    tree: &DependencyTree,
     output_format: &str,
     max_nodes: Option<usize>,
 ) -> Result<String, KanbusError> {
     match output_format {
-        "json" => {
-            serde_json::to_string_pretty(node).map_err(|error| KanbusError::Io(error.to_string()))
-        }
-        "dot" => Ok(render_dot(node)),
-        "text" => Ok(render_ascii(node, max_nodes.unwrap_or(MAX_TREE_NODES))),
+        "json" => render_json(tree),
+        "dot" => Ok(render_dot(&tree.root)),
+        "porcelain" => Ok(render_porcelain(&tree.root)),
+        "text" => Ok(render_ascii(
+            &tree.root,
+            max_nodes.unwrap_or(MAX_TREE_NODES),
+        )),
         _ => Err(KanbusError::IssueOperation("invalid format".to_string())),
     }
 }
 
-fn load_issues(issues_dir: &Path) -> Result<BTreeMap<String, IssueData>, KanbusError> {
-    let mut issues: BTreeMap<String, IssueData> = BTreeMap::new();
-    for entry in fs::read_dir(issues_dir).map_err(|error| KanbusError::Io(error.to_string()))? {
-        let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
-        let path = entry.path();
-        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
-            continue;
+/// Replace every fully closed dependency subtree with a summary node.
+///
+/// A subtree is fully closed when its top issue and all issues beneath it are
+/// closed. The placeholder keeps the top issue's identifier and dependency
+/// type and is titled with the number of closed issues it stands for. The
+/// tree root itself is never collapsed.
+///
+/// # Arguments
+/// * `tree` - Dependency tree to collapse in place.
+pub fn collapse_closed_subtrees(tree: &mut DependencyTree) {
+    collapse_children(&mut tree.root);
+}
+
+fn collapse_children(node: &mut DependencyTreeNode) {
+    for child in &mut node.dependencies {
+        match closed_subtree_size(child) {
+            Some(count) => {
+                child.title = format!("{count} closed");
+                child.dependencies.clear();
+                child.collapsed_closed = Some(count);
+            }
+            None => collapse_children(child),
         }
-        let issue = read_issue_from_file(&path)?;
-        issues.insert(issue.identifier.clone(), issue);
     }
-    Ok(issues)
 }
 
+fn closed_subtree_size(node: &DependencyTreeNode) -> Option<usize> {
+    if node.status != "closed" {
+        return None;
+    }
+    node.dependencies
+        .iter()
+        .try_fold(1, |total, child| Some(total + closed_subtree_size(child)?))
+}
+
+#[allow(clippy::too_many_arguments)]
 fn build_node(
+    key: &str,
+    label: &str,
     issue: &IssueData,
-    issues: &BTreeMap<String, IssueData>,
+    index: &ProjectIssueIndex,
     max_depth: Option<usize>,
     depth: usize,
-    visited: &mut HashSet<String>,
+    state: &mut TraversalState,
     dependency_type: Option<String>,
 ) -> Result<DependencyTreeNode, KanbusError> {
-    if visited.contains(&issue.identifier) {
+    state.max_depth_reached = state.max_depth_reached.max(depth);
+    if state.visited.contains(key) {
         return Ok(DependencyTreeNode {
-            identifier: issue.identifier.clone(),
+            identifier: key.to_string(),
             title: issue.title.clone(),
             dependency_type,
             dependencies: Vec::new(),
+            status: issue.status.clone(),
+            collapsed_closed: None,
         });
     }
-    visited.insert(issue.identifier.clone());
+    state.visited.insert(key.to_string());
 
     let mut dependencies = Vec::new();
     if max_depth.is_none_or(|limit| depth < limit) {
         for dependency in &issue.dependencies {
             dependencies.push(build_dependency(
                 dependency,
-                issues,
+                label,
+                index,
                 max_depth,
                 depth + 1,
-                visited,
+                state,
             )?);
         }
+    } else if !issue.dependencies.is_empty() {
+        state.truncated = true;
     }
 
     Ok(DependencyTreeNode {
-        identifier: issue.identifier.clone(),
+        identifier: key.to_string(),
         title: issue.title.clone(),
         dependency_type,
         dependencies,
+        status: issue.status.clone(),
+        collapsed_closed: None,
     })
 }
 
 fn build_dependency(
     dependency: &DependencyLink,
-    issues: &BTreeMap<String, IssueData>,
+    owner_label: &str,
+    index: &ProjectIssueIndex,
     max_depth: Option<usize>,
     depth: usize,
-    visited: &mut HashSet<String>,
+    state: &mut TraversalState,
 ) -> Result<DependencyTreeNode, KanbusError> {
-    let issue = issues.get(&dependency.target).ok_or_else(|| {
+    let key = index.key(owner_label, &dependency.target);
+    let (label, issue) = index.get(&key).ok_or_else(|| {
         KanbusError::IssueOperation(format!(
             "dependency target '{}' does not exist",
             dependency.target
         ))
     })?;
     build_node(
+        &key,
+        label,
         issue,
-        issues,
+        index,
         max_depth,
         depth,
-        visited,
+        state,
         Some(dependency.dependency_type.clone()),
     )
 }
 
+fn render_json(tree: &DependencyTree) -> Result<String, KanbusError> {
+    let payload = json!({
+        "root_id": tree.root.identifier,
+        "generated_at": tree.generated_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+        "max_depth_reached": tree.max_depth_reached,
+        "truncated": tree.truncated,
+        "tree": tree.root,
+    });
+    serde_json::to_string_pretty(&payload).map_err(|error| KanbusError::Io(error.to_string()))
+}
+
 fn render_ascii(node: &DependencyTreeNode, max_nodes: usize) -> String {
     let mut lines: Vec<String> = Vec::new();
     let mut count = 0;
@@ -167,14 +251,19 @@ fn render_ascii(node: &DependencyTreeNode, max_nodes: usize) -> String {
             return;
         }
 
+        let mut label = if current.collapsed_closed.is_some() {
+            current.title.clone()
+        } else {
+            format!("{} {}", current.identifier, current.title)
+        };
+        if let Some(dependency_type) = current.dependency_type.as_deref() {
+            label = format!("[{}] {label}", relationship_tag(dependency_type));
+        }
         if prefix.is_empty() {
-            lines.push(format!("{} {}", current.identifier, current.title));
+            lines.push(label);
         } else {
             let connector = if is_last { "`-- " } else { "|-- " };
-            lines.push(format!(
-                "{}{}{} {}",
-                prefix, connector, current.identifier, current.title
-            ));
+            lines.push(format!("{prefix}{connector}{label}"));
         }
         *count += 1;
 
@@ -212,13 +301,57 @@ fn render_ascii(node: &DependencyTreeNode, max_nodes: usize) -> String {
     lines.join("\n")
 }
 
+/// Short relationship tag shown before each child in text output.
+fn relationship_tag(dependency_type: &str) -> &str {
+    match dependency_type {
+        "blocked-by" => "blocks",
+        "relates-to" => "relates",
+        other => other,
+    }
+}
+
+fn render_porcelain(node: &DependencyTreeNode) -> String {
+    let mut edges: Vec<(String, String, String)> = Vec::new();
+
+    fn visit(current: &DependencyTreeNode, edges: &mut Vec<(String, String, String)>) {
+        for child in &current.dependencies {
+            edges.push((
+                current.identifier.clone(),
+                child.dependency_type.clone().unwrap_or_default(),
+                child.identifier.clone(),
+            ));
+            visit(child, edges);
+        }
+    }
+
+    visit(node, &mut edges);
+    edges.sort();
+    edges.dedup();
+    edges
+        .iter()
+        .map(|(source, dependency_type, target)| format!("{source}\t{dependency_type}\t{target}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn render_dot(node: &DependencyTreeNode) -> String {
     let mut edges: Vec<String> = Vec::new();
 
     fn visit(current: &DependencyTreeNode, edges: &mut Vec<String>) {
         for child in &current.dependencies {
+            if child.collapsed_closed.is_some() {
+                edges.push(format!(
+                    "  \"{}\" [label=\"{}\"];",
+                    child.identifier, child.title
+                ));
+            }
+            let style = if child.dependency_type.as_deref() == Some("relates-to") {
+                " [style=dashed]"
+            } else {
+                ""
+            };
             edges.push(format!(
-                "  \"{}\" -> \"{}\";",
+                "  \"{}\" -> \"{}\"{style};",
                 current.identifier, child.identifier
             ));
             visit(child, edges);
@@ -228,6 +361,9 @@ fn render_dot(node: &DependencyTreeNode) -> String {
     visit(node, &mut edges);
     let mut lines = Vec::new();
     lines.push("digraph dependencies {".to_string());
+    lines.push(
+        "  \"legend\" [shape=note, label=\"solid: blocked-by\\ndashed: relates-to\"];".to_string(),
+    );
     lines.extend(edges);
     lines.push("}".to_string());
     lines.join("\n")
@@ -2,27 +2,66 @@
 
 use std::path::{Path, PathBuf};
 
-use crate::config_loader::load_project_configuration;
+use crate::config_loader::load_project_configuration_with_warnings;
+use crate::daemon_client::probe_daemon_protocol_version;
+use crate::daemon_protocol::{validate_protocol_compatibility, PROTOCOL_VERSION};
 use crate::error::KanbusError;
-use crate::file_io::{ensure_git_repository, get_configuration_path, load_project_directory};
+use crate::file_io::{
+    ensure_git_repository, find_unmatched_ignore_patterns, get_configuration_path,
+    load_project_directory, IGNORE_FILE_NAME,
+};
+use crate::maintenance::{
+    describe_orphan, describe_orphaned_event_file, find_orphan_issues, find_orphaned_event_files,
+};
 
 /// Result of running doctor checks.
 #[derive(Debug, Clone)]
 pub struct DoctorResult {
     pub project_dir: PathBuf,
+    pub warnings: Vec<String>,
+    pub fixes: Vec<String>,
 }
 
 /// Run diagnostic checks for Kanbus.
 ///
 /// # Arguments
 /// * `root` - Repository root path.
+/// * `fix` - Remove orphaned event files instead of reporting them.
 ///
 /// # Errors
 /// Returns `KanbusError` if any check fails.
-pub fn run_doctor(root: &Path) -> Result<DoctorResult, KanbusError> {
+pub fn run_doctor(root: &Path, fix: bool) -> Result<DoctorResult, KanbusError> {
     ensure_git_repository(root)?;
     let project_dir = load_project_directory(root)?;
     let configuration_path = get_configuration_path(project_dir.as_path())?;
-    load_project_configuration(&configuration_path)?;
-    Ok(DoctorResult { project_dir })
+    let (_, mut warnings) = load_project_configuration_with_warnings(&configuration_path)?;
+    warnings.extend(find_orphan_issues(root)?.iter().map(describe_orphan));
+    let mut fixes = Vec::new();
+    for orphan in find_orphaned_event_files(root)? {
+        let description = describe_orphaned_event_file(root, &orphan);
+        if fix {
+            std::fs::remove_file(&orphan.path)
+                .map_err(|error| KanbusError::Io(error.to_string()))?;
+            fixes.push(format!("removed {description}"));
+        } else {
+            warnings.push(description);
+        }
+    }
+    for pattern in find_unmatched_ignore_patterns(&project_dir)? {
+        warnings.push(format!(
+            "{IGNORE_FILE_NAME} pattern '{pattern}' matches no files"
+        ));
+    }
+    if let Some(daemon_version) = probe_daemon_protocol_version(root)? {
+        if let Err(error) = validate_protocol_compatibility(PROTOCOL_VERSION, &daemon_version) {
+            warnings.push(format!(
+                "running daemon speaks protocol {daemon_version} but this client speaks {PROTOCOL_VERSION} ({error}); run `kanbus daemon-stop` so the next command starts a current daemon"
+            ));
+        }
+    }
+    Ok(DoctorResult {
+        project_dir,
+        warnings,
+        fixes,
+    })
 }
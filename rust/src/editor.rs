@@ -0,0 +1,101 @@
+//! Composing issue text in the user's editor.
+
+use std::fs;
+use std::io::{IsTerminal, Write};
+use std::process::Command;
+
+use crate::error::KanbusError;
+
+/// Environment variable that overrides the editor command.
+pub const EDITOR_ENV: &str = "KANBUS_EDITOR";
+
+const DEFAULT_EDITOR: &str = "vi";
+
+/// Resolve the editor command.
+///
+/// `KANBUS_EDITOR` takes precedence over `EDITOR`, falling back to `vi`.
+pub fn editor_command() -> String {
+    std::env::var(EDITOR_ENV)
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .map(|command| command.trim().to_string())
+        .filter(|command| !command.is_empty())
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string())
+}
+
+/// Compose a new issue's title and description in the editor.
+///
+/// The file starts with the title as a Markdown heading followed by an empty
+/// body. An edited heading replaces the title, and everything after it
+/// becomes the description.
+///
+/// # Arguments
+/// * `title` - Title given on the command line, possibly empty.
+///
+/// # Returns
+/// The title and description.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if no terminal is attached, the
+/// editor fails, or the file is left unchanged or without a description.
+pub fn compose_issue_text(title: &str) -> Result<(String, String), KanbusError> {
+    let template = format!("# {title}\n\n");
+    let edited = edit_text(&template)?;
+    if edited.trim() == template.trim() {
+        return Err(KanbusError::IssueOperation(
+            "aborting create: issue text unchanged".to_string(),
+        ));
+    }
+    let (edited_title, description) = parse_issue_text(&edited);
+    if description.is_empty() {
+        return Err(KanbusError::IssueOperation(
+            "aborting create: empty description".to_string(),
+        ));
+    }
+    Ok((
+        edited_title.unwrap_or_else(|| title.to_string()),
+        description,
+    ))
+}
+
+fn parse_issue_text(text: &str) -> (Option<String>, String) {
+    let text = text.trim_start();
+    let Some(heading) = text.lines().next().and_then(|line| line.strip_prefix("# ")) else {
+        return (None, text.trim_end().to_string());
+    };
+    let body = text.split_once('\n').map(|(_, body)| body).unwrap_or("");
+    let title = Some(heading.trim().to_string()).filter(|title| !title.is_empty());
+    (title, body.trim().to_string())
+}
+
+fn edit_text(initial: &str) -> Result<String, KanbusError> {
+    let interactive = std::io::stdin().is_terminal() && std::io::stdout().is_terminal();
+    if !interactive && std::env::var_os("KANBUS_TEST_INTERACTIVE").is_none() {
+        return Err(KanbusError::IssueOperation(
+            "--edit requires an interactive terminal".to_string(),
+        ));
+    }
+    let mut file = tempfile::Builder::new()
+        .prefix("kanbus-issue-")
+        .suffix(".md")
+        .tempfile()
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    file.write_all(initial.as_bytes())
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+
+    let command = editor_command();
+    // Like git, run through the shell so the editor may carry arguments.
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{command} \"$@\""))
+        .arg(&command)
+        .arg(file.path())
+        .status()
+        .map_err(|error| KanbusError::IssueOperation(format!("failed to start editor: {error}")))?;
+    if !status.success() {
+        return Err(KanbusError::IssueOperation(format!(
+            "aborting create: editor exited with {status}"
+        )));
+    }
+    fs::read_to_string(file.path()).map_err(|error| KanbusError::Io(error.to_string()))
+}
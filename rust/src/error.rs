@@ -38,4 +38,37 @@ impl Display for KanbusError {
     }
 }
 
+impl KanbusError {
+    /// Stable machine-readable code for the error.
+    ///
+    /// The CLI reports it when `KANBUS_JSON_ERRORS=1` and the daemon sends it
+    /// in error envelopes, so both surfaces agree.
+    pub fn code(&self) -> &'static str {
+        match self {
+            KanbusError::Initialization(_) => "initialization_failed",
+            KanbusError::Io(_) => "io_error",
+            KanbusError::IdGenerationFailed(_) => "id_generation_failed",
+            KanbusError::Configuration(_) => "configuration_error",
+            KanbusError::InvalidTransition(_) => "invalid_transition",
+            KanbusError::InvalidHierarchy(_) => "invalid_hierarchy",
+            KanbusError::IssueOperation(message) => {
+                if message.starts_with("ambiguous") || message.contains(" ambiguous") {
+                    "ambiguous_id"
+                } else if message == "not found" || message.ends_with(" not found") {
+                    "not_found"
+                } else {
+                    "issue_operation_failed"
+                }
+            }
+            KanbusError::ProtocolError(message) => {
+                if message == "protocol version unsupported" {
+                    "protocol_version_unsupported"
+                } else {
+                    "protocol_version_mismatch"
+                }
+            }
+        }
+    }
+}
+
 impl std::error::Error for KanbusError {}
@@ -10,6 +10,7 @@ use uuid::Uuid;
 
 use crate::error::KanbusError;
 use crate::file_io::find_project_local_directory;
+use crate::issue_files::find_issue_file;
 use crate::models::IssueData;
 
 pub const EVENT_SCHEMA_VERSION: u32 = 1;
@@ -28,6 +29,8 @@ pub enum EventType {
     IssueDeleted,
     IssueLocalized,
     IssuePromoted,
+    IssueArchived,
+    IssueUnarchived,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -94,8 +97,7 @@ pub fn events_dir_for_issue_path(
 
 pub fn events_dir_for_issue(project_dir: &Path, issue_id: &str) -> PathBuf {
     if let Some(local_dir) = find_project_local_directory(project_dir) {
-        let local_issue = local_dir.join("issues").join(format!("{issue_id}.json"));
-        if local_issue.exists() {
+        if let Ok(Some(_)) = find_issue_file(&local_dir.join("issues"), issue_id) {
             return local_dir.join("events");
         }
     }
@@ -192,6 +194,15 @@ pub fn comment_updated_payload(comment_id: &str, comment_author: &str) -> Value
     })
 }
 
+pub fn comment_resolution_payload(comment_id: &str, comment_author: &str, resolved: bool) -> Value {
+    json!({
+        "comment_id": comment_id,
+        "comment_author": comment_author,
+        "changed_fields": ["resolved"],
+        "resolved": resolved,
+    })
+}
+
 pub fn dependency_payload(dependency_type: &str, target_id: &str) -> Value {
     json!({
         "dependency_type": dependency_type,
@@ -244,6 +255,30 @@ pub fn field_update_payload(before: &IssueData, after: &IssueData) -> Option<Val
         json!(before.parent),
         json!(after.parent),
     );
+    push_change(
+        &mut changes,
+        "links",
+        json!(before.links),
+        json!(after.links),
+    );
+    push_change(
+        &mut changes,
+        "due_at",
+        json!(before.due_at),
+        json!(after.due_at),
+    );
+    push_change(
+        &mut changes,
+        "estimate_minutes",
+        json!(before.estimate_minutes),
+        json!(after.estimate_minutes),
+    );
+    push_change(
+        &mut changes,
+        "spent_minutes",
+        json!(before.spent_minutes),
+        json!(after.spent_minutes),
+    );
     if changes.is_empty() {
         None
     } else {
@@ -251,6 +286,18 @@ pub fn field_update_payload(before: &IssueData, after: &IssueData) -> Option<Val
     }
 }
 
+/// Field update payload for an issue whose only change is its `updated_at`.
+pub fn touch_payload(before: &IssueData, after: &IssueData) -> Value {
+    let mut changes = Map::new();
+    push_change(
+        &mut changes,
+        "updated_at",
+        json!(before.updated_at),
+        json!(after.updated_at),
+    );
+    json!({ "changes": changes })
+}
+
 fn push_change(changes: &mut Map<String, Value>, field: &str, from: Value, to: Value) {
     if from == to {
         return;
@@ -335,3 +382,93 @@ pub fn load_issue_events(
     };
     Ok((results, cursor))
 }
+
+/// Load every event recorded for an issue, oldest first.
+pub fn load_issue_history(
+    project_dir: &Path,
+    issue_id: &str,
+) -> Result<Vec<EventRecord>, KanbusError> {
+    let (mut events, _) = load_issue_events(project_dir, issue_id, None, usize::MAX)?;
+    events.reverse();
+    Ok(events)
+}
+
+/// Format an event as a timeline line: timestamp, actor, and what changed.
+pub fn format_history_line(event: &EventRecord) -> String {
+    format!(
+        "{}  {}  {}",
+        event.occurred_at,
+        event.actor_id,
+        describe_event(event)
+    )
+}
+
+fn describe_event(event: &EventRecord) -> String {
+    let field = |key: &str| {
+        event
+            .payload
+            .get(key)
+            .map(display_value)
+            .unwrap_or_else(|| "-".to_string())
+    };
+    match event.event_type {
+        EventType::IssueCreated => format!("created \"{}\"", field("title")),
+        EventType::StateTransition => {
+            format!("status {} -> {}", field("from_status"), field("to_status"))
+        }
+        EventType::FieldUpdated => {
+            let changes = event
+                .payload
+                .get("changes")
+                .and_then(Value::as_object)
+                .map(|changes| {
+                    changes
+                        .iter()
+                        .map(|(name, change)| describe_change(name, change))
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                })
+                .unwrap_or_default();
+            format!("updated {changes}")
+        }
+        EventType::CommentAdded => format!("commented {}", field("comment_id")),
+        EventType::CommentUpdated => format!("edited comment {}", field("comment_id")),
+        EventType::CommentDeleted => format!("deleted comment {}", field("comment_id")),
+        EventType::DependencyAdded => {
+            format!("added {} {}", field("dependency_type"), field("target_id"))
+        }
+        EventType::DependencyRemoved => {
+            format!(
+                "removed {} {}",
+                field("dependency_type"),
+                field("target_id")
+            )
+        }
+        EventType::IssueDeleted => "deleted".to_string(),
+        EventType::IssueLocalized => "moved to project-local".to_string(),
+        EventType::IssuePromoted => "promoted to project".to_string(),
+        EventType::IssueArchived => "archived".to_string(),
+        EventType::IssueUnarchived => "unarchived".to_string(),
+    }
+}
+
+fn describe_change(name: &str, change: &Value) -> String {
+    if name == "description" {
+        return "description".to_string();
+    }
+    let side = |key: &str| {
+        change
+            .get(key)
+            .map(display_value)
+            .unwrap_or_else(|| "-".to_string())
+    };
+    format!("{name}: {} -> {}", side("from"), side("to"))
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
@@ -1,9 +1,10 @@
 //! File system helpers for initialization.
 
+use std::fs::{File, OpenOptions};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
-use crate::config::default_project_configuration;
+use crate::config::{template_project_configuration, write_default_configuration};
 use crate::config_loader::load_project_configuration;
 use crate::error::KanbusError;
 use crate::models::ProjectConfiguration;
@@ -11,7 +12,6 @@ use crate::project_management_template::{
     DEFAULT_PROJECT_MANAGEMENT_TEMPLATE, DEFAULT_PROJECT_MANAGEMENT_TEMPLATE_FILENAME,
 };
 use serde_json;
-use serde_yaml;
 
 /// A resolved project directory with its label.
 #[derive(Debug, Clone)]
@@ -69,17 +69,32 @@ pub fn ensure_git_repository(root: &Path) -> Result<(), KanbusError> {
 ///
 /// * `root` - Repository root.
 /// * `create_local` - Whether to create project-local.
+/// * `template` - Optional configuration template for a new `.kanbus.yml`.
 ///
 /// # Errors
 ///
-/// Returns `KanbusError::Initialization` if already initialized.
-pub fn initialize_project(root: &Path, create_local: bool) -> Result<(), KanbusError> {
+/// Returns `KanbusError::Initialization` if already initialized, if the
+/// template is unknown, or if a template is given but `.kanbus.yml` exists.
+pub fn initialize_project(
+    root: &Path,
+    create_local: bool,
+    template: Option<&str>,
+) -> Result<(), KanbusError> {
     let project_dir = root.join("project");
     if project_dir.exists() {
         return Err(KanbusError::Initialization(
             "already initialized".to_string(),
         ));
     }
+    let config_path = root.join(".kanbus.yml");
+    if let Some(name) = template {
+        template_project_configuration(name)?;
+        if config_path.exists() {
+            return Err(KanbusError::Initialization(
+                "cannot apply template: .kanbus.yml already exists".to_string(),
+            ));
+        }
+    }
 
     let issues_dir = project_dir.join("issues");
     let events_dir = project_dir.join("events");
@@ -87,13 +102,8 @@ pub fn initialize_project(root: &Path, create_local: bool) -> Result<(), KanbusE
     std::fs::create_dir(&project_dir).map_err(|error| KanbusError::Io(error.to_string()))?;
     std::fs::create_dir(&issues_dir).map_err(|error| KanbusError::Io(error.to_string()))?;
     std::fs::create_dir(&events_dir).map_err(|error| KanbusError::Io(error.to_string()))?;
-    let config_path = root.join(".kanbus.yml");
     if !config_path.exists() {
-        let default_configuration = default_project_configuration();
-        let contents = serde_yaml::to_string(&default_configuration)
-            .map_err(|error| KanbusError::Io(error.to_string()))?;
-        std::fs::write(&config_path, contents)
-            .map_err(|error| KanbusError::Io(error.to_string()))?;
+        write_default_configuration(&config_path, template)?;
     }
     let template_path = root.join(DEFAULT_PROJECT_MANAGEMENT_TEMPLATE_FILENAME);
     if !template_path.exists() {
@@ -285,6 +295,134 @@ pub fn find_project_local_directory(project_dir: &Path) -> Option<PathBuf> {
     }
 }
 
+/// Name of the file listing glob patterns for issue files to skip.
+pub const IGNORE_FILE_NAME: &str = ".kanbusignore";
+
+/// Load glob patterns from a project's `.kanbusignore`.
+///
+/// Blank lines and lines starting with `#` are skipped. A missing file yields
+/// no patterns.
+///
+/// # Arguments
+///
+/// * `project_dir` - Project directory containing the ignore file.
+///
+/// # Errors
+///
+/// Returns `KanbusError::Io` if the ignore file exists but cannot be read.
+pub fn load_ignore_patterns(project_dir: &Path) -> Result<Vec<String>, KanbusError> {
+    let ignore_path = project_dir.join(IGNORE_FILE_NAME);
+    if !ignore_path.is_file() {
+        return Ok(Vec::new());
+    }
+    let contents = std::fs::read_to_string(&ignore_path)
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect())
+}
+
+/// Check whether a path is excluded by any ignore pattern.
+///
+/// Paths are matched relative to `project_dir`. `*` matches any run of
+/// characters within one path segment, and a pattern ending in `/` matches
+/// everything under that directory.
+///
+/// # Arguments
+///
+/// * `project_dir` - Project directory the patterns are relative to.
+/// * `patterns` - Patterns loaded from the ignore file.
+/// * `path` - Candidate file path.
+pub fn is_ignored_path(project_dir: &Path, patterns: &[String], path: &Path) -> bool {
+    let Some(relative) = relative_ignore_path(project_dir, path) else {
+        return false;
+    };
+    patterns
+        .iter()
+        .any(|pattern| ignore_pattern_matches(pattern, &relative))
+}
+
+/// List ignore patterns that match no file under the project directory.
+///
+/// # Arguments
+///
+/// * `project_dir` - Project directory containing the ignore file.
+///
+/// # Errors
+///
+/// Returns `KanbusError::Io` if the ignore file or project directory cannot be read.
+pub fn find_unmatched_ignore_patterns(project_dir: &Path) -> Result<Vec<String>, KanbusError> {
+    let patterns = load_ignore_patterns(project_dir)?;
+    if patterns.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut relative_paths = Vec::new();
+    let mut pending = vec![project_dir.to_path_buf()];
+    while let Some(directory) = pending.pop() {
+        for entry in
+            std::fs::read_dir(&directory).map_err(|error| KanbusError::Io(error.to_string()))?
+        {
+            let path = entry
+                .map_err(|error| KanbusError::Io(error.to_string()))?
+                .path();
+            if path.is_dir() {
+                pending.push(path);
+            } else if let Some(relative) = relative_ignore_path(project_dir, &path) {
+                relative_paths.push(relative);
+            }
+        }
+    }
+    Ok(patterns
+        .into_iter()
+        .filter(|pattern| {
+            !relative_paths
+                .iter()
+                .any(|relative| ignore_pattern_matches(pattern, relative))
+        })
+        .collect())
+}
+
+fn relative_ignore_path(project_dir: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(project_dir).ok()?;
+    let segments = relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    Some(segments.join("/"))
+}
+
+fn ignore_pattern_matches(pattern: &str, relative: &str) -> bool {
+    let Some(directory) = pattern.strip_suffix('/') else {
+        return glob_matches(pattern.as_bytes(), relative.as_bytes());
+    };
+    relative
+        .match_indices('/')
+        .any(|(index, _)| glob_matches(directory.as_bytes(), &relative.as_bytes()[..index]))
+}
+
+/// Match `text` against a pattern where `*` matches within a single path segment.
+fn glob_matches(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => {
+            let mut index = 0;
+            loop {
+                if glob_matches(rest, &text[index..]) {
+                    return true;
+                }
+                if index == text.len() || text[index] == b'/' {
+                    return false;
+                }
+                index += 1;
+            }
+        }
+        Some((expected, rest)) => text.first() == Some(expected) && glob_matches(rest, &text[1..]),
+    }
+}
+
 /// Ensure the project-local directory exists and is gitignored.
 ///
 /// # Arguments
@@ -458,6 +596,26 @@ fn resolve_project_directories(
     Ok(projects)
 }
 
+/// Hold an exclusive lock on a file in the project `.cache` directory.
+///
+/// The lock is released when the returned file is dropped.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if the lock file cannot be created or locked.
+pub(crate) fn lock_project_cache_file(project_dir: &Path, name: &str) -> Result<File, KanbusError> {
+    let cache_dir = project_dir.join(".cache");
+    std::fs::create_dir_all(&cache_dir).map_err(|error| KanbusError::Io(error.to_string()))?;
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(cache_dir.join(name))
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    file.lock()
+        .map_err(|error| KanbusError::Io(format!("failed to lock {name}: {error}")))?;
+    Ok(file)
+}
+
 pub(crate) fn is_path_ignored(path: &Path, base: &Path, ignore_paths: &[String]) -> bool {
     for ignore_pattern in ignore_paths {
         let ignore_path = base.join(ignore_pattern);
@@ -1,7 +1,13 @@
 //! Hierarchy validation for parent-child relationships.
 
+use std::path::Path;
+
+use crate::config_loader::load_project_configuration;
 use crate::error::KanbusError;
-use crate::models::ProjectConfiguration;
+use crate::file_io::get_configuration_path;
+use crate::issue_lookup::load_issue_from_project;
+use crate::issue_update::{update_issue, IssueUpdateRequest};
+use crate::models::{IssueData, ProjectConfiguration};
 
 /// Return the allowed child types for a parent issue type.
 ///
@@ -54,3 +60,89 @@ pub fn validate_parent_child_relationship(
     }
     Ok(())
 }
+
+/// Move an issue under a new parent, or detach it when `parent` is `None`.
+///
+/// The new parent must exist, must not be the issue itself or one of its
+/// descendants, and must be allowed to contain the issue's type under the
+/// configured hierarchy. The change is applied through the regular update
+/// workflow, so events and notifications are recorded.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `identifier` - Issue identifier to move.
+/// * `parent` - New parent identifier, or `None` to clear the parent.
+///
+/// # Returns
+/// Updated issue data.
+///
+/// # Errors
+/// Returns `KanbusError` if the parent is missing, the move would form a
+/// cycle, or the hierarchy does not permit the relationship.
+pub fn move_issue(
+    root: &Path,
+    identifier: &str,
+    parent: Option<&str>,
+) -> Result<IssueData, KanbusError> {
+    let lookup = load_issue_from_project(root, identifier)?;
+    let issue = lookup.issue;
+    let Some(parent) = parent else {
+        return update_issue(&IssueUpdateRequest {
+            clear_parent: true,
+            ..IssueUpdateRequest::new(root, issue.identifier.clone())
+        });
+    };
+    let parent_issue = load_issue_from_project(root, parent)?.issue;
+    ensure_no_parent_cycle(root, &issue.identifier, &parent_issue)?;
+    let configuration =
+        load_project_configuration(&get_configuration_path(lookup.project_dir.as_path())?)?;
+    validate_parent_child_relationship(
+        &configuration,
+        &parent_issue.issue_type,
+        &issue.issue_type,
+    )?;
+    update_issue(&IssueUpdateRequest {
+        parent: Some(parent_issue.identifier.clone()),
+        ..IssueUpdateRequest::new(root, issue.identifier.clone())
+    })
+}
+
+/// Reject a parent that is the issue itself or one of its descendants.
+///
+/// Walks the ancestor chain of `parent_issue`; if it reaches `identifier`,
+/// the error names the whole chain from the new parent up to the issue.
+fn ensure_no_parent_cycle(
+    root: &Path,
+    identifier: &str,
+    parent_issue: &IssueData,
+) -> Result<(), KanbusError> {
+    if parent_issue.identifier == identifier {
+        return Err(KanbusError::InvalidHierarchy(format!(
+            "cannot move {identifier} under itself"
+        )));
+    }
+    let mut chain = vec![parent_issue.identifier.clone()];
+    let mut current = parent_issue.clone();
+    loop {
+        if current.identifier == identifier {
+            return Err(KanbusError::InvalidHierarchy(format!(
+                "cannot move {identifier} under {}: {identifier} is its ancestor ({})",
+                parent_issue.identifier,
+                chain.join(" -> ")
+            )));
+        }
+        let Some(next) = current.parent.clone() else {
+            return Ok(());
+        };
+        if chain.contains(&next) {
+            return Ok(());
+        }
+        let lookup = match load_issue_from_project(root, &next) {
+            Ok(lookup) => lookup,
+            Err(KanbusError::IssueOperation(message)) if message == "not found" => return Ok(()),
+            Err(error) => return Err(error),
+        };
+        chain.push(next);
+        current = lookup.issue;
+    }
+}
@@ -5,15 +5,16 @@ use std::sync::{Mutex, OnceLock};
 use uuid::Uuid;
 
 use crate::error::KanbusError;
+use crate::models::ProjectConfiguration;
 
 /// Request to generate a unique issue identifier.
 #[derive(Debug, Clone)]
 pub struct IssueIdentifierRequest {
     /// Issue title.
     pub title: String,
-    /// Existing identifiers to avoid collisions.
+    /// Existing identifiers to avoid collisions, whatever their prefix.
     pub existing_ids: HashSet<String>,
-    /// ID project key (prefix).
+    /// ID prefix, from `identifier_prefix`.
     pub prefix: String,
 }
 
@@ -46,6 +47,27 @@ fn next_uuid() -> Uuid {
     Uuid::new_v4()
 }
 
+/// Return the identifier prefix for a new issue of the given type.
+///
+/// Uses the type's entry in `type_prefixes`, falling back to `project_key`.
+pub fn identifier_prefix<'a>(configuration: &'a ProjectConfiguration, issue_type: &str) -> &'a str {
+    configuration
+        .type_prefixes
+        .get(issue_type)
+        .unwrap_or(&configuration.project_key)
+}
+
+/// Return every prefix the project assigns to identifiers, `project_key` first.
+pub fn identifier_prefixes(configuration: &ProjectConfiguration) -> Vec<String> {
+    let mut prefixes = vec![configuration.project_key.clone()];
+    for prefix in configuration.type_prefixes.values() {
+        if !prefixes.contains(prefix) {
+            prefixes.push(prefix.clone());
+        }
+    }
+    prefixes
+}
+
 /// Produce a display-friendly issue key.
 ///
 /// # Arguments
@@ -108,6 +130,9 @@ pub fn format_issue_key(identifier: &str, project_context: bool) -> String {
 ///
 /// A unique ID string with format '{prefix}-{uuid}'.
 ///
+/// A UUID already used by an existing identifier is rejected even under a
+/// different prefix, so issue types with their own prefixes never share one.
+///
 /// # Errors
 ///
 /// Returns `KanbusError::IdGenerationFailed` if unable to generate unique ID after 10 attempts.
@@ -115,9 +140,15 @@ pub fn generate_issue_identifier(
     request: &IssueIdentifierRequest,
 ) -> Result<IssueIdentifierResult, KanbusError> {
     for _ in 0..10 {
-        let identifier = format!("{}-{}", request.prefix, next_uuid());
-        if !request.existing_ids.contains(&identifier) {
-            return Ok(IssueIdentifierResult { identifier });
+        let uuid = next_uuid().to_string();
+        let taken = request
+            .existing_ids
+            .iter()
+            .any(|existing| existing.ends_with(&format!("-{uuid}")));
+        if !taken {
+            return Ok(IssueIdentifierResult {
+                identifier: format!("{}-{}", request.prefix, uuid),
+            });
         }
     }
 
@@ -6,12 +6,18 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::thread;
 
+use serde::{Deserialize, Serialize};
+
+use crate::cache::mtime_from_path;
 use crate::error::KanbusError;
+use crate::issue_files::{list_issue_files, parse_issue};
 use crate::models::IssueData;
 
+const ISSUE_FILE_CACHE_VERSION: u32 = 1;
+
 fn read_issue_data(path: &Path) -> Result<IssueData, KanbusError> {
     let contents = fs::read(path).map_err(|error| KanbusError::Io(error.to_string()))?;
-    serde_json::from_slice(&contents).map_err(|error| KanbusError::Io(error.to_string()))
+    parse_issue(path, &contents)
 }
 
 fn add_issue_to_index(index: &mut IssueIndex, issue: IssueData) {
@@ -87,17 +93,7 @@ impl IssueIndex {
 /// Returns `KanbusError::Io` if file reads or JSON parsing fails.
 pub fn build_index_from_directory(issues_directory: &Path) -> Result<IssueIndex, KanbusError> {
     let mut index = IssueIndex::new();
-    let entries =
-        fs::read_dir(issues_directory).map_err(|error| KanbusError::Io(error.to_string()))?;
-    let mut json_entries: Vec<PathBuf> = Vec::new();
-    for entry in entries {
-        let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
-        let path = entry.path();
-        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
-            continue;
-        }
-        json_entries.push(path);
-    }
+    let mut json_entries: Vec<PathBuf> = list_issue_files(issues_directory)?;
     json_entries.sort_by(|left, right| left.file_name().cmp(&right.file_name()));
 
     if json_entries.is_empty() {
@@ -154,3 +150,122 @@ pub fn build_index_from_directory(issues_directory: &Path) -> Result<IssueIndex,
 
     Ok(index)
 }
+
+/// Parsed issue file remembered by the issue file cache.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedIssueFile {
+    pub mtime: f64,
+    pub size: u64,
+    pub issue: IssueData,
+}
+
+/// On-disk snapshot of parsed issue files, keyed by path relative to the
+/// issues directory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IssueFileCache {
+    pub version: u32,
+    pub files: BTreeMap<String, CachedIssueFile>,
+}
+
+/// Return the issue file cache path for a project directory.
+///
+/// # Arguments
+/// * `project_dir` - Project directory containing `issues/`.
+pub fn issue_file_cache_path(project_dir: &Path) -> PathBuf {
+    project_dir.join(".cache").join("issue_files.json")
+}
+
+/// Load every issue in a directory, reusing cached parses of unchanged files.
+///
+/// A file is re-read only when its modification time or size differs from
+/// the cached entry. The cache is rewritten when any entry was added,
+/// refreshed, or removed. A missing or unreadable cache is rebuilt, and a
+/// failure to write it does not fail the load.
+///
+/// # Arguments
+/// * `issues_directory` - Directory containing issue files.
+/// * `cache_path` - Location of the issue file cache.
+///
+/// # Returns
+/// Issues sorted by identifier.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if an issue file cannot be read or parsed.
+pub fn load_issues_with_cache(
+    issues_directory: &Path,
+    cache_path: &Path,
+) -> Result<Vec<IssueData>, KanbusError> {
+    let mut previous = read_issue_file_cache(cache_path);
+    let mut files = BTreeMap::new();
+    let mut changed = false;
+    for path in list_issue_files(issues_directory)? {
+        let size = fs::metadata(&path)
+            .map_err(|error| KanbusError::Io(error.to_string()))?
+            .len();
+        let mtime = mtime_from_path(&path)?;
+        let key = path
+            .strip_prefix(issues_directory)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .to_string();
+        let entry = match previous.files.remove(&key) {
+            Some(entry) if entry.mtime == mtime && entry.size == size => entry,
+            _ => {
+                changed = true;
+                CachedIssueFile {
+                    mtime,
+                    size,
+                    issue: read_issue_data(&path)?,
+                }
+            }
+        };
+        files.insert(key, entry);
+    }
+    let removed = !previous.files.is_empty();
+
+    let mut issues: Vec<IssueData> = files.values().map(|entry| entry.issue.clone()).collect();
+    issues.sort_by(|left, right| left.identifier.cmp(&right.identifier));
+    if changed || removed {
+        let cache = IssueFileCache {
+            version: ISSUE_FILE_CACHE_VERSION,
+            files,
+        };
+        let _ = write_issue_file_cache(&cache, cache_path);
+    }
+    Ok(issues)
+}
+
+fn read_issue_file_cache(cache_path: &Path) -> IssueFileCache {
+    fs::read(cache_path)
+        .ok()
+        .and_then(|contents| serde_json::from_slice::<IssueFileCache>(&contents).ok())
+        .filter(|cache| cache.version == ISSUE_FILE_CACHE_VERSION)
+        .unwrap_or_default()
+}
+
+fn write_issue_file_cache(cache: &IssueFileCache, cache_path: &Path) -> Result<(), KanbusError> {
+    let parent = cache_path
+        .parent()
+        .ok_or_else(|| KanbusError::Io("invalid cache path".to_string()))?;
+    fs::create_dir_all(parent).map_err(|error| KanbusError::Io(error.to_string()))?;
+    let payload = serde_json::to_vec(cache).map_err(|error| KanbusError::Io(error.to_string()))?;
+    // Concurrent commands may refresh the cache at once; never expose a partial file.
+    let temp_path = parent.join(format!(".issue_files.{}.tmp", uuid::Uuid::new_v4()));
+    fs::write(&temp_path, payload).map_err(|error| KanbusError::Io(error.to_string()))?;
+    fs::rename(&temp_path, cache_path).map_err(|error| {
+        let _ = fs::remove_file(&temp_path);
+        KanbusError::Io(error.to_string())
+    })
+}
+
+/// Build an IssueIndex from already loaded issues.
+///
+/// # Arguments
+/// * `issues` - Issues to index.
+pub fn build_index_from_issues(issues: Vec<IssueData>) -> IssueIndex {
+    let mut index = IssueIndex::new();
+    for issue in issues {
+        add_issue_to_index(&mut index, issue);
+    }
+    index
+}
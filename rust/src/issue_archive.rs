@@ -0,0 +1,183 @@
+//! Archiving of closed issues.
+
+use std::fs;
+use std::path::Path;
+
+use chrono::{Duration, Utc};
+
+use crate::config_loader::load_project_configuration;
+use crate::error::KanbusError;
+use crate::event_history::{
+    events_dir_for_project, now_timestamp, transfer_payload, write_events_batch, EventRecord,
+    EventType,
+};
+use crate::file_io::{get_configuration_path, load_project_directory};
+use crate::ids::identifier_prefixes;
+use crate::issue_creation::resolve_issue_identifier;
+use crate::issue_files::{
+    ensure_issue_directory, existing_issue_extension, find_issue_file, issue_path_for_identifier,
+    issue_path_for_layout, list_issue_files, read_issue_from_file,
+};
+use crate::models::IssueData;
+use crate::users::get_current_user;
+
+/// Name of the archive directory inside a project.
+pub const ARCHIVE_DIRECTORY: &str = "archive";
+
+/// Move closed issues older than a threshold into the archive directory.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `older_than_days` - Minimum age of `closed_at`, in days.
+///
+/// # Errors
+/// Returns `KanbusError` if an issue cannot be moved.
+pub fn archive_issues(root: &Path, older_than_days: u32) -> Result<Vec<String>, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let archive_dir = project_dir.join(ARCHIVE_DIRECTORY);
+    let cutoff = Utc::now() - Duration::days(i64::from(older_than_days));
+
+    let mut archived = Vec::new();
+    for path in list_issue_files(&project_dir.join("issues"))? {
+        let issue = read_issue_from_file(&path)?;
+        let eligible = issue.status == "closed"
+            && issue.closed_at.is_some_and(|closed_at| closed_at <= cutoff);
+        if !eligible {
+            continue;
+        }
+        let target_path = issue_path_for_identifier(
+            &archive_dir,
+            &issue.identifier,
+            existing_issue_extension(&path),
+        );
+        ensure_issue_directory(&target_path)?;
+        move_issue(
+            &project_dir,
+            &issue,
+            &path,
+            &target_path,
+            EventType::IssueArchived,
+            transfer_payload("shared", ARCHIVE_DIRECTORY),
+        )?;
+
+        use crate::notification_events::NotificationEvent;
+        use crate::notification_publisher::publish_notification;
+        let _ = publish_notification(
+            root,
+            NotificationEvent::IssueDeleted {
+                issue_id: issue.identifier.clone(),
+            },
+        );
+        archived.push(issue.identifier);
+    }
+    archived.sort();
+    Ok(archived)
+}
+
+/// Restore an archived issue into the shared issues directory.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `identifier` - Full or short issue identifier.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the issue is not archived or
+/// already exists in the issues directory.
+pub fn unarchive_issue(root: &Path, identifier: &str) -> Result<IssueData, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let archive_dir = project_dir.join(ARCHIVE_DIRECTORY);
+    if !archive_dir.is_dir() {
+        return Err(KanbusError::IssueOperation(
+            "issue is not archived".to_string(),
+        ));
+    }
+    let configuration = load_project_configuration(&get_configuration_path(&project_dir)?)?;
+    let identifier = resolve_issue_identifier(
+        &archive_dir,
+        &identifier_prefixes(&configuration),
+        identifier,
+    )
+    .map_err(|_| KanbusError::IssueOperation("issue is not archived".to_string()))?;
+    let Some(archived_path) = find_issue_file(&archive_dir, &identifier)? else {
+        return Err(KanbusError::IssueOperation(
+            "issue is not archived".to_string(),
+        ));
+    };
+
+    let issues_dir = project_dir.join("issues");
+    if find_issue_file(&issues_dir, &identifier)?.is_some() {
+        return Err(KanbusError::IssueOperation("already exists".to_string()));
+    }
+
+    let issue = read_issue_from_file(&archived_path)?;
+    let target_path = issue_path_for_layout(
+        &issues_dir,
+        &issue,
+        &configuration.issue_layout,
+        existing_issue_extension(&archived_path),
+    );
+    ensure_issue_directory(&target_path)?;
+    move_issue(
+        &project_dir,
+        &issue,
+        &archived_path,
+        &target_path,
+        EventType::IssueUnarchived,
+        transfer_payload(ARCHIVE_DIRECTORY, "shared"),
+    )?;
+
+    use crate::notification_events::NotificationEvent;
+    use crate::notification_publisher::publish_notification;
+    let _ = publish_notification(
+        root,
+        NotificationEvent::IssueCreated {
+            issue_id: issue.identifier.clone(),
+            issue_data: issue.clone(),
+        },
+    );
+    Ok(issue)
+}
+
+/// Load archived issues for the project.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+///
+/// # Errors
+/// Returns `KanbusError` if archived issue files cannot be read.
+pub fn list_archived_issues(root: &Path) -> Result<Vec<IssueData>, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let archive_dir = project_dir.join(ARCHIVE_DIRECTORY);
+    if !archive_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    list_issue_files(&archive_dir)?
+        .iter()
+        .map(|path| read_issue_from_file(path))
+        .collect()
+}
+
+fn move_issue(
+    project_dir: &Path,
+    issue: &IssueData,
+    source_path: &Path,
+    target_path: &Path,
+    event_type: EventType,
+    payload: serde_json::Value,
+) -> Result<(), KanbusError> {
+    fs::rename(source_path, target_path).map_err(|error| KanbusError::Io(error.to_string()))?;
+
+    let event = EventRecord::new(
+        issue.identifier.clone(),
+        event_type,
+        get_current_user(),
+        payload,
+        now_timestamp(),
+    );
+    if let Err(error) = write_events_batch(&events_dir_for_project(project_dir), &[event]) {
+        fs::rename(target_path, source_path)
+            .map_err(|io_error| KanbusError::Io(io_error.to_string()))?;
+        return Err(error);
+    }
+    Ok(())
+}
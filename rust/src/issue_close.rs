@@ -3,7 +3,7 @@
 use std::path::Path;
 
 use crate::error::KanbusError;
-use crate::issue_update::update_issue;
+use crate::issue_update::{update_issue, IssueUpdateRequest};
 use crate::models::IssueData;
 
 /// Close an issue by transitioning it to closed status.
@@ -15,19 +15,8 @@ use crate::models::IssueData;
 /// # Errors
 /// Returns `KanbusError` if closing fails.
 pub fn close_issue(root: &Path, identifier: &str) -> Result<IssueData, KanbusError> {
-    update_issue(
-        root,
-        identifier,
-        None,
-        None,
-        Some("closed"),
-        None,
-        None,
-        false,
-        true,
-        &[],
-        &[],
-        None,
-        None,
-    )
+    update_issue(&IssueUpdateRequest {
+        status: Some("closed".to_string()),
+        ..IssueUpdateRequest::new(root, identifier)
+    })
 }
@@ -1,16 +1,20 @@
 //! Issue comment management.
 
-use chrono::Utc;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
 use std::path::Path;
 use uuid::Uuid;
 
+use crate::config_loader::load_project_configuration;
 use crate::error::KanbusError;
 use crate::event_history::{
-    comment_payload, comment_updated_payload, events_dir_for_issue_path, now_timestamp,
-    write_events_batch, EventRecord, EventType,
+    comment_payload, comment_resolution_payload, comment_updated_payload,
+    events_dir_for_issue_path, now_timestamp, write_events_batch, EventRecord, EventType,
 };
-use crate::issue_files::write_issue_to_file;
-use crate::issue_lookup::load_issue_from_project;
+use crate::file_io::{get_configuration_path, load_project_directory};
+use crate::issue_files::{list_issue_files, read_issue_from_file, write_issue_to_file};
+use crate::issue_lookup::{load_issue_from_project, IssueLookupResult};
+use crate::maintenance::{configured_time_zone, start_of_day};
 use crate::models::{IssueComment, IssueData};
 use crate::users::get_current_user;
 
@@ -21,7 +25,22 @@ pub struct IssueCommentResult {
     pub comment: IssueComment,
 }
 
-fn generate_comment_id() -> String {
+/// A comment collected across issues for an activity digest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CommentDigestEntry {
+    pub issue_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    pub author: String,
+    pub text: String,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub resolved: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<String>,
+}
+
+pub(crate) fn generate_comment_id() -> String {
     Uuid::new_v4().to_string()
 }
 
@@ -38,6 +57,9 @@ pub fn ensure_comment_ids(issue: &IssueData) -> (IssueData, bool) {
                     author: comment.author.clone(),
                     text: comment.text.clone(),
                     created_at: comment.created_at,
+                    system: comment.system,
+                    resolved: comment.resolved,
+                    reply_to: comment.reply_to.clone(),
                 }
             } else {
                 comment.clone()
@@ -95,6 +117,44 @@ fn find_comment_by_prefix(issue: &IssueData, prefix: &str) -> Result<usize, Kanb
     }
 }
 
+/// Index of the root comment of the thread containing the comment at `index`.
+///
+/// A reply whose root was deleted stands as the root of its own thread.
+fn thread_root_index(issue: &IssueData, index: usize) -> usize {
+    let Some(root_id) = issue.comments[index].reply_to.as_deref() else {
+        return index;
+    };
+    issue
+        .comments
+        .iter()
+        .position(|comment| comment.id.as_deref() == Some(root_id))
+        .unwrap_or(index)
+}
+
+/// Group comments into threads of a root comment and its replies.
+///
+/// Threads are ordered by their root; replies keep their stored order.
+pub fn comment_threads(issue: &IssueData) -> Vec<(&IssueComment, Vec<&IssueComment>)> {
+    let mut threads: Vec<(&IssueComment, Vec<&IssueComment>)> = Vec::new();
+    let mut replies = Vec::new();
+    for (index, comment) in issue.comments.iter().enumerate() {
+        if thread_root_index(issue, index) == index {
+            threads.push((comment, Vec::new()));
+        } else {
+            replies.push(comment);
+        }
+    }
+    for reply in replies {
+        if let Some((_, thread)) = threads
+            .iter_mut()
+            .find(|(thread_root, _)| thread_root.id == reply.reply_to)
+        {
+            thread.push(reply);
+        }
+    }
+    threads
+}
+
 /// Add a comment to an issue.
 ///
 /// # Arguments
@@ -102,6 +162,7 @@ fn find_comment_by_prefix(issue: &IssueData, prefix: &str) -> Result<usize, Kanb
 /// * `identifier` - Issue identifier.
 /// * `author` - Comment author.
 /// * `text` - Comment text.
+/// * `created_at` - Original comment time for backfilled history (defaults to now).
 ///
 /// # Errors
 /// Returns `KanbusError` if the issue cannot be found or updated.
@@ -110,18 +171,78 @@ pub fn add_comment(
     identifier: &str,
     author: &str,
     text: &str,
+    created_at: Option<DateTime<Utc>>,
+) -> Result<IssueCommentResult, KanbusError> {
+    insert_comment(root, identifier, author, text, created_at, None)
+}
+
+/// Reply to a comment, joining its thread.
+///
+/// Replies always attach to the thread's root comment and inherit its
+/// resolution state.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `identifier` - Issue identifier.
+/// * `author` - Comment author.
+/// * `text` - Comment text.
+/// * `created_at` - Original comment time for backfilled history (defaults to now).
+/// * `reply_to_prefix` - Id (full or prefix) of any comment in the thread.
+///
+/// # Errors
+/// Returns `KanbusError` if the issue or comment cannot be found or updated.
+pub fn add_reply(
+    root: &Path,
+    identifier: &str,
+    author: &str,
+    text: &str,
+    created_at: Option<DateTime<Utc>>,
+    reply_to_prefix: &str,
+) -> Result<IssueCommentResult, KanbusError> {
+    insert_comment(
+        root,
+        identifier,
+        author,
+        text,
+        created_at,
+        Some(reply_to_prefix),
+    )
+}
+
+fn insert_comment(
+    root: &Path,
+    identifier: &str,
+    author: &str,
+    text: &str,
+    created_at: Option<DateTime<Utc>>,
+    reply_to_prefix: Option<&str>,
 ) -> Result<IssueCommentResult, KanbusError> {
     let lookup = load_issue_from_project(root, identifier)?;
     let timestamp = Utc::now();
+    let (base_issue, _) = ensure_comment_ids(&lookup.issue);
+    let (reply_to, resolved) = match reply_to_prefix {
+        Some(prefix) => {
+            let index = find_comment_by_prefix(&base_issue, prefix)?;
+            let thread_root = &base_issue.comments[thread_root_index(&base_issue, index)];
+            (thread_root.id.clone(), thread_root.resolved)
+        }
+        None => (None, false),
+    };
     let comment = IssueComment {
         id: Some(generate_comment_id()),
         author: author.to_string(),
         text: text.to_string(),
-        created_at: timestamp,
+        created_at: created_at.unwrap_or(timestamp),
+        system: false,
+        resolved,
+        reply_to,
     };
-    let (base_issue, _) = ensure_comment_ids(&lookup.issue);
     let mut comments = base_issue.comments.clone();
-    comments.push(comment.clone());
+    let position = comments
+        .iter()
+        .position(|existing| existing.created_at > comment.created_at)
+        .unwrap_or(comments.len());
+    comments.insert(position, comment.clone());
     let updated = IssueData {
         comments,
         updated_at: timestamp,
@@ -187,8 +308,75 @@ pub fn update_comment(
     text: &str,
 ) -> Result<IssueData, KanbusError> {
     let lookup = load_issue_from_project(root, identifier)?;
-    let (mut issue, changed) = ensure_comment_ids(&lookup.issue);
+    let (issue, _changed) = ensure_comment_ids(&lookup.issue);
     let index = find_comment_by_prefix(&issue, comment_id_prefix)?;
+    replace_comment_text(root, &lookup, issue, index, text)
+}
+
+/// Replace the text of the most recent comment by `author`.
+///
+/// The latest non-system comment on the issue must belong to `author`
+/// unless `force` is set, in which case the author's own latest comment is
+/// edited even when others have commented since.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `identifier` - Issue identifier.
+/// * `author` - Comment author, usually the current user.
+/// * `text` - Replacement comment text.
+/// * `force` - Edit the author's latest comment even if it is not the last one.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if no matching comment exists.
+pub fn update_last_comment(
+    root: &Path,
+    identifier: &str,
+    author: &str,
+    text: &str,
+    force: bool,
+) -> Result<IssueData, KanbusError> {
+    let lookup = load_issue_from_project(root, identifier)?;
+    let (issue, _changed) = ensure_comment_ids(&lookup.issue);
+    let Some(last_index) = latest_comment_index(&issue, None) else {
+        return Err(KanbusError::IssueOperation(
+            "issue has no comments".to_string(),
+        ));
+    };
+    let last_author = &issue.comments[last_index].author;
+    let index = if last_author == author {
+        last_index
+    } else if force {
+        latest_comment_index(&issue, Some(author)).ok_or_else(|| {
+            KanbusError::IssueOperation(format!("no comments by {author} on this issue"))
+        })?
+    } else {
+        return Err(KanbusError::IssueOperation(format!(
+            "last comment was written by {last_author} (use --force to edit your latest comment)"
+        )));
+    };
+    replace_comment_text(root, &lookup, issue, index, text)
+}
+
+/// Index of the newest non-system comment, optionally limited to one author.
+fn latest_comment_index(issue: &IssueData, author: Option<&str>) -> Option<usize> {
+    issue
+        .comments
+        .iter()
+        .enumerate()
+        .filter(|(_, comment)| {
+            !comment.system && author.is_none_or(|author| comment.author == author)
+        })
+        .max_by_key(|(index, comment)| (comment.created_at, *index))
+        .map(|(index, _)| index)
+}
+
+fn replace_comment_text(
+    root: &Path,
+    lookup: &IssueLookupResult,
+    mut issue: IssueData,
+    index: usize,
+    text: &str,
+) -> Result<IssueData, KanbusError> {
     let existing_comment = issue
         .comments
         .get(index)
@@ -200,9 +388,6 @@ pub fn update_comment(
     }
     issue.updated_at = timestamp;
     write_issue_to_file(&issue, &lookup.issue_path)?;
-    if changed {
-        // already written updated issue with ids
-    }
 
     let comment_id = existing_comment
         .id
@@ -241,6 +426,74 @@ pub fn update_comment(
     Ok(issue)
 }
 
+/// Mark a comment thread as resolved or reopen it.
+///
+/// Resolution lives on the thread's root comment and is propagated to every
+/// reply, so targeting any comment in the thread affects the whole thread.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `identifier` - Issue identifier.
+/// * `comment_id_prefix` - Id (full or prefix) of any comment in the thread.
+/// * `resolved` - Whether the thread is resolved.
+///
+/// # Errors
+/// Returns `KanbusError` if the comment cannot be found or updated.
+pub fn set_comment_resolved(
+    root: &Path,
+    identifier: &str,
+    comment_id_prefix: &str,
+    resolved: bool,
+) -> Result<IssueData, KanbusError> {
+    let lookup = load_issue_from_project(root, identifier)?;
+    let (mut issue, _changed) = ensure_comment_ids(&lookup.issue);
+    let index = find_comment_by_prefix(&issue, comment_id_prefix)?;
+    let thread_root = &issue.comments[thread_root_index(&issue, index)];
+    let comment_id = thread_root
+        .id
+        .clone()
+        .ok_or_else(|| KanbusError::IssueOperation("comment id is required".to_string()))?;
+    let comment_author = thread_root.author.clone();
+    for comment in issue.comments.iter_mut() {
+        if comment.id.as_deref() == Some(comment_id.as_str())
+            || comment.reply_to.as_deref() == Some(comment_id.as_str())
+        {
+            comment.resolved = resolved;
+        }
+    }
+    issue.updated_at = Utc::now();
+    write_issue_to_file(&issue, &lookup.issue_path)?;
+
+    let occurred_at = now_timestamp();
+    let actor_id = get_current_user();
+    let event = EventRecord::new(
+        issue.identifier.clone(),
+        EventType::CommentUpdated,
+        actor_id,
+        comment_resolution_payload(&comment_id, &comment_author, resolved),
+        occurred_at,
+    );
+    let events_dir = events_dir_for_issue_path(&lookup.project_dir, &lookup.issue_path)?;
+    if let Err(error) = write_events_batch(&events_dir, &[event]) {
+        write_issue_to_file(&lookup.issue, &lookup.issue_path)?;
+        return Err(error);
+    }
+
+    // Publish real-time notification
+    use crate::notification_events::NotificationEvent;
+    use crate::notification_publisher::publish_notification;
+    let _ = publish_notification(
+        root,
+        NotificationEvent::IssueUpdated {
+            issue_id: issue.identifier.clone(),
+            fields_changed: vec!["comments".to_string()],
+            issue_data: issue.clone(),
+        },
+    );
+
+    Ok(issue)
+}
+
 /// Delete an existing comment by id prefix.
 pub fn delete_comment(
     root: &Path,
@@ -290,3 +543,53 @@ pub fn delete_comment(
 
     Ok(issue)
 }
+
+/// Collect comments from every issue in the project created on or after a day.
+///
+/// The day starts at midnight in the configured `time_zone` (UTC when unset).
+/// Entries are sorted chronologically.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `since` - First day of the window.
+/// * `author` - Only include comments by this author.
+///
+/// # Errors
+/// Returns `KanbusError` if configuration or issue files cannot be read.
+pub fn list_comments_since(
+    root: &Path,
+    since: NaiveDate,
+    author: Option<&str>,
+) -> Result<Vec<CommentDigestEntry>, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let configuration =
+        load_project_configuration(&get_configuration_path(project_dir.as_path())?)?;
+    let window_start = start_of_day(configured_time_zone(&configuration)?, since);
+
+    let mut entries = Vec::new();
+    for path in list_issue_files(&project_dir.join("issues"))? {
+        let issue = read_issue_from_file(&path)?;
+        for comment in issue.comments {
+            if comment.created_at < window_start
+                || author.is_some_and(|name| comment.author != name)
+            {
+                continue;
+            }
+            entries.push(CommentDigestEntry {
+                issue_id: issue.identifier.clone(),
+                comment_id: comment.id,
+                author: comment.author,
+                text: comment.text,
+                created_at: comment.created_at,
+                resolved: comment.resolved,
+                reply_to: comment.reply_to,
+            });
+        }
+    }
+    entries.sort_by(|left, right| {
+        left.created_at
+            .cmp(&right.created_at)
+            .then_with(|| left.issue_id.cmp(&right.issue_id))
+    });
+    Ok(entries)
+}
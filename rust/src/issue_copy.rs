@@ -0,0 +1,85 @@
+//! Issue duplication workflow.
+
+use std::path::Path;
+
+use crate::dependencies::add_dependency;
+use crate::error::KanbusError;
+use crate::file_io::load_project_directory;
+use crate::issue_creation::{create_issue, IssueCreationRequest, SEQUENCE_FIELD};
+use crate::issue_files::write_issue_to_file;
+use crate::issue_lookup::load_issue_from_project;
+use crate::models::IssueData;
+
+/// Copy an existing issue into a new issue with a fresh identifier.
+///
+/// The copy is created through `create_issue`, so it is validated like any
+/// new issue and gets a fresh creation sequence number. It keeps the title,
+/// description, type, priority, labels, and custom fields of the original.
+/// Status is reset to the initial status and comments, dependencies,
+/// assignee, and parent are not carried over. The current user is recorded
+/// as the creator of the copy.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `identifier` - Identifier of the issue to copy.
+/// * `title` - Optional title override for the copy.
+/// * `link` - Whether to link the copy to the original with `relates-to`.
+///
+/// # Errors
+/// Returns `KanbusError` if the source issue cannot be found, the copy fails
+/// validation, or writing fails.
+pub fn copy_issue(
+    root: &Path,
+    identifier: &str,
+    title: Option<&str>,
+    link: bool,
+) -> Result<IssueData, KanbusError> {
+    let lookup = load_issue_from_project(root, identifier)?;
+    let source = lookup.issue;
+
+    let resolved_title = match title {
+        Some(value) if !value.trim().is_empty() => value.trim().to_string(),
+        Some(_) => return Err(KanbusError::IssueOperation("title is required".to_string())),
+        None => source.title.clone(),
+    };
+    let priority = u8::try_from(source.priority).map_err(|_| {
+        KanbusError::IssueOperation(format!("invalid priority: {}", source.priority))
+    })?;
+    let shared_issues_dir = load_project_directory(root)?.join("issues");
+    let request = IssueCreationRequest {
+        root: root.to_path_buf(),
+        title: resolved_title,
+        issue_type: Some(source.issue_type.clone()),
+        priority: Some(priority),
+        assignee: None,
+        parent: None,
+        priority_from_parent: false,
+        labels: source.labels.clone(),
+        description: Some(source.description.clone()),
+        status: None,
+        local: !lookup.issue_path.starts_with(&shared_issues_dir),
+        validate: true,
+        allow_duplicate_title: title.is_none(),
+        due_at: None,
+        estimate_minutes: None,
+    };
+    let mut issue = create_issue(&request)?.issue;
+
+    let inherited_custom: Vec<_> = source
+        .custom
+        .into_iter()
+        .filter(|(key, _)| key != SEQUENCE_FIELD)
+        .collect();
+    if !inherited_custom.is_empty() {
+        let created = load_issue_from_project(root, &issue.identifier)?;
+        issue = created.issue;
+        issue.custom.extend(inherited_custom);
+        write_issue_to_file(&issue, &created.issue_path)?;
+    }
+
+    if link {
+        return add_dependency(root, &issue.identifier, &source.identifier, "relates-to");
+    }
+
+    Ok(issue)
+}
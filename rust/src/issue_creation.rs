@@ -1,6 +1,8 @@
 //! Issue creation workflow.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::config_loader::load_project_configuration;
@@ -10,21 +12,30 @@ use crate::event_history::{
     write_events_batch, EventRecord, EventType,
 };
 use crate::hierarchy::validate_parent_child_relationship;
-use crate::ids::{generate_issue_identifier, IssueIdentifierRequest};
+use crate::ids::{
+    generate_issue_identifier, identifier_prefix, identifier_prefixes, IssueIdentifierRequest,
+};
 use crate::issue_files::{
-    issue_path_for_identifier, list_issue_identifiers, read_issue_from_file, write_issue_to_file,
+    ensure_issue_directory, find_issue_file, issue_file_extension, issue_path_for_layout,
+    list_issue_files, list_issue_identifiers, read_issue_from_file, write_issue_to_file,
 };
 use crate::models::{IssueData, ProjectConfiguration};
 use crate::users::get_current_user;
-use crate::workflows::validate_status_value;
+use crate::workflows::{is_terminal_status, validate_status_value};
 use crate::{
     file_io::{
         ensure_project_local_directory, find_project_local_directory, get_configuration_path,
-        load_project_directory,
+        load_project_directory, lock_project_cache_file,
     },
     models::DependencyLink,
 };
 
+/// Custom field holding an issue's per-project creation sequence number.
+pub const SEQUENCE_FIELD: &str = "seq";
+
+/// Counter file in the project directory that stores the last sequence number.
+const SEQUENCE_FILE: &str = ".sequence";
+
 /// Request payload for issue creation.
 #[derive(Debug, Clone)]
 pub struct IssueCreationRequest {
@@ -34,10 +45,15 @@ pub struct IssueCreationRequest {
     pub priority: Option<u8>,
     pub assignee: Option<String>,
     pub parent: Option<String>,
+    pub priority_from_parent: bool,
     pub labels: Vec<String>,
     pub description: Option<String>,
+    pub status: Option<String>,
     pub local: bool,
     pub validate: bool,
+    pub allow_duplicate_title: bool,
+    pub due_at: Option<DateTime<Utc>>,
+    pub estimate_minutes: Option<u32>,
 }
 
 /// Result payload for issue creation.
@@ -45,6 +61,8 @@ pub struct IssueCreationRequest {
 pub struct IssueCreationResult {
     pub issue: IssueData,
     pub configuration: ProjectConfiguration,
+    /// Existing issue with the same title, when creation was allowed anyway.
+    pub duplicate_of: Option<String>,
 }
 
 /// Create a new issue and write it to disk.
@@ -66,14 +84,37 @@ pub fn create_issue(request: &IssueCreationRequest) -> Result<IssueCreationResul
     let configuration = load_project_configuration(&config_path)?;
 
     let resolved_type = request.issue_type.as_deref().unwrap_or("task");
-    let resolved_priority = request.priority.unwrap_or(configuration.default_priority);
+    let resolved_status = request
+        .status
+        .clone()
+        .unwrap_or_else(|| configuration.initial_status.clone());
     // Resolve parent: accept full id or unique short id (projectkey-<prefix>).
     let mut resolved_parent = request.parent.clone();
     if let Some(parent_identifier) = resolved_parent.clone() {
-        let full_id =
-            resolve_issue_identifier(&issues_dir, &configuration.project_key, &parent_identifier)?;
+        let full_id = resolve_issue_identifier(
+            &issues_dir,
+            &identifier_prefixes(&configuration),
+            &parent_identifier,
+        )?;
         resolved_parent = Some(full_id);
     }
+    let inherited_priority = match resolved_parent.as_deref() {
+        Some(parent_identifier) if request.priority_from_parent && request.priority.is_none() => {
+            let Some(parent_path) = find_issue_file(&issues_dir, parent_identifier)? else {
+                return Err(KanbusError::IssueOperation("not found".to_string()));
+            };
+            let parent_priority = read_issue_from_file(&parent_path)?.priority;
+            Some(u8::try_from(parent_priority).map_err(|_| {
+                KanbusError::IssueOperation(format!("invalid parent priority: {parent_priority}"))
+            })?)
+        }
+        _ => None,
+    };
+    let resolved_priority = request
+        .priority
+        .or(inherited_priority)
+        .unwrap_or(configuration.default_priority);
+    let mut duplicate_of = None;
     if request.validate {
         validate_issue_type(&configuration, resolved_type)?;
         if !configuration.priorities.contains_key(&resolved_priority) {
@@ -81,10 +122,9 @@ pub fn create_issue(request: &IssueCreationRequest) -> Result<IssueCreationResul
         }
 
         if let Some(parent_identifier) = resolved_parent.as_deref() {
-            let parent_path = issue_path_for_identifier(&issues_dir, parent_identifier);
-            if !parent_path.exists() {
+            let Some(parent_path) = find_issue_file(&issues_dir, parent_identifier)? else {
                 return Err(KanbusError::IssueOperation("not found".to_string()));
-            }
+            };
             let parent_issue = read_issue_from_file(&parent_path)?;
             validate_parent_child_relationship(
                 &configuration,
@@ -93,14 +133,22 @@ pub fn create_issue(request: &IssueCreationRequest) -> Result<IssueCreationResul
             )?;
         }
 
-        if let Some(duplicate_identifier) = find_duplicate_title(&issues_dir, &request.title)? {
-            return Err(KanbusError::IssueOperation(format!(
-                "duplicate title: \"{}\" already exists as {}",
-                request.title, duplicate_identifier
-            )));
+        if let Some(duplicate_identifier) =
+            find_duplicate_title(&configuration, &issues_dir, &request.title)?
+        {
+            if !configuration.warn_on_duplicate_title && !request.allow_duplicate_title {
+                return Err(KanbusError::IssueOperation(format!(
+                    "duplicate title: \"{}\" already exists as {}",
+                    request.title, duplicate_identifier
+                )));
+            }
+            duplicate_of = Some(duplicate_identifier);
         }
 
-        validate_status_value(&configuration, resolved_type, &configuration.initial_status)?;
+        validate_status_value(&configuration, resolved_type, &resolved_status)?;
+        if let Some(description) = request.description.as_deref() {
+            validate_description_length(&configuration, description)?;
+        }
     }
 
     let mut existing_ids = list_issue_identifiers(&project_dir.join("issues"))?;
@@ -114,40 +162,56 @@ pub fn create_issue(request: &IssueCreationRequest) -> Result<IssueCreationResul
     let identifier_request = IssueIdentifierRequest {
         title: request.title.clone(),
         existing_ids,
-        prefix: configuration.project_key.clone(),
+        prefix: identifier_prefix(&configuration, resolved_type).to_string(),
     };
     let identifier = generate_issue_identifier(&identifier_request)?.identifier;
     let updated_at = created_at;
+    let closed_at = (resolved_status == "closed").then_some(created_at);
 
     let resolved_assignee = request
         .assignee
         .clone()
         .or_else(|| configuration.assignee.clone());
+    let actor_id = get_current_user();
+    let mut custom = BTreeMap::new();
+    custom.insert(
+        SEQUENCE_FIELD.to_string(),
+        serde_json::Value::from(next_issue_sequence(&project_dir)?),
+    );
 
     let issue = IssueData {
         identifier,
         title: request.title.clone(),
         description: request.description.clone().unwrap_or_default(),
         issue_type: resolved_type.to_string(),
-        status: configuration.initial_status.clone(),
+        status: resolved_status,
         priority: resolved_priority as i32,
         assignee: resolved_assignee,
-        creator: None,
+        creator: Some(actor_id.clone()),
         parent: resolved_parent.clone(),
         labels: request.labels.clone(),
         dependencies: Vec::<DependencyLink>::new(),
         comments: Vec::new(),
+        links: Vec::new(),
         created_at,
         updated_at,
-        closed_at: None,
-        custom: std::collections::BTreeMap::new(),
+        closed_at,
+        due_at: request.due_at,
+        estimate_minutes: request.estimate_minutes,
+        spent_minutes: 0,
+        custom,
     };
 
-    let issue_path = issue_path_for_identifier(&issues_dir, &issue.identifier);
+    let issue_path = issue_path_for_layout(
+        &issues_dir,
+        &issue,
+        &configuration.issue_layout,
+        issue_file_extension(&configuration.issue_format),
+    );
+    ensure_issue_directory(&issue_path)?;
     write_issue_to_file(&issue, &issue_path)?;
 
     let occurred_at = now_timestamp();
-    let actor_id = get_current_user();
     let event = EventRecord::new(
         issue.identifier.clone(),
         EventType::IssueCreated,
@@ -190,9 +254,92 @@ pub fn create_issue(request: &IssueCreationRequest) -> Result<IssueCreationResul
     Ok(IssueCreationResult {
         issue,
         configuration,
+        duplicate_of,
     })
 }
 
+/// Reserve the next creation sequence number for a project.
+///
+/// The counter lives in `.sequence` in the project directory and is read and
+/// replaced under an exclusive lock, so concurrent creations never share a
+/// number. The first issue gets 1.
+///
+/// # Arguments
+/// * `project_dir` - Project directory.
+///
+/// # Errors
+/// Returns `KanbusError` if the counter cannot be read, parsed, or written.
+pub fn next_issue_sequence(project_dir: &Path) -> Result<u64, KanbusError> {
+    let _lock = lock_project_cache_file(project_dir, "sequence.lock")?;
+    let counter_path = project_dir.join(SEQUENCE_FILE);
+    let current = match fs::read_to_string(&counter_path) {
+        Ok(contents) => contents.trim().parse::<u64>().map_err(|_| {
+            KanbusError::IssueOperation(format!(
+                "invalid sequence counter in {}",
+                counter_path.display()
+            ))
+        })?,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => 0,
+        Err(error) => return Err(KanbusError::Io(error.to_string())),
+    };
+    let next = current + 1;
+    let mut file = tempfile::NamedTempFile::new_in(project_dir)
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    std::io::Write::write_all(&mut file, format!("{next}\n").as_bytes())
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    file.persist(&counter_path)
+        .map_err(|error| KanbusError::Io(error.error.to_string()))?;
+    Ok(next)
+}
+
+/// Validate a description against the configured `max_description_length`.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` naming the limit when the description is too long.
+pub fn validate_description_length(
+    configuration: &ProjectConfiguration,
+    description: &str,
+) -> Result<(), KanbusError> {
+    let Some(limit) = configuration.max_description_length else {
+        return Ok(());
+    };
+    let length = description.chars().count();
+    if length > limit {
+        return Err(KanbusError::IssueOperation(format!(
+            "description is {length} characters; max_description_length is {limit}"
+        )));
+    }
+    Ok(())
+}
+
+/// Resolve an imported textual priority to a configured priority level.
+///
+/// The value is first translated through `priority_import_aliases`; values
+/// without an alias are used unchanged. The result is then matched against
+/// priority names, ignoring case, or read as a numeric level.
+///
+/// # Returns
+/// The priority level, or `None` when no configured priority matches.
+pub fn resolve_imported_priority(configuration: &ProjectConfiguration, value: &str) -> Option<u8> {
+    let value = value.trim();
+    let canonical = configuration
+        .priority_import_aliases
+        .get(value)
+        .map(String::as_str)
+        .unwrap_or(value);
+    configuration
+        .priorities
+        .iter()
+        .find(|(_, priority)| priority.name.eq_ignore_ascii_case(canonical))
+        .map(|(level, _)| *level)
+        .or_else(|| {
+            canonical
+                .parse::<u8>()
+                .ok()
+                .filter(|level| configuration.priorities.contains_key(level))
+        })
+}
+
 fn validate_issue_type(
     configuration: &ProjectConfiguration,
     issue_type: &str,
@@ -210,17 +357,17 @@ fn validate_issue_type(
     Ok(())
 }
 
-fn find_duplicate_title(issues_dir: &Path, title: &str) -> Result<Option<String>, KanbusError> {
+fn find_duplicate_title(
+    configuration: &ProjectConfiguration,
+    issues_dir: &Path,
+    title: &str,
+) -> Result<Option<String>, KanbusError> {
     let normalized_title = title.trim().to_lowercase();
-    for entry in
-        std::fs::read_dir(issues_dir).map_err(|error| KanbusError::Io(error.to_string()))?
-    {
-        let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
-        let path = entry.path();
-        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+    for path in list_issue_files(issues_dir)? {
+        let issue = read_issue_from_file(&path)?;
+        if is_terminal_status(configuration, &issue.status) {
             continue;
         }
-        let issue = read_issue_from_file(&path)?;
         if issue.title.trim().to_lowercase() == normalized_title {
             return Ok(Some(issue.identifier));
         }
@@ -230,15 +377,15 @@ fn find_duplicate_title(issues_dir: &Path, title: &str) -> Result<Option<String>
 
 /// Resolve an issue identifier from a user-provided value.
 ///
-/// Accepts a full id or a unique short id (`{project_key}-{prefix}` up to 6 chars).
+/// Accepts a full id or a unique short id (`{key}-{prefix}` up to 6 chars),
+/// where `key` is one of the project's identifier `prefixes`.
 pub fn resolve_issue_identifier(
     issues_dir: &Path,
-    project_key: &str,
+    prefixes: &[String],
     candidate: &str,
 ) -> Result<String, KanbusError> {
     // First, try exact match on filename.
-    let exact_path = issue_path_for_identifier(issues_dir, candidate);
-    if exact_path.exists() {
+    if find_issue_file(issues_dir, candidate)?.is_some() {
         return Ok(candidate.to_string());
     }
 
@@ -246,7 +393,7 @@ pub fn resolve_issue_identifier(
     let identifiers = list_issue_identifiers(issues_dir)?;
     let mut matches: Vec<String> = identifiers
         .into_iter()
-        .filter(|full_id| short_id_matches(candidate, project_key, full_id))
+        .filter(|full_id| short_id_matches(candidate, prefixes, full_id))
         .collect();
 
     match matches.len() {
@@ -259,14 +406,13 @@ pub fn resolve_issue_identifier(
 }
 
 /// Determine whether a short identifier matches a full identifier.
-pub fn short_id_matches(candidate: &str, project_key: &str, full_id: &str) -> bool {
-    if !candidate.starts_with(project_key) {
-        return false;
-    }
+///
+/// Both must carry the same key, and it must be one of `prefixes`.
+pub fn short_id_matches(candidate: &str, prefixes: &[String], full_id: &str) -> bool {
     let mut parts = candidate.splitn(2, '-');
     let prefix_key = parts.next().unwrap_or("");
     let prefix = parts.next().unwrap_or("");
-    if prefix_key != project_key {
+    if !prefixes.iter().any(|key| key == prefix_key) {
         return false;
     }
     if prefix.is_empty() || prefix.len() > 6 {
@@ -275,7 +421,7 @@ pub fn short_id_matches(candidate: &str, project_key: &str, full_id: &str) -> bo
     let mut full_parts = full_id.splitn(2, '-');
     let full_key = full_parts.next().unwrap_or("");
     let full_suffix = full_parts.next().unwrap_or("");
-    if full_key != project_key {
+    if full_key != prefix_key {
         return false;
     }
     full_suffix.starts_with(prefix)
@@ -1,9 +1,14 @@
 //! Issue display formatting helpers.
 
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use owo_colors::{AnsiColors, OwoColorize};
+use std::collections::BTreeMap;
 
 use crate::ids::format_issue_key;
-use crate::models::{IssueData, ProjectConfiguration};
+use crate::issue_comment::comment_threads;
+use crate::issue_time::format_minutes;
+use crate::models::{IssueComment, IssueData, ProjectConfiguration};
 
 fn dim(text: &str, use_color: bool) -> String {
     if use_color {
@@ -13,6 +18,47 @@ fn dim(text: &str, use_color: bool) -> String {
     }
 }
 
+/// Collapse a resolved comment to a single dimmed summary line.
+pub(crate) fn collapse_resolved_comment(text: &str, use_color: bool) -> String {
+    let mut text_lines = text.lines();
+    let first_line = text_lines.next().unwrap_or("");
+    let summary = if text_lines.next().is_some() {
+        format!("[resolved] {first_line} ...")
+    } else {
+        format!("[resolved] {first_line}")
+    };
+    dim(&summary, use_color)
+}
+
+/// Render one comment line with its id prefix and author.
+fn format_comment_line(
+    comment: &IssueComment,
+    text: &str,
+    indent: &str,
+    use_color: bool,
+) -> String {
+    let author = if comment.author.is_empty() {
+        "unknown"
+    } else {
+        comment.author.as_str()
+    };
+    let prefix = comment
+        .id
+        .as_deref()
+        .unwrap_or("")
+        .chars()
+        .take(6)
+        .collect::<String>();
+    if prefix.is_empty() {
+        format!("{indent}{} {text}", dim(&format!("{author}:"), use_color))
+    } else {
+        format!(
+            "{indent}[{prefix}] {} {text}",
+            dim(&format!("{author}:"), use_color)
+        )
+    }
+}
+
 fn paint(value: &str, color: Option<AnsiColors>, use_color: bool) -> String {
     match (use_color, color) {
         (true, Some(color_value)) => value.color(color_value).to_string(),
@@ -20,7 +66,8 @@ fn paint(value: &str, color: Option<AnsiColors>, use_color: bool) -> String {
     }
 }
 
-fn parse_color(name: &str) -> Option<AnsiColors> {
+/// Map a configured color name to an ANSI color.
+pub(crate) fn parse_color(name: &str) -> Option<AnsiColors> {
     match name.to_ascii_lowercase().as_str() {
         "black" => Some(AnsiColors::Black),
         "red" => Some(AnsiColors::Red),
@@ -105,6 +152,27 @@ fn type_color(
     })
 }
 
+fn label_color(label: &str, configuration: Option<&ProjectConfiguration>) -> Option<AnsiColors> {
+    configuration
+        .and_then(|config| config.label_colors.get(label))
+        .and_then(|color| parse_color(color))
+}
+
+fn format_labels(
+    labels: &[String],
+    configuration: Option<&ProjectConfiguration>,
+    use_color: bool,
+) -> String {
+    if labels.is_empty() {
+        return dim("-", use_color);
+    }
+    labels
+        .iter()
+        .map(|label| paint(label, label_color(label, configuration), use_color))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Format an issue for human-readable display.
 pub fn format_issue_for_display(
     issue: &IssueData,
@@ -112,11 +180,47 @@ pub fn format_issue_for_display(
     use_color: bool,
     project_context: bool,
 ) -> String {
-    let labels = if issue.labels.is_empty() {
-        "-".to_string()
-    } else {
-        issue.labels.join(", ")
-    };
+    format_issue_with_dependencies(
+        issue,
+        configuration,
+        use_color,
+        project_context,
+        &BTreeMap::new(),
+        None,
+    )
+}
+
+/// Collect the issues that declare a `blocked-by` edge on `issue`.
+///
+/// The result is sorted by identifier so output is stable.
+pub fn blocked_issues<'a>(issue: &IssueData, all_issues: &'a [IssueData]) -> Vec<&'a IssueData> {
+    let mut blocked: Vec<&IssueData> = all_issues
+        .iter()
+        .filter(|candidate| candidate.identifier != issue.identifier)
+        .filter(|candidate| {
+            candidate.dependencies.iter().any(|dependency| {
+                dependency.dependency_type == "blocked-by" && dependency.target == issue.identifier
+            })
+        })
+        .collect();
+    blocked.sort_by(|left, right| left.identifier.cmp(&right.identifier));
+    blocked
+}
+
+/// Format an issue for display with dependency targets resolved to live data.
+///
+/// Each dependency whose target is present in `dependency_targets` is shown
+/// with its current status and title. Blockers that are not closed are marked
+/// as still open. When `all_issues` is provided, a reverse "Blocks" section
+/// lists every issue that is `blocked-by` this one.
+pub fn format_issue_with_dependencies(
+    issue: &IssueData,
+    configuration: Option<&ProjectConfiguration>,
+    use_color: bool,
+    project_context: bool,
+    dependency_targets: &BTreeMap<String, IssueData>,
+    all_issues: Option<&[IssueData]>,
+) -> String {
     let assignee = issue.assignee.clone().unwrap_or_else(|| "-".to_string());
     let parent = issue.parent.clone().unwrap_or_else(|| "-".to_string());
 
@@ -145,7 +249,6 @@ pub fn format_issue_for_display(
         ),
         ("Assignee:", assignee, None, issue.assignee.is_none()),
         ("Parent:", parent, None, issue.parent.is_none()),
-        ("Labels:", labels, None, issue.labels.is_empty()),
     ];
 
     let mut lines = Vec::new();
@@ -161,6 +264,30 @@ pub fn format_issue_for_display(
             paint(&value, final_color, use_color)
         ));
     }
+    lines.push(format!(
+        "{} {}",
+        dim("Labels:", use_color),
+        format_labels(&issue.labels, configuration, use_color)
+    ));
+    if let Some(due_at) = issue.due_at {
+        lines.push(format!(
+            "{} {}",
+            dim("Due:", use_color),
+            format_due_at(due_at, configuration)
+        ));
+    }
+    if issue.estimate_minutes.is_some() || issue.spent_minutes > 0 {
+        let estimate = issue
+            .estimate_minutes
+            .map(format_minutes)
+            .unwrap_or_else(|| "none".to_string());
+        lines.push(format!(
+            "{} {} estimated, {} spent",
+            dim("Time:", use_color),
+            estimate,
+            format_minutes(issue.spent_minutes)
+        ));
+    }
     if !issue.description.is_empty() {
         lines.push(dim("Description:", use_color));
         lines.push(paint(&issue.description, None, use_color));
@@ -168,41 +295,167 @@ pub fn format_issue_for_display(
     if !issue.dependencies.is_empty() {
         lines.push(dim("Dependencies:", use_color));
         for dependency in &issue.dependencies {
+            let Some(target) = dependency_targets.get(&dependency.target) else {
+                lines.push(format!(
+                    "  {}: {}",
+                    dependency.dependency_type, dependency.target
+                ));
+                continue;
+            };
+            let mut line = format!(
+                "  {}: {} [{}] {}",
+                dependency.dependency_type,
+                dependency.target,
+                paint(
+                    &target.status,
+                    status_color(&target.status, configuration),
+                    use_color
+                ),
+                target.title
+            );
+            if dependency.dependency_type == "blocked-by" && target.status != "closed" {
+                line.push_str(&format!(
+                    " {}",
+                    paint("(still open)", Some(AnsiColors::Red), use_color)
+                ));
+            }
+            lines.push(line);
+        }
+    }
+    let blocked = all_issues
+        .map(|all_issues| blocked_issues(issue, all_issues))
+        .unwrap_or_default();
+    if !blocked.is_empty() {
+        lines.push(dim("Blocks:", use_color));
+        for blocked_issue in blocked {
             lines.push(format!(
-                "  {}: {}",
-                dependency.dependency_type, dependency.target
+                "  {} [{}] {}",
+                blocked_issue.identifier,
+                paint(
+                    &blocked_issue.status,
+                    status_color(&blocked_issue.status, configuration),
+                    use_color
+                ),
+                blocked_issue.title
             ));
         }
     }
+    if !issue.links.is_empty() {
+        lines.push(dim("Links:", use_color));
+        for link in &issue.links {
+            match &link.title {
+                Some(title) => lines.push(format!("  {title}: {}", link.url)),
+                None => lines.push(format!("  {}", link.url)),
+            }
+        }
+    }
     if !issue.comments.is_empty() {
         lines.push(dim("Comments:", use_color));
+        for (thread_root, replies) in comment_threads(issue) {
+            if thread_root.resolved {
+                let mut text = collapse_resolved_comment(&thread_root.text, use_color);
+                match replies.len() {
+                    0 => {}
+                    1 => text.push_str(&dim(" (1 reply)", use_color)),
+                    count => text.push_str(&dim(&format!(" ({count} replies)"), use_color)),
+                }
+                lines.push(format_comment_line(thread_root, &text, "  ", use_color));
+                continue;
+            }
+            lines.push(format_comment_line(
+                thread_root,
+                &thread_root.text,
+                "  ",
+                use_color,
+            ));
+            for reply in replies {
+                lines.push(format_comment_line(reply, &reply.text, "    ", use_color));
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+/// Render a due date in the configured `time_zone` (UTC when unset).
+fn format_due_at(due_at: DateTime<Utc>, configuration: Option<&ProjectConfiguration>) -> String {
+    let zone = configuration
+        .and_then(|configuration| configuration.time_zone.as_deref())
+        .and_then(|name| name.parse::<Tz>().ok());
+    match zone {
+        Some(tz) => due_at
+            .with_timezone(&tz)
+            .format("%Y-%m-%d %H:%M %Z")
+            .to_string(),
+        None => due_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+    }
+}
+
+/// Format an issue as a Markdown document.
+///
+/// The document has the identifier and title as its heading, a metadata
+/// table, the description, and a comments section. No color is applied.
+pub fn format_issue_markdown(issue: &IssueData, project_context: bool) -> String {
+    let identifier = format_issue_key(&issue.identifier, project_context);
+    let optional = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+    let rows = [
+        ("Status", issue.status.clone()),
+        ("Type", issue.issue_type.clone()),
+        ("Priority", issue.priority.to_string()),
+        ("Assignee", optional(&issue.assignee)),
+        ("Parent", optional(&issue.parent)),
+        ("Created", issue.created_at.to_rfc3339()),
+        ("Updated", issue.updated_at.to_rfc3339()),
+        (
+            "Closed",
+            issue
+                .closed_at
+                .map(|closed_at| closed_at.to_rfc3339())
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+    ];
+
+    let mut lines = vec![
+        format!("# {identifier}: {}", issue.title),
+        String::new(),
+        "| Field | Value |".to_string(),
+        "| --- | --- |".to_string(),
+    ];
+    for (label, value) in rows {
+        lines.push(format!("| {label} | {} |", escape_table_cell(&value)));
+    }
+    if !issue.labels.is_empty() {
+        lines.push(format!(
+            "| Labels | {} |",
+            escape_table_cell(&issue.labels.join(", "))
+        ));
+    }
+    if !issue.description.is_empty() {
+        lines.push(String::new());
+        lines.push("## Description".to_string());
+        lines.push(String::new());
+        lines.push(issue.description.trim_end().to_string());
+    }
+    if !issue.comments.is_empty() {
+        lines.push(String::new());
+        lines.push("## Comments".to_string());
         for comment in &issue.comments {
             let author = if comment.author.is_empty() {
                 "unknown"
             } else {
                 comment.author.as_str()
             };
-            let prefix = comment
-                .id
-                .as_deref()
-                .unwrap_or("")
-                .chars()
-                .take(6)
-                .collect::<String>();
-            if prefix.is_empty() {
-                lines.push(format!(
-                    "  {} {}",
-                    dim(&format!("{author}:"), use_color),
-                    comment.text
-                ));
-            } else {
-                lines.push(format!(
-                    "  [{prefix}] {} {}",
-                    dim(&format!("{author}:"), use_color),
-                    comment.text
-                ));
-            }
+            lines.push(String::new());
+            lines.push(format!(
+                "**{author}** ({})",
+                comment.created_at.to_rfc3339()
+            ));
+            lines.push(String::new());
+            lines.push(comment.text.trim_end().to_string());
         }
     }
     lines.join("\n")
 }
+
+fn escape_table_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
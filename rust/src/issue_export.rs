@@ -0,0 +1,266 @@
+//! Issue export formats.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde_json::{Map, Value};
+use std::path::Path;
+
+use crate::config_loader::load_project_configuration;
+use crate::error::KanbusError;
+use crate::file_io::get_configuration_path;
+use crate::issue_listing::{list_issues, ListOptions};
+use crate::models::IssueData;
+
+/// Custom field holding an issue due date.
+pub const DUE_FIELD: &str = "due";
+
+/// Maximum octets on one physical iCalendar line before it is folded.
+const ICAL_LINE_OCTETS: usize = 75;
+
+/// Columns written by the CSV export, in order.
+pub const CSV_COLUMNS: [&str; 8] = [
+    "id",
+    "title",
+    "type",
+    "status",
+    "priority",
+    "assignee",
+    "created_at",
+    "updated_at",
+];
+
+/// Export issues in the requested format.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `output_format` - Export format (csv, ical, json).
+/// * `flatten_custom` - Hoist custom fields to top-level keys in JSON output.
+/// * `status` - Status filter.
+/// * `issue_type` - Type filter.
+/// * `assignee` - Assignee filter.
+///
+/// # Errors
+/// Returns `KanbusError` if issues cannot be loaded or the format is unsupported.
+pub fn export_issues(
+    root: &Path,
+    output_format: &str,
+    flatten_custom: bool,
+    status: Option<&str>,
+    issue_type: Option<&str>,
+    assignee: Option<&str>,
+) -> Result<String, KanbusError> {
+    let config_path = get_configuration_path(root)?;
+    let configuration = load_project_configuration(&config_path)?;
+    let issues = list_issues(
+        root,
+        &ListOptions {
+            status,
+            issue_type,
+            assignee,
+            ..ListOptions::default()
+        },
+    )?;
+    match output_format {
+        "csv" => Ok(render_csv(&issues)),
+        "ical" => render_ical(&issues, configuration.time_zone.as_deref()),
+        "json" => render_json(&issues, flatten_custom),
+        _ => Err(KanbusError::IssueOperation(format!(
+            "unsupported export format: {output_format}"
+        ))),
+    }
+}
+
+/// Render issues as CSV with a header row of [`CSV_COLUMNS`].
+///
+/// Fields containing commas, quotes, or line breaks are quoted, with inner
+/// quotes doubled. Rows are separated by CRLF as RFC 4180 specifies.
+///
+/// # Arguments
+/// * `issues` - Issues to export.
+pub fn render_csv(issues: &[IssueData]) -> String {
+    let mut lines = vec![CSV_COLUMNS.join(",")];
+    for issue in issues {
+        let fields = [
+            issue.identifier.clone(),
+            issue.title.clone(),
+            issue.issue_type.clone(),
+            issue.status.clone(),
+            issue.priority.to_string(),
+            issue.assignee.clone().unwrap_or_default(),
+            issue.created_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            issue.updated_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+        ];
+        lines.push(
+            fields
+                .iter()
+                .map(|field| escape_csv_field(field))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+    }
+    lines.join("\r\n")
+}
+
+fn escape_csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render issues as a pretty-printed JSON array.
+///
+/// With `flatten_custom`, each `custom` entry becomes a top-level
+/// `custom_<key>` field; names that collide with existing fields gain
+/// further `custom_` prefixes.
+///
+/// # Arguments
+/// * `issues` - Issues to export.
+/// * `flatten_custom` - Whether to hoist custom fields.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if serialization fails.
+pub fn render_json(issues: &[IssueData], flatten_custom: bool) -> Result<String, KanbusError> {
+    let values = issues
+        .iter()
+        .map(|issue| {
+            let mut value =
+                serde_json::to_value(issue).map_err(|error| KanbusError::Io(error.to_string()))?;
+            if flatten_custom {
+                if let Some(object) = value.as_object_mut() {
+                    flatten_custom_fields(object);
+                }
+            }
+            Ok(value)
+        })
+        .collect::<Result<Vec<_>, KanbusError>>()?;
+    serde_json::to_string_pretty(&values).map_err(|error| KanbusError::Io(error.to_string()))
+}
+
+fn flatten_custom_fields(object: &mut Map<String, Value>) {
+    let Some(Value::Object(custom)) = object.remove("custom") else {
+        return;
+    };
+    for (key, value) in custom {
+        let mut name = format!("custom_{key}");
+        while object.contains_key(&name) {
+            name = format!("custom_{name}");
+        }
+        object.insert(name, value);
+    }
+}
+
+/// Render issues with a due date as an iCalendar feed of VTODO entries.
+///
+/// Due times are written in UTC, content lines are folded at 75 octets, and
+/// every line ends with CRLF, as RFC 5545 requires.
+///
+/// The due date comes from `due_at`, falling back to a parseable
+/// `custom.due` value; issues with neither are skipped.
+///
+/// # Arguments
+/// * `issues` - Issues to export.
+/// * `time_zone` - Optional IANA time zone used for due times without an offset.
+///
+/// # Errors
+/// Returns `KanbusError::Configuration` if the time zone is not recognized.
+pub fn render_ical(issues: &[IssueData], time_zone: Option<&str>) -> Result<String, KanbusError> {
+    let zone = time_zone
+        .map(|name| {
+            name.parse::<Tz>()
+                .map_err(|_| KanbusError::Configuration(format!("unknown time zone: {name}")))
+        })
+        .transpose()?;
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Kanbus//Kanbus//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    for issue in issues {
+        let due = match issue.due_at {
+            Some(due_at) => Some(format!("DUE:{}", format_utc(due_at))),
+            None => issue
+                .custom
+                .get(DUE_FIELD)
+                .and_then(|value| value.as_str())
+                .and_then(|value| format_due(value, zone)),
+        };
+        let Some(due) = due else {
+            continue;
+        };
+        let status = if issue.closed_at.is_some() {
+            "COMPLETED"
+        } else {
+            "NEEDS-ACTION"
+        };
+        lines.push("BEGIN:VTODO".to_string());
+        lines.push(format!("UID:{}@kanbus", issue.identifier));
+        lines.push(format!("DTSTAMP:{}", format_utc(issue.updated_at)));
+        lines.push(format!("SUMMARY:{}", escape_text(&issue.title)));
+        if !issue.description.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", escape_text(&issue.description)));
+        }
+        lines.push(due);
+        lines.push(format!("STATUS:{status}"));
+        lines.push("END:VTODO".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+    Ok(lines
+        .iter()
+        .map(|line| format!("{}\r\n", fold_line(line)))
+        .collect())
+}
+
+/// Fold a content line so no physical line exceeds 75 octets, continuing
+/// on lines that start with a space and never splitting a character.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut width = 0;
+    for character in line.chars() {
+        let octets = character.len_utf8();
+        if width + octets > ICAL_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(character);
+        width += octets;
+    }
+    folded
+}
+
+fn format_due(value: &str, zone: Option<Tz>) -> Option<String> {
+    let trimmed = value.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
+        return Some(format!("DUE;VALUE=DATE:{}", date.format("%Y%m%d")));
+    }
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(trimmed) {
+        return Some(format!("DUE:{}", format_utc(timestamp.with_timezone(&Utc))));
+    }
+    let naive = NaiveDateTime::parse_from_str(trimmed, "%Y-%m-%dT%H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(trimmed, "%Y-%m-%d %H:%M"))
+        .ok()?;
+    let utc = match zone {
+        Some(tz) => tz
+            .from_local_datetime(&naive)
+            .earliest()?
+            .with_timezone(&Utc),
+        None => Utc.from_utc_datetime(&naive),
+    };
+    Some(format!("DUE:{}", format_utc(utc)))
+}
+
+fn format_utc(timestamp: DateTime<Utc>) -> String {
+    timestamp.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
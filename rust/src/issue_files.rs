@@ -5,9 +5,107 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::error::KanbusError;
+use crate::file_io::{is_ignored_path, load_ignore_patterns};
 use crate::models::IssueData;
 
-/// List issue identifiers based on JSON filenames.
+/// Issue file layout: all files directly under the issues directory.
+pub const ISSUE_LAYOUT_FLAT: &str = "flat";
+/// Issue file layout: files grouped into one subdirectory per issue type.
+pub const ISSUE_LAYOUT_BY_TYPE: &str = "by-type";
+/// Issue file layout: files grouped into one subdirectory per parent issue.
+pub const ISSUE_LAYOUT_BY_PARENT: &str = "by-parent";
+/// Supported values for the `issue_layout` configuration field.
+pub const ISSUE_LAYOUTS: [&str; 3] = [
+    ISSUE_LAYOUT_FLAT,
+    ISSUE_LAYOUT_BY_TYPE,
+    ISSUE_LAYOUT_BY_PARENT,
+];
+/// Issue file format: pretty-printed JSON in `.json` files.
+pub const ISSUE_FORMAT_JSON: &str = "json";
+/// Issue file format: YAML in `.yml` files.
+pub const ISSUE_FORMAT_YAML: &str = "yaml";
+/// Supported values for the `issue_format` configuration field.
+pub const ISSUE_FORMATS: [&str; 2] = [ISSUE_FORMAT_JSON, ISSUE_FORMAT_YAML];
+
+const JSON_EXTENSION: &str = "json";
+const YAML_EXTENSION: &str = "yml";
+
+/// Return the file extension used for new issues in a format.
+///
+/// # Arguments
+/// * `format` - Configured issue format.
+pub fn issue_file_extension(format: &str) -> &'static str {
+    if format == ISSUE_FORMAT_YAML {
+        YAML_EXTENSION
+    } else {
+        JSON_EXTENSION
+    }
+}
+
+/// Return the extension of an existing issue file, so moves keep its format.
+///
+/// # Arguments
+/// * `issue_path` - Path to the issue file.
+pub fn existing_issue_extension(issue_path: &Path) -> &'static str {
+    if is_yaml_issue_file(issue_path) {
+        YAML_EXTENSION
+    } else {
+        JSON_EXTENSION
+    }
+}
+
+/// Check whether a path names a JSON or YAML issue file.
+///
+/// # Arguments
+/// * `path` - Candidate file path.
+pub fn is_issue_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some(JSON_EXTENSION | YAML_EXTENSION)
+    )
+}
+
+/// Check whether a path names a YAML issue file.
+///
+/// # Arguments
+/// * `path` - Candidate file path.
+pub fn is_yaml_issue_file(path: &Path) -> bool {
+    path.extension().and_then(|ext| ext.to_str()) == Some(YAML_EXTENSION)
+}
+
+/// List issue JSON and YAML files under an issues directory, including nested layouts.
+///
+/// Files matched by the `.kanbusignore` patterns of the enclosing project
+/// directory are skipped.
+///
+/// # Arguments
+/// * `issues_directory` - Directory containing issue files.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if directory entries cannot be read.
+pub fn list_issue_files(issues_directory: &Path) -> Result<Vec<PathBuf>, KanbusError> {
+    let project_dir = issues_directory.parent().unwrap_or(issues_directory);
+    let ignore_patterns = load_ignore_patterns(project_dir)?;
+    let mut files = Vec::new();
+    let mut pending = vec![issues_directory.to_path_buf()];
+    while let Some(directory) = pending.pop() {
+        for entry in fs::read_dir(&directory).map_err(|error| KanbusError::Io(error.to_string()))? {
+            let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
+            let path = entry.path();
+            if path.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            if is_issue_file(&path) && !is_ignored_path(project_dir, &ignore_patterns, &path) {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// List issue identifiers based on issue filenames.
 ///
 /// # Arguments
 /// * `issues_directory` - Directory containing issue files.
@@ -16,14 +114,7 @@ use crate::models::IssueData;
 /// Returns `KanbusError::Io` if directory entries cannot be read.
 pub fn list_issue_identifiers(issues_directory: &Path) -> Result<HashSet<String>, KanbusError> {
     let mut identifiers = HashSet::new();
-    for entry in
-        fs::read_dir(issues_directory).map_err(|error| KanbusError::Io(error.to_string()))?
-    {
-        let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
-        let path = entry.path();
-        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
-            continue;
-        }
+    for path in list_issue_files(issues_directory)? {
         if let Some(stem) = path.file_stem().and_then(|name| name.to_str()) {
             identifiers.insert(stem.to_string());
         }
@@ -31,39 +122,187 @@ pub fn list_issue_identifiers(issues_directory: &Path) -> Result<HashSet<String>
     Ok(identifiers)
 }
 
-/// Read an issue from a JSON file.
+/// Locate an existing issue file by identifier regardless of layout.
+///
+/// # Arguments
+/// * `issues_directory` - Directory containing issue files.
+/// * `identifier` - Issue identifier.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if directory entries cannot be read.
+pub fn find_issue_file(
+    issues_directory: &Path,
+    identifier: &str,
+) -> Result<Option<PathBuf>, KanbusError> {
+    for extension in [JSON_EXTENSION, YAML_EXTENSION] {
+        let flat_path = issue_path_for_identifier(issues_directory, identifier, extension);
+        if flat_path.is_file() && !is_ignored_flat_path(issues_directory, &flat_path)? {
+            return Ok(Some(flat_path));
+        }
+    }
+    if !issues_directory.is_dir() {
+        return Ok(None);
+    }
+    Ok(list_issue_files(issues_directory)?
+        .into_iter()
+        .find(|path| path.file_stem().and_then(|name| name.to_str()) == Some(identifier)))
+}
+
+fn is_ignored_flat_path(issues_directory: &Path, path: &Path) -> Result<bool, KanbusError> {
+    let project_dir = issues_directory.parent().unwrap_or(issues_directory);
+    let ignore_patterns = load_ignore_patterns(project_dir)?;
+    Ok(is_ignored_path(project_dir, &ignore_patterns, path))
+}
+
+/// Resolve the path an issue should be stored at for a layout.
+///
+/// # Arguments
+/// * `issues_directory` - Directory containing issue files.
+/// * `issue` - Issue to place.
+/// * `layout` - Configured issue layout.
+/// * `extension` - Issue file extension.
+pub fn issue_path_for_layout(
+    issues_directory: &Path,
+    issue: &IssueData,
+    layout: &str,
+    extension: &str,
+) -> PathBuf {
+    let directory = match layout {
+        ISSUE_LAYOUT_BY_TYPE => issues_directory.join(&issue.issue_type),
+        ISSUE_LAYOUT_BY_PARENT => match issue.parent.as_deref() {
+            Some(parent) => issues_directory.join(parent),
+            None => issues_directory.to_path_buf(),
+        },
+        _ => issues_directory.to_path_buf(),
+    };
+    issue_path_for_identifier(&directory, &issue.identifier, extension)
+}
+
+/// Read an issue from a JSON or YAML file.
 ///
 /// # Arguments
-/// * `issue_path` - Path to the issue JSON file.
+/// * `issue_path` - Path to the issue file.
 ///
 /// # Errors
 /// Returns `KanbusError::Io` if reading or parsing fails.
 pub fn read_issue_from_file(issue_path: &Path) -> Result<IssueData, KanbusError> {
     let contents = fs::read(issue_path).map_err(|error| KanbusError::Io(error.to_string()))?;
-    let issue: IssueData =
-        serde_json::from_slice(&contents).map_err(|error| KanbusError::Io(error.to_string()))?;
-    Ok(issue)
+    parse_issue(issue_path, &contents)
 }
 
-/// Write an issue to a JSON file with pretty formatting.
+/// Parse issue file contents in the format implied by the file extension.
+///
+/// # Arguments
+/// * `issue_path` - Path the contents were read from.
+/// * `contents` - Raw file contents.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if parsing fails.
+pub fn parse_issue(issue_path: &Path, contents: &[u8]) -> Result<IssueData, KanbusError> {
+    if is_yaml_issue_file(issue_path) {
+        serde_yaml::from_slice(contents).map_err(|error| KanbusError::Io(error.to_string()))
+    } else {
+        serde_json::from_slice(contents).map_err(|error| KanbusError::Io(error.to_string()))
+    }
+}
+
+/// Serialize an issue into its canonical on-disk JSON form.
+///
+/// Fields are emitted in struct order with map keys sorted, text fields use
+/// LF line endings, and the output ends with a single trailing newline, so an
+/// unchanged issue always serializes to identical bytes.
 ///
 /// # Arguments
 /// * `issue` - Issue data to serialize.
-/// * `issue_path` - Path to the issue JSON file.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if serialization fails.
+pub fn serialize_issue(issue: &IssueData) -> Result<String, KanbusError> {
+    let contents = serde_json::to_string_pretty(&normalize_issue(issue))
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    Ok(format!("{contents}\n"))
+}
+
+/// Serialize an issue into its canonical on-disk YAML form.
+///
+/// # Arguments
+/// * `issue` - Issue data to serialize.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if serialization fails.
+pub fn serialize_issue_yaml(issue: &IssueData) -> Result<String, KanbusError> {
+    serde_yaml::to_string(&normalize_issue(issue))
+        .map_err(|error| KanbusError::Io(error.to_string()))
+}
+
+/// Write an issue in canonical form, as YAML for `.yml` paths and JSON otherwise.
+///
+/// # Arguments
+/// * `issue` - Issue data to serialize.
+/// * `issue_path` - Path to the issue file.
 ///
 /// # Errors
 /// Returns `KanbusError::Io` if writing fails.
 pub fn write_issue_to_file(issue: &IssueData, issue_path: &Path) -> Result<(), KanbusError> {
-    let contents =
-        serde_json::to_string_pretty(issue).map_err(|error| KanbusError::Io(error.to_string()))?;
+    let contents = if is_yaml_issue_file(issue_path) {
+        serialize_issue_yaml(issue)?
+    } else {
+        serialize_issue(issue)?
+    };
     fs::write(issue_path, contents).map_err(|error| KanbusError::Io(error.to_string()))
 }
 
+fn normalize_issue(issue: &IssueData) -> IssueData {
+    let mut normalized = issue.clone();
+    normalized.title = normalize_line_endings(&normalized.title);
+    normalized.description = normalize_line_endings(&normalized.description);
+    for comment in &mut normalized.comments {
+        comment.text = normalize_line_endings(&comment.text);
+    }
+    normalized
+}
+
+fn normalize_line_endings(text: &str) -> String {
+    text.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// Ensure the directory holding an issue file exists.
+///
+/// # Arguments
+/// * `issue_path` - Path to the issue file.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if the directory cannot be created.
+pub fn ensure_issue_directory(issue_path: &Path) -> Result<(), KanbusError> {
+    match issue_path.parent() {
+        Some(parent) => {
+            fs::create_dir_all(parent).map_err(|error| KanbusError::Io(error.to_string()))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Return the issues directory that contains an issue file in any layout.
+///
+/// # Arguments
+/// * `issue_path` - Path to the issue file.
+pub fn issues_directory_for_path(issue_path: &Path) -> Option<PathBuf> {
+    issue_path
+        .ancestors()
+        .find(|ancestor| ancestor.file_name().and_then(|name| name.to_str()) == Some("issues"))
+        .map(Path::to_path_buf)
+}
+
 /// Resolve an issue file path by identifier.
 ///
 /// # Arguments
 /// * `issues_directory` - Directory containing issue files.
 /// * `identifier` - Issue identifier.
-pub fn issue_path_for_identifier(issues_directory: &Path, identifier: &str) -> PathBuf {
-    issues_directory.join(format!("{identifier}.json"))
+/// * `extension` - Issue file extension.
+pub fn issue_path_for_identifier(
+    issues_directory: &Path,
+    identifier: &str,
+    extension: &str,
+) -> PathBuf {
+    issues_directory.join(format!("{identifier}.{extension}"))
 }
@@ -0,0 +1,87 @@
+//! Project-wide label management.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::error::KanbusError;
+use crate::issue_listing::{list_issues, ListOptions};
+use crate::issue_update::{update_issue, IssueUpdateRequest};
+
+/// Count how many issues carry each label.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+///
+/// # Returns
+/// Label and issue count pairs, most used first, ties sorted by name.
+///
+/// # Errors
+/// Returns `KanbusError` if issues cannot be listed.
+pub fn label_counts(root: &Path) -> Result<Vec<(String, usize)>, KanbusError> {
+    let issues = list_issues(root, &ListOptions::default())?;
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for issue in issues {
+        for label in issue.labels {
+            *counts.entry(label).or_default() += 1;
+        }
+    }
+    let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+    counts.sort_by(|left, right| right.1.cmp(&left.1).then_with(|| left.0.cmp(&right.0)));
+    Ok(counts)
+}
+
+/// Rename a label on every issue that carries it.
+///
+/// Each issue is updated through the regular update workflow, so events and
+/// notifications are recorded per issue. The new label takes the old one's
+/// place; an issue that already has it keeps a single copy.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `from` - Current label.
+/// * `to` - New label.
+///
+/// # Returns
+/// Identifiers of the issues that were relabeled.
+///
+/// # Errors
+/// Returns `KanbusError` if the new label is invalid or an update fails.
+pub fn rename_label(root: &Path, from: &str, to: &str) -> Result<Vec<String>, KanbusError> {
+    let to = to.trim();
+    if to.is_empty() {
+        return Err(KanbusError::IssueOperation(
+            "new label is required".to_string(),
+        ));
+    }
+    if to.contains(',') {
+        return Err(KanbusError::IssueOperation(
+            "labels cannot contain commas".to_string(),
+        ));
+    }
+    if from == to {
+        return Err(KanbusError::IssueOperation(
+            "source and target label are the same".to_string(),
+        ));
+    }
+    let issues = list_issues(root, &ListOptions::default())?;
+    let mut renamed = Vec::new();
+    for issue in issues {
+        if !issue.labels.iter().any(|label| label == from) {
+            continue;
+        }
+        let mut labels: Vec<&str> = Vec::new();
+        for label in &issue.labels {
+            let label = if label == from { to } else { label.as_str() };
+            if !labels.contains(&label) {
+                labels.push(label);
+            }
+        }
+        update_issue(&IssueUpdateRequest {
+            set_labels: Some(labels.join(",")),
+            ..IssueUpdateRequest::new(root, issue.identifier.clone())
+        })?;
+        renamed.push(issue.identifier);
+    }
+    renamed.sort();
+    Ok(renamed)
+}
@@ -2,9 +2,13 @@
 
 use owo_colors::{AnsiColors, OwoColorize};
 
+use crate::error::KanbusError;
 use crate::ids::format_issue_key;
 use crate::models::{IssueData, ProjectConfiguration};
 
+/// Fields accepted by grouped list output.
+pub const GROUP_BY_FIELDS: &[&str] = &["status", "category"];
+
 /// Column widths for list output.
 #[derive(Debug, Clone, Copy)]
 pub struct Widths {
@@ -140,6 +144,96 @@ pub fn format_issue_line(
     )
 }
 
+/// Render list lines grouped by status or category.
+///
+/// Groups follow the configured status or category order and each starts
+/// with a `name (count)` header painted in the status or category color.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if `group_by` is not supported.
+pub fn format_grouped_issue_lines(
+    issues: &[IssueData],
+    group_by: &str,
+    porcelain: bool,
+    project_context: bool,
+    configuration: Option<&ProjectConfiguration>,
+    use_color_override: Option<bool>,
+) -> Result<String, KanbusError> {
+    if !GROUP_BY_FIELDS.contains(&group_by) {
+        return Err(KanbusError::IssueOperation(format!(
+            "unsupported group-by field: {group_by} (expected one of: {})",
+            GROUP_BY_FIELDS.join(", ")
+        )));
+    }
+    let use_color = !porcelain && use_color_override.unwrap_or_else(should_use_color);
+    let statuses = configuration
+        .map(|config| config.statuses.as_slice())
+        .unwrap_or_default();
+    let group_name = |issue: &IssueData| -> String {
+        if group_by == "status" {
+            return issue.status.clone();
+        }
+        statuses
+            .iter()
+            .find(|status| status.key == issue.status)
+            .map(|status| status.category.clone())
+            .unwrap_or_else(|| "Uncategorized".to_string())
+    };
+    let mut order: Vec<String> = if group_by == "status" {
+        statuses.iter().map(|status| status.key.clone()).collect()
+    } else {
+        configuration
+            .map(|config| {
+                config
+                    .categories
+                    .iter()
+                    .map(|category| category.name.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    for issue in issues {
+        let name = group_name(issue);
+        if !order.contains(&name) {
+            order.push(name);
+        }
+    }
+
+    let widths = (!porcelain).then(|| compute_widths(issues, project_context));
+    let mut sections = Vec::new();
+    for name in order {
+        let members: Vec<&IssueData> = issues
+            .iter()
+            .filter(|issue| group_name(issue) == name)
+            .collect();
+        if members.is_empty() {
+            continue;
+        }
+        let color = if group_by == "status" {
+            status_color(&name, configuration)
+        } else {
+            category_color(&name, configuration)
+        };
+        let mut lines = vec![paint(
+            &format!("{name} ({})", members.len()),
+            color,
+            use_color,
+        )];
+        lines.extend(members.iter().map(|issue| {
+            format_issue_line(
+                issue,
+                widths.as_ref(),
+                porcelain,
+                project_context,
+                configuration,
+                Some(use_color),
+            )
+        }));
+        sections.push(lines.join("\n"));
+    }
+    Ok(sections.join("\n\n"))
+}
+
 fn should_use_color() -> bool {
     use std::io::IsTerminal;
     // Disable colors if NO_COLOR is set or if stdout is not a TTY
@@ -196,6 +290,16 @@ fn status_color(status: &str, configuration: Option<&ProjectConfiguration>) -> O
     })
 }
 
+fn category_color(
+    category: &str,
+    configuration: Option<&ProjectConfiguration>,
+) -> Option<AnsiColors> {
+    configuration
+        .and_then(|config| config.categories.iter().find(|c| c.name == category))
+        .and_then(|definition| definition.color.as_deref())
+        .and_then(parse_color)
+}
+
 fn priority_color(
     priority: i32,
     configuration: Option<&ProjectConfiguration>,
@@ -0,0 +1,127 @@
+//! External reference links on issues.
+
+use std::path::Path;
+
+use crate::error::KanbusError;
+use crate::event_history::{
+    build_update_events, events_dir_for_issue_path, now_timestamp, write_events_batch,
+};
+use crate::issue_files::write_issue_to_file;
+use crate::issue_lookup::{load_issue_from_project, IssueLookupResult};
+use crate::models::{ExternalLink, IssueData};
+use crate::users::get_current_user;
+
+/// Attach an external link to an issue.
+///
+/// Adding a URL that is already linked is a no-op.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `identifier` - Issue identifier.
+/// * `url` - Link URL.
+/// * `title` - Optional display title.
+///
+/// # Returns
+/// Updated issue data.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the URL is malformed.
+pub fn add_issue_link(
+    root: &Path,
+    identifier: &str,
+    url: &str,
+    title: Option<&str>,
+) -> Result<IssueData, KanbusError> {
+    validate_link_url(url)?;
+    let lookup = load_issue_from_project(root, identifier)?;
+    if lookup.issue.links.iter().any(|link| link.url == url) {
+        return Ok(lookup.issue);
+    }
+    let mut updated_issue = lookup.issue.clone();
+    updated_issue.links.push(ExternalLink {
+        url: url.to_string(),
+        title: title
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string),
+    });
+    persist_links(root, &lookup, updated_issue)
+}
+
+/// Remove an external link from an issue.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `identifier` - Issue identifier.
+/// * `url` - Link URL to remove.
+///
+/// # Returns
+/// Updated issue data.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the issue has no such link.
+pub fn remove_issue_link(
+    root: &Path,
+    identifier: &str,
+    url: &str,
+) -> Result<IssueData, KanbusError> {
+    let lookup = load_issue_from_project(root, identifier)?;
+    if !lookup.issue.links.iter().any(|link| link.url == url) {
+        return Err(KanbusError::IssueOperation(format!(
+            "link not found: {url}"
+        )));
+    }
+    let mut updated_issue = lookup.issue.clone();
+    updated_issue.links.retain(|link| link.url != url);
+    persist_links(root, &lookup, updated_issue)
+}
+
+fn validate_link_url(url: &str) -> Result<(), KanbusError> {
+    let valid = url.split_once("://").is_some_and(|(scheme, rest)| {
+        !scheme.is_empty()
+            && scheme
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || "+-.".contains(character))
+            && !rest.is_empty()
+    }) && !url.chars().any(char::is_whitespace);
+    if valid {
+        Ok(())
+    } else {
+        Err(KanbusError::IssueOperation(format!(
+            "invalid link url: {url}"
+        )))
+    }
+}
+
+fn persist_links(
+    root: &Path,
+    lookup: &IssueLookupResult,
+    mut updated_issue: IssueData,
+) -> Result<IssueData, KanbusError> {
+    updated_issue.updated_at = chrono::Utc::now();
+    write_issue_to_file(&updated_issue, &lookup.issue_path)?;
+
+    let events = build_update_events(
+        &lookup.issue,
+        &updated_issue,
+        &get_current_user(),
+        &now_timestamp(),
+    );
+    let events_dir = events_dir_for_issue_path(&lookup.project_dir, &lookup.issue_path)?;
+    if let Err(error) = write_events_batch(&events_dir, &events) {
+        write_issue_to_file(&lookup.issue, &lookup.issue_path)?;
+        return Err(error);
+    }
+
+    use crate::notification_events::NotificationEvent;
+    use crate::notification_publisher::publish_notification;
+    let _ = publish_notification(
+        root,
+        NotificationEvent::IssueUpdated {
+            issue_id: updated_issue.identifier.clone(),
+            fields_changed: vec!["links".to_string()],
+            issue_data: updated_issue.clone(),
+        },
+    );
+    Ok(updated_issue)
+}
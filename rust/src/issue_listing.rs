@@ -2,57 +2,93 @@
 
 use std::io::ErrorKind;
 use std::path::Path;
+use std::process::Command;
 
 use crate::config_loader::load_project_configuration;
 use crate::daemon_client::{is_daemon_enabled, request_index_list};
 use crate::error::KanbusError;
 use crate::file_io::{
     canonicalize_path, discover_kanbus_projects, discover_project_directories,
-    find_project_local_directory, get_configuration_path, load_project_directory,
-    resolve_labeled_projects,
+    ensure_git_repository, find_project_local_directory, get_configuration_path,
+    load_project_directory, resolve_labeled_projects,
 };
+use crate::index::{issue_file_cache_path, load_issues_with_cache};
+use crate::issue_files::{is_issue_file, list_issue_files};
 use crate::models::IssueData;
-use crate::queries::{filter_issues, search_issues, sort_issues};
+use crate::queries::{
+    filter_issues, search_issues, sort_issues, IssueFilter, PRIORITY_ORDER_ASCENDING,
+};
 use std::collections::HashSet;
 
+/// Options for `list_issues`.
+///
+/// The default lists every shared and local issue in the current projects,
+/// reusing the cache, with no filters.
+#[derive(Debug, Clone)]
+pub struct ListOptions<'a> {
+    /// Status filter.
+    pub status: Option<&'a str>,
+    /// Type filter.
+    pub issue_type: Option<&'a str>,
+    /// Assignee filter.
+    pub assignee: Option<&'a str>,
+    /// Label filter.
+    pub label: Option<&'a str>,
+    /// Sort key.
+    pub sort: Option<&'a str>,
+    /// Full-text search query.
+    pub search: Option<&'a str>,
+    /// Project labels to list; empty lists the discovered projects.
+    pub project_filter: &'a [String],
+    /// Include project-local issues.
+    pub include_local: bool,
+    /// List only project-local issues.
+    pub local_only: bool,
+    /// Reuse cached parses of unchanged issue files and the daemon; when
+    /// false every issue file is read from disk.
+    pub use_cache: bool,
+}
+
+impl Default for ListOptions<'_> {
+    fn default() -> Self {
+        Self {
+            status: None,
+            issue_type: None,
+            assignee: None,
+            label: None,
+            sort: None,
+            search: None,
+            project_filter: &[],
+            include_local: true,
+            local_only: false,
+            use_cache: true,
+        }
+    }
+}
+
 /// List issues for the project.
 ///
 /// # Arguments
 /// * `root` - Repository root path.
+/// * `options` - Filters, sort order, and sources to list.
 ///
 /// # Errors
 /// Returns `KanbusError` when listing fails.
-#[allow(clippy::too_many_arguments)]
-pub fn list_issues(
-    root: &Path,
-    status: Option<&str>,
-    issue_type: Option<&str>,
-    assignee: Option<&str>,
-    label: Option<&str>,
-    sort: Option<&str>,
-    search: Option<&str>,
-    project_filter: &[String],
-    include_local: bool,
-    local_only: bool,
-) -> Result<Vec<IssueData>, KanbusError> {
+pub fn list_issues(root: &Path, options: &ListOptions) -> Result<Vec<IssueData>, KanbusError> {
+    let ListOptions {
+        project_filter,
+        include_local,
+        local_only,
+        use_cache,
+        ..
+    } = *options;
     if local_only && !include_local {
         return Err(KanbusError::IssueOperation(
             "local-only conflicts with no-local".to_string(),
         ));
     }
     if !project_filter.is_empty() {
-        return list_with_project_filter(
-            root,
-            project_filter,
-            status,
-            issue_type,
-            assignee,
-            label,
-            sort,
-            search,
-            include_local,
-            local_only,
-        );
+        return list_with_project_filter(root, options);
     }
     let mut projects = Vec::new();
     discover_project_directories(root, &mut projects)?;
@@ -67,12 +103,14 @@ pub fn list_issues(
     }
     normalized.sort();
     normalized.dedup();
+    let mut priority_order = PRIORITY_ORDER_ASCENDING.to_string();
     if let Ok(config_path) = get_configuration_path(root) {
         if let Ok(configuration) = load_project_configuration(&config_path) {
             let base = config_path.parent().unwrap_or_else(|| Path::new(""));
             normalized.retain(|project_path| {
                 !crate::file_io::is_path_ignored(project_path, base, &configuration.ignore_paths)
             });
+            priority_order = configuration.priority_order;
         }
     }
     let mut permission_error = None;
@@ -98,14 +136,15 @@ pub fn list_issues(
         ));
     }
     if projects.len() > 1 {
-        let issues = list_issues_across_projects(root, &projects, include_local, local_only)?;
-        return apply_query(issues, status, issue_type, assignee, label, sort, search);
+        let issues =
+            list_issues_across_projects(root, &projects, include_local, local_only, use_cache)?;
+        return apply_query(issues, options, &priority_order);
     }
 
     if include_local || local_only {
         let project_dir = load_project_directory(root)?;
         let local_dir = find_project_local_directory(&project_dir);
-        if !local_only && is_daemon_enabled() {
+        if !local_only && use_cache && is_daemon_enabled() {
             let payloads = request_index_list(root)?;
             let mut issues: Vec<IssueData> = payloads
                 .into_iter()
@@ -118,37 +157,137 @@ pub fn list_issues(
                     issues.extend(load_issues_from_directory(&local_issues_dir)?);
                 }
             }
-            return apply_query(issues, status, issue_type, assignee, label, sort, search);
+            return apply_query(issues, options, &priority_order);
         }
-        let issues = list_issues_with_local(&project_dir, local_dir.as_deref(), local_only)?;
-        return apply_query(issues, status, issue_type, assignee, label, sort, search);
+        let issues =
+            list_issues_with_local(&project_dir, local_dir.as_deref(), local_only, use_cache)?;
+        return apply_query(issues, options, &priority_order);
     }
-    if is_daemon_enabled() {
+    if use_cache && is_daemon_enabled() {
         let payloads = request_index_list(root)?;
         let issues: Vec<IssueData> = payloads
             .into_iter()
             .map(serde_json::from_value::<IssueData>)
             .map(|result| result.map_err(|error| KanbusError::Io(error.to_string())))
             .collect::<Result<Vec<IssueData>, KanbusError>>()?;
-        return apply_query(issues, status, issue_type, assignee, label, sort, search);
+        return apply_query(issues, options, &priority_order);
+    }
+    let issues = list_issues_local(root, use_cache)?;
+    apply_query(issues, options, &priority_order)
+}
+
+/// Collect identifiers of issues whose files have uncommitted git changes.
+///
+/// Modified, added, renamed, and untracked files in the project issues
+/// directory are included.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+///
+/// # Errors
+/// Returns `KanbusError::Initialization` if the project is not inside a git
+/// repository, or `KanbusError::Io` if git cannot be run.
+pub fn list_git_modified_issue_identifiers(root: &Path) -> Result<HashSet<String>, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    ensure_git_repository(&project_dir)?;
+    let stdout = run_git(
+        &project_dir,
+        &[
+            "status",
+            "--porcelain",
+            "-z",
+            "--untracked-files=all",
+            "--",
+            "issues",
+        ],
+    )?;
+
+    let mut identifiers = HashSet::new();
+    let mut entries = stdout.split('\0').filter(|entry| !entry.is_empty());
+    while let Some(entry) = entries.next() {
+        let Some((code, path)) = entry.split_at_checked(3) else {
+            continue;
+        };
+        // Renames and copies are followed by the original path.
+        if code.starts_with('R') || code.starts_with('C') {
+            entries.next();
+        }
+        identifiers.extend(issue_identifier_from_path(path));
+    }
+    Ok(identifiers)
+}
+
+/// Collect identifiers of issues whose files changed since a git revision.
+///
+/// Compares the working tree against `reference`, so issues added, modified,
+/// or deleted after that commit are included.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `reference` - Git revision such as a tag, branch, or commit hash.
+///
+/// # Errors
+/// Returns `KanbusError::Initialization` if the project is not inside a git
+/// repository, or `KanbusError::IssueOperation` if the revision is unknown.
+pub fn list_issue_identifiers_changed_since(
+    root: &Path,
+    reference: &str,
+) -> Result<HashSet<String>, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    ensure_git_repository(&project_dir)?;
+    let revision = format!("{reference}^{{commit}}");
+    if reference.starts_with('-')
+        || run_git(
+            &project_dir,
+            &["rev-parse", "--verify", "--quiet", &revision],
+        )
+        .is_err()
+    {
+        return Err(KanbusError::IssueOperation(format!(
+            "unknown git revision: {reference}"
+        )));
+    }
+    let stdout = run_git(
+        &project_dir,
+        &["diff", "--name-only", "-z", reference, "--", "issues"],
+    )?;
+    Ok(stdout
+        .split('\0')
+        .filter_map(issue_identifier_from_path)
+        .collect())
+}
+
+fn run_git(project_dir: &Path, args: &[&str]) -> Result<String, KanbusError> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(project_dir)
+        .output()
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    if !output.status.success() {
+        return Err(KanbusError::Io(format!(
+            "git {} failed: {}",
+            args[0],
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
     }
-    let issues = list_issues_local(root)?;
-    apply_query(issues, status, issue_type, assignee, label, sort, search)
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn issue_identifier_from_path(path: &str) -> Option<String> {
+    let path = Path::new(path);
+    if !is_issue_file(path) {
+        return None;
+    }
+    path.file_stem()
+        .and_then(|value| value.to_str())
+        .map(str::to_string)
 }
 
-#[allow(clippy::too_many_arguments)]
 fn list_with_project_filter(
     root: &Path,
-    project_filter: &[String],
-    status: Option<&str>,
-    issue_type: Option<&str>,
-    assignee: Option<&str>,
-    label: Option<&str>,
-    sort: Option<&str>,
-    search: Option<&str>,
-    include_local: bool,
-    local_only: bool,
+    options: &ListOptions,
 ) -> Result<Vec<IssueData>, KanbusError> {
+    let project_filter = options.project_filter;
     let labeled = resolve_labeled_projects(root)?;
     if labeled.is_empty() {
         return Err(KanbusError::IssueOperation(
@@ -169,16 +308,29 @@ fn list_with_project_filter(
         .filter(|p| allowed.contains(p.label.as_str()))
         .map(|p| p.project_dir)
         .collect();
-    let issues = list_issues_across_projects(root, &project_dirs, include_local, local_only)?;
-    apply_query(issues, status, issue_type, assignee, label, sort, search)
+    let issues = list_issues_across_projects(
+        root,
+        &project_dirs,
+        options.include_local,
+        options.local_only,
+        options.use_cache,
+    )?;
+    let priority_order = get_configuration_path(root)
+        .and_then(|path| load_project_configuration(&path))
+        .map(|configuration| configuration.priority_order)
+        .unwrap_or_else(|_| PRIORITY_ORDER_ASCENDING.to_string());
+    apply_query(issues, options, &priority_order)
 }
 
-fn list_issues_local(root: &Path) -> Result<Vec<IssueData>, KanbusError> {
+fn list_issues_local(root: &Path, use_cache: bool) -> Result<Vec<IssueData>, KanbusError> {
     let project_dir = load_project_directory(root)?;
-    list_issues_for_project(&project_dir)
+    list_issues_for_project(&project_dir, use_cache)
 }
 
-fn list_issues_for_project(project_dir: &Path) -> Result<Vec<IssueData>, KanbusError> {
+fn list_issues_for_project(
+    project_dir: &Path,
+    use_cache: bool,
+) -> Result<Vec<IssueData>, KanbusError> {
     let issues_dir = project_dir.join("issues");
     if !issues_dir.is_dir() {
         return Err(KanbusError::IssueOperation(format!(
@@ -186,6 +338,9 @@ fn list_issues_for_project(project_dir: &Path) -> Result<Vec<IssueData>, KanbusE
             issues_dir.display()
         )));
     }
+    if use_cache {
+        return load_issues_with_cache(&issues_dir, &issue_file_cache_path(project_dir));
+    }
     load_issues_from_directory(&issues_dir)
 }
 
@@ -193,13 +348,14 @@ fn list_issues_with_local(
     project_dir: &Path,
     local_dir: Option<&Path>,
     local_only: bool,
+    use_cache: bool,
 ) -> Result<Vec<IssueData>, KanbusError> {
     if std::env::var("KANBUS_TEST_LOCAL_LISTING_ERROR").is_ok() {
         return Err(KanbusError::IssueOperation(
             "local listing failed".to_string(),
         ));
     }
-    let shared_issues = list_issues_for_project(project_dir)?;
+    let shared_issues = list_issues_for_project(project_dir, use_cache)?;
     let mut local_issues = Vec::new();
     if let Some(local_dir) = local_dir {
         let issues_dir = local_dir.join("issues");
@@ -218,6 +374,7 @@ fn list_issues_across_projects(
     projects: &[std::path::PathBuf],
     include_local: bool,
     local_only: bool,
+    use_cache: bool,
 ) -> Result<Vec<IssueData>, KanbusError> {
     let mut issues = Vec::new();
     for project_dir in projects {
@@ -234,7 +391,7 @@ fn list_issues_across_projects(
             continue;
         }
         let mut project_issues =
-            list_issues_with_local(project_dir, local_dir.as_deref(), local_only)?;
+            list_issues_with_local(project_dir, local_dir.as_deref(), local_only, use_cache)?;
         for issue in &mut project_issues {
             tag_issue_project(issue, root, project_dir);
         }
@@ -256,14 +413,7 @@ fn tag_issue_project(issue: &mut IssueData, root: &Path, project_dir: &Path) {
 
 fn load_issues_from_directory(issues_dir: &Path) -> Result<Vec<IssueData>, KanbusError> {
     let mut issues = Vec::new();
-    for entry in
-        std::fs::read_dir(issues_dir).map_err(|error| KanbusError::Io(error.to_string()))?
-    {
-        let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
-        let path = entry.path();
-        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
-            continue;
-        }
+    for path in list_issue_files(issues_dir)? {
         issues.push(crate::issue_files::read_issue_from_file(&path)?);
     }
     issues.sort_by(|left, right| left.identifier.cmp(&right.identifier));
@@ -272,14 +422,19 @@ fn load_issues_from_directory(issues_dir: &Path) -> Result<Vec<IssueData>, Kanbu
 
 fn apply_query(
     issues: Vec<IssueData>,
-    status: Option<&str>,
-    issue_type: Option<&str>,
-    assignee: Option<&str>,
-    label: Option<&str>,
-    sort: Option<&str>,
-    search: Option<&str>,
+    options: &ListOptions,
+    priority_order: &str,
 ) -> Result<Vec<IssueData>, KanbusError> {
-    let filtered = filter_issues(issues, status, issue_type, assignee, label);
-    let searched = search_issues(filtered, search);
-    sort_issues(searched, sort)
+    let filtered = filter_issues(
+        issues,
+        &IssueFilter {
+            status: options.status,
+            issue_type: options.issue_type,
+            assignee: options.assignee,
+            label: options.label,
+            ..IssueFilter::default()
+        },
+    );
+    let searched = search_issues(filtered, options.search);
+    sort_issues(searched, options.sort, priority_order)
 }
@@ -1,15 +1,17 @@
 //! Issue lookup helpers for project directories.
 
-use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::error::KanbusError;
 use crate::file_io::find_project_local_directory;
 use crate::ids::format_issue_key;
-use crate::issue_files::{issue_path_for_identifier, read_issue_from_file};
+use crate::issue_files::{find_issue_file, list_issue_files, read_issue_from_file};
 use crate::models::IssueData;
 use crate::project::discover_project_directories;
 
+/// Identifier token resolving to the most recently updated issue.
+pub const LAST_ISSUE_TOKEN: &str = "@last";
+
 /// Issue lookup result.
 #[derive(Debug)]
 pub struct IssueLookupResult {
@@ -36,13 +38,34 @@ pub fn load_issue_from_project(
             "project not initialized".to_string(),
         ));
     }
+    let resolved = resolve_identifier(root, identifier)?;
+    find_issue_in_projects(&project_dirs, &resolved)
+}
 
+/// Load an issue by identifier from one project directory.
+///
+/// # Arguments
+/// * `project_dir` - Project directory to search, including its project-local issues.
+/// * `identifier` - Issue identifier (full or abbreviated).
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the issue cannot be found.
+pub fn load_issue_from_project_directory(
+    project_dir: &Path,
+    identifier: &str,
+) -> Result<IssueLookupResult, KanbusError> {
+    find_issue_in_projects(&[project_dir.to_path_buf()], identifier)
+}
+
+fn find_issue_in_projects(
+    project_dirs: &[PathBuf],
+    identifier: &str,
+) -> Result<IssueLookupResult, KanbusError> {
     let mut all_matches: Vec<(String, PathBuf, PathBuf)> = Vec::new();
 
-    for project_dir in &project_dirs {
+    for project_dir in project_dirs {
         for issues_dir in search_directories(project_dir) {
-            let issue_path = issue_path_for_identifier(&issues_dir, identifier);
-            if issue_path.exists() {
+            if let Some(issue_path) = find_issue_file(&issues_dir, identifier)? {
                 let issue = read_issue_from_file(&issue_path)?;
                 return Ok(IssueLookupResult {
                     issue,
@@ -80,6 +103,51 @@ pub fn load_issue_from_project(
     }
 }
 
+/// Resolve relative identifier tokens such as `@last` to a concrete identifier.
+///
+/// Identifiers that are not relative tokens are returned unchanged.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `identifier` - Issue identifier or relative token.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if a token cannot be resolved.
+pub fn resolve_identifier(root: &Path, identifier: &str) -> Result<String, KanbusError> {
+    if identifier != LAST_ISSUE_TOKEN {
+        return Ok(identifier.to_string());
+    }
+    let mut issues = Vec::new();
+    for project_dir in discover_project_directories(root)? {
+        for issues_dir in search_directories(&project_dir) {
+            if !issues_dir.is_dir() {
+                continue;
+            }
+            for path in list_issue_files(&issues_dir)? {
+                issues.push(read_issue_from_file(&path)?);
+            }
+        }
+    }
+    most_recently_updated(&issues)
+        .map(|issue| issue.identifier.clone())
+        .ok_or_else(|| {
+            KanbusError::IssueOperation(format!("no issues to resolve {LAST_ISSUE_TOKEN}"))
+        })
+}
+
+/// Return the issue with the newest `updated_at` timestamp.
+///
+/// # Arguments
+/// * `issues` - Issues to inspect.
+pub fn most_recently_updated(issues: &[IssueData]) -> Option<&IssueData> {
+    issues.iter().max_by(|left, right| {
+        left.updated_at
+            .cmp(&right.updated_at)
+            .then_with(|| left.created_at.cmp(&right.created_at))
+            .then_with(|| right.identifier.cmp(&left.identifier))
+    })
+}
+
 /// Return issue directories to search for a given project directory.
 fn search_directories(project_dir: &Path) -> Vec<PathBuf> {
     let mut dirs = vec![project_dir.join("issues")];
@@ -106,20 +174,11 @@ fn find_matching_issues(
 ) -> Result<Vec<(String, PathBuf)>, KanbusError> {
     let mut matches = Vec::new();
 
-    let entries = fs::read_dir(issues_dir).map_err(|error| {
+    let paths = list_issue_files(issues_dir).map_err(|error| {
         KanbusError::IssueOperation(format!("cannot read issues directory: {error}"))
     })?;
 
-    for entry in entries {
-        let entry = entry.map_err(|error| {
-            KanbusError::IssueOperation(format!("cannot read directory entry: {error}"))
-        })?;
-
-        let path = entry.path();
-        if !path.is_file() || path.extension().and_then(|s| s.to_str()) != Some("json") {
-            continue;
-        }
-
+    for path in paths {
         let file_stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
 
         if issue_matches(identifier, file_stem) {
@@ -0,0 +1,99 @@
+//! Bulk reassignment of open issues.
+
+use std::path::Path;
+
+use crate::config_loader::load_project_configuration;
+use crate::error::KanbusError;
+use crate::file_io::{get_configuration_path, load_project_directory};
+use crate::ids::format_issue_key;
+use crate::issue_listing::{list_issues, ListOptions};
+use crate::issue_lookup::load_issue_from_project;
+use crate::issue_update::{update_issue, IssueUpdateRequest};
+use crate::workflows::is_terminal_status;
+
+/// Reassign every open issue from one assignee to another.
+///
+/// An issue is open unless its status is terminal (in the last configured
+/// category). Every matching issue is loaded and checked before any is
+/// changed, then each is updated through the regular update workflow, so
+/// events and notifications are recorded per issue.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `from` - Current assignee.
+/// * `to` - New assignee.
+/// * `dry_run` - Whether to report matching issues without changing them.
+///
+/// # Returns
+/// Identifiers of the issues that were (or would be) reassigned.
+///
+/// # Errors
+/// Returns `KanbusError` if listing or checking issues fails, or if an update
+/// fails; in that case the message names the issues already reassigned.
+pub fn reassign_issues(
+    root: &Path,
+    from: &str,
+    to: &str,
+    dry_run: bool,
+) -> Result<Vec<String>, KanbusError> {
+    if to.trim().is_empty() {
+        return Err(KanbusError::IssueOperation(
+            "new assignee is required".to_string(),
+        ));
+    }
+    if from == to {
+        return Err(KanbusError::IssueOperation(
+            "source and target assignee are the same".to_string(),
+        ));
+    }
+    let project_dir = load_project_directory(root)?;
+    let configuration =
+        load_project_configuration(&get_configuration_path(project_dir.as_path())?)?;
+    let issues = list_issues(
+        root,
+        &ListOptions {
+            assignee: Some(from),
+            ..ListOptions::default()
+        },
+    )?;
+    let identifiers: Vec<String> = issues
+        .into_iter()
+        .filter(|issue| !is_terminal_status(&configuration, &issue.status))
+        .map(|issue| issue.identifier)
+        .collect();
+    for identifier in &identifiers {
+        let lookup = load_issue_from_project(root, identifier)?;
+        if lookup.issue.assignee.as_deref() != Some(from) {
+            return Err(KanbusError::IssueOperation(format!(
+                "{} is no longer assigned to {from}",
+                format_issue_key(identifier, false)
+            )));
+        }
+    }
+    if dry_run {
+        return Ok(identifiers);
+    }
+    for (index, identifier) in identifiers.iter().enumerate() {
+        let result = update_issue(&IssueUpdateRequest {
+            assignee: Some(to.to_string()),
+            ..IssueUpdateRequest::new(root, identifier)
+        });
+        if let Err(error) = result {
+            let reassigned = identifiers[..index]
+                .iter()
+                .map(|identifier| format_issue_key(identifier, false))
+                .collect::<Vec<_>>();
+            let progress = if reassigned.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", reassigned.join(", "))
+            };
+            return Err(KanbusError::IssueOperation(format!(
+                "failed to reassign {}: {error}; reassigned {index} of {} issues{progress}",
+                format_issue_key(identifier, false),
+                identifiers.len()
+            )));
+        }
+    }
+    Ok(identifiers)
+}
@@ -0,0 +1,70 @@
+//! Issue reopen workflow.
+
+use std::path::Path;
+
+use crate::beads_write::update_beads_issue;
+use crate::config_loader::load_project_configuration;
+use crate::error::KanbusError;
+use crate::file_io::get_configuration_path;
+use crate::issue_lookup::load_issue_from_project;
+use crate::issue_update::{update_issue, IssueUpdateRequest};
+use crate::migration::load_beads_issue_by_id;
+use crate::models::IssueData;
+
+/// Reopen a closed issue by returning it to the configured initial status.
+///
+/// `closed_at` is cleared by the status transition.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `identifier` - Issue identifier.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the issue is not closed, or
+/// `KanbusError::InvalidTransition` if the workflow forbids reopening.
+pub fn reopen_issue(root: &Path, identifier: &str) -> Result<IssueData, KanbusError> {
+    let lookup = load_issue_from_project(root, identifier)?;
+    ensure_closed(&lookup.issue)?;
+    let configuration =
+        load_project_configuration(&get_configuration_path(lookup.project_dir.as_path())?)?;
+    update_issue(&IssueUpdateRequest {
+        status: Some(configuration.initial_status.clone()),
+        ..IssueUpdateRequest::new(root, lookup.issue.identifier.clone())
+    })
+}
+
+/// Reopen a closed Beads issue by returning it to `open`.
+///
+/// # Arguments
+/// * `root` - Directory containing `.beads`.
+/// * `identifier` - Beads issue identifier.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the issue is not closed.
+pub fn reopen_beads_issue(root: &Path, identifier: &str) -> Result<IssueData, KanbusError> {
+    let issue = load_beads_issue_by_id(root, identifier)?;
+    ensure_closed(&issue)?;
+    update_beads_issue(
+        root,
+        &issue.identifier,
+        Some("open"),
+        None,
+        None,
+        None,
+        None,
+        &[],
+        &[],
+        None,
+    )
+}
+
+fn ensure_closed(issue: &IssueData) -> Result<(), KanbusError> {
+    if issue.status == "closed" {
+        Ok(())
+    } else {
+        Err(KanbusError::IssueOperation(format!(
+            "issue is not closed (status: {})",
+            issue.status
+        )))
+    }
+}
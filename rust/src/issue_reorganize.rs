@@ -0,0 +1,91 @@
+//! Issue file layout migration.
+
+use std::fs;
+use std::path::Path;
+
+use crate::config_loader::load_project_configuration;
+use crate::error::KanbusError;
+use crate::file_io::{
+    find_project_local_directory, get_configuration_path, load_project_directory,
+};
+use crate::issue_files::{
+    ensure_issue_directory, existing_issue_extension, issue_path_for_layout, list_issue_files,
+    read_issue_from_file,
+};
+
+/// Result of reorganizing issue files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReorganizeResult {
+    pub moved_count: usize,
+}
+
+/// Move issue files so they match the configured `issue_layout`.
+///
+/// Shared and project-local issue directories are both reorganized. Empty
+/// subdirectories left behind by moved files are removed.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+///
+/// # Errors
+/// Returns `KanbusError` if configuration loading or file moves fail.
+pub fn reorganize_issues(root: &Path) -> Result<ReorganizeResult, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let configuration = load_project_configuration(&get_configuration_path(root)?)?;
+
+    let mut issues_dirs = vec![project_dir.join("issues")];
+    if let Some(local_dir) = find_project_local_directory(&project_dir) {
+        issues_dirs.push(local_dir.join("issues"));
+    }
+
+    let mut moved_count = 0;
+    for issues_dir in issues_dirs {
+        if !issues_dir.is_dir() {
+            continue;
+        }
+        for path in list_issue_files(&issues_dir)? {
+            let issue = read_issue_from_file(&path)?;
+            let target_path = issue_path_for_layout(
+                &issues_dir,
+                &issue,
+                &configuration.issue_layout,
+                existing_issue_extension(&path),
+            );
+            if target_path == path {
+                continue;
+            }
+            if target_path.exists() {
+                return Err(KanbusError::IssueOperation(format!(
+                    "cannot move {}: {} already exists",
+                    issue.identifier,
+                    target_path.display()
+                )));
+            }
+            ensure_issue_directory(&target_path)?;
+            fs::rename(&path, &target_path).map_err(|error| KanbusError::Io(error.to_string()))?;
+            moved_count += 1;
+        }
+        remove_empty_directories(&issues_dir)?;
+    }
+
+    Ok(ReorganizeResult { moved_count })
+}
+
+fn remove_empty_directories(issues_dir: &Path) -> Result<(), KanbusError> {
+    for entry in fs::read_dir(issues_dir).map_err(|error| KanbusError::Io(error.to_string()))? {
+        let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        remove_empty_directories(&path)?;
+        let is_empty = fs::read_dir(&path)
+            .map_err(|error| KanbusError::Io(error.to_string()))?
+            .next()
+            .is_none();
+        if is_empty {
+            fs::remove_dir(&path).map_err(|error| KanbusError::Io(error.to_string()))?;
+        }
+    }
+    Ok(())
+}
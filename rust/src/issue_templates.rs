@@ -0,0 +1,175 @@
+//! Reusable issue skeletons stored in the project `templates/` directory.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config_loader::load_project_configuration;
+use crate::error::KanbusError;
+use crate::file_io::{get_configuration_path, load_project_directory};
+use crate::issue_creation::{create_issue, IssueCreationRequest, IssueCreationResult};
+
+/// Issue fields captured by a template.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueTemplate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Save a template as `templates/<name>.json` in the project directory,
+/// replacing any template with the same name.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `name` - Template name.
+/// * `template` - Fields to capture.
+///
+/// # Returns
+/// Path of the written template file.
+///
+/// # Errors
+/// Returns `KanbusError` if the name is invalid or the file cannot be written.
+pub fn save_template(
+    root: &Path,
+    name: &str,
+    template: &IssueTemplate,
+) -> Result<PathBuf, KanbusError> {
+    let path = template_path(root, name)?;
+    let directory = path
+        .parent()
+        .ok_or_else(|| KanbusError::Io("template path unavailable".to_string()))?;
+    fs::create_dir_all(directory).map_err(|error| KanbusError::Io(error.to_string()))?;
+    let contents = serde_json::to_string_pretty(template)
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    fs::write(&path, format!("{contents}\n"))
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    Ok(path)
+}
+
+/// List the names of saved templates, sorted.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if the templates directory cannot be read.
+pub fn list_templates(root: &Path) -> Result<Vec<String>, KanbusError> {
+    let directory = load_project_directory(root)?.join("templates");
+    if !directory.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut names = Vec::new();
+    for entry in fs::read_dir(&directory).map_err(|error| KanbusError::Io(error.to_string()))? {
+        let path = entry
+            .map_err(|error| KanbusError::Io(error.to_string()))?
+            .path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+        if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+            names.push(stem.to_string());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Load a saved template by name.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `name` - Template name.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the template does not exist or
+/// cannot be parsed.
+pub fn load_template(root: &Path, name: &str) -> Result<IssueTemplate, KanbusError> {
+    let path = template_path(root, name)?;
+    if !path.is_file() {
+        return Err(KanbusError::IssueOperation(format!(
+            "template '{name}' not found"
+        )));
+    }
+    let contents = fs::read_to_string(&path).map_err(|error| KanbusError::Io(error.to_string()))?;
+    serde_json::from_str(&contents).map_err(|error| {
+        KanbusError::IssueOperation(format!("template '{name}' is invalid: {error}"))
+    })
+}
+
+/// Create an issue from a saved template.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `name` - Template name.
+/// * `title` - Title override; the template title is used when absent.
+///
+/// # Errors
+/// Returns `KanbusError` if the template is missing, names an issue type the
+/// project does not configure, or issue creation fails.
+pub fn apply_template(
+    root: &Path,
+    name: &str,
+    title: Option<&str>,
+) -> Result<IssueCreationResult, KanbusError> {
+    let template = load_template(root, name)?;
+    let title = title
+        .map(str::to_string)
+        .or_else(|| template.title.clone())
+        .filter(|title| !title.trim().is_empty())
+        .ok_or_else(|| KanbusError::IssueOperation("title is required".to_string()))?;
+    if let Some(issue_type) = template.issue_type.as_deref() {
+        let configuration = load_project_configuration(&get_configuration_path(root)?)?;
+        let is_known = configuration
+            .hierarchy
+            .iter()
+            .chain(configuration.types.iter())
+            .any(|entry| entry == issue_type);
+        if !is_known {
+            return Err(KanbusError::IssueOperation(format!(
+                "template '{name}' uses unknown issue type '{issue_type}'"
+            )));
+        }
+    }
+    create_issue(&IssueCreationRequest {
+        root: root.to_path_buf(),
+        title,
+        issue_type: template.issue_type,
+        priority: template.priority,
+        assignee: None,
+        parent: None,
+        priority_from_parent: false,
+        labels: template.labels,
+        description: template.description,
+        status: None,
+        local: false,
+        validate: true,
+        allow_duplicate_title: false,
+        due_at: None,
+        estimate_minutes: None,
+    })
+}
+
+fn template_path(root: &Path, name: &str) -> Result<PathBuf, KanbusError> {
+    let is_valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || "-_.".contains(character));
+    if !is_valid {
+        return Err(KanbusError::IssueOperation(format!(
+            "invalid template name '{name}'"
+        )));
+    }
+    Ok(load_project_directory(root)?
+        .join("templates")
+        .join(format!("{name}.json")))
+}
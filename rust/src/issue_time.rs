@@ -0,0 +1,101 @@
+//! Time tracking on issues.
+
+use std::path::Path;
+
+use chrono::Utc;
+
+use crate::error::KanbusError;
+use crate::event_history::{
+    build_update_events, comment_payload, events_dir_for_issue_path, now_timestamp,
+    write_events_batch, EventRecord, EventType,
+};
+use crate::issue_comment::{ensure_comment_ids, generate_comment_id};
+use crate::issue_files::write_issue_to_file;
+use crate::issue_lookup::load_issue_from_project;
+use crate::models::{IssueComment, IssueData};
+use crate::notification_events::NotificationEvent;
+use crate::notification_publisher::publish_notification;
+use crate::users::get_current_user;
+
+/// Record time spent on an issue.
+///
+/// Adds `minutes` to the issue's `spent_minutes` and appends a comment
+/// noting the logged time, so the comment timestamp records when the work
+/// was logged. Both changes are saved in a single write and announced with
+/// a single notification.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `identifier` - Issue identifier.
+/// * `minutes` - Minutes of work to log.
+///
+/// # Returns
+/// Updated issue data, including the new comment.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if `minutes` is zero or the issue
+/// cannot be updated.
+pub fn log_time(root: &Path, identifier: &str, minutes: u32) -> Result<IssueData, KanbusError> {
+    if minutes == 0 {
+        return Err(KanbusError::IssueOperation(
+            "minutes must be greater than zero".to_string(),
+        ));
+    }
+    let lookup = load_issue_from_project(root, identifier)?;
+    let actor_id = get_current_user();
+    let timestamp = Utc::now();
+    let comment_id = generate_comment_id();
+    let comment = IssueComment {
+        id: Some(comment_id.clone()),
+        author: actor_id.clone(),
+        text: format!("Logged {}", format_minutes(minutes)),
+        created_at: timestamp,
+        system: false,
+        resolved: false,
+        reply_to: None,
+    };
+    let (mut updated_issue, _) = ensure_comment_ids(&lookup.issue);
+    updated_issue.spent_minutes = updated_issue.spent_minutes.saturating_add(minutes);
+    let position = updated_issue
+        .comments
+        .iter()
+        .position(|existing| existing.created_at > comment.created_at)
+        .unwrap_or(updated_issue.comments.len());
+    updated_issue.comments.insert(position, comment);
+    updated_issue.updated_at = timestamp;
+    write_issue_to_file(&updated_issue, &lookup.issue_path)?;
+
+    let occurred_at = now_timestamp();
+    let mut events = build_update_events(&lookup.issue, &updated_issue, &actor_id, &occurred_at);
+    events.push(EventRecord::new(
+        updated_issue.identifier.clone(),
+        EventType::CommentAdded,
+        actor_id.clone(),
+        comment_payload(&comment_id, &actor_id),
+        occurred_at,
+    ));
+    let events_dir = events_dir_for_issue_path(&lookup.project_dir, &lookup.issue_path)?;
+    if let Err(error) = write_events_batch(&events_dir, &events) {
+        write_issue_to_file(&lookup.issue, &lookup.issue_path)?;
+        return Err(error);
+    }
+
+    let _ = publish_notification(
+        root,
+        NotificationEvent::IssueUpdated {
+            issue_id: updated_issue.identifier.clone(),
+            fields_changed: vec!["spent_minutes".to_string(), "comments".to_string()],
+            issue_data: updated_issue.clone(),
+        },
+    );
+    Ok(updated_issue)
+}
+
+/// Format a minute count for display, such as `1 minute` or `90 minutes`.
+pub fn format_minutes(minutes: u32) -> String {
+    if minutes == 1 {
+        "1 minute".to_string()
+    } else {
+        format!("{minutes} minutes")
+    }
+}
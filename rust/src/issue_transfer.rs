@@ -1,15 +1,21 @@
 //! Local and shared issue transfer helpers.
 
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+use crate::config_loader::load_project_configuration;
 use crate::error::KanbusError;
 use crate::event_history::{
     events_dir_for_local, events_dir_for_project, now_timestamp, transfer_payload,
     write_events_batch, EventRecord, EventType,
 };
-use crate::file_io::{ensure_project_local_directory, find_project_local_directory};
-use crate::issue_files::read_issue_from_file;
+use crate::file_io::{
+    ensure_project_local_directory, find_project_local_directory, get_configuration_path,
+};
+use crate::issue_files::{
+    ensure_issue_directory, existing_issue_extension, find_issue_file, issue_path_for_layout,
+    read_issue_from_file,
+};
 use crate::issue_lookup::load_issue_from_project;
 use crate::models::IssueData;
 use crate::users::get_current_user;
@@ -19,31 +25,41 @@ use crate::users::get_current_user;
 /// # Arguments
 /// * `root` - Repository root path.
 /// * `identifier` - Issue identifier.
+/// * `force` - Whether to replace a shared issue with the same identifier.
 ///
 /// # Errors
-/// Returns `KanbusError::IssueOperation` if promotion fails.
-pub fn promote_issue(root: &Path, identifier: &str) -> Result<IssueData, KanbusError> {
+/// Returns `KanbusError::IssueOperation` if promotion fails or the identifier
+/// already exists in shared and `force` is not set.
+pub fn promote_issue(root: &Path, identifier: &str, force: bool) -> Result<IssueData, KanbusError> {
     let lookup = load_issue_from_project(root, identifier)?;
     let project_dir = lookup.project_dir;
 
     let local_dir = find_project_local_directory(&project_dir)
         .ok_or_else(|| KanbusError::IssueOperation("project-local not initialized".to_string()))?;
 
-    let local_issue_path = local_dir.join("issues").join(format!("{identifier}.json"));
-    if !local_issue_path.exists() {
+    let Some(local_issue_path) = find_issue_file(&local_dir.join("issues"), identifier)? else {
         return Err(KanbusError::IssueOperation(
             "issue is not in project-local".to_string(),
         ));
-    }
+    };
 
-    let target_path = project_dir
-        .join("issues")
-        .join(format!("{identifier}.json"));
-    if target_path.exists() {
-        return Err(KanbusError::IssueOperation("already exists".to_string()));
+    let shared_issues_dir = project_dir.join("issues");
+    let conflicting_path = find_issue_file(&shared_issues_dir, identifier)?;
+    if conflicting_path.is_some() && !force {
+        return Err(KanbusError::IssueOperation(format!(
+            "promote conflict: {identifier} already exists in shared"
+        )));
     }
 
+    let configuration = load_project_configuration(&get_configuration_path(&project_dir)?)?;
     let issue = read_issue_from_file(&local_issue_path)?;
+    let target_path = issue_path_for_layout(
+        &shared_issues_dir,
+        &issue,
+        &configuration.issue_layout,
+        existing_issue_extension(&local_issue_path),
+    );
+    ensure_issue_directory(&target_path)?;
     fs::rename(&local_issue_path, &target_path)
         .map_err(|error| KanbusError::Io(error.to_string()))?;
 
@@ -65,6 +81,7 @@ pub fn promote_issue(root: &Path, identifier: &str) -> Result<IssueData, KanbusE
             return Err(error);
         }
     }
+    remove_replaced_issue_file(conflicting_path, &target_path)?;
     Ok(issue)
 }
 
@@ -73,28 +90,42 @@ pub fn promote_issue(root: &Path, identifier: &str) -> Result<IssueData, KanbusE
 /// # Arguments
 /// * `root` - Repository root path.
 /// * `identifier` - Issue identifier.
+/// * `force` - Whether to replace a project-local issue with the same identifier.
 ///
 /// # Errors
-/// Returns `KanbusError::IssueOperation` if localization fails.
-pub fn localize_issue(root: &Path, identifier: &str) -> Result<IssueData, KanbusError> {
+/// Returns `KanbusError::IssueOperation` if localization fails or the
+/// identifier already exists in project-local and `force` is not set.
+pub fn localize_issue(
+    root: &Path,
+    identifier: &str,
+    force: bool,
+) -> Result<IssueData, KanbusError> {
     let lookup = load_issue_from_project(root, identifier)?;
     let project_dir = lookup.project_dir;
-    let shared_issue_path = project_dir
-        .join("issues")
-        .join(format!("{identifier}.json"));
-    if !shared_issue_path.exists() {
+    let Some(shared_issue_path) = find_issue_file(&project_dir.join("issues"), identifier)? else {
         return Err(KanbusError::IssueOperation(
             "issue is not in shared project".to_string(),
         ));
-    }
+    };
 
     let local_dir = ensure_project_local_directory(&project_dir)?;
-    let target_path = local_dir.join("issues").join(format!("{identifier}.json"));
-    if target_path.exists() {
-        return Err(KanbusError::IssueOperation("already exists".to_string()));
+    let local_issues_dir = local_dir.join("issues");
+    let conflicting_path = find_issue_file(&local_issues_dir, identifier)?;
+    if conflicting_path.is_some() && !force {
+        return Err(KanbusError::IssueOperation(format!(
+            "localize conflict: {identifier} already exists in project-local"
+        )));
     }
 
+    let configuration = load_project_configuration(&get_configuration_path(&project_dir)?)?;
     let issue = read_issue_from_file(&shared_issue_path)?;
+    let target_path = issue_path_for_layout(
+        &local_issues_dir,
+        &issue,
+        &configuration.issue_layout,
+        existing_issue_extension(&shared_issue_path),
+    );
+    ensure_issue_directory(&target_path)?;
     fs::rename(&shared_issue_path, &target_path)
         .map_err(|error| KanbusError::Io(error.to_string()))?;
 
@@ -123,5 +154,23 @@ pub fn localize_issue(root: &Path, identifier: &str) -> Result<IssueData, Kanbus
             return Err(error);
         }
     }
+    remove_replaced_issue_file(conflicting_path, &target_path)?;
     Ok(issue)
 }
+
+/// Remove a conflicting issue file that the transfer did not overwrite.
+///
+/// Forced transfers rename over the conflicting file instead of deleting it
+/// first, so a failed move leaves both copies in place. Only a conflict stored
+/// under a different layout or format is still on disk afterwards.
+fn remove_replaced_issue_file(
+    conflicting_path: Option<PathBuf>,
+    target_path: &Path,
+) -> Result<(), KanbusError> {
+    match conflicting_path {
+        Some(path) if path != target_path => {
+            fs::remove_file(&path).map_err(|error| KanbusError::Io(error.to_string()))
+        }
+        _ => Ok(()),
+    }
+}
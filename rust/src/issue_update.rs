@@ -1,53 +1,112 @@
 //! Issue update workflow.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::config_loader::load_project_configuration;
 use crate::error::KanbusError;
 use crate::event_history::{
-    build_update_events, events_dir_for_issue_path, now_timestamp, write_events_batch,
+    build_update_events, comment_payload, events_dir_for_issue_path, now_timestamp, touch_payload,
+    write_events_batch, EventRecord, EventType,
 };
 use crate::file_io::get_configuration_path;
-use crate::issue_creation::resolve_issue_identifier;
-use crate::issue_files::{read_issue_from_file, write_issue_to_file};
+use crate::ids::identifier_prefixes;
+use crate::issue_comment::generate_comment_id;
+use crate::issue_creation::{resolve_issue_identifier, validate_description_length};
+use crate::issue_files::{
+    ensure_issue_directory, existing_issue_extension, find_issue_file, issue_path_for_layout,
+    issues_directory_for_path, list_issue_files, read_issue_from_file, write_issue_to_file,
+};
 use crate::issue_lookup::load_issue_from_project;
-use crate::models::IssueData;
+use crate::models::{IssueComment, IssueData};
 use crate::users::get_current_user;
 use crate::workflows::{
-    apply_transition_side_effects, validate_status_transition, validate_status_value,
+    apply_transition_side_effects, next_workflow_status, validate_status_transition,
+    validate_status_value,
 };
 
-/// Update an issue and persist it to disk.
+/// Resolve the status an issue advances to with `--status-next`.
 ///
 /// # Arguments
 /// * `root` - Repository root path.
 /// * `identifier` - Issue identifier.
-/// * `title` - Updated title if provided.
-/// * `description` - Updated description if provided.
-/// * `status` - Updated status if provided.
-/// * `assignee` - Updated assignee if provided.
-/// * `claim` - Whether to claim the issue.
+///
+/// # Errors
+/// Returns `KanbusError::InvalidTransition` if the workflow offers no single
+/// forward transition from the current status.
+pub fn resolve_next_status(root: &Path, identifier: &str) -> Result<String, KanbusError> {
+    let lookup = load_issue_from_project(root, identifier)?;
+    let config_path = get_configuration_path(lookup.project_dir.as_path())?;
+    let configuration = load_project_configuration(&config_path)?;
+    next_workflow_status(
+        &configuration,
+        &lookup.issue.issue_type,
+        &lookup.issue.status,
+    )
+}
+
+/// Request payload for issue updates.
+#[derive(Debug, Clone, Default)]
+pub struct IssueUpdateRequest {
+    pub root: PathBuf,
+    pub identifier: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub assignee: Option<String>,
+    pub clear_assignee: bool,
+    pub priority: Option<u8>,
+    pub claim: bool,
+    pub validate: bool,
+    pub add_labels: Vec<String>,
+    pub remove_labels: Vec<String>,
+    pub set_labels: Option<String>,
+    pub parent: Option<String>,
+    pub clear_parent: bool,
+    pub due_at: Option<DateTime<Utc>>,
+    pub estimate_minutes: Option<u32>,
+}
+
+impl IssueUpdateRequest {
+    /// Build a validated request for `identifier` that changes nothing.
+    ///
+    /// Callers set the fields they change with struct update syntax.
+    pub fn new(root: &Path, identifier: impl Into<String>) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            identifier: identifier.into(),
+            validate: true,
+            ..Self::default()
+        }
+    }
+}
+
+/// Update an issue and persist it to disk.
+///
+/// # Arguments
+/// * `request` - Issue update request payload.
 ///
 /// # Errors
 /// Returns `KanbusError` if the update fails.
-#[allow(clippy::too_many_arguments)]
-pub fn update_issue(
-    root: &Path,
-    identifier: &str,
-    title: Option<&str>,
-    description: Option<&str>,
-    status: Option<&str>,
-    assignee: Option<&str>,
-    priority: Option<u8>,
-    claim: bool,
-    validate: bool,
-    add_labels: &[String],
-    remove_labels: &[String],
-    set_labels: Option<&str>,
-    parent: Option<&str>,
-) -> Result<IssueData, KanbusError> {
+pub fn update_issue(request: &IssueUpdateRequest) -> Result<IssueData, KanbusError> {
+    let root = request.root.as_path();
+    let identifier = request.identifier.as_str();
+    let title = request.title.as_deref();
+    let description = request.description.as_deref();
+    let status = request.status.as_deref();
+    let assignee = request.assignee.as_deref();
+    let clear_assignee = request.clear_assignee;
+    let priority = request.priority;
+    let claim = request.claim;
+    let validate = request.validate;
+    let add_labels = request.add_labels.as_slice();
+    let remove_labels = request.remove_labels.as_slice();
+    let set_labels = request.set_labels.as_deref();
+    let parent = request.parent.as_deref();
+    let clear_parent = request.clear_parent;
+    let due_at = request.due_at;
+    let estimate_minutes = request.estimate_minutes;
     let lookup = load_issue_from_project(root, identifier)?;
     let before_issue = lookup.issue.clone();
     let config_path = get_configuration_path(lookup.project_dir.as_path())?;
@@ -55,6 +114,7 @@ pub fn update_issue(
 
     let mut updated_issue = lookup.issue.clone();
     let current_time = Utc::now();
+    let actor_id = get_current_user();
 
     let mut resolved_status = if claim { Some("in_progress") } else { status };
 
@@ -80,6 +140,9 @@ pub fn update_issue(
     if let Some(new_description) = description {
         let normalized_description = new_description.trim();
         if normalized_description != updated_issue.description {
+            if validate {
+                validate_description_length(&configuration, normalized_description)?;
+            }
             updated_description = Some(normalized_description.to_string());
         }
     }
@@ -90,6 +153,7 @@ pub fn update_issue(
             updated_assignee = Some(new_assignee.to_string());
         }
     }
+    let assignee_cleared = clear_assignee && updated_issue.assignee.is_some();
 
     let mut updated_priority: Option<i32> = None;
     if let Some(new_priority) = priority {
@@ -105,6 +169,16 @@ pub fn update_issue(
         resolved_status = None;
     }
 
+    if let Some(new_status) = resolved_status {
+        let auto_claim = configuration
+            .auto_claim_on_status
+            .iter()
+            .any(|candidate| candidate == new_status);
+        if auto_claim && assignee.is_none() && !clear_assignee && updated_issue.assignee.is_none() {
+            updated_assignee = Some(actor_id.clone());
+        }
+    }
+
     let mut updated_labels: Option<Vec<String>> = None;
     if set_labels.is_some() || !add_labels.is_empty() || !remove_labels.is_empty() {
         let mut labels = if let Some(value) = set_labels {
@@ -137,14 +211,16 @@ pub fn update_issue(
     let mut updated_parent: Option<String> = None;
     if let Some(parent_candidate) = parent {
         let issues_dir = lookup.project_dir.join("issues");
-        let resolved_parent =
-            resolve_issue_identifier(&issues_dir, &configuration.project_key, parent_candidate)?;
+        let resolved_parent = resolve_issue_identifier(
+            &issues_dir,
+            &identifier_prefixes(&configuration),
+            parent_candidate,
+        )?;
         if updated_issue.parent.as_deref() != Some(resolved_parent.as_str()) {
             if validate {
-                let parent_path = issues_dir.join(format!("{resolved_parent}.json"));
-                if !parent_path.exists() {
+                let Some(parent_path) = find_issue_file(&issues_dir, &resolved_parent)? else {
                     return Err(KanbusError::IssueOperation("not found".to_string()));
-                }
+                };
                 let parent_issue = read_issue_from_file(&parent_path)?;
                 crate::hierarchy::validate_parent_child_relationship(
                     &configuration,
@@ -156,19 +232,30 @@ pub fn update_issue(
         }
     }
 
+    let parent_cleared = clear_parent && updated_issue.parent.is_some();
+
+    let updated_due_at = due_at.filter(|value| updated_issue.due_at != Some(*value));
+    let updated_estimate =
+        estimate_minutes.filter(|value| updated_issue.estimate_minutes != Some(*value));
+
     if resolved_status.is_none()
         && updated_title.is_none()
         && updated_description.is_none()
         && updated_assignee.is_none()
+        && !assignee_cleared
         && updated_priority.is_none()
         && updated_labels.is_none()
         && updated_parent.is_none()
+        && !parent_cleared
+        && updated_due_at.is_none()
+        && updated_estimate.is_none()
     {
         return Err(KanbusError::IssueOperation(
             "no updates requested".to_string(),
         ));
     }
 
+    let mut transition_comment: Option<IssueComment> = None;
     if let Some(new_status) = resolved_status {
         if validate {
             validate_status_value(&configuration, &updated_issue.issue_type, new_status)?;
@@ -179,8 +266,20 @@ pub fn update_issue(
                 new_status,
             )?;
         }
+        let previous_status = updated_issue.status.clone();
         updated_issue = apply_transition_side_effects(&updated_issue, new_status, current_time);
         updated_issue.status = new_status.to_string();
+        if configuration.comment_on_transition {
+            transition_comment = Some(build_transition_comment(
+                &previous_status,
+                new_status,
+                &actor_id,
+                current_time,
+            ));
+        }
+    }
+    if let Some(comment) = &transition_comment {
+        updated_issue.comments.push(comment.clone());
     }
 
     if let Some(new_title) = updated_title {
@@ -192,6 +291,9 @@ pub fn update_issue(
     if let Some(new_assignee) = updated_assignee {
         updated_issue.assignee = Some(new_assignee);
     }
+    if assignee_cleared {
+        updated_issue.assignee = None;
+    }
     if let Some(new_priority) = updated_priority {
         updated_issue.priority = new_priority;
     }
@@ -201,18 +303,52 @@ pub fn update_issue(
     if let Some(new_parent) = updated_parent {
         updated_issue.parent = Some(new_parent);
     }
+    if parent_cleared {
+        updated_issue.parent = None;
+    }
+    if let Some(new_due_at) = updated_due_at {
+        updated_issue.due_at = Some(new_due_at);
+    }
+    if let Some(new_estimate) = updated_estimate {
+        updated_issue.estimate_minutes = Some(new_estimate);
+    }
     updated_issue.updated_at = current_time;
 
-    write_issue_to_file(&updated_issue, &lookup.issue_path)?;
+    let issue_path = issues_directory_for_path(&lookup.issue_path)
+        .map(|issues_dir| {
+            issue_path_for_layout(
+                &issues_dir,
+                &updated_issue,
+                &configuration.issue_layout,
+                existing_issue_extension(&lookup.issue_path),
+            )
+        })
+        .unwrap_or_else(|| lookup.issue_path.clone());
+    ensure_issue_directory(&issue_path)?;
+    write_issue_to_file(&updated_issue, &issue_path)?;
+    if issue_path != lookup.issue_path {
+        fs::remove_file(&lookup.issue_path).map_err(|error| KanbusError::Io(error.to_string()))?;
+    }
 
     let occurred_at = now_timestamp();
-    let actor_id = get_current_user();
-    let events = build_update_events(&before_issue, &updated_issue, &actor_id, &occurred_at);
+    let mut events = build_update_events(&before_issue, &updated_issue, &actor_id, &occurred_at);
+    if let Some(comment) = &transition_comment {
+        events.push(EventRecord::new(
+            updated_issue.identifier.clone(),
+            EventType::CommentAdded,
+            &actor_id,
+            comment_payload(comment.id.as_deref().unwrap_or_default(), &comment.author),
+            occurred_at.clone(),
+        ));
+    }
     let events_dir = events_dir_for_issue_path(&lookup.project_dir, &lookup.issue_path)?;
     match write_events_batch(&events_dir, &events) {
         Ok(_paths) => {}
         Err(error) => {
             write_issue_to_file(&before_issue, &lookup.issue_path)?;
+            if issue_path != lookup.issue_path {
+                fs::remove_file(&issue_path).map_err(|error| KanbusError::Io(error.to_string()))?;
+            }
             return Err(error);
         }
     }
@@ -230,15 +366,24 @@ pub fn update_issue(
     if description.is_some() {
         fields_changed.push("description".to_string());
     }
-    if assignee.is_some() || claim {
+    if assignee.is_some() || clear_assignee || claim {
         fields_changed.push("assignee".to_string());
     }
     if priority.is_some() {
         fields_changed.push("priority".to_string());
     }
-    if parent.is_some() {
+    if parent.is_some() || clear_parent {
         fields_changed.push("parent".to_string());
     }
+    if due_at.is_some() {
+        fields_changed.push("due_at".to_string());
+    }
+    if estimate_minutes.is_some() {
+        fields_changed.push("estimate_minutes".to_string());
+    }
+    if before_issue.closed_at != updated_issue.closed_at {
+        fields_changed.push("closed_at".to_string());
+    }
     let _ = publish_notification(
         root,
         NotificationEvent::IssueUpdated {
@@ -251,18 +396,74 @@ pub fn update_issue(
     Ok(updated_issue)
 }
 
+/// Mark an issue as recently active by bumping only `updated_at`.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `identifier` - Issue identifier.
+///
+/// # Returns
+/// Updated issue data.
+///
+/// # Errors
+/// Returns `KanbusError` if the issue cannot be found or written.
+pub fn touch_issue(root: &Path, identifier: &str) -> Result<IssueData, KanbusError> {
+    let lookup = load_issue_from_project(root, identifier)?;
+    let before_issue = lookup.issue;
+    let mut updated_issue = before_issue.clone();
+    updated_issue.updated_at = Utc::now();
+    write_issue_to_file(&updated_issue, &lookup.issue_path)?;
+
+    let event = EventRecord::new(
+        updated_issue.identifier.clone(),
+        EventType::FieldUpdated,
+        get_current_user(),
+        touch_payload(&before_issue, &updated_issue),
+        now_timestamp(),
+    );
+    let events_dir = events_dir_for_issue_path(&lookup.project_dir, &lookup.issue_path)?;
+    if let Err(error) = write_events_batch(&events_dir, &[event]) {
+        write_issue_to_file(&before_issue, &lookup.issue_path)?;
+        return Err(error);
+    }
+
+    use crate::notification_events::NotificationEvent;
+    use crate::notification_publisher::publish_notification;
+    let _ = publish_notification(
+        root,
+        NotificationEvent::IssueUpdated {
+            issue_id: updated_issue.identifier.clone(),
+            fields_changed: vec!["updated_at".to_string()],
+            issue_data: updated_issue.clone(),
+        },
+    );
+    Ok(updated_issue)
+}
+
+fn build_transition_comment(
+    from_status: &str,
+    to_status: &str,
+    actor_id: &str,
+    created_at: DateTime<Utc>,
+) -> IssueComment {
+    IssueComment {
+        id: Some(generate_comment_id()),
+        author: actor_id.to_string(),
+        text: format!("moved from {from_status} to {to_status} by {actor_id}"),
+        created_at,
+        system: true,
+        resolved: false,
+        reply_to: None,
+    }
+}
+
 fn find_duplicate_title(
     issues_dir: &Path,
     title: &str,
     current_identifier: &str,
 ) -> Result<Option<String>, KanbusError> {
     let normalized_title = title.trim().to_lowercase();
-    for entry in fs::read_dir(issues_dir).map_err(|error| KanbusError::Io(error.to_string()))? {
-        let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
-        let path = entry.path();
-        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
-            continue;
-        }
+    for path in list_issue_files(issues_dir)? {
         if path
             .file_stem()
             .and_then(|stem| stem.to_str())
@@ -9,11 +9,13 @@ use std::path::Path;
 use chrono::{DateTime, Utc};
 use serde_json::Value;
 
+use crate::config_loader::load_project_configuration;
 use crate::error::KanbusError;
-use crate::file_io::load_project_directory;
+use crate::file_io::{get_configuration_path, load_project_directory};
 use crate::ids::{generate_issue_identifier, IssueIdentifierRequest};
 use crate::issue_files::{
-    issue_path_for_identifier, list_issue_identifiers, read_issue_from_file, write_issue_to_file,
+    find_issue_file, issue_file_extension, issue_path_for_identifier, list_issue_identifiers,
+    read_issue_from_file, write_issue_to_file,
 };
 use crate::models::{IssueComment, IssueData, JiraConfiguration};
 
@@ -55,6 +57,8 @@ pub fn pull_from_jira(
             "issues directory does not exist".to_string(),
         ));
     }
+    let configuration = load_project_configuration(&get_configuration_path(&project_dir)?)?;
+    let extension = issue_file_extension(&configuration.issue_format);
 
     let jira_issues = fetch_all_jira_issues(jira_config, &user_email, &api_token)?;
 
@@ -108,7 +112,8 @@ pub fn pull_from_jira(
         issue.identifier = kanbus_id.clone();
 
         // For updates, preserve fields not managed by Jira
-        let issue_path = issue_path_for_identifier(&issues_dir, &kanbus_id);
+        let issue_path = find_issue_file(&issues_dir, &kanbus_id)?
+            .unwrap_or_else(|| issue_path_for_identifier(&issues_dir, &kanbus_id, extension));
         if action == "updated" {
             if let Ok(existing) = read_issue_from_file(&issue_path) {
                 issue.created_at = existing.created_at;
@@ -199,7 +204,9 @@ fn build_jira_key_index(
 ) -> BTreeMap<String, String> {
     let mut index = BTreeMap::new();
     for id in existing_ids {
-        let path = issue_path_for_identifier(issues_dir, id);
+        let Ok(Some(path)) = find_issue_file(issues_dir, id) else {
+            continue;
+        };
         if let Ok(issue) = read_issue_from_file(&path) {
             if let Some(Value::String(jira_key)) = issue.custom.get("jira_key") {
                 index.insert(jira_key.clone(), id.clone());
@@ -295,9 +302,13 @@ fn map_jira_to_kanbus(
         labels,
         dependencies: Vec::new(),
         comments,
+        links: Vec::new(),
         created_at,
         updated_at,
         closed_at,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom,
     })
 }
@@ -371,6 +382,9 @@ fn extract_comments(comment_field: &Value) -> Vec<IssueComment> {
                     text
                 },
                 created_at,
+                system: false,
+                resolved: false,
+                reply_to: None,
             }
         })
         .collect()
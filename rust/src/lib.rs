@@ -5,6 +5,7 @@ pub mod beads_write;
 pub mod cache;
 pub mod cli;
 pub mod config;
+pub mod config_edit;
 pub mod config_loader;
 pub mod console_backend;
 pub mod console_snapshot;
@@ -18,32 +19,46 @@ pub mod daemon_server;
 pub mod dependencies;
 pub mod dependency_tree;
 pub mod doctor;
+pub mod editor;
 pub mod error;
 pub mod event_history;
 pub mod file_io;
 pub mod hierarchy;
 pub mod ids;
 pub mod index;
+pub mod issue_archive;
 pub mod issue_close;
 pub mod issue_comment;
+pub mod issue_copy;
 pub mod issue_creation;
 pub mod issue_delete;
 pub mod issue_display;
+pub mod issue_export;
 pub mod issue_files;
+pub mod issue_labels;
 pub mod issue_line;
+pub mod issue_links;
 pub mod issue_listing;
 pub mod issue_lookup;
+pub mod issue_reassign;
+pub mod issue_reopen;
+pub mod issue_reorganize;
+pub mod issue_templates;
+pub mod issue_time;
 pub mod issue_transfer;
 pub mod issue_update;
 pub mod jira_sync;
 pub mod maintenance;
+pub mod markdown_render;
 pub mod migration;
 pub mod models;
 pub mod notification_events;
 pub mod notification_publisher;
+pub mod pager;
 pub mod project;
 pub mod project_management_template;
 pub mod queries;
 pub mod users;
+pub mod watch;
 pub mod wiki;
 pub mod workflows;
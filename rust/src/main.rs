@@ -1,8 +1,12 @@
-use kanbus::cli::run_from_env;
+use kanbus::cli::{format_error, run_from_env};
 
 fn main() {
-    if let Err(error) = run_from_env() {
-        eprintln!("{error}");
-        std::process::exit(1);
+    match run_from_env() {
+        Ok(0) => {}
+        Ok(code) => std::process::exit(code),
+        Err(error) => {
+            eprintln!("{}", format_error(&error));
+            std::process::exit(1);
+        }
     }
 }
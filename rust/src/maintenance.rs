@@ -2,24 +2,107 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::Serialize;
 
 use crate::config_loader::load_project_configuration;
 use crate::error::KanbusError;
-use crate::file_io::{get_configuration_path, load_project_directory};
+use crate::event_history::{events_dir_for_project, EventRecord};
+use crate::file_io::{
+    find_project_local_directory, get_configuration_path, load_project_directory,
+};
 use crate::hierarchy::validate_parent_child_relationship;
-use crate::models::IssueData;
+use crate::issue_archive::{list_archived_issues, ARCHIVE_DIRECTORY};
+use crate::issue_files::{
+    is_yaml_issue_file, list_issue_files, list_issue_identifiers, read_issue_from_file,
+};
+use crate::migration::load_beads_issues;
+use crate::models::{IssueData, ProjectConfiguration};
 use crate::workflows::get_workflow_for_issue_type;
 
 const ALLOWED_DEPENDENCY_TYPES: [&str; 2] = ["blocked-by", "relates-to"];
 
+/// Group name used for issues without an assignee in stats breakdowns.
+pub const UNASSIGNED_GROUP: &str = "(unassigned)";
+
+/// Open and closed issue counts for one stats group.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct StatusCounts {
+    pub open: usize,
+    pub closed: usize,
+}
+
 /// Aggregate issue statistics for a project.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ProjectStats {
     pub total: usize,
     pub open_count: usize,
     pub closed_count: usize,
     pub type_counts: BTreeMap<String, usize>,
+    /// Sum of `estimate_minutes` across open issues.
+    pub open_estimate_minutes: u64,
+    /// Sum of `spent_minutes` across open issues.
+    pub open_spent_minutes: u64,
+    /// Counts per assignee, present when requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_counts: Option<BTreeMap<String, StatusCounts>>,
+    /// Counts per label, present when requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label_counts: Option<BTreeMap<String, StatusCounts>>,
+}
+
+/// Severity of a validation finding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationSeverity {
+    Error,
+    Warning,
+}
+
+/// A single problem reported by project validation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValidationIssue {
+    pub severity: ValidationSeverity,
+    /// Stable machine-readable problem code, such as `missing_parent`.
+    pub code: String,
+    pub issue_id: Option<String>,
+    /// Issue file path relative to the repository root.
+    pub path: Option<String>,
+    pub message: String,
+    /// File name or identifier that prefixes the text description.
+    #[serde(skip)]
+    subject: String,
+}
+
+impl ValidationIssue {
+    fn error(code: &str, subject: &str, message: String) -> Self {
+        Self {
+            severity: ValidationSeverity::Error,
+            code: code.to_string(),
+            issue_id: None,
+            path: None,
+            message,
+            subject: subject.to_string(),
+        }
+    }
+
+    fn with_issue_id(mut self, issue_id: &str) -> Self {
+        self.issue_id = Some(issue_id.to_string());
+        self
+    }
+
+    fn with_path(mut self, path: Option<&String>) -> Self {
+        self.path = path.cloned();
+        self
+    }
+
+    /// Describe the problem as a single line of text output.
+    pub fn describe(&self) -> String {
+        format!("{}: {}", self.subject, self.message)
+    }
 }
 
 /// Validate issue data and configuration for a Kanbus project.
@@ -28,8 +111,35 @@ pub struct ProjectStats {
 /// * `root` - Repository root path.
 ///
 /// # Errors
-/// Returns `KanbusError::IssueOperation` if validation fails.
+/// Returns `KanbusError::IssueOperation` if validation finds any errors.
 pub fn validate_project(root: &Path) -> Result<(), KanbusError> {
+    let errors: Vec<String> = collect_validation_issues(root, false)?
+        .iter()
+        .filter(|issue| issue.severity == ValidationSeverity::Error)
+        .map(ValidationIssue::describe)
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(KanbusError::IssueOperation(format_errors(&errors)))
+    }
+}
+
+/// Collect validation findings for a Kanbus project.
+///
+/// Event files whose issue no longer exists are reported as warnings.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `include_orphans` - Also report leaf issues that have no parent as errors.
+///
+/// # Errors
+/// Returns `KanbusError` if the project cannot be loaded or the issues
+/// directory is missing.
+pub fn collect_validation_issues(
+    root: &Path,
+    include_orphans: bool,
+) -> Result<Vec<ValidationIssue>, KanbusError> {
     let project_dir = load_project_directory(root)?;
     let issues_dir = project_dir.join("issues");
     if !issues_dir.exists() {
@@ -41,15 +151,11 @@ pub fn validate_project(root: &Path) -> Result<(), KanbusError> {
     let configuration =
         load_project_configuration(&get_configuration_path(project_dir.as_path())?)?;
 
-    let mut errors: Vec<String> = Vec::new();
+    let mut problems: Vec<ValidationIssue> = Vec::new();
     let mut issues: BTreeMap<String, IssueData> = BTreeMap::new();
+    let mut issue_paths: BTreeMap<String, String> = BTreeMap::new();
 
-    let mut paths: Vec<_> = fs::read_dir(&issues_dir)
-        .map_err(|error| KanbusError::Io(error.to_string()))?
-        .filter_map(|entry| entry.ok())
-        .map(|entry| entry.path())
-        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("json"))
-        .collect();
+    let mut paths = list_issue_files(&issues_dir)?;
     paths.sort_by(|left, right| left.file_name().cmp(&right.file_name()));
 
     for path in paths {
@@ -57,18 +163,25 @@ pub fn validate_project(root: &Path) -> Result<(), KanbusError> {
             .file_name()
             .and_then(|name| name.to_str())
             .unwrap_or("unknown");
+        let relative_path = relative_display(root, &path);
+        let file_error = |code: &str, message: String| {
+            ValidationIssue::error(code, filename, message).with_path(Some(&relative_path))
+        };
         let contents = match fs::read_to_string(&path) {
             Ok(contents) => contents,
             Err(error) => {
-                errors.push(format!("{filename}: unable to read issue: {error}"));
+                problems.push(file_error(
+                    "unreadable_issue",
+                    format!("unable to read issue: {error}"),
+                ));
                 continue;
             }
         };
 
-        let payload: serde_json::Value = match serde_json::from_str(&contents) {
+        let payload = match parse_issue_payload(&path, &contents) {
             Ok(payload) => payload,
-            Err(error) => {
-                errors.push(format!("{filename}: invalid json: {error}"));
+            Err((code, message)) => {
+                problems.push(file_error(code, message));
                 continue;
             }
         };
@@ -76,43 +189,85 @@ pub fn validate_project(root: &Path) -> Result<(), KanbusError> {
         let issue: IssueData = match serde_json::from_value(payload) {
             Ok(issue) => issue,
             Err(error) => {
-                errors.push(format!("{filename}: invalid issue data: {error}"));
+                problems.push(file_error(
+                    "invalid_issue_data",
+                    format!("invalid issue data: {error}"),
+                ));
                 continue;
             }
         };
 
         if issues.contains_key(&issue.identifier) {
-            errors.push(format!(
-                "{filename}: duplicate issue id '{}'",
-                issue.identifier
-            ));
+            problems.push(
+                file_error(
+                    "duplicate_id",
+                    format!("duplicate issue id '{}'", issue.identifier),
+                )
+                .with_issue_id(&issue.identifier),
+            );
             continue;
         }
 
-        validate_issue_fields(filename, &issue, &configuration, &mut errors);
+        problems.extend(
+            validate_issue_fields(filename, &issue, &configuration)
+                .into_iter()
+                .map(|(code, message)| file_error(code, message).with_issue_id(&issue.identifier)),
+        );
+        issue_paths.insert(issue.identifier.clone(), relative_path);
         issues.insert(issue.identifier.clone(), issue);
     }
 
-    validate_references(&issues, &configuration, &mut errors);
+    validate_references(&issues, &issue_paths, &configuration, &mut problems);
 
-    if errors.is_empty() {
-        Ok(())
-    } else {
-        Err(KanbusError::IssueOperation(format_errors(&errors)))
+    if include_orphans {
+        for issue in issues
+            .values()
+            .filter(|issue| is_orphan(issue, &configuration))
+        {
+            problems.push(
+                ValidationIssue::error(
+                    "orphaned_issue",
+                    &issue.identifier,
+                    format!("orphaned {} has no parent", issue.issue_type),
+                )
+                .with_issue_id(&issue.identifier)
+                .with_path(issue_paths.get(&issue.identifier)),
+            );
+        }
     }
+
+    for orphan in find_orphaned_event_files(root)? {
+        let path = relative_display(root, &orphan.path);
+        problems.push(ValidationIssue {
+            severity: ValidationSeverity::Warning,
+            code: "orphaned_event_file".to_string(),
+            issue_id: Some(orphan.issue_id.clone()),
+            message: format!("orphaned event file {path}"),
+            path: Some(path),
+            subject: orphan.issue_id,
+        });
+    }
+
+    Ok(problems)
 }
 
 /// Collect project statistics from issue data.
 ///
 /// # Arguments
 /// * `root` - Repository root path.
+/// * `by_assignee` - Whether to break counts down per assignee.
+/// * `by_label` - Whether to break counts down per label.
 ///
 /// # Returns
 /// Aggregated project statistics.
 ///
 /// # Errors
 /// Returns `KanbusError::IssueOperation` if stats cannot be computed.
-pub fn collect_project_stats(root: &Path) -> Result<ProjectStats, KanbusError> {
+pub fn collect_project_stats(
+    root: &Path,
+    by_assignee: bool,
+    by_label: bool,
+) -> Result<ProjectStats, KanbusError> {
     let project_dir = load_project_directory(root)?;
     let issues_dir = project_dir.join("issues");
     if !issues_dir.exists() {
@@ -122,20 +277,15 @@ pub fn collect_project_stats(root: &Path) -> Result<ProjectStats, KanbusError> {
     }
 
     let mut issues: Vec<IssueData> = Vec::new();
-    for entry in fs::read_dir(&issues_dir).map_err(|error| KanbusError::Io(error.to_string()))? {
-        let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
-        let path = entry.path();
-        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
-            continue;
-        }
+    for path in list_issue_files(&issues_dir)? {
         let contents =
             fs::read_to_string(&path).map_err(|error| KanbusError::Io(error.to_string()))?;
         let filename = path
             .file_name()
             .and_then(|name| name.to_str())
             .unwrap_or("unknown");
-        let payload: serde_json::Value = serde_json::from_str(&contents).map_err(|error| {
-            KanbusError::IssueOperation(format!("{filename}: invalid json: {error}"))
+        let payload = parse_issue_payload(&path, &contents).map_err(|(_, message)| {
+            KanbusError::IssueOperation(format!("{filename}: {message}"))
         })?;
         let issue: IssueData = serde_json::from_value(payload).map_err(|error| {
             KanbusError::IssueOperation(format!("{filename}: invalid issue data: {error}"))
@@ -149,6 +299,23 @@ pub fn collect_project_stats(root: &Path) -> Result<ProjectStats, KanbusError> {
         .filter(|issue| issue.status == "closed")
         .count();
     let open_count = total - closed_count;
+    let open_issues = || issues.iter().filter(|issue| issue.status != "closed");
+    let open_estimate_minutes = open_issues()
+        .filter_map(|issue| issue.estimate_minutes)
+        .map(u64::from)
+        .sum();
+    let open_spent_minutes = open_issues()
+        .map(|issue| u64::from(issue.spent_minutes))
+        .sum();
+    let assignee_counts = by_assignee.then(|| {
+        group_status_counts(&issues, |issue| {
+            vec![issue
+                .assignee
+                .clone()
+                .unwrap_or_else(|| UNASSIGNED_GROUP.to_string())]
+        })
+    });
+    let label_counts = by_label.then(|| group_status_counts(&issues, |issue| issue.labels.clone()));
     let mut type_counts: BTreeMap<String, usize> = BTreeMap::new();
     for issue in issues {
         *type_counts.entry(issue.issue_type).or_insert(0) += 1;
@@ -159,32 +326,277 @@ pub fn collect_project_stats(root: &Path) -> Result<ProjectStats, KanbusError> {
         open_count,
         closed_count,
         type_counts,
+        open_estimate_minutes,
+        open_spent_minutes,
+        assignee_counts,
+        label_counts,
     })
 }
 
+fn group_status_counts(
+    issues: &[IssueData],
+    groups_for: impl Fn(&IssueData) -> Vec<String>,
+) -> BTreeMap<String, StatusCounts> {
+    let mut counts: BTreeMap<String, StatusCounts> = BTreeMap::new();
+    for issue in issues {
+        for group in groups_for(issue) {
+            let entry = counts.entry(group).or_default();
+            if issue.status == "closed" {
+                entry.closed += 1;
+            } else {
+                entry.open += 1;
+            }
+        }
+    }
+    counts
+}
+
+/// Reconstruct the number of open issues at the end of each day in a range.
+///
+/// An issue counts as open at the end of a day when it was created before
+/// the day ended and was not yet closed. Days end at midnight in the
+/// configured `time_zone`, or UTC when none is set. Archived issues are
+/// included because they were open for part of their history.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `since` - First day of the series.
+/// * `until` - Last day of the series.
+///
+/// # Errors
+/// Returns `KanbusError` if issues cannot be read or the time zone is unknown.
+pub fn compute_burndown(
+    root: &Path,
+    since: NaiveDate,
+    until: NaiveDate,
+) -> Result<Vec<(NaiveDate, usize)>, KanbusError> {
+    if since > until {
+        return Err(KanbusError::IssueOperation(
+            "--since must not be after --until".to_string(),
+        ));
+    }
+    let project_dir = load_project_directory(root)?;
+    let configuration =
+        load_project_configuration(&get_configuration_path(project_dir.as_path())?)?;
+    let zone = configured_time_zone(&configuration)?;
+    let mut issues = list_issue_files(&project_dir.join("issues"))?
+        .iter()
+        .map(|path| read_issue_from_file(path))
+        .collect::<Result<Vec<_>, _>>()?;
+    issues.extend(list_archived_issues(root)?);
+    let intervals: Vec<(DateTime<Utc>, Option<DateTime<Utc>>)> = issues
+        .iter()
+        .map(|issue| {
+            let closed_at = issue
+                .closed_at
+                .or_else(|| (issue.status == "closed").then_some(issue.updated_at));
+            (issue.created_at, closed_at)
+        })
+        .collect();
+
+    let mut series = Vec::new();
+    for day in since.iter_days().take_while(|day| *day <= until) {
+        let day_end = start_of_day(zone, day.succ_opt().unwrap_or(day));
+        let open = intervals
+            .iter()
+            .filter(|(created_at, closed_at)| {
+                *created_at < day_end && closed_at.is_none_or(|closed_at| closed_at >= day_end)
+            })
+            .count();
+        series.push((day, open));
+    }
+    Ok(series)
+}
+
+/// Parse the configured `time_zone`, if any.
+///
+/// # Errors
+/// Returns `KanbusError::Configuration` if the zone name is unknown.
+pub(crate) fn configured_time_zone(
+    configuration: &ProjectConfiguration,
+) -> Result<Option<Tz>, KanbusError> {
+    configuration
+        .time_zone
+        .as_deref()
+        .map(|name| {
+            name.parse::<Tz>()
+                .map_err(|_| KanbusError::Configuration(format!("unknown time zone: {name}")))
+        })
+        .transpose()
+}
+
+/// Return the instant a calendar day starts in `zone` (UTC when unset).
+pub(crate) fn start_of_day(zone: Option<Tz>, day: NaiveDate) -> DateTime<Utc> {
+    let midnight = day.and_time(NaiveTime::MIN);
+    match zone {
+        Some(tz) => tz
+            .from_local_datetime(&midnight)
+            .earliest()
+            .map(|local| local.with_timezone(&Utc))
+            .unwrap_or_else(|| Utc.from_utc_datetime(&midnight)),
+        None => Utc.from_utc_datetime(&midnight),
+    }
+}
+
+/// Find issues of the leaf hierarchy type that have no parent.
+///
+/// Leaf issues (such as sub-tasks) only make sense beneath a parent, so an
+/// unparented one is reported as orphaned.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+///
+/// # Errors
+/// Returns `KanbusError` if configuration or issue files cannot be read.
+pub fn find_orphan_issues(root: &Path) -> Result<Vec<IssueData>, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let configuration =
+        load_project_configuration(&get_configuration_path(project_dir.as_path())?)?;
+    let issues_dir = project_dir.join("issues");
+    if !issues_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut orphans = Vec::new();
+    for path in list_issue_files(&issues_dir)? {
+        let issue = read_issue_from_file(&path)?;
+        if is_orphan(&issue, &configuration) {
+            orphans.push(issue);
+        }
+    }
+    orphans.sort_by(|left, right| left.identifier.cmp(&right.identifier));
+    Ok(orphans)
+}
+
+/// Whether an issue is of the leaf hierarchy type and has no parent.
+fn is_orphan(issue: &IssueData, configuration: &ProjectConfiguration) -> bool {
+    configuration
+        .hierarchy
+        .last()
+        .is_some_and(|leaf_type| &issue.issue_type == leaf_type && issue.parent.is_none())
+}
+
+/// Describe an orphaned issue for validation and doctor output.
+///
+/// # Arguments
+/// * `issue` - Orphaned issue.
+pub fn describe_orphan(issue: &IssueData) -> String {
+    format!(
+        "{}: orphaned {} has no parent",
+        issue.identifier, issue.issue_type
+    )
+}
+
+/// Event file that references an issue which no longer exists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrphanedEventFile {
+    pub path: PathBuf,
+    pub issue_id: String,
+}
+
+/// Find event files whose issue no longer exists.
+///
+/// Shared, project-local, and archived issues all count as existing, as do
+/// Beads issues when Beads compatibility is enabled.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+///
+/// # Errors
+/// Returns `KanbusError` if issue or event files cannot be read.
+pub fn find_orphaned_event_files(root: &Path) -> Result<Vec<OrphanedEventFile>, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let configuration_path = get_configuration_path(project_dir.as_path())?;
+    let configuration = load_project_configuration(&configuration_path)?;
+    let local_dir = find_project_local_directory(&project_dir);
+
+    let mut issue_dirs = vec![
+        project_dir.join("issues"),
+        project_dir.join(ARCHIVE_DIRECTORY),
+    ];
+    let mut events_dirs = vec![events_dir_for_project(&project_dir)];
+    if let Some(local_dir) = &local_dir {
+        issue_dirs.push(local_dir.join("issues"));
+        events_dirs.push(local_dir.join("events"));
+    }
+    let mut known: BTreeSet<String> = BTreeSet::new();
+    for issues_dir in issue_dirs.iter().filter(|dir| dir.is_dir()) {
+        known.extend(list_issue_identifiers(issues_dir)?);
+    }
+    if configuration.beads_compatibility {
+        let beads_root = configuration_path.parent().unwrap_or(root);
+        if beads_root.join(".beads").join("issues.jsonl").is_file() {
+            known.extend(
+                load_beads_issues(beads_root)?
+                    .into_iter()
+                    .map(|issue| issue.identifier),
+            );
+        }
+    }
+
+    let mut orphaned = Vec::new();
+    for events_dir in events_dirs.iter().filter(|dir| dir.is_dir()) {
+        for entry in fs::read_dir(events_dir).map_err(|error| KanbusError::Io(error.to_string()))? {
+            let path = entry
+                .map_err(|error| KanbusError::Io(error.to_string()))?
+                .path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let contents =
+                fs::read_to_string(&path).map_err(|error| KanbusError::Io(error.to_string()))?;
+            let Ok(event) = serde_json::from_str::<EventRecord>(&contents) else {
+                continue;
+            };
+            if !known.contains(&event.issue_id) {
+                orphaned.push(OrphanedEventFile {
+                    path,
+                    issue_id: event.issue_id,
+                });
+            }
+        }
+    }
+    orphaned.sort_by(|left, right| left.path.cmp(&right.path));
+    Ok(orphaned)
+}
+
+/// Describe an orphaned event file for diagnostics output.
+///
+/// # Arguments
+/// * `root` - Repository root path, used to shorten the file path.
+/// * `orphan` - Orphaned event file.
+pub fn describe_orphaned_event_file(root: &Path, orphan: &OrphanedEventFile) -> String {
+    let path = orphan.path.strip_prefix(root).unwrap_or(&orphan.path);
+    format!(
+        "{}: orphaned event file {}",
+        orphan.issue_id,
+        path.display()
+    )
+}
+
 fn validate_issue_fields(
     filename: &str,
     issue: &IssueData,
     configuration: &crate::models::ProjectConfiguration,
-    errors: &mut Vec<String>,
-) {
+) -> Vec<(&'static str, String)> {
+    let mut errors = Vec::new();
     let expected_id = Path::new(filename)
         .file_stem()
         .and_then(|stem| stem.to_str())
         .unwrap_or(filename);
     if issue.identifier != expected_id {
-        errors.push(format!(
-            "{filename}: issue id '{}' does not match filename",
-            issue.identifier
+        errors.push((
+            "id_mismatch",
+            format!("issue id '{}' does not match filename", issue.identifier),
         ));
     }
 
     let mut valid_types = configuration.hierarchy.clone();
     valid_types.extend(configuration.types.clone());
     if !valid_types.iter().any(|entry| entry == &issue.issue_type) {
-        errors.push(format!(
-            "{filename}: unknown issue type '{}'",
-            issue.issue_type
+        errors.push((
+            "unknown_type",
+            format!("unknown issue type '{}'", issue.issue_type),
         ));
     }
 
@@ -196,21 +608,31 @@ fn validate_issue_fields(
     let priority_valid =
         priority_value.is_some_and(|value| configuration.priorities.contains_key(&value));
     if !priority_valid {
-        errors.push(format!("{filename}: invalid priority '{}'", issue.priority));
+        errors.push((
+            "invalid_priority",
+            format!("invalid priority '{}'", issue.priority),
+        ));
     }
 
     if let Ok(statuses) = collect_workflow_statuses(configuration, &issue.issue_type) {
         if !statuses.contains(&issue.status) {
-            errors.push(format!("{filename}: invalid status '{}'", issue.status));
+            errors.push((
+                "invalid_status",
+                format!("invalid status '{}'", issue.status),
+            ));
         }
     }
 
     if issue.status == "closed" && issue.closed_at.is_none() {
-        errors.push(format!("{filename}: closed issues must have closed_at set"));
+        errors.push((
+            "missing_closed_at",
+            "closed issues must have closed_at set".to_string(),
+        ));
     }
     if issue.status != "closed" && issue.closed_at.is_some() {
-        errors.push(format!(
-            "{filename}: non-closed issues must not set closed_at"
+        errors.push((
+            "unexpected_closed_at",
+            "non-closed issues must not set closed_at".to_string(),
         ));
     }
 
@@ -219,12 +641,13 @@ fn validate_issue_fields(
             .iter()
             .any(|entry| *entry == dependency.dependency_type)
         {
-            errors.push(format!(
-                "{filename}: invalid dependency type '{}'",
-                dependency.dependency_type
+            errors.push((
+                "invalid_dependency_type",
+                format!("invalid dependency type '{}'", dependency.dependency_type),
             ));
         }
     }
+    errors
 }
 
 fn collect_workflow_statuses(
@@ -241,10 +664,16 @@ fn collect_workflow_statuses(
 
 fn validate_references(
     issues: &BTreeMap<String, IssueData>,
+    issue_paths: &BTreeMap<String, String>,
     configuration: &crate::models::ProjectConfiguration,
-    errors: &mut Vec<String>,
+    problems: &mut Vec<ValidationIssue>,
 ) {
     for issue in issues.values() {
+        let reference_error = |code: &str, message: String| {
+            ValidationIssue::error(code, &issue.identifier, message)
+                .with_issue_id(&issue.identifier)
+                .with_path(issue_paths.get(&issue.identifier))
+        };
         if let Some(parent_id) = &issue.parent {
             match issues.get(parent_id) {
                 Some(parent_issue) => {
@@ -253,27 +682,47 @@ fn validate_references(
                         &parent_issue.issue_type,
                         &issue.issue_type,
                     ) {
-                        errors.push(format!("{}: {}", issue.identifier, error));
+                        problems.push(reference_error("invalid_hierarchy", error.to_string()));
                     }
                 }
-                None => errors.push(format!(
-                    "{}: parent '{}' does not exist",
-                    issue.identifier, parent_id
+                None => problems.push(reference_error(
+                    "missing_parent",
+                    format!("parent '{parent_id}' does not exist"),
                 )),
             }
         }
 
         for dependency in &issue.dependencies {
             if !issues.contains_key(&dependency.target) {
-                errors.push(format!(
-                    "{}: dependency target '{}' does not exist",
-                    issue.identifier, dependency.target
+                problems.push(reference_error(
+                    "missing_dependency_target",
+                    format!("dependency target '{}' does not exist", dependency.target),
                 ));
             }
         }
     }
 }
 
+fn parse_issue_payload(
+    path: &Path,
+    contents: &str,
+) -> Result<serde_json::Value, (&'static str, String)> {
+    if is_yaml_issue_file(path) {
+        serde_yaml::from_str(contents)
+            .map_err(|error| ("invalid_yaml", format!("invalid yaml: {error}")))
+    } else {
+        serde_json::from_str(contents)
+            .map_err(|error| ("invalid_json", format!("invalid json: {error}")))
+    }
+}
+
+fn relative_display(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
 fn format_errors(errors: &[String]) -> String {
     format!("validation failed:\n{}", errors.join("\n"))
 }
@@ -0,0 +1,20 @@
+//! Markdown rendering for issue descriptions and comments.
+
+use pulldown_cmark::{html, Options, Parser};
+
+/// Render markdown to HTML with scripts, event handlers, and other unsafe
+/// markup removed.
+///
+/// # Arguments
+/// * `source` - Markdown source text.
+///
+/// # Returns
+/// Sanitized HTML.
+pub fn render_markdown(source: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, Parser::new_ext(source, options));
+    ammonia::clean(&rendered)
+}
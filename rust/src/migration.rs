@@ -2,7 +2,7 @@
 
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, TimeZone, Utc};
 use serde_json::Value;
@@ -12,10 +12,14 @@ use crate::config_loader::load_project_configuration;
 use crate::error::KanbusError;
 use crate::file_io::{
     discover_kanbus_projects, discover_project_directories, ensure_git_repository,
-    get_configuration_path, initialize_project,
+    get_configuration_path, initialize_project, load_project_directory,
 };
 use crate::hierarchy::validate_parent_child_relationship;
-use crate::issue_files::write_issue_to_file;
+use crate::issue_creation::resolve_imported_priority;
+use crate::issue_files::{
+    ensure_issue_directory, find_issue_file, issue_file_extension, issue_path_for_identifier,
+    issue_path_for_layout, read_issue_from_file, write_issue_to_file,
+};
 use crate::models::{
     CategoryDefinition, DependencyLink, IssueComment, IssueData, PriorityDefinition,
     ProjectConfiguration, StatusDefinition,
@@ -26,6 +30,9 @@ use crate::workflows::get_workflow_for_issue_type;
 #[derive(Debug, Clone)]
 pub struct MigrationResult {
     pub issue_count: usize,
+    pub created_count: usize,
+    pub updated_count: usize,
+    pub skipped_count: usize,
 }
 
 /// Load Beads issues.jsonl without migrating to project files.
@@ -48,7 +55,7 @@ pub fn load_beads_issues(root: &Path) -> Result<Vec<IssueData>, KanbusError> {
         return Err(KanbusError::IssueOperation("no issues.jsonl".to_string()));
     }
 
-    let records = load_beads_records(&issues_path)?;
+    let records = load_sharded_beads_records(&beads_dir)?;
     let configuration = build_beads_configuration(&records);
     let mut record_by_id: HashMap<String, Value> = HashMap::new();
     for record in &records {
@@ -66,6 +73,51 @@ pub fn load_beads_issues(root: &Path) -> Result<Vec<IssueData>, KanbusError> {
     Ok(issues)
 }
 
+/// List the `.jsonl` shards in a `.beads` directory.
+///
+/// The primary `issues.jsonl` comes first, followed by the other shards
+/// sorted by file name.
+///
+/// # Arguments
+/// * `beads_dir` - Path to the `.beads` directory.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if the directory cannot be read.
+pub fn beads_shard_paths(beads_dir: &Path) -> Result<Vec<PathBuf>, KanbusError> {
+    let primary = beads_dir.join("issues.jsonl");
+    let mut shards = Vec::new();
+    for entry in fs::read_dir(beads_dir).map_err(|error| KanbusError::Io(error.to_string()))? {
+        let path = entry
+            .map_err(|error| KanbusError::Io(error.to_string()))?
+            .path();
+        if path != primary
+            && path.is_file()
+            && path.extension().and_then(|ext| ext.to_str()) == Some("jsonl")
+        {
+            shards.push(path);
+        }
+    }
+    shards.sort();
+    if primary.is_file() {
+        shards.insert(0, primary);
+    }
+    Ok(shards)
+}
+
+/// Convert one Beads record to an issue.
+///
+/// # Arguments
+/// * `records` - Every loaded Beads record, used for configuration and
+///   dependency context.
+/// * `record` - The record to convert.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the record is invalid.
+pub fn beads_record_to_issue(records: &[Value], record: &Value) -> Result<IssueData, KanbusError> {
+    let configuration = build_beads_configuration(records);
+    convert_record(record, &index_records(records)?, &configuration)
+}
+
 /// Load a single Beads issue by identifier.
 ///
 /// # Arguments
@@ -140,55 +192,147 @@ fn issue_id_matches(abbreviated: &str, full_id: &str) -> bool {
 /// Returns `KanbusError` if migration fails.
 pub fn migrate_from_beads(root: &Path) -> Result<MigrationResult, KanbusError> {
     ensure_git_repository(root)?;
+    let beads_dir = beads_directory(root)?;
 
-    let beads_dir = root.join(".beads");
-    if !beads_dir.exists() {
+    let mut projects = Vec::new();
+    discover_project_directories(root, &mut projects)?;
+    let mut dotfile_projects = discover_kanbus_projects(root)?;
+    projects.append(&mut dotfile_projects);
+    if !projects.is_empty() {
         return Err(KanbusError::IssueOperation(
-            "no .beads directory".to_string(),
+            "already initialized".to_string(),
         ));
     }
 
-    let issues_path = beads_dir.join("issues.jsonl");
-    if !issues_path.exists() {
-        return Err(KanbusError::IssueOperation("no issues.jsonl".to_string()));
+    initialize_project(root, false, None)?;
+    let project_dir = root.join("project");
+    let configuration =
+        load_project_configuration(&get_configuration_path(project_dir.as_path())?)?;
+
+    let records = load_sharded_beads_records(&beads_dir)?;
+    let record_by_id = index_records(&records)?;
+
+    for record in &records {
+        let issue = convert_record(record, &record_by_id, &configuration)?;
+        let issue_path = issue_path_for_identifier(
+            &project_dir.join("issues"),
+            &issue.identifier,
+            issue_file_extension(&configuration.issue_format),
+        );
+        write_issue_to_file(&issue, &issue_path)?;
     }
 
+    Ok(MigrationResult {
+        issue_count: records.len(),
+        created_count: records.len(),
+        updated_count: 0,
+        skipped_count: 0,
+    })
+}
+
+/// Import Beads issues into a Kanbus project, keeping issues that already exist.
+///
+/// The project is initialized first when none exists, so repeated runs are
+/// incremental: only Beads issues without a Kanbus counterpart are created.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `update_existing` - Overwrite existing issues with the Beads data instead of skipping them.
+///
+/// # Errors
+/// Returns `KanbusError` if migration fails.
+pub fn merge_from_beads(
+    root: &Path,
+    update_existing: bool,
+) -> Result<MigrationResult, KanbusError> {
+    ensure_git_repository(root)?;
+    let beads_dir = beads_directory(root)?;
+
     let mut projects = Vec::new();
     discover_project_directories(root, &mut projects)?;
     let mut dotfile_projects = discover_kanbus_projects(root)?;
     projects.append(&mut dotfile_projects);
-    if !projects.is_empty() {
+    if projects.is_empty() {
+        initialize_project(root, false, None)?;
+    }
+    let project_dir = load_project_directory(root)?;
+    let configuration =
+        load_project_configuration(&get_configuration_path(project_dir.as_path())?)?;
+    let issues_dir = project_dir.join("issues");
+
+    let records = load_sharded_beads_records(&beads_dir)?;
+    let record_by_id = index_records(&records)?;
+
+    let mut result = MigrationResult {
+        issue_count: records.len(),
+        created_count: 0,
+        updated_count: 0,
+        skipped_count: 0,
+    };
+    for record in &records {
+        let issue = convert_record(record, &record_by_id, &configuration)?;
+        match find_issue_file(&issues_dir, &issue.identifier)? {
+            None => {
+                let issue_path = issue_path_for_layout(
+                    &issues_dir,
+                    &issue,
+                    &configuration.issue_layout,
+                    issue_file_extension(&configuration.issue_format),
+                );
+                ensure_issue_directory(&issue_path)?;
+                write_issue_to_file(&issue, &issue_path)?;
+                result.created_count += 1;
+            }
+            Some(existing_path) if update_existing => {
+                let existing = read_issue_from_file(&existing_path)?;
+                let unchanged =
+                    serde_json::to_value(&existing).ok() == serde_json::to_value(&issue).ok();
+                if unchanged {
+                    result.skipped_count += 1;
+                } else {
+                    write_issue_to_file(&issue, &existing_path)?;
+                    result.updated_count += 1;
+                }
+            }
+            Some(_) => result.skipped_count += 1,
+        }
+    }
+    Ok(result)
+}
+
+fn beads_directory(root: &Path) -> Result<PathBuf, KanbusError> {
+    let beads_dir = root.join(".beads");
+    if !beads_dir.exists() {
         return Err(KanbusError::IssueOperation(
-            "already initialized".to_string(),
+            "no .beads directory".to_string(),
         ));
     }
 
-    initialize_project(root, false)?;
-    let project_dir = root.join("project");
-    let configuration =
-        load_project_configuration(&get_configuration_path(project_dir.as_path())?)?;
+    let issues_path = beads_dir.join("issues.jsonl");
+    if !issues_path.exists() {
+        return Err(KanbusError::IssueOperation("no issues.jsonl".to_string()));
+    }
+    Ok(beads_dir)
+}
 
-    let records = load_beads_records(&issues_path)?;
+fn index_records(records: &[Value]) -> Result<HashMap<String, Value>, KanbusError> {
     let mut record_by_id: HashMap<String, Value> = HashMap::new();
-    for record in &records {
+    for record in records {
         let identifier = record
             .get("id")
             .and_then(Value::as_str)
             .ok_or_else(|| KanbusError::IssueOperation("missing id".to_string()))?;
         record_by_id.insert(identifier.to_string(), record.clone());
     }
+    Ok(record_by_id)
+}
 
-    for record in &records {
-        let issue = convert_record(record, &record_by_id, &configuration)?;
-        let issue_path = project_dir
-            .join("issues")
-            .join(format!("{}.json", issue.identifier));
-        write_issue_to_file(&issue, &issue_path)?;
+fn load_sharded_beads_records(beads_dir: &Path) -> Result<Vec<Value>, KanbusError> {
+    let mut records = Vec::new();
+    for shard_path in beads_shard_paths(beads_dir)? {
+        records.extend(load_beads_records(&shard_path)?);
     }
-
-    Ok(MigrationResult {
-        issue_count: records.len(),
-    })
+    Ok(records)
 }
 
 fn load_beads_records(path: &Path) -> Result<Vec<Value>, KanbusError> {
@@ -225,9 +369,14 @@ fn convert_record(
     let priority_value = record
         .get("priority")
         .ok_or_else(|| KanbusError::IssueOperation("priority is required".to_string()))?;
-    let priority = priority_value
-        .as_i64()
-        .ok_or_else(|| KanbusError::IssueOperation("priority is required".to_string()))?;
+    let priority = match priority_value {
+        Value::String(text) => resolve_imported_priority(configuration, text)
+            .map(i64::from)
+            .ok_or_else(|| KanbusError::IssueOperation("invalid priority".to_string()))?,
+        _ => priority_value
+            .as_i64()
+            .ok_or_else(|| KanbusError::IssueOperation("priority is required".to_string()))?,
+    };
     if !configuration.priorities.contains_key(&(priority as u8)) {
         return Err(KanbusError::IssueOperation("invalid priority".to_string()));
     }
@@ -320,9 +469,13 @@ fn convert_record(
             .unwrap_or_default(),
         dependencies,
         comments,
+        links: Vec::new(),
         created_at,
         updated_at,
         closed_at,
+        due_at: None,
+        estimate_minutes: None,
+        spent_minutes: 0,
         custom,
     })
 }
@@ -444,6 +597,9 @@ fn convert_comments(
                 author: author.to_string(),
                 text: text.to_string(),
                 created_at,
+                system: false,
+                resolved: false,
+                reply_to: None,
             });
         }
     }
@@ -692,12 +848,24 @@ fn build_beads_configuration(records: &[Value]) -> ProjectConfiguration {
         initial_status: "open".to_string(),
         priorities: priority_defs,
         default_priority: 2,
+        priority_import_aliases: BTreeMap::new(),
         assignee: None,
         time_zone: None,
         statuses,
         categories,
         type_colors: BTreeMap::new(),
+        type_prefixes: BTreeMap::new(),
+        label_colors: BTreeMap::new(),
+        accessible_colors: false,
         beads_compatibility: false,
+        comment_on_transition: false,
+        issue_layout: "flat".to_string(),
+        issue_format: "json".to_string(),
+        priority_order: "ascending".to_string(),
+        max_description_length: None,
+        auto_claim_on_status: Vec::new(),
+        notification_socket_path: None,
+        warn_on_duplicate_title: true,
         jira: None,
     }
 }
@@ -28,6 +28,21 @@ pub struct IssueComment {
     pub author: String,
     pub text: String,
     pub created_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub system: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub resolved: bool,
+    /// Id of the thread's root comment when this comment is a reply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<String>,
+}
+
+/// External reference attached to an issue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalLink {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
 }
 
 /// Issue data representation.
@@ -47,12 +62,26 @@ pub struct IssueData {
     pub labels: Vec<String>,
     pub dependencies: Vec<DependencyLink>,
     pub comments: Vec<IssueComment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<ExternalLink>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub closed_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_at: Option<DateTime<Utc>>,
+    /// Estimated effort in minutes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate_minutes: Option<u32>,
+    /// Time logged against the issue, in minutes.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub spent_minutes: u32,
     pub custom: BTreeMap<String, serde_json::Value>,
 }
 
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
 /// Jira synchronization configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JiraConfiguration {
@@ -70,6 +99,22 @@ fn default_jira_sync_direction() -> String {
     "pull".to_string()
 }
 
+fn default_issue_layout() -> String {
+    "flat".to_string()
+}
+
+fn default_warn_on_duplicate_title() -> bool {
+    true
+}
+
+fn default_issue_format() -> String {
+    "json".to_string()
+}
+
+fn default_priority_order() -> String {
+    "ascending".to_string()
+}
+
 /// Configuration for a single virtual project.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VirtualProjectConfig {
@@ -100,6 +145,9 @@ pub struct ProjectConfiguration {
     pub initial_status: String,
     pub priorities: BTreeMap<u8, PriorityDefinition>,
     pub default_priority: u8,
+    /// External priority labels mapped to priority names on import.
+    #[serde(default)]
+    pub priority_import_aliases: BTreeMap<String, String>,
     #[serde(default)]
     pub assignee: Option<String>,
     #[serde(default)]
@@ -109,9 +157,34 @@ pub struct ProjectConfiguration {
     pub categories: Vec<CategoryDefinition>,
     #[serde(default)]
     pub type_colors: BTreeMap<String, String>,
+    /// Identifier prefix per issue type, used instead of `project_key`.
+    #[serde(default)]
+    pub type_prefixes: BTreeMap<String, String>,
+    #[serde(default)]
+    pub label_colors: BTreeMap<String, String>,
+    /// Replace status colors with the color-blind-safe palette when loading.
+    #[serde(default)]
+    pub accessible_colors: bool,
     #[serde(default)]
     pub beads_compatibility: bool,
     #[serde(default)]
+    pub comment_on_transition: bool,
+    #[serde(default = "default_issue_layout")]
+    pub issue_layout: String,
+    #[serde(default = "default_issue_format")]
+    pub issue_format: String,
+    #[serde(default = "default_priority_order")]
+    pub priority_order: String,
+    #[serde(default)]
+    pub max_description_length: Option<usize>,
+    #[serde(default)]
+    pub auto_claim_on_status: Vec<String>,
+    #[serde(default)]
+    pub notification_socket_path: Option<String>,
+    /// Warn instead of failing when a new issue repeats an existing title.
+    #[serde(default = "default_warn_on_duplicate_title")]
+    pub warn_on_duplicate_title: bool,
+    #[serde(default)]
     pub jira: Option<JiraConfiguration>,
 }
 
@@ -1,5 +1,6 @@
 //! Real-time notification events for issue operations.
 
+use crate::ids::format_issue_key;
 use crate::models::IssueData;
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +33,9 @@ pub enum NotificationEvent {
     },
     /// UI control command to manipulate console UI state.
     UiControl { action: UiControlAction },
+    /// Issue or event files changed on disk and the daemon index was refreshed.
+    #[serde(rename = "index.changed")]
+    IndexChanged,
 }
 
 /// UI control actions that can be sent to the console frontend.
@@ -73,6 +77,52 @@ impl NotificationEvent {
             NotificationEvent::IssueDeleted { issue_id } => Some(issue_id),
             NotificationEvent::IssueFocused { issue_id, .. } => Some(issue_id),
             NotificationEvent::UiControl { .. } => None,
+            NotificationEvent::IndexChanged => None,
+        }
+    }
+
+    /// Format the event as a single `kanbus watch` line, such as
+    /// `[created] kanbus-abc123 Title` or `[updated] kanbus-abc123 status`.
+    pub fn watch_line(&self) -> String {
+        match self {
+            NotificationEvent::IssueCreated {
+                issue_id,
+                issue_data,
+            } => format!(
+                "[created] {} {}",
+                format_issue_key(issue_id, false),
+                issue_data.title
+            ),
+            NotificationEvent::IssueUpdated {
+                issue_id,
+                fields_changed,
+                ..
+            } => {
+                let key = format_issue_key(issue_id, false);
+                if fields_changed.is_empty() {
+                    format!("[updated] {key}")
+                } else {
+                    format!("[updated] {key} {}", fields_changed.join(", "))
+                }
+            }
+            NotificationEvent::IssueDeleted { issue_id } => {
+                format!("[deleted] {}", format_issue_key(issue_id, false))
+            }
+            NotificationEvent::IssueFocused { issue_id, user, .. } => {
+                let key = format_issue_key(issue_id, false);
+                match user {
+                    Some(user) => format!("[focused] {key} by {user}"),
+                    None => format!("[focused] {key}"),
+                }
+            }
+            NotificationEvent::UiControl { action } => {
+                let name = serde_json::to_value(action)
+                    .ok()
+                    .and_then(|value| value["action"].as_str().map(str::to_string))
+                    .unwrap_or_default();
+                format!("[ui] {name}")
+            }
+            NotificationEvent::IndexChanged => "[index] changed".to_string(),
         }
     }
 
@@ -106,6 +156,7 @@ impl NotificationEvent {
             NotificationEvent::UiControl { action } => {
                 format!("UI control: {:?}", action)
             }
+            NotificationEvent::IndexChanged => "Index changed".to_string(),
         }
     }
 }
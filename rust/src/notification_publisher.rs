@@ -1,6 +1,8 @@
 //! Notification publisher for sending real-time events to the console server via Unix domain socket.
 
+use crate::config_loader::load_project_configuration;
 use crate::error::KanbusError;
+use crate::file_io::get_configuration_path;
 use crate::notification_events::NotificationEvent;
 use sha2::{Digest, Sha256};
 #[cfg(unix)]
@@ -8,12 +10,48 @@ use std::io::Write;
 #[cfg(unix)]
 use std::os::unix::net::UnixStream;
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, Once, PoisonError};
+use std::time::{Duration, Instant};
 
-/// Get the Unix domain socket path for the current project.
+/// Environment variable that overrides the notification socket path.
+pub const NOTIFY_SOCKET_ENV: &str = "KANBUS_NOTIFY_SOCKET";
+
+/// Environment variable that enables notification coalescing. Its value is
+/// the window in milliseconds; unset or `0` sends every event immediately.
+pub const NOTIFY_DEBOUNCE_ENV: &str = "KANBUS_NOTIFY_DEBOUNCE_MS";
+
+struct PendingNotification {
+    root: PathBuf,
+    event: NotificationEvent,
+    due: Instant,
+}
+
+static PENDING: Mutex<Vec<PendingNotification>> = Mutex::new(Vec::new());
+/// Held while drained events are sent, so a final flush waits for the
+/// background thread instead of exiting mid-delivery.
+static DELIVERING: Mutex<()> = Mutex::new(());
+static FLUSHER: Once = Once::new();
+
+/// Get the Unix domain socket path for notifications for a project.
 ///
-/// The socket path is derived from the project root directory to ensure
-/// each project has its own isolated notification channel.
-fn get_socket_path(root: &Path) -> PathBuf {
+/// Shared by the CLI publisher and the console listener. The
+/// `KANBUS_NOTIFY_SOCKET` environment variable wins, then the
+/// `notification_socket_path` configuration value (relative paths resolve
+/// against the configuration file directory). Otherwise the path is derived
+/// from a hash of the canonical project root so each project has its own
+/// isolated notification channel.
+pub fn get_notification_socket_path(root: &Path) -> PathBuf {
+    if let Some(value) = std::env::var_os(NOTIFY_SOCKET_ENV).filter(|value| !value.is_empty()) {
+        return PathBuf::from(value);
+    }
+    if let Ok(config_path) = get_configuration_path(root) {
+        if let Ok(configuration) = load_project_configuration(&config_path) {
+            if let Some(path) = configuration.notification_socket_path {
+                let base = config_path.parent().unwrap_or(root);
+                return base.join(path);
+            }
+        }
+    }
     let canonical = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
     let mut hasher = Sha256::new();
     hasher.update(canonical.to_string_lossy().as_bytes());
@@ -26,13 +64,121 @@ fn get_socket_path(root: &Path) -> PathBuf {
 /// Publish a notification event to the console server via Unix domain socket.
 ///
 /// This function sends the event to the console server's Unix socket.
-/// The socket path is derived from the project root directory to ensure
-/// each project has its own isolated notification channel.
+/// The socket path comes from `get_notification_socket_path`. The event is
+/// also relayed to a running index daemon for `kanbus watch` subscribers.
 ///
 /// Errors are logged but not propagated - notification failures should
 /// not block CRUD operations.
+///
+/// When `KANBUS_NOTIFY_DEBOUNCE_MS` is set, events are held for that window
+/// and successive `IssueUpdated` events for the same issue collapse into one
+/// carrying the union of `fields_changed`. Held events are sent by a
+/// background thread once their window passes, or by `flush_notifications`.
 pub fn publish_notification(root: &Path, event: NotificationEvent) -> Result<(), KanbusError> {
-    let socket_path = get_socket_path(root);
+    match debounce_window() {
+        Some(window) => enqueue_notification(root, event, window),
+        None => deliver_notification(root, &event),
+    }
+    Ok(())
+}
+
+/// Send every held notification now.
+///
+/// Short-lived processes call this before exiting so coalesced events are
+/// not lost. It does nothing when debouncing is disabled.
+pub fn flush_notifications() {
+    let _delivering = DELIVERING.lock().unwrap_or_else(PoisonError::into_inner);
+    let pending = std::mem::take(&mut *PENDING.lock().unwrap_or_else(PoisonError::into_inner));
+    for entry in pending {
+        deliver_notification(&entry.root, &entry.event);
+    }
+}
+
+fn debounce_window() -> Option<Duration> {
+    std::env::var(NOTIFY_DEBOUNCE_ENV)
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .filter(|millis| *millis > 0)
+        .map(Duration::from_millis)
+}
+
+fn enqueue_notification(root: &Path, event: NotificationEvent, window: Duration) {
+    {
+        let mut pending = PENDING.lock().unwrap_or_else(PoisonError::into_inner);
+        if !coalesce_notification(&mut pending, root, &event) {
+            pending.push(PendingNotification {
+                root: root.to_path_buf(),
+                event,
+                due: Instant::now() + window,
+            });
+        }
+    }
+    FLUSHER.call_once(|| {
+        std::thread::spawn(move || loop {
+            std::thread::sleep(window);
+            deliver_due_notifications(Instant::now());
+        });
+    });
+}
+
+/// Merge an `IssueUpdated` event into the latest held event for the same
+/// issue when that event is also an update. Returns whether it was merged.
+fn coalesce_notification(
+    pending: &mut [PendingNotification],
+    root: &Path,
+    event: &NotificationEvent,
+) -> bool {
+    let NotificationEvent::IssueUpdated {
+        issue_id,
+        fields_changed,
+        issue_data,
+    } = event
+    else {
+        return false;
+    };
+    let Some(latest) = pending
+        .iter_mut()
+        .rev()
+        .find(|entry| entry.root == root && entry.event.issue_id() == Some(issue_id.as_str()))
+    else {
+        return false;
+    };
+    let NotificationEvent::IssueUpdated {
+        fields_changed: merged_fields,
+        issue_data: merged_data,
+        ..
+    } = &mut latest.event
+    else {
+        return false;
+    };
+    // An empty list means the changed fields are unknown, which absorbs any union.
+    if merged_fields.is_empty() || fields_changed.is_empty() {
+        merged_fields.clear();
+    } else {
+        for field in fields_changed {
+            if !merged_fields.contains(field) {
+                merged_fields.push(field.clone());
+            }
+        }
+    }
+    *merged_data = issue_data.clone();
+    true
+}
+
+fn deliver_due_notifications(now: Instant) {
+    let _delivering = DELIVERING.lock().unwrap_or_else(PoisonError::into_inner);
+    let due = {
+        let mut pending = PENDING.lock().unwrap_or_else(PoisonError::into_inner);
+        let count = pending.iter().take_while(|entry| entry.due <= now).count();
+        pending.drain(..count).collect::<Vec<_>>()
+    };
+    for entry in due {
+        deliver_notification(&entry.root, &entry.event);
+    }
+}
+
+fn deliver_notification(root: &Path, event: &NotificationEvent) {
+    let socket_path = get_notification_socket_path(root);
 
     // Debug: write to file
     use std::io::Write;
@@ -48,7 +194,7 @@ pub fn publish_notification(root: &Path, event: NotificationEvent) -> Result<(),
         );
     }
 
-    let result = send_notification_sync(&socket_path, &event);
+    let result = send_notification_sync(&socket_path, event);
 
     if let Err(e) = result {
         // Log error but don't fail - notification is best-effort
@@ -66,7 +212,10 @@ pub fn publish_notification(root: &Path, event: NotificationEvent) -> Result<(),
         let _ = writeln!(f, "CLI: Notification sent successfully");
     }
 
-    Ok(())
+    // Relay to daemon subscribers such as `kanbus watch`, also best-effort.
+    if let Ok(value) = serde_json::to_value(event) {
+        let _ = crate::daemon_client::forward_notification(root, &value);
+    }
 }
 
 /// Synchronously send notification via Unix domain socket.
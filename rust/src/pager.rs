@@ -0,0 +1,62 @@
+//! Paging of long command output.
+
+use std::io::{ErrorKind, IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+/// Environment variable that overrides the pager command.
+pub const PAGER_ENV: &str = "KANBUS_PAGER";
+
+const DEFAULT_PAGER: &str = "less -R";
+
+/// Resolve the pager command.
+///
+/// `KANBUS_PAGER` takes precedence over `PAGER`, falling back to `less -R`.
+/// An empty value or `cat` disables paging.
+pub fn pager_command() -> Option<String> {
+    let command = std::env::var(PAGER_ENV)
+        .or_else(|_| std::env::var("PAGER"))
+        .unwrap_or_else(|_| DEFAULT_PAGER.to_string());
+    let command = command.trim();
+    if command.is_empty() || command == "cat" {
+        None
+    } else {
+        Some(command.to_string())
+    }
+}
+
+/// Print command output, through the pager when stdout is a terminal.
+///
+/// Like git, `LESS=FRX` is set when unset so `less` exits immediately when
+/// the output fits on one screen. Output is printed directly when stdout is
+/// not a terminal or the pager cannot be started.
+///
+/// # Arguments
+/// * `text` - Output to print.
+pub fn print_paged(text: &str) {
+    if !std::io::stdout().is_terminal() {
+        println!("{text}");
+        return;
+    }
+    let Some(command) = pager_command() else {
+        println!("{text}");
+        return;
+    };
+    let mut pager = Command::new("sh");
+    pager.arg("-c").arg(&command).stdin(Stdio::piped());
+    if std::env::var_os("LESS").is_none() {
+        pager.env("LESS", "FRX");
+    }
+    let Ok(mut child) = pager.spawn() else {
+        println!("{text}");
+        return;
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // The user may quit the pager before reading everything.
+        if let Err(error) = writeln!(stdin, "{text}") {
+            if error.kind() != ErrorKind::BrokenPipe {
+                eprintln!("Warning: failed to write to pager: {error}");
+            }
+        }
+    }
+    let _ = child.wait();
+}
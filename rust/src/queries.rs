@@ -1,58 +1,218 @@
 //! Query utilities for issue listing.
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
 
 use crate::error::KanbusError;
+use crate::issue_creation::SEQUENCE_FIELD;
 use crate::models::IssueData;
 
+/// Priority order where lower numbers sort first.
+pub const PRIORITY_ORDER_ASCENDING: &str = "ascending";
+/// Priority order where higher numbers sort first.
+pub const PRIORITY_ORDER_DESCENDING: &str = "descending";
+/// Supported values for the `priority_order` configuration field.
+pub const PRIORITY_ORDERS: [&str; 2] = [PRIORITY_ORDER_ASCENDING, PRIORITY_ORDER_DESCENDING];
+
+/// Field filters applied by `filter_issues`.
+///
+/// The default filter keeps every issue.
+#[derive(Debug, Clone, Default)]
+pub struct IssueFilter<'a> {
+    /// Status filter.
+    pub status: Option<&'a str>,
+    /// Type filter.
+    pub issue_type: Option<&'a str>,
+    /// Assignee filter.
+    pub assignee: Option<&'a str>,
+    /// Case-insensitive assignee substring filter.
+    pub assignee_contains: Option<&'a str>,
+    /// Label filter.
+    pub label: Option<&'a str>,
+    /// Label prefix filter (matches any label starting with it).
+    pub label_prefix: Option<&'a str>,
+    /// Keep only issues due before this instant.
+    pub due_before: Option<DateTime<Utc>>,
+    /// Keep only open issues whose due date is in the past.
+    pub overdue: bool,
+    /// Statuses to remove, applied after the inclusion filters.
+    pub exclude_statuses: &'a [String],
+    /// Types to remove, applied after the inclusion filters.
+    pub exclude_types: &'a [String],
+}
+
 /// Filter issues by common fields.
 ///
 /// # Arguments
 /// * `issues` - Issues to filter.
-/// * `status` - Status filter.
-/// * `issue_type` - Type filter.
-/// * `assignee` - Assignee filter.
-/// * `label` - Label filter.
-pub fn filter_issues(
-    issues: Vec<IssueData>,
-    status: Option<&str>,
-    issue_type: Option<&str>,
-    assignee: Option<&str>,
-    label: Option<&str>,
-) -> Vec<IssueData> {
+/// * `filter` - Filters to apply.
+pub fn filter_issues(issues: Vec<IssueData>, filter: &IssueFilter) -> Vec<IssueData> {
+    let IssueFilter {
+        status,
+        issue_type,
+        assignee,
+        assignee_contains,
+        label,
+        label_prefix,
+        due_before,
+        overdue,
+        exclude_statuses,
+        exclude_types,
+    } = *filter;
+    let assignee_contains = assignee_contains.map(str::to_lowercase);
+    let now = Utc::now();
     issues
         .into_iter()
         .filter(|issue| status.is_none_or(|value| issue.status == value))
         .filter(|issue| issue_type.is_none_or(|value| issue.issue_type == value))
         .filter(|issue| assignee.is_none_or(|value| issue.assignee.as_deref() == Some(value)))
+        .filter(|issue| {
+            assignee_contains.as_deref().is_none_or(|value| {
+                issue
+                    .assignee
+                    .as_deref()
+                    .is_some_and(|assignee| assignee.to_lowercase().contains(value))
+            })
+        })
         .filter(|issue| label.is_none_or(|value| issue.labels.iter().any(|label| label == value)))
+        .filter(|issue| {
+            label_prefix
+                .is_none_or(|value| issue.labels.iter().any(|label| label.starts_with(value)))
+        })
+        .filter(|issue| {
+            due_before.is_none_or(|cutoff| issue.due_at.is_some_and(|due_at| due_at < cutoff))
+        })
+        .filter(|issue| {
+            !overdue
+                || (issue.status != "closed" && issue.due_at.is_some_and(|due_at| due_at < now))
+        })
+        .filter(|issue| !exclude_statuses.contains(&issue.status))
+        .filter(|issue| !exclude_types.contains(&issue.issue_type))
         .collect()
 }
 
+/// Parse a `--since` value into the instant it refers to.
+///
+/// Accepts an RFC 3339 timestamp or a relative duration such as `90m`, `24h`,
+/// `7d`, or `2w`, measured back from `now`.
+///
+/// # Arguments
+/// * `value` - Timestamp or relative duration.
+/// * `now` - Reference instant for relative durations.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the value is neither form.
+pub fn parse_since(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, KanbusError> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+    let invalid = || {
+        KanbusError::IssueOperation(format!(
+            "invalid since value: {value} (expected RFC 3339 timestamp or duration like 24h or 7d)"
+        ))
+    };
+    let unit_start = value
+        .find(|character: char| !character.is_ascii_digit())
+        .ok_or_else(invalid)?;
+    let (amount, unit) = value.split_at(unit_start);
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    let duration = match unit {
+        "m" => Duration::try_minutes(amount),
+        "h" => Duration::try_hours(amount),
+        "d" => Duration::try_days(amount),
+        "w" => Duration::try_weeks(amount),
+        _ => None,
+    }
+    .ok_or_else(invalid)?;
+    now.checked_sub_signed(duration).ok_or_else(invalid)
+}
+
+/// Collect identifiers of issues below a parent in the hierarchy.
+///
+/// # Arguments
+/// * `issues` - All issues to traverse.
+/// * `parent` - Identifier of the root parent (not included in the result).
+/// * `max_depth` - Maximum levels below the parent (1 = direct children), unlimited if `None`.
+pub fn collect_descendant_identifiers(
+    issues: &[IssueData],
+    parent: &str,
+    max_depth: Option<usize>,
+) -> HashSet<String> {
+    let mut descendants = HashSet::new();
+    let mut frontier = vec![parent.to_string()];
+    let mut depth = 0;
+    while !frontier.is_empty() && max_depth.is_none_or(|limit| depth < limit) {
+        let mut next = Vec::new();
+        for issue in issues {
+            let is_child = issue
+                .parent
+                .as_deref()
+                .is_some_and(|value| frontier.iter().any(|id| id == value));
+            if is_child && descendants.insert(issue.identifier.clone()) {
+                next.push(issue.identifier.clone());
+            }
+        }
+        frontier = next;
+        depth += 1;
+    }
+    descendants
+}
+
 /// Sort issues by a supported key.
 ///
 /// # Arguments
 /// * `issues` - Issues to sort.
 /// * `sort_key` - Sort key name.
+/// * `priority_order` - Configured priority order (ascending or descending).
 ///
 /// # Errors
 /// Returns `KanbusError::IssueOperation` if the sort key is unsupported.
 pub fn sort_issues(
     mut issues: Vec<IssueData>,
     sort_key: Option<&str>,
+    priority_order: &str,
 ) -> Result<Vec<IssueData>, KanbusError> {
     let Some(key) = sort_key else {
         return Ok(issues);
     };
 
     if key == "priority" {
-        issues.sort_by_key(|issue| issue.priority);
+        issues
+            .sort_by(|left, right| compare_priority(left.priority, right.priority, priority_order));
+        return Ok(issues);
+    }
+
+    if key == SEQUENCE_FIELD {
+        // Issues created before sequence numbers existed sort last.
+        issues.sort_by_key(|issue| {
+            let sequence = issue
+                .custom
+                .get(SEQUENCE_FIELD)
+                .and_then(serde_json::Value::as_u64);
+            (sequence.is_none(), sequence, issue.created_at)
+        });
         return Ok(issues);
     }
 
     Err(KanbusError::IssueOperation("invalid sort key".to_string()))
 }
 
+/// Compare two priorities according to the configured priority order.
+///
+/// # Arguments
+/// * `left` - First priority.
+/// * `right` - Second priority.
+/// * `priority_order` - Configured priority order (ascending or descending).
+pub fn compare_priority(left: i32, right: i32, priority_order: &str) -> std::cmp::Ordering {
+    if priority_order == PRIORITY_ORDER_DESCENDING {
+        right.cmp(&left)
+    } else {
+        left.cmp(&right)
+    }
+}
+
 /// Search issues by title, description, and comments.
 ///
 /// # Arguments
@@ -88,3 +248,104 @@ pub fn search_issues(issues: Vec<IssueData>, term: Option<&str>) -> Vec<IssueDat
 
     matches
 }
+
+/// Rank issues by how often the query terms appear in their text.
+///
+/// The query and the issue text are split on whitespace and compared
+/// case-insensitively. Each title, description, or comment word containing a
+/// query term adds one to the score. Issues that match nothing are dropped;
+/// the rest are sorted by score (descending), then by most recent update.
+///
+/// # Arguments
+/// * `issues` - Issues to rank.
+/// * `query` - Search query.
+///
+/// # Returns
+/// Matching issues paired with their scores.
+pub fn rank_issues(issues: Vec<IssueData>, query: &str) -> Vec<(IssueData, usize)> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    let mut ranked: Vec<(IssueData, usize)> = issues
+        .into_iter()
+        .filter_map(|issue| {
+            let score = score_issue(&issue, &terms);
+            (score > 0).then_some((issue, score))
+        })
+        .collect();
+    ranked.sort_by(|left, right| {
+        right
+            .1
+            .cmp(&left.1)
+            .then_with(|| right.0.updated_at.cmp(&left.0.updated_at))
+            .then_with(|| left.0.identifier.cmp(&right.0.identifier))
+    });
+    ranked
+}
+
+fn score_issue(issue: &IssueData, terms: &[String]) -> usize {
+    let texts = [issue.title.as_str(), issue.description.as_str()]
+        .into_iter()
+        .chain(issue.comments.iter().map(|comment| comment.text.as_str()));
+    texts
+        .flat_map(str::split_whitespace)
+        .map(|word| {
+            let word = word.to_lowercase();
+            terms
+                .iter()
+                .filter(|term| word.contains(term.as_str()))
+                .count()
+        })
+        .sum()
+}
+
+/// Number of issues sharing one value of a counted field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldCount {
+    pub key: String,
+    pub count: usize,
+}
+
+/// Count issues by the distinct values of a field.
+///
+/// Issues with several labels are counted once per label. Results are sorted
+/// by count (descending) and then by value.
+///
+/// # Arguments
+/// * `issues` - Issues to count.
+/// * `field` - Field name (status, type, assignee, priority, label).
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the field is unsupported.
+pub fn count_issues_by(issues: &[IssueData], field: &str) -> Result<Vec<FieldCount>, KanbusError> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for issue in issues {
+        let values = match field {
+            "status" => vec![issue.status.clone()],
+            "type" => vec![issue.issue_type.clone()],
+            "assignee" => vec![issue
+                .assignee
+                .clone()
+                .unwrap_or_else(|| "unassigned".to_string())],
+            "priority" => vec![issue.priority.to_string()],
+            "label" => issue.labels.clone(),
+            _ => {
+                return Err(KanbusError::IssueOperation(format!(
+                    "invalid count-by field: {field}"
+                )))
+            }
+        };
+        for value in values {
+            *counts.entry(value).or_insert(0) += 1;
+        }
+    }
+    let mut sorted: Vec<FieldCount> = counts
+        .into_iter()
+        .map(|(key, count)| FieldCount { key, count })
+        .collect();
+    sorted.sort_by(|left, right| {
+        right
+            .count
+            .cmp(&left.count)
+            .then_with(|| left.key.cmp(&right.key))
+    });
+    Ok(sorted)
+}
@@ -0,0 +1,120 @@
+//! Streaming of notification events to the terminal.
+
+use std::io::{BufRead, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::daemon_client::subscribe_notifications;
+use crate::error::KanbusError;
+use crate::notification_events::NotificationEvent;
+
+/// Delay before resubscribing after the daemon goes away.
+const RECONNECT_DELAY: Duration = Duration::from_millis(500);
+/// Longest delay between failed subscription attempts.
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(8);
+/// Consecutive failed subscription attempts before giving up.
+const MAX_RECONNECT_ATTEMPTS: u32 = 8;
+
+/// Print notification events as they happen.
+///
+/// Subscribes through the index daemon, starting it when needed, and
+/// resubscribes whenever the daemon stops, so events published while no
+/// daemon runs are missed. Failed subscription attempts are reported to
+/// `diagnostics` and retried with a doubling delay until
+/// `MAX_RECONNECT_ATTEMPTS` fail in a row.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `json` - Print each event as serialized JSON instead of a summary line.
+/// * `max_events` - Return after printing this many events.
+/// * `output` - Destination for printed events.
+/// * `diagnostics` - Destination for reconnect warnings.
+///
+/// # Errors
+/// Returns `KanbusError` if daemon mode is disabled, the daemon rejects the
+/// subscription or stays unreachable, or output cannot be written.
+pub fn watch_notifications(
+    root: &Path,
+    json: bool,
+    max_events: Option<usize>,
+    output: &mut dyn Write,
+    diagnostics: &mut dyn Write,
+) -> Result<(), KanbusError> {
+    let mut printed = 0;
+    let mut failures = 0;
+    let mut delay = RECONNECT_DELAY;
+    loop {
+        let reader = match subscribe_notifications(root) {
+            Ok(reader) => {
+                failures = 0;
+                delay = RECONNECT_DELAY;
+                reader
+            }
+            Err(KanbusError::Io(message)) => {
+                failures += 1;
+                if failures >= MAX_RECONNECT_ATTEMPTS {
+                    return Err(KanbusError::Io(format!(
+                        "daemon unreachable after {failures} attempts: {message}"
+                    )));
+                }
+                let _ = writeln!(
+                    diagnostics,
+                    "Warning: daemon unreachable ({message}); retrying in {} ms",
+                    delay.as_millis()
+                );
+                std::thread::sleep(delay);
+                delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+                continue;
+            }
+            Err(error) => return Err(error),
+        };
+        let remaining = max_events.map(|limit| limit - printed);
+        printed += print_notifications(reader, json, remaining, output)?;
+        if max_events.is_some_and(|limit| printed >= limit) {
+            return Ok(());
+        }
+        std::thread::sleep(RECONNECT_DELAY);
+    }
+}
+
+/// Print events from a subscription until it ends or `max_events` is reached.
+///
+/// # Returns
+/// The number of events printed.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if output cannot be written.
+pub fn print_notifications(
+    reader: Box<dyn BufRead + Send>,
+    json: bool,
+    max_events: Option<usize>,
+    output: &mut dyn Write,
+) -> Result<usize, KanbusError> {
+    let mut printed = 0;
+    for line in reader.lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let text = if json {
+            line.to_string()
+        } else {
+            match serde_json::from_str::<NotificationEvent>(line) {
+                Ok(NotificationEvent::IndexChanged) => continue,
+                Ok(event) => event.watch_line(),
+                Err(_) => continue,
+            }
+        };
+        writeln!(output, "{text}")
+            .and_then(|_| output.flush())
+            .map_err(|error| KanbusError::Io(error.to_string()))?;
+        printed += 1;
+        if max_events.is_some_and(|limit| printed >= limit) {
+            break;
+        }
+    }
+    Ok(printed)
+}
@@ -7,7 +7,7 @@ use minijinja::value::{Kwargs, Value};
 use minijinja::{context, Environment, Error, ErrorKind};
 
 use crate::error::KanbusError;
-use crate::issue_listing::list_issues;
+use crate::issue_listing::{list_issues, ListOptions};
 use crate::models::IssueData;
 
 /// Request for rendering a wiki page.
@@ -33,18 +33,7 @@ pub fn render_wiki_page(request: &WikiRenderRequest) -> Result<String, KanbusErr
     let page_path = request.root.join(&request.page_path);
     validate_page_exists(&page_path)?;
 
-    let issues = list_issues(
-        &request.root,
-        None,
-        None,
-        None,
-        None,
-        None,
-        None,
-        &[],
-        true,
-        false,
-    )?;
+    let issues = list_issues(&request.root, &ListOptions::default())?;
     let issues = Arc::new(issues);
 
     let mut env = Environment::new();
@@ -101,9 +90,13 @@ pub fn render_wiki_page(request: &WikiRenderRequest) -> Result<String, KanbusErr
             labels: Vec::new(),
             dependencies: Vec::new(),
             comments: Vec::new(),
+            links: Vec::new(),
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             closed_at: None,
+            due_at: None,
+            estimate_minutes: None,
+            spent_minutes: 0,
             custom: std::collections::BTreeMap::new(),
         };
         let mut dummy_list = vec![dummy_issue];
@@ -67,6 +67,58 @@ pub fn validate_status_transition(
     Ok(())
 }
 
+/// Return the single forward transition available from a status.
+///
+/// Forward transitions are the workflow targets that appear after the
+/// current status in the configured status order.
+///
+/// # Arguments
+/// * `configuration` - Project configuration containing workflow definitions.
+/// * `issue_type` - Issue type being advanced.
+/// * `current_status` - Issue's current status.
+///
+/// # Errors
+/// Returns `KanbusError::InvalidTransition` if there is no forward transition
+/// or more than one.
+pub fn next_workflow_status(
+    configuration: &ProjectConfiguration,
+    issue_type: &str,
+    current_status: &str,
+) -> Result<String, KanbusError> {
+    let workflow = get_workflow_for_issue_type(configuration, issue_type)?;
+    let position = |status: &str| {
+        configuration
+            .statuses
+            .iter()
+            .position(|definition| definition.key == status)
+    };
+    let current_position = position(current_status);
+    let candidates: Vec<&String> = workflow
+        .get(current_status)
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+        .iter()
+        .filter(|status| match (current_position, position(status)) {
+            (Some(current), Some(target)) => target > current,
+            _ => false,
+        })
+        .collect();
+    match candidates.as_slice() {
+        [next] => Ok((*next).clone()),
+        [] => Err(KanbusError::InvalidTransition(format!(
+            "no next status from '{current_status}' for type '{issue_type}'"
+        ))),
+        choices => Err(KanbusError::InvalidTransition(format!(
+            "multiple next statuses from '{current_status}' for type '{issue_type}': {}",
+            choices
+                .iter()
+                .map(|status| status.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))),
+    }
+}
+
 /// Validate that a status value exists in the global status definitions.
 ///
 /// # Errors
@@ -90,6 +142,22 @@ pub fn validate_status_value(
     Ok(())
 }
 
+/// Whether a status is terminal, meaning it belongs to the last configured
+/// category (such as "Done").
+///
+/// # Arguments
+/// * `configuration` - Project configuration containing statuses and categories.
+/// * `status` - Status key to check.
+pub fn is_terminal_status(configuration: &ProjectConfiguration, status: &str) -> bool {
+    let Some(final_category) = configuration.categories.last() else {
+        return false;
+    };
+    configuration
+        .statuses
+        .iter()
+        .any(|definition| definition.key == status && definition.category == final_category.name)
+}
+
 /// Apply workflow side effects based on a status transition.
 ///
 /// # Arguments
@@ -13,10 +13,18 @@ mod step_definitions;
 
 use step_definitions::initialization_steps::KanbusWorld;
 
+/// Parses every feature file below the input directory and below any
+/// additional roots, such as the Rust-only specs in `rust/features`.
 #[derive(Clone, Debug, Default)]
-struct RecursiveFeatureParser;
+struct RecursiveFeatureParser {
+    additional_roots: Vec<PathBuf>,
+}
 
 impl RecursiveFeatureParser {
+    fn with_additional_roots(additional_roots: Vec<PathBuf>) -> Self {
+        Self { additional_roots }
+    }
+
     fn collect_features(root: &Path) -> Result<Vec<PathBuf>, gherkin::ParseFileError> {
         let mut feature_files = Vec::new();
         Self::collect_feature_files(root, &mut feature_files).map_err(|error| {
@@ -52,7 +60,13 @@ impl<I: AsRef<Path>> Parser<I> for RecursiveFeatureParser {
         let features: Vec<ParserResult<gherkin::Feature>> = if path.is_file() {
             vec![gherkin::Feature::parse_path(path, GherkinEnv::default()).map_err(Into::into)]
         } else {
-            match Self::collect_features(path) {
+            let roots =
+                std::iter::once(path).chain(self.additional_roots.iter().map(PathBuf::as_path));
+            match roots
+                .map(Self::collect_features)
+                .collect::<Result<Vec<_>, _>>()
+                .map(|paths| paths.into_iter().flatten().collect::<Vec<_>>())
+            {
                 Ok(feature_paths) => feature_paths
                     .into_iter()
                     .map(|feature_path| {
@@ -82,10 +96,13 @@ async fn main() {
     if !features_dir.exists() {
         panic!("features directory missing at {}", features_dir.display());
     }
+    let rust_features_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("features");
     #[cfg(tarpaulin)]
     cover_additional_paths();
     KanbusWorld::cucumber::<PathBuf>()
-        .with_parser(RecursiveFeatureParser::default())
+        .with_parser(RecursiveFeatureParser::with_additional_roots(vec![
+            rust_features_dir,
+        ]))
         .max_concurrent_scenarios(1)
         .filter_run_and_exit(features_dir, |feature, _, scenario| {
             let scenario_has_wip = scenario.tags.iter().any(|tag| tag == "wip");
@@ -117,8 +134,8 @@ fn cover_additional_paths() {
     use kanbus::doctor::run_doctor;
     use kanbus::file_io::{get_configuration_path, initialize_project, load_project_directory};
     use kanbus::issue_creation::{create_issue, IssueCreationRequest};
-    use kanbus::issue_listing::list_issues;
-    use kanbus::issue_update::update_issue;
+    use kanbus::issue_listing::{list_issues, ListOptions};
+    use kanbus::issue_update::{update_issue, IssueUpdateRequest};
     use kanbus::migration::{load_beads_issue_by_id, load_beads_issues, migrate_from_beads};
 
     std::env::set_var("KANBUS_NO_DAEMON", "1");
@@ -177,66 +194,28 @@ fn cover_additional_paths() {
     })
     .expect("create issue three");
 
-    let _ = update_issue(
-        root,
-        &issue_one.issue.identifier,
-        Some("First issue updated"),
-        Some("Updated description"),
-        Some("in_progress"),
-        Some("dev@example.com"),
-        None,
-        true,
-        true,
-        &[],
-        &[],
-        None,
-        None,
-    );
-    let _ = update_issue(
-        root,
-        &issue_two.issue.identifier,
-        Some("First issue updated"),
-        None,
-        None,
-        None,
-        None,
-        false,
-        true,
-        &[],
-        &[],
-        None,
-        None,
-    );
-    let _ = update_issue(
-        root,
-        &issue_one.issue.identifier,
-        Some("First issue updated again"),
-        None,
-        Some("open"),
-        None,
-        None,
-        false,
-        true,
-        &[],
-        &[],
-        None,
-        None,
-    );
-    let _ = update_issue(
-        root,
-        &issue_three.issue.identifier,
-        Some("Fourth issue updated"),
-        None,
-        None,
-        Some("dev@example.com"),
-        None,
-        false,
-        true,
-        &[],
-        &[],
-        None,
-        None,
-    );
+    let _ = update_issue(&IssueUpdateRequest {
+        title: Some("First issue updated".to_string()),
+        description: Some("Updated description".to_string()),
+        status: Some("in_progress".to_string()),
+        assignee: Some("dev@example.com".to_string()),
+        claim: true,
+        ..IssueUpdateRequest::new(root, issue_one.issue.identifier.clone())
+    });
+    let _ = update_issue(&IssueUpdateRequest {
+        title: Some("First issue updated".to_string()),
+        ..IssueUpdateRequest::new(root, issue_two.issue.identifier.clone())
+    });
+    let _ = update_issue(&IssueUpdateRequest {
+        title: Some("First issue updated again".to_string()),
+        status: Some("open".to_string()),
+        ..IssueUpdateRequest::new(root, issue_one.issue.identifier.clone())
+    });
+    let _ = update_issue(&IssueUpdateRequest {
+        title: Some("Fourth issue updated".to_string()),
+        assignee: Some("dev@example.com".to_string()),
+        ..IssueUpdateRequest::new(root, issue_three.issue.identifier.clone())
+    });
 
     let _ = add_dependency(
         root,
@@ -257,25 +236,21 @@ fn cover_additional_paths() {
     let issues_dir = root.join("project").join("issues");
     fs::write(issues_dir.join("notes.txt"), "note").expect("non-json file");
     fs::write(issues_dir.join("invalid.json"), "{").expect("invalid json");
-    let _ = update_issue(
+    let _ = update_issue(&IssueUpdateRequest {
+        title: Some("First issue".to_string()),
+        ..IssueUpdateRequest::new(root, issue_two.issue.identifier.clone())
+    });
+
+    let _ = list_issues(root, &ListOptions::default());
+    let _ = list_issues(
         root,
-        &issue_two.issue.identifier,
-        Some("First issue"),
-        None,
-        None,
-        None,
-        None,
-        false,
-        true,
-        &[],
-        &[],
-        None,
-        None,
+        &ListOptions {
+            include_local: false,
+            local_only: true,
+            ..ListOptions::default()
+        },
     );
 
-    let _ = list_issues(root, None, None, None, None, None, None, &[], true, false);
-    let _ = list_issues(root, None, None, None, None, None, None, &[], false, true);
-
     let _ = build_console_snapshot(root);
 
     let beads_dir = root.join(".beads");
@@ -384,27 +359,17 @@ fn cover_additional_paths() {
     let _ = load_project_directory(root_multi);
     let _ = list_issues(
         root_multi,
-        None,
-        None,
-        None,
-        None,
-        None,
-        None,
-        &[],
-        true,
-        true,
+        &ListOptions {
+            local_only: true,
+            ..ListOptions::default()
+        },
     );
     let _ = list_issues(
         root_multi,
-        None,
-        None,
-        None,
-        None,
-        None,
-        None,
-        &[],
-        false,
-        false,
+        &ListOptions {
+            include_local: false,
+            ..ListOptions::default()
+        },
     );
     std::env::remove_var("KANBUS_NO_DAEMON");
     let _ = run_from_args_with_output(["kanbus", "daemon-status"], root_multi);
@@ -432,21 +397,11 @@ fn cover_additional_paths() {
         validate: true,
     })
     .expect("create update issue");
-    let _ = update_issue(
-        root_update,
-        &issue_update.issue.identifier,
-        Some("Update issue renamed"),
-        None,
-        Some("open"),
-        None,
-        None,
-        false,
-        true,
-        &[],
-        &[],
-        None,
-        None,
-    );
+    let _ = update_issue(&IssueUpdateRequest {
+        title: Some("Update issue renamed".to_string()),
+        status: Some("open".to_string()),
+        ..IssueUpdateRequest::new(root_update, issue_update.issue.identifier.clone())
+    });
 
     let temp_dir = TempDir::new().expect("tempdir");
     let root_migrate_invalid = temp_dir.path();